@@ -8,16 +8,22 @@ use crossbeam::channel::{bounded, Receiver, Sender};
 
 use crate::{
     cmd::Command,
+    config::Config,
     message::Message,
-    workers::{KubeWorker, Render, Tick, UserInput},
+    workers::{kube::KubeWorkerConfig, KubeWorker, Render, Tick, UserInput},
 };
 
 pub struct App;
 
 impl App {
-    pub fn run(cmd: Command) -> Result<()> {
+    pub fn run(cmd: Command, config: Config) -> Result<()> {
         let split_direction = cmd.split_direction();
-        let kube_worker_config = cmd.kube_worker_config();
+        let kube_worker_config = KubeWorkerConfig {
+            yaml_filter: config.yaml,
+            secret_mask: config.secret,
+            config_file: Config::path(cmd.config_file.clone()),
+            ..cmd.kube_worker_config()
+        };
 
         let (tx_input, rx_main): (Sender<Message>, Receiver<Message>) = bounded(128);
         let (tx_main, rx_kube): (Sender<Message>, Receiver<Message>) = bounded(256);
@@ -46,6 +52,9 @@ impl App {
             rx_main.clone(),
             is_terminated.clone(),
             split_direction,
+            config.clipboard,
+            config.list.views,
+            cmd.read_only,
         );
 
         thread::scope(|s| {
@@ -1,20 +1,193 @@
+use std::io::Write as _;
+
 use anyhow::Result;
+use base64::{engine::general_purpose::STANDARD, Engine as _};
+use serde::Deserialize;
+
+use crate::logger;
+
+/// 設定ファイルで指定するクリップボードのバックエンド
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ClipboardBackend {
+    /// ディスプレイサーバーがない環境やSSH接続時はOSC 52、それ以外はシステムのクリップボードを使う
+    #[default]
+    Auto,
+    /// システムのクリップボード（arboard）
+    System,
+    /// OSC 52 エスケープシーケンスで端末に保存を依頼する
+    Osc52,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Backend {
+    /// システムのクリップボード
+    ///
+    /// fallbackがtrueのときは失敗時にOSC 52を使う
+    System { fallback: bool },
+    /// tmuxがtrueのときはtmuxのパススルーシーケンスで囲む
+    Osc52 { tmux: bool },
+}
 
-pub struct Clipboard;
+pub struct Clipboard {
+    backend: Backend,
+}
 
 impl Clipboard {
-    pub fn new() -> Self {
-        Self
+    pub fn new(backend: ClipboardBackend) -> Self {
+        let backend = resolve_backend(backend, |key| std::env::var_os(key).is_some());
+
+        logger!(info, "Clipboard backend: {:?}", backend);
+
+        Self { backend }
     }
 
+    /// クリップボードに文字列を保存する
     pub fn set_contents(&mut self, contents: String) -> Result<()> {
-        let mut c = arboard::Clipboard::new()?;
-        Ok(c.set_text(contents)?)
+        match self.backend {
+            Backend::System { fallback } => {
+                let result =
+                    arboard::Clipboard::new().and_then(|mut c| c.set_text(contents.clone()));
+
+                match result {
+                    Ok(()) => Ok(()),
+                    Err(e) if fallback => {
+                        logger!(info, "Fallback to OSC 52 clipboard: {}", e);
+
+                        set_contents_osc52(
+                            &contents,
+                            is_tmux(|key| std::env::var_os(key).is_some()),
+                        )
+                    }
+                    Err(e) => Err(e.into()),
+                }
+            }
+            Backend::Osc52 { tmux } => set_contents_osc52(&contents, tmux),
+        }
     }
 }
 
 impl Default for Clipboard {
     fn default() -> Self {
-        Self::new()
+        Self::new(ClipboardBackend::default())
+    }
+}
+
+/// 環境変数からバックエンドを決定する
+///
+/// has_env: 環境変数が設定されているかどうかを返す関数
+fn resolve_backend(backend: ClipboardBackend, has_env: impl Fn(&str) -> bool) -> Backend {
+    let tmux = is_tmux(&has_env);
+
+    match backend {
+        ClipboardBackend::System => Backend::System { fallback: false },
+        ClipboardBackend::Osc52 => Backend::Osc52 { tmux },
+        ClipboardBackend::Auto => {
+            let is_remote = has_env("SSH_CONNECTION") || has_env("SSH_TTY");
+
+            // macOSとWindowsはディスプレイサーバーの環境変数を持たない
+            let has_display =
+                !cfg!(target_os = "linux") || has_env("DISPLAY") || has_env("WAYLAND_DISPLAY");
+
+            if is_remote || !has_display {
+                Backend::Osc52 { tmux }
+            } else {
+                Backend::System { fallback: true }
+            }
+        }
+    }
+}
+
+fn is_tmux(has_env: impl Fn(&str) -> bool) -> bool {
+    has_env("TMUX")
+}
+
+fn set_contents_osc52(contents: &str, tmux: bool) -> Result<()> {
+    let mut stdout = std::io::stdout().lock();
+
+    stdout.write_all(osc52_sequence(contents, tmux).as_bytes())?;
+    stdout.flush()?;
+
+    Ok(())
+}
+
+/// OSC 52 のエスケープシーケンスを返す
+///
+/// tmux内ではパススルーシーケンスで囲み、ESCを二重にする
+fn osc52_sequence(contents: &str, tmux: bool) -> String {
+    let sequence = format!("\x1b]52;c;{}\x07", STANDARD.encode(contents));
+
+    if tmux {
+        format!("\x1bPtmux;{}\x1b\\", sequence.replace('\x1b', "\x1b\x1b"))
+    } else {
+        sequence
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn osc52のエスケープシーケンスを返す() {
+        assert_eq!(osc52_sequence("hello", false), "\x1b]52;c;aGVsbG8=\x07");
+    }
+
+    #[test]
+    fn tmux内ではパススルーシーケンスで囲む() {
+        assert_eq!(
+            osc52_sequence("hello", true),
+            "\x1bPtmux;\x1b\x1b]52;c;aGVsbG8=\x07\x1b\\"
+        );
+    }
+
+    mod resolve_backend {
+        use pretty_assertions::assert_eq;
+
+        use super::super::*;
+
+        fn env(keys: &'static [&'static str]) -> impl Fn(&str) -> bool {
+            move |key| keys.contains(&key)
+        }
+
+        #[test]
+        fn ssh接続時はosc52を使う() {
+            let actual = resolve_backend(
+                ClipboardBackend::Auto,
+                env(&["SSH_CONNECTION", "DISPLAY", "TMUX"]),
+            );
+
+            assert_eq!(actual, Backend::Osc52 { tmux: true });
+        }
+
+        #[cfg(target_os = "linux")]
+        #[test]
+        fn ディスプレイサーバーがないときはosc52を使う() {
+            let actual = resolve_backend(ClipboardBackend::Auto, env(&[]));
+
+            assert_eq!(actual, Backend::Osc52 { tmux: false });
+        }
+
+        #[test]
+        fn ディスプレイサーバーがあるときはシステムのクリップボードを使う() {
+            let actual = resolve_backend(ClipboardBackend::Auto, env(&["WAYLAND_DISPLAY"]));
+
+            assert_eq!(actual, Backend::System { fallback: true });
+        }
+
+        #[test]
+        fn 指定したバックエンドを使う() {
+            assert_eq!(
+                resolve_backend(ClipboardBackend::System, env(&["SSH_TTY"])),
+                Backend::System { fallback: false }
+            );
+
+            assert_eq!(
+                resolve_backend(ClipboardBackend::Osc52, env(&["DISPLAY"])),
+                Backend::Osc52 { tmux: false }
+            );
+        }
     }
 }
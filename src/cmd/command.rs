@@ -65,6 +65,14 @@ pub struct Command {
     /// Logging
     #[arg(short = 'l', long, display_order = 1000)]
     pub logging: bool,
+
+    /// Config file path (default: ~/.config/kubetui/config.yaml)
+    #[arg(long, display_order = 1000)]
+    pub config_file: Option<PathBuf>,
+
+    /// Read-only mode (disable actions that create or modify resources)
+    #[arg(long, display_order = 1000)]
+    pub read_only: bool,
 }
 
 impl Command {
@@ -82,6 +90,7 @@ impl Command {
             context,
             all_namespaces,
             kubeconfig,
+            read_only,
             ..
         } = self.clone();
 
@@ -90,6 +99,8 @@ impl Command {
             target_namespaces: namespaces,
             context,
             all_namespaces: all_namespaces.into(),
+            read_only,
+            ..Default::default()
         }
     }
 }
@@ -0,0 +1,194 @@
+use std::path::PathBuf;
+
+use anyhow::{Context as _, Result};
+use serde::Deserialize;
+
+use crate::{
+    clipboard::ClipboardBackend,
+    features::{api_resources::kube::ListConfig, config::kube::SecretMask, yaml::kube::YamlFilter},
+};
+
+const CONFIG_FILE_NAME: &str = "config.yaml";
+
+/// 設定ファイル
+///
+/// ```yaml
+/// yaml:
+///   hide_noisy_fields: true
+///   hide_status: false
+/// clipboard: auto # auto | system | osc52
+/// secret:
+///   mask: true
+/// list:
+///   views:
+///     - name: ingress-stack
+///       key: 1
+///       resources: [ingresses, services, certificates.cert-manager.io, httproutes]
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct Config {
+    pub yaml: YamlFilter,
+    pub clipboard: ClipboardBackend,
+    pub secret: SecretMask,
+    pub list: ListConfig,
+}
+
+impl Config {
+    /// 設定ファイルを読み込む
+    ///
+    /// パスが指定されていないときは `$XDG_CONFIG_HOME/kubetui/config.yaml` または
+    /// `$HOME/.config/kubetui/config.yaml` を読み込み、存在しなければデフォルト値を返す
+    pub fn load(path: Option<PathBuf>) -> Result<Self> {
+        let path = match path {
+            Some(path) => path,
+            None => match default_path() {
+                Some(path) if path.exists() => path,
+                _ => return Ok(Self::default()),
+            },
+        };
+
+        let contents = std::fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read config file {}", path.display()))?;
+
+        Self::from_yaml(&contents)
+            .with_context(|| format!("Failed to parse config file {}", path.display()))
+    }
+
+    /// 書き込み先の設定ファイルのパス
+    ///
+    /// パスが指定されていないときは読み込みと同じデフォルトのパスを返す
+    pub fn path(path: Option<PathBuf>) -> Option<PathBuf> {
+        path.or_else(default_path)
+    }
+
+    fn from_yaml(contents: &str) -> Result<Self> {
+        // 空のファイルはnullとして扱われるため、デフォルト値を返す
+        if contents.trim().is_empty() {
+            return Ok(Self::default());
+        }
+
+        Ok(serde_yaml::from_str(contents)?)
+    }
+}
+
+fn default_path() -> Option<PathBuf> {
+    let config_dir = std::env::var_os("XDG_CONFIG_HOME")
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+
+    Some(config_dir.join("kubetui").join(CONFIG_FILE_NAME))
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+
+    use crate::features::api_resources::kube::SavedView;
+
+    use super::*;
+
+    #[test]
+    fn 空のファイルのときデフォルト値を返す() {
+        let actual = Config::from_yaml("").unwrap();
+
+        assert_eq!(actual, Config::default());
+    }
+
+    #[test]
+    fn 指定していない項目はデフォルト値になる() {
+        let actual = Config::from_yaml(indoc! {
+            r#"
+            yaml:
+              hide_status: true
+            "#
+        })
+        .unwrap();
+
+        let expected = Config {
+            yaml: YamlFilter {
+                hide_noisy_fields: true,
+                hide_status: true,
+            },
+            clipboard: ClipboardBackend::Auto,
+            secret: SecretMask::default(),
+            list: ListConfig::default(),
+        };
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn クリップボードのバックエンドを指定する() {
+        let actual = Config::from_yaml("clipboard: osc52").unwrap();
+
+        assert_eq!(actual.clipboard, ClipboardBackend::Osc52);
+    }
+
+    #[test]
+    fn secretの伏せ字を無効にする() {
+        let actual = Config::from_yaml(indoc! {
+            r#"
+            secret:
+              mask: false
+            "#
+        })
+        .unwrap();
+
+        assert!(!actual.secret.mask);
+    }
+
+    #[test]
+    fn listタブのビューを指定する() {
+        let actual = Config::from_yaml(indoc! {
+            r#"
+            list:
+              views:
+                - name: ingress-stack
+                  key: 1
+                  resources:
+                    - ingresses
+                    - services
+                    - certificates.cert-manager.io
+                - name: workloads
+                  resources: [deployments, statefulsets]
+            "#
+        })
+        .unwrap();
+
+        let expected = ListConfig {
+            views: vec![
+                SavedView {
+                    name: "ingress-stack".into(),
+                    key: Some(1),
+                    resources: vec![
+                        "ingresses".into(),
+                        "services".into(),
+                        "certificates.cert-manager.io".into(),
+                    ],
+                },
+                SavedView {
+                    name: "workloads".into(),
+                    key: None,
+                    resources: vec!["deployments".into(), "statefulsets".into()],
+                },
+            ],
+        };
+
+        assert_eq!(actual.list, expected);
+    }
+
+    #[test]
+    fn 不正な値のときエラーを返す() {
+        let actual = Config::from_yaml(indoc! {
+            r#"
+            yaml:
+              hide_noisy_fields: hoge
+            "#
+        });
+
+        assert!(actual.is_err());
+    }
+}
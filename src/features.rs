@@ -4,8 +4,11 @@ pub mod config;
 pub mod context;
 pub mod event;
 pub mod get;
+pub mod helm;
 pub mod help;
 pub mod namespace;
 pub mod network;
 pub mod pod;
+pub mod rbac;
+pub mod workload;
 pub mod yaml;
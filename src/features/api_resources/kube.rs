@@ -1,3 +1,11 @@
 mod api_resources;
+mod conditions;
+mod owner_tree;
+mod saved_view;
+mod selector;
 
 pub use api_resources::*;
+pub use conditions::*;
+pub use owner_tree::*;
+pub use saved_view::*;
+pub use selector::*;
@@ -1,8 +1,8 @@
-use std::{fmt::Display, hash::Hash, ops::Deref, sync::Arc, time};
+use std::{collections::BTreeMap, fmt::Display, hash::Hash, ops::Deref, sync::Arc, time};
 
 use anyhow::Result;
 use async_trait::async_trait;
-use futures::future::try_join_all;
+use futures::future::join_all;
 use kube::{
     discovery::{verbs, ApiGroup, Scope},
     Discovery,
@@ -12,15 +12,20 @@ use serde_json::Value as JsonValue;
 use tokio::{sync::RwLock, time::Instant};
 
 use crate::{
-    features::api_resources::message::ApiResponse,
+    features::api_resources::{
+        kube::{ListSelector, SharedListQuery},
+        message::ApiResponse,
+    },
     kube::{
+        access_review::explain_forbidden,
         apis::{
             metrics::{NodeMetricsList, PodMetricsList},
             v1_table::{Table, TableColumnDefinition, Value},
         },
-        table::insert_ns,
-        KubeClient, KubeClientRequest as _,
+        table::{insert_ns, partition_namespaces, KubeTable, KubeTableRow, SkippedNamespace},
+        KubeClient, KubeClientRequest,
     },
+    logger,
     workers::kube::{
         PollerBase, SharedTargetApiResources, TargetApiResources, TargetNamespaces, Worker,
         WorkerResult,
@@ -119,7 +124,6 @@ impl ApiResource {
         matches!(self, Self::Api { .. })
     }
 
-    #[allow(dead_code)]
     pub fn is_apis(&self) -> bool {
         matches!(self, Self::Apis { .. })
     }
@@ -178,16 +182,20 @@ impl ApiResource {
         format!("{}/{}", self.group_version_url(), self.name())
     }
 
+    /// kubectlで指定するときのリソース名（例: pods, deployments.apps）
+    pub fn kubectl_name(&self) -> String {
+        match self {
+            Self::Api { name, .. } => name.to_string(),
+            Self::Apis { name, group, .. } => format!("{}.{}", name, group),
+        }
+    }
+
     fn scope(&self) -> &Scope {
         match self {
             Self::Api { scope, .. } => scope,
             Self::Apis { scope, .. } => scope,
         }
     }
-
-    fn to_table_header(&self) -> String {
-        format!("\x1b[90m[ {} ]\x1b[0m\n", self)
-    }
 }
 
 impl Ord for ApiResource {
@@ -230,6 +238,7 @@ pub struct ApiPoller {
     base: PollerBase,
     shared_target_api_resources: SharedTargetApiResources,
     shared_api_resources: SharedApiResources,
+    shared_list_query: SharedListQuery,
 }
 
 impl ApiPoller {
@@ -237,11 +246,13 @@ impl ApiPoller {
         base: PollerBase,
         shared_target_api_resources: SharedTargetApiResources,
         shared_api_resources: SharedApiResources,
+        shared_list_query: SharedListQuery,
     ) -> Self {
         Self {
             base,
             shared_target_api_resources,
             shared_api_resources,
+            shared_list_query,
         }
     }
 }
@@ -261,6 +272,7 @@ impl Worker for ApiPoller {
                 },
             shared_target_api_resources,
             shared_api_resources,
+            shared_list_query,
         } = self;
 
         match fetch_api_resources(kube_client).await {
@@ -315,15 +327,25 @@ impl Worker for ApiPoller {
                 continue;
             }
 
+            let selector = match ListSelector::parse(&shared_list_query.read().await) {
+                Ok(selector) => selector,
+                Err(err) => {
+                    tx.send(ApiResponse::Poll(Err(err)).into())
+                        .expect("Failed to send ApiResponse::Poll");
+                    continue;
+                }
+            };
+
             let result = FetchTargetApiResources::new(
                 kube_client,
                 &target_api_resources,
                 &target_namespaces,
+                &selector,
             )
             .fetch_table()
             .await;
 
-            tx.send(ApiResponse::Poll(result).into())
+            tx.send(ApiResponse::Poll(Ok(result)).into())
                 .expect("Failed to send ApiResponse::Poll");
         }
 
@@ -414,7 +436,7 @@ fn merge_tables(fetch_data: Vec<FetchData>, insert_ns: bool) -> Table {
     base_table
 }
 
-async fn try_fetch_table(client: &KubeClient, path: &str) -> Result<Table> {
+async fn try_fetch_table<C: KubeClientRequest>(client: &C, path: &str) -> Result<Table> {
     let table = client.table_request::<Table>(path).await;
 
     if let Ok(t) = table {
@@ -437,12 +459,15 @@ struct FetchData {
     table: Table,
 }
 
-async fn fetch_table_per_namespace(
-    client: &KubeClient,
+async fn fetch_table_per_namespace<C: KubeClientRequest>(
+    client: &C,
     path: String,
     ns: &str,
 ) -> Result<FetchData> {
-    let table = try_fetch_table(client, &path).await?;
+    let table = match try_fetch_table(client, &path).await {
+        Ok(table) => table,
+        Err(err) => return Err(explain_forbidden("list", &path, err)),
+    };
 
     Ok(FetchData {
         namespace: ns.to_string(),
@@ -450,68 +475,169 @@ async fn fetch_table_per_namespace(
     })
 }
 
+/// 取得できなかったネームスペースは除いてテーブルをまとめる
 #[inline]
-async fn get_table_namespaced_resource(
-    client: &KubeClient,
+async fn get_table_namespaced_resource<C: KubeClientRequest>(
+    client: &C,
     api_resource: &ApiResource,
     namespaces: &[String],
-) -> Result<Table> {
-    let jobs =
-        try_join_all(namespaces.iter().map(|ns| {
-            fetch_table_per_namespace(client, api_resource.api_url_with_namespace(ns), ns)
-        }))
-        .await?;
+    selector: &ListSelector,
+) -> Result<(Table, Vec<SkippedNamespace>)> {
+    let jobs = join_all(namespaces.iter().map(|ns| {
+        fetch_table_per_namespace(
+            client,
+            api_resource.api_url_with_namespace(ns) + &selector.query_params(),
+            ns,
+        )
+    }))
+    .await;
 
-    let result: Vec<FetchData> = jobs.into_iter().collect();
+    let (result, skipped) = partition_namespaces(namespaces.iter().cloned().zip(jobs))?;
 
-    Ok(merge_tables(result, insert_ns(namespaces)))
+    Ok((merge_tables(result, insert_ns(namespaces)), skipped))
 }
 
 #[inline]
-async fn get_table_cluster_resource(client: &KubeClient, path: &str) -> Result<Table> {
-    try_fetch_table(client, path).await
+async fn get_table_cluster_resource<C: KubeClientRequest>(client: &C, path: &str) -> Result<Table> {
+    try_fetch_table(client, path)
+        .await
+        .map_err(|err| explain_forbidden("list", path, err))
 }
 
-struct FetchTargetApiResources<'a> {
-    client: &'a KubeClient,
+struct FetchTargetApiResources<'a, C: KubeClientRequest> {
+    client: &'a C,
     target_api_resources: &'a TargetApiResources,
     target_namespace: &'a TargetNamespaces,
+    selector: &'a ListSelector,
 }
 
-impl<'a> FetchTargetApiResources<'a> {
+impl<'a, C: KubeClientRequest> FetchTargetApiResources<'a, C> {
     fn new(
-        client: &'a KubeClient,
+        client: &'a C,
         target_api_resources: &'a TargetApiResources,
         target_namespace: &'a TargetNamespaces,
+        selector: &'a ListSelector,
     ) -> Self {
         Self {
             client,
             target_api_resources,
             target_namespace,
+            selector,
         }
     }
 
-    async fn fetch_table(&self) -> Result<Vec<String>> {
+    /// 種類ごとにテーブルを取得する
+    ///
+    /// フィールドセレクタに対応していない種類などで取得できなかったときは、その種類のみエラーにする
+    async fn fetch_table(&self) -> Vec<ApiTable> {
         let mut ret = Vec::new();
         for api_resource in self.target_api_resources {
-            let table = if api_resource.is_namespaced() {
-                get_table_namespaced_resource(self.client, api_resource, self.target_namespace)
-                    .await
-            } else {
-                get_table_cluster_resource(self.client, &api_resource.api_url()).await
-            }?;
-
-            let data = if table.rows.is_empty() {
-                api_resource.to_table_header()
+            let result = if api_resource.is_namespaced() {
+                get_table_namespaced_resource(
+                    self.client,
+                    api_resource,
+                    self.target_namespace,
+                    self.selector,
+                )
+                .await
             } else {
-                api_resource.to_table_header() + &table.to_print()
+                get_table_cluster_resource(
+                    self.client,
+                    &(api_resource.api_url() + &self.selector.query_params()),
+                )
+                .await
+                .map(|table| (table, Vec::new()))
             };
 
-            ret.extend(data.lines().map(ToString::to_string).collect::<Vec<_>>());
-            ret.push("".to_string());
+            let table = result.map(|(table, skipped)| KubeTable {
+                skipped,
+                ..to_kube_table(api_resource, table)
+            });
+
+            if let Err(err) = &table {
+                logger!(error, "Failed to fetch {}: {:?}", api_resource, err);
+            }
+
+            ret.push(ApiTable {
+                table,
+                resource: api_resource.clone(),
+            });
         }
 
-        Ok(ret)
+        ret
+    }
+}
+
+/// APIリソースごとのテーブル
+#[derive(Debug)]
+pub struct ApiTable {
+    pub resource: ApiResource,
+    /// 取得できなかったときはエラー
+    pub table: Result<KubeTable>,
+}
+
+/// 行のmetadataに設定するAPIリソースのキー
+pub const API_RESOURCE_KEY: &str = "api_resource";
+
+/// サーバーが返すカラムのうちpriorityが0のものを表示する
+///
+/// 各行のnamespaceとnameはobjectのmetadataから取得し、含まれていないときはカラムの値を使う
+fn to_kube_table(api_resource: &ApiResource, table: Table) -> KubeTable {
+    let columns: Vec<usize> = table
+        .column_definitions
+        .iter()
+        .enumerate()
+        .filter(|(_, coldef)| coldef.priority == 0)
+        .map(|(i, _)| i)
+        .collect();
+
+    let header = columns
+        .iter()
+        .map(|i| table.column_definitions[*i].name.to_uppercase())
+        .collect();
+
+    let name_index = table.find_index("Name");
+    let namespace_index = table.find_index("Namespace");
+
+    let metadata = BTreeMap::from([
+        ("kind".to_string(), api_resource.kubectl_name()),
+        (
+            API_RESOURCE_KEY.to_string(),
+            serde_json::to_string(api_resource).unwrap_or_default(),
+        ),
+    ]);
+
+    let rows = table
+        .rows
+        .into_iter()
+        .map(|row| {
+            let object_metadata = row
+                .object
+                .as_ref()
+                .and_then(|object| object.0.get("metadata"));
+
+            let field = |key: &str, index: Option<usize>| {
+                object_metadata
+                    .and_then(|metadata| metadata.get(key))
+                    .and_then(JsonValue::as_str)
+                    .map(ToString::to_string)
+                    .or_else(|| index.map(|i| row.cells[i].to_string()))
+                    .unwrap_or_default()
+            };
+
+            KubeTableRow {
+                namespace: field("namespace", namespace_index),
+                name: field("name", name_index),
+                metadata: Some(metadata.clone()),
+                row: columns.iter().map(|i| row.cells[*i].to_string()).collect(),
+            }
+        })
+        .collect();
+
+    KubeTable {
+        header,
+        rows,
+        ..Default::default()
     }
 }
 
@@ -533,4 +659,163 @@ mod tests {
             assert_eq!(key.to_string(), expected)
         }
     }
+
+    mod to_kube_table {
+        use super::*;
+        use pretty_assertions::assert_eq;
+        use serde_json::json;
+
+        #[test]
+        fn priorityが0のカラムとobjectのmetadataから行を生成する() {
+            let table: Table = serde_json::from_value(json!({
+                "columnDefinitions": [
+                    {"name": "Namespace", "type": "string", "format": "", "description": "", "priority": 0},
+                    {"name": "Name", "type": "string", "format": "name", "description": "", "priority": 0},
+                    {"name": "Ready", "type": "string", "format": "", "description": "", "priority": 0},
+                    {"name": "Selector", "type": "string", "format": "", "description": "", "priority": 1}
+                ],
+                "rows": [
+                    {
+                        "cells": ["default", "app", "1/1", "app=app"],
+                        "object": {"metadata": {"name": "app", "namespace": "default"}}
+                    },
+                    {
+                        "cells": ["kube-system", "dns", "0/1", "app=dns"]
+                    }
+                ]
+            }))
+            .unwrap();
+
+            let api_resource = ApiResource::Apis {
+                name: "deployments".into(),
+                group: "apps".into(),
+                version: "v1".into(),
+                preferred_version: true,
+                scope: Scope::Namespaced,
+            };
+
+            let actual = to_kube_table(&api_resource, table);
+
+            assert_eq!(actual.header, vec!["NAMESPACE", "NAME", "READY"]);
+
+            assert_eq!(
+                actual
+                    .rows
+                    .iter()
+                    .map(|row| (row.namespace.as_str(), row.name.as_str(), row.row.clone()))
+                    .collect::<Vec<_>>(),
+                vec![
+                    (
+                        "default",
+                        "app",
+                        vec!["default".to_string(), "app".into(), "1/1".into()]
+                    ),
+                    (
+                        "kube-system",
+                        "dns",
+                        vec!["kube-system".to_string(), "dns".into(), "0/1".into()]
+                    ),
+                ]
+            );
+
+            let metadata = actual.rows[0].metadata.as_ref().unwrap();
+
+            assert_eq!(metadata["kind"], "deployments.apps");
+            assert_eq!(
+                serde_json::from_str::<ApiResource>(&metadata[API_RESOURCE_KEY]).unwrap(),
+                api_resource
+            );
+        }
+    }
+
+    mod fetch_target_api_resources {
+        use super::*;
+        use kube::core::ErrorResponse;
+        use mockall::predicate::eq;
+        use pretty_assertions::assert_eq;
+        use serde_json::json;
+
+        use crate::{kube::mock::MockTestKubeClient, mock_expect};
+
+        fn bad_request() -> anyhow::Error {
+            kube::Error::Api(ErrorResponse {
+                status: "Failure".into(),
+                message: "field label not supported: status.phase".into(),
+                reason: "BadRequest".into(),
+                code: 400,
+            })
+            .into()
+        }
+
+        #[tokio::test]
+        async fn 取得できない種類のみエラーにする() {
+            let mut client = MockTestKubeClient::new();
+
+            let deployments_path =
+                "apis/apps/v1/namespaces/default/deployments?fieldSelector=status.phase%3DRunning";
+
+            mock_expect!(
+                client,
+                table_request,
+                [
+                    (
+                        Table,
+                        eq("api/v1/namespaces/default/pods?fieldSelector=status.phase%3DRunning"),
+                        Ok(serde_json::from_value(json!({
+                            "columnDefinitions": [
+                                {"name": "Name", "type": "string", "format": "name", "description": "", "priority": 0}
+                            ],
+                            "rows": [{"cells": ["app"]}]
+                        }))
+                        .unwrap())
+                    ),
+                    (Table, eq(deployments_path), Err(bad_request())),
+                    (NodeMetricsList, eq(deployments_path), Err(bad_request())),
+                    (PodMetricsList, eq(deployments_path), Err(bad_request()))
+                ]
+            );
+
+            let target_api_resources = vec![
+                ApiResource::Api {
+                    name: "pods".into(),
+                    version: "v1".into(),
+                    scope: Scope::Namespaced,
+                },
+                ApiResource::Apis {
+                    name: "deployments".into(),
+                    group: "apps".into(),
+                    version: "v1".into(),
+                    preferred_version: true,
+                    scope: Scope::Namespaced,
+                },
+            ];
+
+            let selector = ListSelector::parse("fields:status.phase=Running").unwrap();
+
+            let actual = FetchTargetApiResources::new(
+                &client,
+                &target_api_resources,
+                &vec!["default".to_string()],
+                &selector,
+            )
+            .fetch_table()
+            .await;
+
+            assert_eq!(actual.len(), 2);
+
+            assert_eq!(
+                actual[0]
+                    .table
+                    .as_ref()
+                    .unwrap()
+                    .rows
+                    .iter()
+                    .map(|row| row.name.as_str())
+                    .collect::<Vec<_>>(),
+                vec!["app"]
+            );
+
+            assert!(actual[1].table.is_err());
+        }
+    }
 }
@@ -0,0 +1,669 @@
+use anyhow::{bail, Result};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use crossbeam::channel::Sender;
+use serde_json::Value as JsonValue;
+
+use crate::{
+    features::api_resources::{kube::ApiResource, message::ApiResponse},
+    kube::{KubeClient, KubeClientRequest},
+    logger,
+    message::Message,
+    workers::kube::AbortWorker,
+};
+
+/// 値がTrueのときに異常を表すconditionのtypeの接尾辞
+const NEGATIVE_POLARITY_SUFFIXES: [&str; 8] = [
+    "Pressure",
+    "Degraded",
+    "Stalled",
+    "Failed",
+    "Failure",
+    "Error",
+    "Unavailable",
+    "Disrupted",
+];
+
+/// 処理中であることを表すconditionのtype（値に関わらず異常とみなさない）
+const TRANSITIONAL_TYPES: [&str; 3] = ["Reconciling", "Issuing", "Suspended"];
+
+/// CRDのadditionalPrinterColumns
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct PrinterColumn {
+    name: String,
+    json_path: String,
+    type_: String,
+}
+
+/// status.conditionsの要素
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Condition {
+    type_: String,
+    status: String,
+    reason: String,
+    message: String,
+    last_transition_time: Option<DateTime<Utc>>,
+}
+
+impl Condition {
+    fn from_value(value: &JsonValue) -> Option<Self> {
+        let get = |key: &str| {
+            value
+                .get(key)
+                .and_then(JsonValue::as_str)
+                .unwrap_or_default()
+                .to_string()
+        };
+
+        let type_ = get("type");
+
+        if type_.is_empty() {
+            return None;
+        }
+
+        Some(Self {
+            type_,
+            status: get("status"),
+            reason: get("reason"),
+            message: get("message"),
+            last_transition_time: DateTime::parse_from_rfc3339(&get("lastTransitionTime"))
+                .ok()
+                .map(|dt| dt.with_timezone(&Utc)),
+        })
+    }
+
+    fn is_failing(&self) -> bool {
+        if TRANSITIONAL_TYPES.contains(&self.type_.as_str()) {
+            return false;
+        }
+
+        let is_negative = NEGATIVE_POLARITY_SUFFIXES
+            .iter()
+            .any(|suffix| self.type_.ends_with(suffix));
+
+        match self.status.as_str() {
+            "True" => is_negative,
+            "False" => !is_negative,
+            _ => false,
+        }
+    }
+
+    fn is_unknown(&self) -> bool {
+        self.status != "True" && self.status != "False"
+    }
+}
+
+/// 選択したオブジェクトのconditionsを取得して送る
+///
+/// 選択し直したときやcontextを切り替えたときに中断できるよう、EventControllerとは別のタスクで実行する
+#[derive(Clone)]
+pub struct ConditionsWorker {
+    tx: Sender<Message>,
+    client: KubeClient,
+    resource: ApiResource,
+    name: String,
+    namespace: String,
+}
+
+impl ConditionsWorker {
+    pub fn new(
+        tx: Sender<Message>,
+        client: KubeClient,
+        resource: ApiResource,
+        name: String,
+        namespace: String,
+    ) -> Self {
+        Self {
+            tx,
+            client,
+            resource,
+            name,
+            namespace,
+        }
+    }
+}
+
+#[async_trait]
+impl AbortWorker for ConditionsWorker {
+    async fn run(&self) {
+        let conditions =
+            fetch_conditions(&self.client, &self.resource, &self.name, &self.namespace).await;
+
+        self.tx
+            .send(ApiResponse::Conditions(conditions).into())
+            .expect("Failed to send ApiResponse::Conditions");
+    }
+}
+
+/// カスタムリソースのprinter columnsとstatus.conditionsを取得して表示用の文字列にする
+pub async fn fetch_conditions<C: KubeClientRequest>(
+    client: &C,
+    api_resource: &ApiResource,
+    name: &str,
+    namespace: &str,
+) -> Result<Vec<String>> {
+    let path = if api_resource.is_namespaced() {
+        format!(
+            "{}/{}",
+            api_resource.api_url_with_namespace(namespace),
+            name
+        )
+    } else {
+        format!("{}/{}", api_resource.api_url(), name)
+    };
+
+    let object: JsonValue = client.request(&path).await?;
+
+    let columns = if api_resource.is_apis() {
+        fetch_printer_columns(client, api_resource).await
+    } else {
+        Vec::new()
+    };
+
+    Ok(render(&object, &columns, Utc::now()))
+}
+
+/// CRDのadditionalPrinterColumnsを取得する
+///
+/// 組み込みのAPIやCRDを取得する権限がないときは空を返す
+async fn fetch_printer_columns<C: KubeClientRequest>(
+    client: &C,
+    api_resource: &ApiResource,
+) -> Vec<PrinterColumn> {
+    let path = format!(
+        "apis/apiextensions.k8s.io/v1/customresourcedefinitions/{}",
+        api_resource.kubectl_name()
+    );
+
+    match client.request::<JsonValue>(&path).await {
+        Ok(crd) => printer_columns(&crd, api_resource.version()),
+        Err(err) => {
+            logger!(info, "Skip printer columns [{}]: {}", path, err);
+            Vec::new()
+        }
+    }
+}
+
+fn printer_columns(crd: &JsonValue, version: &str) -> Vec<PrinterColumn> {
+    let Some(versions) = crd.pointer("/spec/versions").and_then(JsonValue::as_array) else {
+        return Vec::new();
+    };
+
+    versions
+        .iter()
+        .find(|v| v.get("name").and_then(JsonValue::as_str) == Some(version))
+        .and_then(|v| v.get("additionalPrinterColumns"))
+        .and_then(JsonValue::as_array)
+        .map(|columns| {
+            columns
+                .iter()
+                .filter_map(|column| {
+                    Some(PrinterColumn {
+                        name: column.get("name")?.as_str()?.to_string(),
+                        json_path: column.get("jsonPath")?.as_str()?.to_string(),
+                        type_: column
+                            .get("type")
+                            .and_then(JsonValue::as_str)
+                            .unwrap_or("string")
+                            .to_string(),
+                    })
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn render(object: &JsonValue, columns: &[PrinterColumn], now: DateTime<Utc>) -> Vec<String> {
+    let mut lines = Vec::new();
+
+    if !columns.is_empty() {
+        lines.push("printer columns:".to_string());
+
+        let width = columns.iter().map(|c| c.name.len()).max().unwrap_or(0);
+
+        for column in columns {
+            let value = match evaluate_json_path(object, &column.json_path) {
+                Ok(values) => format_values(&values, &column.type_, now),
+                Err(err) => format!("\x1b[90m{}\x1b[39m", err),
+            };
+
+            lines.push(format!(
+                "  {:width$}  {}",
+                format!("{}:", column.name),
+                value,
+                width = width + 1
+            ));
+        }
+
+        lines.push(String::default());
+    }
+
+    lines.extend(render_conditions(object, now));
+
+    lines
+}
+
+/// status.conditionsを表形式で表示する（異常は赤、Unknownは黄色）
+pub fn render_conditions(object: &JsonValue, now: DateTime<Utc>) -> Vec<String> {
+    let mut lines = Vec::new();
+
+    let conditions: Vec<Condition> = object
+        .pointer("/status/conditions")
+        .and_then(JsonValue::as_array)
+        .map(|conditions| {
+            conditions
+                .iter()
+                .filter_map(Condition::from_value)
+                .collect()
+        })
+        .unwrap_or_default();
+
+    if conditions.is_empty() {
+        lines.push("conditions: <none>".to_string());
+        return lines;
+    }
+
+    lines.push("conditions:".to_string());
+
+    let rows: Vec<[String; 5]> = conditions
+        .iter()
+        .map(|c| {
+            [
+                c.type_.clone(),
+                c.status.clone(),
+                c.reason.clone(),
+                c.last_transition_time
+                    .map(|t| format_age(now - t))
+                    .unwrap_or_else(|| "<unknown>".into()),
+                c.message.clone(),
+            ]
+        })
+        .collect();
+
+    let header = [
+        "TYPE".to_string(),
+        "STATUS".into(),
+        "REASON".into(),
+        "AGE".into(),
+        "MESSAGE".into(),
+    ];
+
+    let widths: Vec<usize> = (0..4)
+        .map(|i| {
+            rows.iter()
+                .map(|row| row[i].len())
+                .chain([header[i].len()])
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+
+    let format_row = |row: &[String; 5]| {
+        format!(
+            "  {:w0$}  {:w1$}  {:w2$}  {:w3$}  {}",
+            row[0],
+            row[1],
+            row[2],
+            row[3],
+            row[4],
+            w0 = widths[0],
+            w1 = widths[1],
+            w2 = widths[2],
+            w3 = widths[3],
+        )
+    };
+
+    lines.push(format_row(&header));
+
+    for (condition, row) in conditions.iter().zip(&rows) {
+        let line = format_row(row);
+
+        if condition.is_failing() {
+            lines.push(format!("\x1b[31m{}\x1b[39m", line));
+        } else if condition.is_unknown() {
+            lines.push(format!("\x1b[33m{}\x1b[39m", line));
+        } else {
+            lines.push(line);
+        }
+    }
+
+    lines
+}
+
+fn format_values(values: &[&JsonValue], type_: &str, now: DateTime<Utc>) -> String {
+    values
+        .iter()
+        .map(|value| match value {
+            JsonValue::String(s) if type_ == "date" => DateTime::parse_from_rfc3339(s)
+                .map(|t| format_age(now - t.with_timezone(&Utc)))
+                .unwrap_or_else(|_| s.to_string()),
+            JsonValue::String(s) => s.to_string(),
+            value => value.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// kubectlと同じ形式の経過時間（例: 45s, 12m, 5h, 3d, 2y）
+pub fn format_age(duration: chrono::Duration) -> String {
+    let seconds = duration.num_seconds().max(0);
+
+    match seconds {
+        s if s < 120 => format!("{}s", s),
+        s if s < 3 * 3600 => format!("{}m", s / 60),
+        s if s < 48 * 3600 => format!("{}h", s / 3600),
+        s if s < 2 * 365 * 86400 => format!("{}d", s / 86400),
+        s => format!("{}y", s / (365 * 86400)),
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum PathSegment {
+    Field(String),
+    Index(usize),
+    Wildcard,
+    /// [?(@.key=="value")]
+    Filter(Vec<String>, String),
+}
+
+/// additionalPrinterColumnsで使われるJSONPathの一部を評価する
+///
+/// フィールド、インデックス、ワイルドカード、等価比較のフィルタのみ対応する
+fn evaluate_json_path<'a>(object: &'a JsonValue, path: &str) -> Result<Vec<&'a JsonValue>> {
+    let segments = parse_json_path(path)?;
+
+    let mut values = vec![object];
+
+    for segment in segments {
+        values = values
+            .into_iter()
+            .flat_map(|value| -> Vec<&JsonValue> {
+                match &segment {
+                    PathSegment::Field(field) => value.get(field).into_iter().collect(),
+                    PathSegment::Index(index) => value.get(index).into_iter().collect(),
+                    PathSegment::Wildcard => match value {
+                        JsonValue::Array(array) => array.iter().collect(),
+                        JsonValue::Object(map) => map.values().collect(),
+                        _ => Vec::new(),
+                    },
+                    PathSegment::Filter(keys, expected) => value
+                        .as_array()
+                        .into_iter()
+                        .flatten()
+                        .filter(|item| {
+                            let actual = keys.iter().try_fold(*item, |v, key| v.get(key));
+
+                            match actual {
+                                Some(JsonValue::String(s)) => s == expected,
+                                Some(v) => serde_json::from_str::<JsonValue>(expected)
+                                    .is_ok_and(|e| &e == v),
+                                None => false,
+                            }
+                        })
+                        .collect(),
+                }
+            })
+            .collect();
+    }
+
+    Ok(values)
+}
+
+fn parse_json_path(path: &str) -> Result<Vec<PathSegment>> {
+    let path = path.trim();
+    let path = path
+        .strip_prefix('{')
+        .and_then(|p| p.strip_suffix('}'))
+        .unwrap_or(path);
+    let mut rest = path.strip_prefix('$').unwrap_or(path);
+
+    let mut segments = Vec::new();
+
+    while !rest.is_empty() {
+        if let Some(r) = rest.strip_prefix('.') {
+            let end = r.find(['.', '[']).unwrap_or(r.len());
+
+            if end == 0 {
+                bail!("Unsupported jsonPath: {}", path);
+            }
+
+            segments.push(PathSegment::Field(r[..end].to_string()));
+            rest = &r[end..];
+        } else if let Some(r) = rest.strip_prefix('[') {
+            let Some(end) = r.find(']') else {
+                bail!("Unsupported jsonPath: {}", path);
+            };
+
+            let inner = r[..end].trim();
+
+            let segment = if inner == "*" {
+                PathSegment::Wildcard
+            } else if let Ok(index) = inner.parse() {
+                PathSegment::Index(index)
+            } else if let Some((keys, expected)) = inner
+                .strip_prefix("?(@.")
+                .and_then(|f| f.strip_suffix(')'))
+                .and_then(|f| f.split_once("=="))
+            {
+                let expected = expected.trim();
+                let expected = expected
+                    .strip_prefix('"')
+                    .and_then(|e| e.strip_suffix('"'))
+                    .or_else(|| {
+                        expected
+                            .strip_prefix('\'')
+                            .and_then(|e| e.strip_suffix('\''))
+                    })
+                    .unwrap_or(expected);
+
+                PathSegment::Filter(
+                    keys.trim().split('.').map(ToString::to_string).collect(),
+                    expected.to_string(),
+                )
+            } else {
+                bail!("Unsupported jsonPath: {}", path);
+            };
+
+            segments.push(segment);
+            rest = &r[end + 1..];
+        } else {
+            bail!("Unsupported jsonPath: {}", path);
+        }
+    }
+
+    Ok(segments)
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use super::*;
+
+    fn now() -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339("2024-01-04T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    fn certificate() -> JsonValue {
+        serde_yaml::from_str(indoc! {
+            r#"
+            metadata:
+              name: example
+              creationTimestamp: "2024-01-01T00:00:00Z"
+            spec:
+              secretName: example-tls
+            status:
+              conditions:
+                - type: Ready
+                  status: "False"
+                  reason: DoesNotExist
+                  message: Issuing certificate as Secret does not exist
+                  lastTransitionTime: "2024-01-03T23:55:00Z"
+                - type: Issuing
+                  status: "True"
+                  reason: DoesNotExist
+                  message: Issuing certificate as Secret does not exist
+                  lastTransitionTime: "2024-01-03T23:59:30Z"
+            "#
+        })
+        .unwrap()
+    }
+
+    mod json_path {
+        use pretty_assertions::assert_eq;
+        use rstest::rstest;
+
+        use super::*;
+
+        #[rstest]
+        #[case(".spec.secretName", json!(["example-tls"]))]
+        #[case(".status.conditions[?(@.type==\"Ready\")].status", json!(["False"]))]
+        #[case(".status.conditions[?(@.type=='Issuing')].reason", json!(["DoesNotExist"]))]
+        #[case(".status.conditions[0].type", json!(["Ready"]))]
+        #[case(".status.conditions[*].type", json!(["Ready", "Issuing"]))]
+        #[case("{.metadata.name}", json!(["example"]))]
+        #[case(".status.notFound", json!([]))]
+        fn jsonpathを評価する(#[case] path: &str, #[case] expected: JsonValue) {
+            let object = certificate();
+
+            let actual: Vec<JsonValue> = evaluate_json_path(&object, path)
+                .unwrap()
+                .into_iter()
+                .cloned()
+                .collect();
+
+            assert_eq!(JsonValue::Array(actual), expected);
+        }
+
+        #[test]
+        fn 対応していない式のときエラーを返す() {
+            assert!(evaluate_json_path(&certificate(), ".status.conditions[?(@.x>1)]").is_err());
+        }
+    }
+
+    #[test]
+    fn crdのバージョンに対応するprinter_columnsを取得する() {
+        let crd = json!({
+            "spec": {
+                "versions": [
+                    {
+                        "name": "v1alpha2",
+                        "additionalPrinterColumns": [
+                            { "name": "Old", "jsonPath": ".spec.old", "type": "string" }
+                        ]
+                    },
+                    {
+                        "name": "v1",
+                        "additionalPrinterColumns": [
+                            {
+                                "name": "Ready",
+                                "jsonPath": ".status.conditions[?(@.type==\"Ready\")].status",
+                                "type": "string"
+                            },
+                            { "name": "Age", "jsonPath": ".metadata.creationTimestamp", "type": "date" }
+                        ]
+                    }
+                ]
+            }
+        });
+
+        let actual = printer_columns(&crd, "v1");
+
+        assert_eq!(
+            actual,
+            vec![
+                PrinterColumn {
+                    name: "Ready".into(),
+                    json_path: ".status.conditions[?(@.type==\"Ready\")].status".into(),
+                    type_: "string".into(),
+                },
+                PrinterColumn {
+                    name: "Age".into(),
+                    json_path: ".metadata.creationTimestamp".into(),
+                    type_: "date".into(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn printer_columnsとconditionsを表示する() {
+        let columns = vec![
+            PrinterColumn {
+                name: "Ready".into(),
+                json_path: ".status.conditions[?(@.type==\"Ready\")].status".into(),
+                type_: "string".into(),
+            },
+            PrinterColumn {
+                name: "Secret".into(),
+                json_path: ".spec.secretName".into(),
+                type_: "string".into(),
+            },
+            PrinterColumn {
+                name: "Age".into(),
+                json_path: ".metadata.creationTimestamp".into(),
+                type_: "date".into(),
+            },
+        ];
+
+        let actual = render(&certificate(), &columns, now());
+
+        let expected = vec![
+            "printer columns:".to_string(),
+            "  Ready:   False".into(),
+            "  Secret:  example-tls".into(),
+            "  Age:     3d".into(),
+            "".into(),
+            "conditions:".into(),
+            "  TYPE     STATUS  REASON        AGE  MESSAGE".into(),
+            "\x1b[31m  Ready    False   DoesNotExist  5m   Issuing certificate as Secret does not exist\x1b[39m".into(),
+            "  Issuing  True    DoesNotExist  30s  Issuing certificate as Secret does not exist".into(),
+        ];
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn conditionsがないときnoneを表示する() {
+        let actual = render(&json!({ "status": {} }), &[], now());
+
+        assert_eq!(actual, vec!["conditions: <none>".to_string()]);
+    }
+
+    mod is_failing {
+        use pretty_assertions::assert_eq;
+        use rstest::rstest;
+
+        use super::*;
+
+        #[rstest]
+        #[case("Ready", "True", false)]
+        #[case("Ready", "False", true)]
+        #[case("Synced", "False", true)]
+        #[case("MemoryPressure", "True", true)]
+        #[case("MemoryPressure", "False", false)]
+        #[case("Stalled", "True", true)]
+        #[case("ComparisonError", "True", true)]
+        #[case("Reconciling", "False", false)]
+        #[case("Ready", "Unknown", false)]
+        fn conditionが異常かどうか判定する(
+            #[case] type_: &str,
+            #[case] status: &str,
+            #[case] expected: bool,
+        ) {
+            let condition = Condition {
+                type_: type_.into(),
+                status: status.into(),
+                reason: String::default(),
+                message: String::default(),
+                last_transition_time: None,
+            };
+
+            assert_eq!(condition.is_failing(), expected);
+        }
+    }
+}
@@ -0,0 +1,610 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use crossbeam::channel::Sender;
+use futures::future::join_all;
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::{APIResourceList, OwnerReference};
+use kube::discovery::Scope;
+use serde_json::Value as JsonValue;
+
+use crate::{
+    features::api_resources::message::ApiResponse,
+    kube::{
+        apis::v1_table::Table,
+        table::{KubeTable, KubeTableRow},
+        KubeClient, KubeClientRequest,
+    },
+    logger,
+    message::Message,
+    workers::kube::AbortWorker,
+};
+
+use super::{ApiResource, SharedApiResources, API_RESOURCE_KEY};
+
+/// 所有者をたどる最大の深さ
+const MAX_OWNER_DEPTH: usize = 10;
+
+/// ログを表示できるリソース（ログクエリの `<resource>/<name>` で指定できるもの）
+const LOG_RESOURCES: [(&str, &str); 7] = [
+    ("", "pods"),
+    ("", "services"),
+    ("apps", "replicasets"),
+    ("apps", "deployments"),
+    ("apps", "statefulsets"),
+    ("apps", "daemonsets"),
+    ("batch", "jobs"),
+];
+
+/// ツリーの行に設定するログクエリのキー
+pub const LOG_QUERY_KEY: &str = "log_query";
+
+/// テーブルの行から取り出したオブジェクトと所有者の情報
+#[derive(Debug, Clone, PartialEq)]
+struct OwnedObject {
+    resource: ApiResource,
+    namespace: String,
+    name: String,
+    uid: String,
+    owners: Vec<OwnerReference>,
+    status: String,
+}
+
+impl OwnedObject {
+    /// コントローラーの所有者を優先して返す
+    fn owner(&self) -> Option<&OwnerReference> {
+        self.owners
+            .iter()
+            .find(|owner| owner.controller == Some(true))
+            .or_else(|| self.owners.first())
+    }
+
+    fn log_query(&self) -> Option<String> {
+        LOG_RESOURCES
+            .iter()
+            .any(|(group, name)| self.resource.group() == *group && self.resource.name() == *name)
+            .then(|| format!("{}/{}", self.resource.name(), self.name))
+    }
+}
+
+/// 選択したオブジェクトの所有者のツリーを取得して送る
+///
+/// 種類ごとに一覧を取得するため時間がかかる。ほかのリクエストを妨げないよう、EventControllerとは別のタスクで実行する
+#[derive(Clone)]
+pub struct OwnerTreeWorker {
+    tx: Sender<Message>,
+    client: KubeClient,
+    shared_api_resources: SharedApiResources,
+    resource: ApiResource,
+    name: String,
+    namespace: String,
+}
+
+impl OwnerTreeWorker {
+    pub fn new(
+        tx: Sender<Message>,
+        client: KubeClient,
+        shared_api_resources: SharedApiResources,
+        resource: ApiResource,
+        name: String,
+        namespace: String,
+    ) -> Self {
+        Self {
+            tx,
+            client,
+            shared_api_resources,
+            resource,
+            name,
+            namespace,
+        }
+    }
+}
+
+#[async_trait]
+impl AbortWorker for OwnerTreeWorker {
+    async fn run(&self) {
+        let api_resources = self.shared_api_resources.read().await.to_vec();
+
+        let tree = fetch_owner_tree(
+            &self.client,
+            &api_resources,
+            &self.resource,
+            &self.name,
+            &self.namespace,
+        )
+        .await;
+
+        self.tx
+            .send(ApiResponse::OwnerTree(tree).into())
+            .expect("Failed to send ApiResponse::OwnerTree");
+    }
+}
+
+/// 選択したオブジェクトの所有者をたどり、最上位の所有者から従属するオブジェクトをツリーで返す
+pub async fn fetch_owner_tree<C: KubeClientRequest>(
+    client: &C,
+    api_resources: &[ApiResource],
+    resource: &ApiResource,
+    name: &str,
+    namespace: &str,
+) -> Result<KubeTable> {
+    let target = fetch_object(client, resource, namespace, name).await?;
+
+    let mut root = target.clone();
+    let mut visited = HashSet::from([root.uid.clone()]);
+
+    for _ in 0..MAX_OWNER_DEPTH {
+        let Some(owner) = root.owner() else {
+            break;
+        };
+
+        let owner_object = match resolve_owner_resource(client, api_resources, owner).await {
+            Ok(resource) => {
+                let namespace = if resource.is_namespaced() {
+                    root.namespace.as_str()
+                } else {
+                    ""
+                };
+
+                fetch_object(client, &resource, namespace, &owner.name).await
+            }
+            Err(err) => Err(err),
+        };
+
+        match owner_object {
+            Ok(object) if visited.insert(object.uid.clone()) => root = object,
+            Ok(_) => break,
+            Err(err) => {
+                logger!(
+                    warn,
+                    "Failed to fetch owner {}/{}: {}",
+                    owner.kind,
+                    owner.name,
+                    err
+                );
+                break;
+            }
+        }
+    }
+
+    let objects = fetch_dependent_candidates(client, api_resources, &root).await;
+
+    Ok(to_kube_table(&build_tree(&root, &objects), &target.uid))
+}
+
+fn object_path(resource: &ApiResource, namespace: &str, name: &str) -> String {
+    if resource.is_namespaced() {
+        format!("{}/{}", resource.api_url_with_namespace(namespace), name)
+    } else {
+        format!("{}/{}", resource.api_url(), name)
+    }
+}
+
+async fn fetch_object<C: KubeClientRequest>(
+    client: &C,
+    resource: &ApiResource,
+    namespace: &str,
+    name: &str,
+) -> Result<OwnedObject> {
+    let path = object_path(resource, namespace, name);
+
+    let table: Table = client.table_request(&path).await?;
+
+    owned_objects(resource, &table)
+        .into_iter()
+        .next()
+        .ok_or_else(|| anyhow!("Not found: {}", path))
+}
+
+/// ownerReferencesのapiVersionとkindからAPIリソースを解決する
+async fn resolve_owner_resource<C: KubeClientRequest>(
+    client: &C,
+    api_resources: &[ApiResource],
+    owner: &OwnerReference,
+) -> Result<ApiResource> {
+    let (path, group, version) = match owner.api_version.split_once('/') {
+        Some((group, version)) => (format!("apis/{}", owner.api_version), group, version),
+        None => (
+            format!("api/{}", owner.api_version),
+            "",
+            owner.api_version.as_str(),
+        ),
+    };
+
+    let list: APIResourceList = client.request(&path).await?;
+
+    let Some(found) = list
+        .resources
+        .iter()
+        .find(|r| r.kind == owner.kind && !r.name.contains('/'))
+    else {
+        return Err(anyhow!(
+            "Unknown kind: {} ({})",
+            owner.kind,
+            owner.api_version
+        ));
+    };
+
+    // 優先バージョンかどうかはDiscoveryの結果を使う
+    if let Some(resource) = api_resources
+        .iter()
+        .find(|r| r.name() == found.name && r.group() == group && r.version() == version)
+    {
+        return Ok(resource.clone());
+    }
+
+    let scope = if found.namespaced {
+        Scope::Namespaced
+    } else {
+        Scope::Cluster
+    };
+
+    Ok(if group.is_empty() {
+        ApiResource::Api {
+            name: found.name.to_string(),
+            version: version.to_string(),
+            scope,
+        }
+    } else {
+        ApiResource::Apis {
+            name: found.name.to_string(),
+            group: group.to_string(),
+            version: version.to_string(),
+            preferred_version: false,
+            scope,
+        }
+    })
+}
+
+/// 従属するオブジェクトの候補を取得する
+///
+/// 最上位の所有者がnamespaceに属するときは同じnamespaceのみ、クラスタスコープのときはすべてのnamespaceを対象にする。
+/// 取得できなかったリソースは無視する
+async fn fetch_dependent_candidates<C: KubeClientRequest>(
+    client: &C,
+    api_resources: &[ApiResource],
+    root: &OwnedObject,
+) -> Vec<OwnedObject> {
+    let is_cluster_root = !root.resource.is_namespaced();
+
+    let targets: Vec<(&ApiResource, String)> = api_resources
+        .iter()
+        .filter(|r| r.is_api() || r.is_preferred_version())
+        .filter(|r| r.name() != "events")
+        .filter_map(|r| {
+            if r.is_namespaced() {
+                Some((
+                    r,
+                    if is_cluster_root {
+                        r.api_url()
+                    } else {
+                        r.api_url_with_namespace(&root.namespace)
+                    },
+                ))
+            } else {
+                is_cluster_root.then(|| (r, r.api_url()))
+            }
+        })
+        .collect();
+
+    let tables = join_all(targets.iter().map(|(resource, path)| async move {
+        match client.table_request::<Table>(path).await {
+            Ok(table) => owned_objects(resource, &table),
+            Err(err) => {
+                logger!(info, "Skip dependents [{}]: {}", path, err);
+                Vec::new()
+            }
+        }
+    }))
+    .await;
+
+    tables.into_iter().flatten().collect()
+}
+
+fn owned_objects(resource: &ApiResource, table: &Table) -> Vec<OwnedObject> {
+    let status_index = ["Status", "Ready", "Phase"]
+        .iter()
+        .find_map(|column| table.find_index(column));
+
+    table
+        .rows
+        .iter()
+        .filter_map(|row| {
+            let metadata = row.object.as_ref()?.0.get("metadata")?;
+
+            let field = |key: &str| {
+                metadata
+                    .get(key)
+                    .and_then(JsonValue::as_str)
+                    .unwrap_or_default()
+                    .to_string()
+            };
+
+            let owners = metadata
+                .get("ownerReferences")
+                .cloned()
+                .and_then(|owners| serde_json::from_value(owners).ok())
+                .unwrap_or_default();
+
+            Some(OwnedObject {
+                resource: resource.clone(),
+                namespace: field("namespace"),
+                name: field("name"),
+                uid: field("uid"),
+                owners,
+                status: status_index
+                    .and_then(|i| row.cells.get(i))
+                    .map(|cell| cell.to_string())
+                    .unwrap_or_default(),
+            })
+        })
+        .collect()
+}
+
+/// ツリーの1行（行頭の罫線と対象のオブジェクト）
+type TreeNode<'a> = (String, &'a OwnedObject);
+
+fn build_tree<'a>(root: &'a OwnedObject, objects: &'a [OwnedObject]) -> Vec<TreeNode<'a>> {
+    let mut children: HashMap<&str, Vec<&OwnedObject>> = HashMap::new();
+    let mut indexed = HashSet::new();
+
+    for object in objects {
+        if !indexed.insert(object.uid.as_str()) {
+            continue;
+        }
+
+        for owner in &object.owners {
+            children.entry(owner.uid.as_str()).or_default().push(object);
+        }
+    }
+
+    children.values_mut().for_each(|vec| {
+        vec.sort_by(|a, b| {
+            (a.resource.kubectl_name(), &a.namespace, &a.name).cmp(&(
+                b.resource.kubectl_name(),
+                &b.namespace,
+                &b.name,
+            ))
+        })
+    });
+
+    let mut nodes = vec![(String::default(), root)];
+    let mut visited = HashSet::from([root.uid.as_str()]);
+
+    push_children(root, "", &children, &mut visited, &mut nodes);
+
+    nodes
+}
+
+fn push_children<'a>(
+    parent: &OwnedObject,
+    indent: &str,
+    children: &HashMap<&str, Vec<&'a OwnedObject>>,
+    visited: &mut HashSet<&'a str>,
+    nodes: &mut Vec<TreeNode<'a>>,
+) {
+    let Some(vec) = children.get(parent.uid.as_str()) else {
+        return;
+    };
+
+    let vec: Vec<&OwnedObject> = vec
+        .iter()
+        .filter(|child| visited.insert(child.uid.as_str()))
+        .copied()
+        .collect();
+
+    for (i, child) in vec.iter().enumerate() {
+        let is_last = i == vec.len() - 1;
+
+        let branch = if is_last { "└─ " } else { "├─ " };
+
+        nodes.push((format!("{}{}", indent, branch), child));
+
+        let next_indent = format!("{}{}", indent, if is_last { "   " } else { "│  " });
+
+        push_children(child, &next_indent, children, visited, nodes);
+    }
+}
+
+fn to_kube_table(nodes: &[TreeNode], target_uid: &str) -> KubeTable {
+    let rows = nodes
+        .iter()
+        .map(|(prefix, object)| {
+            let mut cells = vec![
+                format!("{}/{}", object.resource.kubectl_name(), object.name),
+                object.namespace.to_string(),
+                object.status.to_string(),
+            ];
+
+            // 選択したオブジェクトを強調する
+            if object.uid == target_uid {
+                cells
+                    .iter_mut()
+                    .for_each(|cell| *cell = format!("\x1b[36m{}\x1b[39m", cell));
+            }
+
+            cells[0] = format!("{}{}", prefix, cells[0]);
+
+            let mut metadata = BTreeMap::from([
+                ("kind".to_string(), object.resource.kubectl_name()),
+                (
+                    API_RESOURCE_KEY.to_string(),
+                    serde_json::to_string(&object.resource).unwrap_or_default(),
+                ),
+            ]);
+
+            if let Some(query) = object.log_query() {
+                metadata.insert(LOG_QUERY_KEY.to_string(), query);
+            }
+
+            KubeTableRow {
+                namespace: object.namespace.to_string(),
+                name: object.name.to_string(),
+                metadata: Some(metadata),
+                row: cells,
+            }
+        })
+        .collect();
+
+    KubeTable {
+        header: vec!["NAME".into(), "NAMESPACE".into(), "STATUS".into()],
+        rows,
+        ..Default::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use super::*;
+
+    fn deployments() -> ApiResource {
+        ApiResource::Apis {
+            name: "deployments".into(),
+            group: "apps".into(),
+            version: "v1".into(),
+            preferred_version: true,
+            scope: Scope::Namespaced,
+        }
+    }
+
+    fn replicasets() -> ApiResource {
+        ApiResource::Apis {
+            name: "replicasets".into(),
+            group: "apps".into(),
+            version: "v1".into(),
+            preferred_version: true,
+            scope: Scope::Namespaced,
+        }
+    }
+
+    fn pods() -> ApiResource {
+        ApiResource::Api {
+            name: "pods".into(),
+            version: "v1".into(),
+            scope: Scope::Namespaced,
+        }
+    }
+
+    fn object(resource: ApiResource, name: &str, uid: &str, owner_uids: &[&str]) -> OwnedObject {
+        OwnedObject {
+            resource,
+            namespace: "default".into(),
+            name: name.into(),
+            uid: uid.into(),
+            owners: owner_uids
+                .iter()
+                .map(|uid| OwnerReference {
+                    uid: uid.to_string(),
+                    ..Default::default()
+                })
+                .collect(),
+            status: String::default(),
+        }
+    }
+
+    #[test]
+    fn テーブルの行からオブジェクトと所有者を取り出す() {
+        let table: Table = serde_json::from_value(json!({
+            "columnDefinitions": [
+                { "name": "Name", "type": "string", "format": "name", "description": "", "priority": 0 },
+                { "name": "Ready", "type": "string", "format": "", "description": "", "priority": 0 },
+                { "name": "Status", "type": "string", "format": "", "description": "", "priority": 0 }
+            ],
+            "rows": [
+                {
+                    "cells": ["app-7d4b9-x2k", "1/1", "Running"],
+                    "object": {
+                        "kind": "PartialObjectMetadata",
+                        "metadata": {
+                            "name": "app-7d4b9-x2k",
+                            "namespace": "default",
+                            "uid": "pod-uid",
+                            "ownerReferences": [
+                                {
+                                    "apiVersion": "apps/v1",
+                                    "kind": "ReplicaSet",
+                                    "name": "app-7d4b9",
+                                    "uid": "rs-uid",
+                                    "controller": true
+                                }
+                            ]
+                        }
+                    }
+                }
+            ]
+        }))
+        .unwrap();
+
+        let actual = owned_objects(&pods(), &table);
+
+        assert_eq!(
+            actual,
+            vec![OwnedObject {
+                resource: pods(),
+                namespace: "default".into(),
+                name: "app-7d4b9-x2k".into(),
+                uid: "pod-uid".into(),
+                owners: vec![OwnerReference {
+                    api_version: "apps/v1".into(),
+                    kind: "ReplicaSet".into(),
+                    name: "app-7d4b9".into(),
+                    uid: "rs-uid".into(),
+                    controller: Some(true),
+                    block_owner_deletion: None,
+                }],
+                status: "Running".into(),
+            }]
+        );
+    }
+
+    #[test]
+    fn 最上位の所有者から従属するオブジェクトをツリーにする() {
+        let root = object(deployments(), "app", "deploy-uid", &[]);
+
+        let objects = vec![
+            object(pods(), "app-new-b", "pod-b", &["rs-new"]),
+            object(replicasets(), "app-old", "rs-old", &["deploy-uid"]),
+            object(pods(), "app-new-a", "pod-a", &["rs-new"]),
+            object(replicasets(), "app-new", "rs-new", &["deploy-uid"]),
+            object(pods(), "app-old-a", "pod-c", &["rs-old"]),
+            object(pods(), "other", "pod-d", &["other-uid"]),
+        ];
+
+        let actual: Vec<String> = to_kube_table(&build_tree(&root, &objects), "pod-a")
+            .rows
+            .into_iter()
+            .map(|row| row.row[0].to_string())
+            .collect();
+
+        assert_eq!(
+            actual,
+            vec![
+                "deployments.apps/app",
+                "├─ replicasets.apps/app-new",
+                "│  ├─ \x1b[36mpods/app-new-a\x1b[39m",
+                "│  └─ pods/app-new-b",
+                "└─ replicasets.apps/app-old",
+                "   └─ pods/app-old-a",
+            ]
+        );
+    }
+
+    #[test]
+    fn ログを表示できるリソースにログクエリを設定する() {
+        let root = object(deployments(), "app", "deploy-uid", &[]);
+
+        let actual = to_kube_table(&build_tree(&root, &[]), "deploy-uid");
+
+        assert_eq!(
+            actual.rows[0]
+                .metadata
+                .as_ref()
+                .and_then(|m| m.get(LOG_QUERY_KEY))
+                .map(String::as_str),
+            Some("deployments/app")
+        );
+    }
+}
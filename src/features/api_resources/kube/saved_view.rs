@@ -0,0 +1,550 @@
+use std::path::Path;
+
+use anyhow::{anyhow, bail, Context as _, Result};
+use serde::{Deserialize, Serialize};
+
+use super::ApiResource;
+
+/// Listタブの設定
+///
+/// ```yaml
+/// list:
+///   views:
+///     - name: ingress-stack
+///       key: 1 # Alt+1 で切り替える（省略可）
+///       resources:
+///         - ingresses
+///         - services
+///         - certificates.cert-manager.io
+///         - httproutes
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default)]
+pub struct ListConfig {
+    pub views: Vec<SavedView>,
+}
+
+/// 名前を付けて保存したリソースの組み合わせ
+///
+/// - name: ダイアログに表示する名前
+/// - key: 切り替えに使う数字キー（1〜9）
+/// - resources: kubectlと同じ形式のリソース名（例: pods, deployments.apps）
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SavedView {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub key: Option<u8>,
+    pub resources: Vec<String>,
+}
+
+impl SavedView {
+    /// 数字キーとして使えるときのみ文字を返す
+    pub fn key_char(&self) -> Option<char> {
+        self.key
+            .filter(|key| (1..=9).contains(key))
+            .map(|key| (b'0' + key) as char)
+    }
+}
+
+/// リソース名をクラスタのAPIリソースに解決する
+///
+/// グループを省略したときはコアAPIを優先し、同じリソースが複数のバージョンにあるときは優先バージョンを選ぶ
+pub fn resolve_resources(
+    names: &[String],
+    api_resources: &[ApiResource],
+) -> Result<Vec<ApiResource>> {
+    let mut ret = Vec::new();
+    let mut not_found = Vec::new();
+
+    for name in names {
+        let name = name.trim();
+
+        let mut candidates: Vec<&ApiResource> = api_resources
+            .iter()
+            .filter(|r| r.kubectl_name() == name)
+            .collect();
+
+        if candidates.is_empty() {
+            candidates = api_resources.iter().filter(|r| r.name() == name).collect();
+        }
+
+        let resolved = candidates
+            .iter()
+            .find(|r| r.is_api())
+            .or_else(|| candidates.iter().find(|r| r.is_preferred_version()))
+            .or_else(|| candidates.first());
+
+        match resolved {
+            Some(r) if !ret.contains(*r) => ret.push((*r).clone()),
+            Some(_) => {}
+            None => not_found.push(name),
+        }
+    }
+
+    if !not_found.is_empty() {
+        bail!("Resources not found: {}", not_found.join(", "));
+    }
+
+    Ok(ret)
+}
+
+/// ビューを設定ファイルの`list.views`に追加し、追加後のビューの一覧を返す
+///
+/// ファイルは書き直さずにビューの項目だけを書き足すため、コメントやほかの項目はそのまま残る
+pub fn save_view(path: &Path, view: SavedView) -> Result<Vec<SavedView>> {
+    let contents = if path.exists() {
+        std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read config file {}", path.display()))?
+    } else {
+        String::new()
+    };
+
+    let (contents, views) = insert_view(&contents, view)
+        .with_context(|| format!("Failed to update config file {}", path.display()))?;
+
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)
+            .with_context(|| format!("Failed to create directory {}", dir.display()))?;
+    }
+
+    std::fs::write(path, contents)
+        .with_context(|| format!("Failed to write config file {}", path.display()))?;
+
+    Ok(views)
+}
+
+/// 設定ファイルのうち`list.views`だけを読み込む
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct ConfigFile {
+    list: ListConfig,
+}
+
+impl ConfigFile {
+    fn from_yaml(contents: &str) -> Result<Self> {
+        if contents.trim().is_empty() {
+            return Ok(Self::default());
+        }
+
+        Ok(serde_yaml::from_str(contents)?)
+    }
+}
+
+/// 設定ファイルの内容にビューを書き足す
+///
+/// 空いている数字キーがあればビューに割り当てる。
+/// 同じ名前のビューがあるときや、書き足す位置が分からないときはエラーを返す
+fn insert_view(contents: &str, mut view: SavedView) -> Result<(String, Vec<SavedView>)> {
+    let mut views = ConfigFile::from_yaml(contents)?.list.views;
+
+    if views.iter().any(|v| v.name == view.name) {
+        bail!("View '{}' already exists", view.name);
+    }
+
+    view.key = (1..=9).find(|key| views.iter().all(|v| v.key != Some(*key)));
+
+    let item = serde_yaml::to_string(&[&view])?;
+
+    views.push(view);
+
+    let manual = || {
+        anyhow!(
+            "Cannot add the view without rewriting the file. Add the following to list.views:\n{}",
+            item.trim_end()
+        )
+    };
+
+    let updated = append_view(contents, &item).ok_or_else(manual)?;
+
+    // 書き足した結果が想定どおりに読み込めるときのみ書き込む
+    match ConfigFile::from_yaml(&updated) {
+        Ok(file) if file.list.views == views => Ok((updated, views)),
+        _ => Err(manual()),
+    }
+}
+
+/// `list.views`の末尾にシーケンスの項目を書き足す
+///
+/// ブロック形式で書かれていないときはNoneを返す
+fn append_view(contents: &str, item: &str) -> Option<String> {
+    let mut lines: Vec<String> = contents.lines().map(ToString::to_string).collect();
+
+    let Some(list) = lines
+        .iter()
+        .position(|line| is_key_line(line, "list", Some(0)))
+    else {
+        lines.push("list:".into());
+        lines.push("  views:".into());
+        lines.extend(indent_lines(item, 2));
+
+        return Some(join_lines(lines));
+    };
+
+    let list_end = block_end(&lines, list, 0);
+
+    let views = (list + 1..list_end).find(|i| is_key_line(&lines[*i], "views", None));
+
+    let Some(views) = views else {
+        let indent = (list + 1..list_end)
+            .find(|i| is_content_line(&lines[*i]))
+            .map(|i| indent_of(&lines[i]))
+            .unwrap_or(2);
+
+        let mut insert = vec![format!("{}views:", " ".repeat(indent))];
+        insert.extend(indent_lines(item, indent));
+
+        lines.splice(list + 1..list + 1, insert);
+
+        return Some(join_lines(lines));
+    };
+
+    let views_indent = indent_of(&lines[views]);
+
+    // YAMLではシーケンスの項目をキーと同じインデントで書ける
+    let views_end = (views + 1..list_end)
+        .find(|i| {
+            let line = &lines[*i];
+
+            is_content_line(line)
+                && (indent_of(line) < views_indent
+                    || (indent_of(line) == views_indent && !line.trim_start().starts_with('-')))
+        })
+        .unwrap_or(list_end);
+
+    let item_indent = (views + 1..views_end)
+        .find(|i| lines[*i].trim_start().starts_with('-'))
+        .map(|i| indent_of(&lines[i]))
+        .unwrap_or(views_indent + 2);
+
+    let last = (views + 1..views_end)
+        .rev()
+        .find(|i| is_content_line(&lines[*i]))
+        .unwrap_or(views);
+
+    lines.splice(last + 1..last + 1, indent_lines(item, item_indent));
+
+    Some(join_lines(lines))
+}
+
+/// 値が空の`key:`の行かどうか（行末のコメントは許容する）
+fn is_key_line(line: &str, key: &str, indent: Option<usize>) -> bool {
+    if indent.is_some_and(|indent| indent_of(line) != indent) {
+        return false;
+    }
+
+    line.trim_start()
+        .strip_prefix(key)
+        .and_then(|rest| rest.strip_prefix(':'))
+        .is_some_and(|rest| {
+            let rest = rest.trim();
+            rest.is_empty() || rest.starts_with('#')
+        })
+}
+
+/// 空行とコメント行以外の行
+fn is_content_line(line: &str) -> bool {
+    let trimmed = line.trim_start();
+    !trimmed.is_empty() && !trimmed.starts_with('#')
+}
+
+fn indent_of(line: &str) -> usize {
+    line.len() - line.trim_start_matches(' ').len()
+}
+
+/// インデントが`indent`より深い行が続く範囲の終わり
+fn block_end(lines: &[String], start: usize, indent: usize) -> usize {
+    (start + 1..lines.len())
+        .find(|i| is_content_line(&lines[*i]) && indent_of(&lines[*i]) <= indent)
+        .unwrap_or(lines.len())
+}
+
+fn indent_lines(text: &str, indent: usize) -> Vec<String> {
+    text.lines()
+        .map(|line| format!("{}{}", " ".repeat(indent), line))
+        .collect()
+}
+
+fn join_lines(lines: Vec<String>) -> String {
+    let mut contents = lines.join("\n");
+    contents.push('\n');
+    contents
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+    use kube::discovery::Scope;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn api(name: &str) -> ApiResource {
+        ApiResource::Api {
+            name: name.into(),
+            version: "v1".into(),
+            scope: Scope::Namespaced,
+        }
+    }
+
+    fn apis(name: &str, group: &str, version: &str, preferred_version: bool) -> ApiResource {
+        ApiResource::Apis {
+            name: name.into(),
+            group: group.into(),
+            version: version.into(),
+            preferred_version,
+            scope: Scope::Namespaced,
+        }
+    }
+
+    fn api_resources() -> Vec<ApiResource> {
+        vec![
+            api("services"),
+            api("events"),
+            apis("events", "events.k8s.io", "v1", true),
+            apis("ingresses", "networking.k8s.io", "v1", true),
+            apis("certificates", "cert-manager.io", "v1alpha2", false),
+            apis("certificates", "cert-manager.io", "v1", true),
+        ]
+    }
+
+    #[test]
+    fn リソース名をapiリソースに解決する() {
+        let names = vec![
+            "ingresses".to_string(),
+            "services".to_string(),
+            "certificates.cert-manager.io".to_string(),
+        ];
+
+        let actual = resolve_resources(&names, &api_resources()).unwrap();
+
+        assert_eq!(
+            actual,
+            vec![
+                apis("ingresses", "networking.k8s.io", "v1", true),
+                api("services"),
+                apis("certificates", "cert-manager.io", "v1", true),
+            ]
+        );
+    }
+
+    #[test]
+    fn グループを省略したときコアapiを優先する() {
+        let actual = resolve_resources(&["events".to_string()], &api_resources()).unwrap();
+
+        assert_eq!(actual, vec![api("events")]);
+    }
+
+    #[test]
+    fn 存在しないリソースがあるときエラーを返す() {
+        let names = vec!["services".to_string(), "httproutes".to_string()];
+
+        let actual = resolve_resources(&names, &api_resources());
+
+        assert_eq!(
+            actual.unwrap_err().to_string(),
+            "Resources not found: httproutes"
+        );
+    }
+
+    #[test]
+    fn キーが1から9のときのみ文字を返す() {
+        let view = |key| SavedView {
+            name: "view".into(),
+            key,
+            resources: vec![],
+        };
+
+        assert_eq!(view(Some(1)).key_char(), Some('1'));
+        assert_eq!(view(Some(0)).key_char(), None);
+        assert_eq!(view(Some(10)).key_char(), None);
+        assert_eq!(view(None).key_char(), None);
+    }
+
+    fn saved_view(name: &str, resources: &[&str]) -> SavedView {
+        SavedView {
+            name: name.into(),
+            key: None,
+            resources: resources.iter().map(ToString::to_string).collect(),
+        }
+    }
+
+    #[test]
+    fn コメントとほかの設定を残してビューを書き足す() {
+        let contents = indoc! {
+            "
+            # kubetui config
+            clipboard: osc52 # osc52 for ssh
+            list:
+              views:
+                # ingress
+                - name: ingress-stack
+                  key: 1
+                  resources:
+                    - ingresses
+
+            secret:
+              mask: true
+            "
+        };
+
+        let (actual, views) =
+            insert_view(contents, saved_view("workloads", &["deployments.apps"])).unwrap();
+
+        let expected = indoc! {
+            "
+            # kubetui config
+            clipboard: osc52 # osc52 for ssh
+            list:
+              views:
+                # ingress
+                - name: ingress-stack
+                  key: 1
+                  resources:
+                    - ingresses
+                - name: workloads
+                  key: 2
+                  resources:
+                  - deployments.apps
+
+            secret:
+              mask: true
+            "
+        };
+
+        assert_eq!(actual, expected);
+
+        assert_eq!(
+            views,
+            vec![
+                SavedView {
+                    name: "ingress-stack".into(),
+                    key: Some(1),
+                    resources: vec!["ingresses".into()],
+                },
+                SavedView {
+                    key: Some(2),
+                    ..saved_view("workloads", &["deployments.apps"])
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn listがないときは末尾に書き足す() {
+        let contents = indoc! {
+            "
+            # kubetui config
+            clipboard: osc52
+            "
+        };
+
+        let (actual, _) = insert_view(contents, saved_view("workloads", &["pods"])).unwrap();
+
+        let expected = indoc! {
+            "
+            # kubetui config
+            clipboard: osc52
+            list:
+              views:
+              - name: workloads
+                key: 1
+                resources:
+                - pods
+            "
+        };
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn viewsがないときはlistの先頭に書き足す() {
+        let contents = indoc! {
+            "
+            list:
+              # views
+            yaml:
+              hide_status: true
+            "
+        };
+
+        let (actual, _) = insert_view(contents, saved_view("workloads", &["pods"])).unwrap();
+
+        let expected = indoc! {
+            "
+            list:
+              views:
+              - name: workloads
+                key: 1
+                resources:
+                - pods
+              # views
+            yaml:
+              hide_status: true
+            "
+        };
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn 設定ファイルが空のときビューだけを書き込む() {
+        let (actual, _) = insert_view("", saved_view("workloads", &["pods"])).unwrap();
+
+        let expected = indoc! {
+            "
+            list:
+              views:
+              - name: workloads
+                key: 1
+                resources:
+                - pods
+            "
+        };
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn フロー形式のときは書き足さずにエラーを返す() {
+        let contents = "list: { views: [] }\n";
+
+        let actual = insert_view(contents, saved_view("workloads", &["pods"]));
+
+        assert!(actual
+            .unwrap_err()
+            .to_string()
+            .starts_with("Cannot add the view without rewriting the file."));
+    }
+
+    #[test]
+    fn 数字キーがすべて使われているときはキーを割り当てない() {
+        let contents: String = (1..=9)
+            .map(|key| format!("- {{ name: v{key}, key: {key}, resources: [pods] }}\n"))
+            .fold("list:\n  views:\n".to_string(), |acc, line| {
+                acc + "  " + &line
+            });
+
+        let (_, views) = insert_view(&contents, saved_view("workloads", &["pods"])).unwrap();
+
+        assert_eq!(views.last().unwrap().key, None);
+    }
+
+    #[test]
+    fn 同じ名前のビューがあるときエラーを返す() {
+        let contents = indoc! {
+            "
+            list:
+              views:
+              - name: workloads
+                resources: [pods]
+            "
+        };
+
+        let actual = insert_view(contents, saved_view("workloads", &["services"]));
+
+        assert_eq!(
+            actual.unwrap_err().to_string(),
+            "View 'workloads' already exists"
+        );
+    }
+}
@@ -0,0 +1,287 @@
+use std::{borrow::Cow, sync::Arc};
+
+use anyhow::{bail, Result};
+use nom::{
+    branch::alt,
+    bytes::complete::{is_not, tag},
+    character::complete::{anychar, char, multispace0, multispace1},
+    combinator::{all_consuming, map, verify},
+    error::{ContextError, ParseError},
+    multi::{fold_many0, separated_list0},
+    sequence::{delimited, preceded, separated_pair},
+    IResult,
+};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use tokio::sync::RwLock;
+
+/// Listタブのセレクタ入力欄に入力されたクエリ
+pub type SharedListQuery = Arc<RwLock<String>>;
+
+/// ラベルの名前（プレフィックス付き）
+const LABEL_KEY: &str =
+    r"([a-zA-Z0-9]([-a-zA-Z0-9.]*[a-zA-Z0-9])?/)?[a-zA-Z0-9]([-a-zA-Z0-9_.]*[a-zA-Z0-9])?";
+
+/// ラベルの値
+const LABEL_VALUE: &str = r"([a-zA-Z0-9]([-a-zA-Z0-9_.]*[a-zA-Z0-9])?)?";
+
+static LABEL_REQUIREMENT: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(&format!(
+        r"^(!?{key}|{key}\s*(=|==|!=)\s*{value}|{key}\s+(in|notin)\s+\(\s*{value}(\s*,\s*{value})*\s*\))$",
+        key = LABEL_KEY,
+        value = LABEL_VALUE
+    ))
+    .expect("Invalid label requirement regex")
+});
+
+static FIELD_REQUIREMENT: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^[a-zA-Z0-9.\[\]]+\s*(=|==|!=)\s*[^,=!]*$")
+        .expect("Invalid field requirement regex")
+});
+
+/// テーブルを取得するときに指定するラベルセレクタとフィールドセレクタ
+///
+/// クエリの形式はログクエリと同じ（例: `labels:app=checkout fields:status.phase=Running`）
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ListSelector {
+    pub label_selector: Option<String>,
+    pub field_selector: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+enum SelectorAttribute<'a> {
+    LabelSelector(Cow<'a, str>),
+    FieldSelector(Cow<'a, str>),
+}
+
+impl ListSelector {
+    pub fn parse(query: &str) -> Result<Self> {
+        use nom::{
+            error::{convert_error, VerboseError},
+            Err,
+        };
+
+        let attrs = match parse_attributes::<VerboseError<_>>(query) {
+            Ok((_, attrs)) => attrs,
+            Err(Err::Error(err) | Err::Failure(err)) => bail!(convert_error(query, err)),
+            Err(err) => bail!(err.to_string()),
+        };
+
+        let mut labels = Vec::new();
+        let mut fields = Vec::new();
+
+        for attr in attrs {
+            match attr {
+                SelectorAttribute::LabelSelector(selector) => {
+                    for requirement in split_requirements(&selector) {
+                        if !LABEL_REQUIREMENT.is_match(requirement) {
+                            bail!("Invalid label selector: '{}'", requirement);
+                        }
+
+                        labels.push(requirement.to_string());
+                    }
+                }
+                SelectorAttribute::FieldSelector(selector) => {
+                    for requirement in split_requirements(&selector) {
+                        if !FIELD_REQUIREMENT.is_match(requirement) {
+                            bail!("Invalid field selector: '{}'", requirement);
+                        }
+
+                        fields.push(requirement.to_string());
+                    }
+                }
+            }
+        }
+
+        let join = |vec: Vec<String>| (!vec.is_empty()).then(|| vec.join(","));
+
+        Ok(Self {
+            label_selector: join(labels),
+            field_selector: join(fields),
+        })
+    }
+
+    /// URLに付けるクエリパラメータ（セレクタがないときは空文字）
+    pub fn query_params(&self) -> String {
+        let mut serializer = form_urlencoded::Serializer::new(String::new());
+
+        if let Some(label_selector) = &self.label_selector {
+            serializer.append_pair("labelSelector", label_selector);
+        }
+
+        if let Some(field_selector) = &self.field_selector {
+            serializer.append_pair("fieldSelector", field_selector);
+        }
+
+        let params = serializer.finish();
+
+        if params.is_empty() {
+            params
+        } else {
+            format!("?{}", params)
+        }
+    }
+}
+
+/// 括弧の外にあるカンマで分割する
+fn split_requirements(selector: &str) -> Vec<&str> {
+    let mut ret = Vec::new();
+    let mut depth = 0;
+    let mut start = 0;
+
+    for (i, c) in selector.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            ',' if depth == 0 => {
+                ret.push(selector[start..i].trim());
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+
+    ret.push(selector[start..].trim());
+
+    ret
+}
+
+fn non_space<'a, E: ParseError<&'a str> + ContextError<&'a str>>(
+    s: &'a str,
+) -> IResult<&'a str, Cow<'a, str>, E> {
+    let (remaining, value) = verify(is_not(" \t\r\n"), |s: &str| !s.starts_with(['"', '\'']))(s)?;
+    Ok((remaining, Cow::Borrowed(value)))
+}
+
+fn quoted<'a, E: ParseError<&'a str> + ContextError<&'a str>>(
+    s: &'a str,
+) -> IResult<&'a str, Cow<'a, str>, E> {
+    fn inner<'a, E: ParseError<&'a str> + ContextError<&'a str>>(
+        quote: char,
+        not_quote_slash: &'static str,
+    ) -> impl FnMut(&'a str) -> IResult<&'a str, String, E> {
+        delimited(
+            char(quote),
+            fold_many0(
+                alt((
+                    preceded(char('\\'), map(anychar, |c| c.to_string())),
+                    map(is_not(not_quote_slash), ToString::to_string),
+                )),
+                String::default,
+                |mut s, parsed| {
+                    s.push_str(&parsed);
+                    s
+                },
+            ),
+            char(quote),
+        )
+    }
+
+    let (remaining, value) = alt((inner('"', "\"\\"), inner('\'', "'\\")))(s)?;
+
+    Ok((remaining, Cow::Owned(value)))
+}
+
+fn selector<'a, E: ParseError<&'a str> + ContextError<&'a str>>(
+    s: &'a str,
+) -> IResult<&'a str, Cow<'a, str>, E> {
+    verify(alt((quoted, non_space)), |s: &Cow<str>| {
+        !s.trim().is_empty()
+    })(s)
+}
+
+fn label_selector<'a, E: ParseError<&'a str> + ContextError<&'a str>>(
+    s: &'a str,
+) -> IResult<&'a str, SelectorAttribute<'a>, E> {
+    let (remaining, (_, value)) =
+        separated_pair(alt((tag("labels"), tag("label"))), char(':'), selector)(s)?;
+    Ok((remaining, SelectorAttribute::LabelSelector(value)))
+}
+
+fn field_selector<'a, E: ParseError<&'a str> + ContextError<&'a str>>(
+    s: &'a str,
+) -> IResult<&'a str, SelectorAttribute<'a>, E> {
+    let (remaining, (_, value)) =
+        separated_pair(alt((tag("fields"), tag("field"))), char(':'), selector)(s)?;
+    Ok((remaining, SelectorAttribute::FieldSelector(value)))
+}
+
+fn parse_attributes<'a, E: ParseError<&'a str> + ContextError<&'a str>>(
+    s: &'a str,
+) -> IResult<&'a str, Vec<SelectorAttribute<'a>>, E> {
+    all_consuming(delimited(
+        multispace0,
+        separated_list0(multispace1, alt((label_selector, field_selector))),
+        multispace0,
+    ))(s)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("", None, None)]
+    #[case("labels:app=checkout", Some("app=checkout"), None)]
+    #[case(
+        "label:app!=checkout field:metadata.name=foo",
+        Some("app!=checkout"),
+        Some("metadata.name=foo")
+    )]
+    #[case(
+        "labels:'env in (prod, stg),!canary'",
+        Some("env in (prod, stg),!canary"),
+        None
+    )]
+    #[case(
+        "labels:app=a labels:app.kubernetes.io/part-of=shop",
+        Some("app=a,app.kubernetes.io/part-of=shop"),
+        None
+    )]
+    #[case("fields:status.phase==Running", None, Some("status.phase==Running"))]
+    fn セレクタをパースする(
+        #[case] query: &str,
+        #[case] label_selector: Option<&str>,
+        #[case] field_selector: Option<&str>,
+    ) {
+        let actual = ListSelector::parse(query).unwrap();
+
+        assert_eq!(
+            actual,
+            ListSelector {
+                label_selector: label_selector.map(Into::into),
+                field_selector: field_selector.map(Into::into),
+            }
+        );
+    }
+
+    #[rstest]
+    #[case("app=checkout")]
+    #[case("labels:")]
+    #[case("labels:app=checkout pod:app")]
+    #[case("labels:app=in=valid")]
+    #[case("labels:'env in prod'")]
+    #[case("fields:status.phase")]
+    fn 不正なクエリのときエラーを返す(#[case] query: &str) {
+        assert!(ListSelector::parse(query).is_err());
+    }
+
+    #[test]
+    fn クエリパラメータをエンコードする() {
+        let selector =
+            ListSelector::parse("labels:'env in (prod),app=a' fields:status.phase!=Running")
+                .unwrap();
+
+        assert_eq!(
+            selector.query_params(),
+            "?labelSelector=env+in+%28prod%29%2Capp%3Da&fieldSelector=status.phase%21%3DRunning"
+        );
+    }
+
+    #[test]
+    fn セレクタがないときクエリパラメータは空() {
+        assert_eq!(ListSelector::default().query_params(), "");
+    }
+}
@@ -1,19 +1,43 @@
 use anyhow::Result;
 
-use crate::{message::Message, workers::kube::message::Kube};
+use crate::{kube::table::KubeTable, message::Message, workers::kube::message::Kube};
 
-use super::kube::ApiResource;
+use super::kube::{ApiResource, ApiTable, SavedView};
 
 #[derive(Debug)]
 pub enum ApiRequest {
     Get,
     Set(Vec<ApiResource>),
+    /// ラベルセレクタとフィールドセレクタのクエリ
+    Query(String),
+    /// 保存したビューのリソース名
+    SetView(Vec<String>),
+    /// 選択中のリソースを名前を付けてビューとして保存する
+    SaveView(String),
+    /// printer columnsとstatus.conditionsの要約
+    Conditions {
+        resource: ApiResource,
+        name: String,
+        namespace: String,
+    },
+    /// 所有者と従属するオブジェクトのツリー
+    OwnerTree {
+        resource: ApiResource,
+        name: String,
+        namespace: String,
+    },
 }
 
 #[derive(Debug)]
 pub enum ApiResponse {
     Get(Result<Vec<ApiResource>>),
-    Poll(Result<Vec<String>>),
+    Poll(Result<Vec<ApiTable>>),
+    /// ビューのリソース名を解決した結果
+    SetView(Result<Vec<ApiResource>>),
+    /// 保存後のビューの一覧
+    SaveView(Result<Vec<SavedView>>),
+    Conditions(Result<Vec<String>>),
+    OwnerTree(Result<KubeTable>),
 }
 
 #[derive(Debug)]
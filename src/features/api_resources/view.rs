@@ -1,6 +1,11 @@
+mod conditions;
+mod dialog;
+mod kind;
+mod owner_tree;
+mod saved_view;
+mod selector;
 mod tab;
 mod widget;
-mod dialog;
 
+pub use saved_view::{switch_view, view_items};
 pub use tab::*;
-
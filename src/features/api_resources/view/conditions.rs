@@ -0,0 +1,22 @@
+use std::{cell::RefCell, rc::Rc};
+
+use crate::{
+    clipboard::Clipboard,
+    features::component_id::LIST_CONDITIONS_DIALOG_ID,
+    ui::widget::{Text, Widget, WidgetBase},
+};
+
+/// printer columnsとstatus.conditionsの要約を表示するダイアログ
+pub fn conditions_dialog(clipboard: &Option<Rc<RefCell<Clipboard>>>) -> Widget<'static> {
+    let builder = Text::builder()
+        .id(LIST_CONDITIONS_DIALOG_ID)
+        .widget_base(WidgetBase::builder().title("Conditions").build());
+
+    if let Some(cb) = clipboard {
+        builder.clipboard(cb.clone())
+    } else {
+        builder
+    }
+    .build()
+    .into()
+}
@@ -3,7 +3,7 @@ use crossbeam::channel::Sender;
 use crate::{
     features::{
         api_resources::message::ApiRequest,
-        component_id::{LIST_DIALOG_ID, LIST_WIDGET_ID},
+        component_id::{LIST_DIALOG_ID, LIST_KIND_WIDGET_ID, LIST_WIDGET_ID},
     },
     message::Message,
     ui::{
@@ -47,7 +47,8 @@ pub fn dialog_widget(tx: &Sender<Message>) -> Widget<'static> {
             }
 
             if widget.selected_items().is_empty() {
-                w.widget_clear(LIST_WIDGET_ID)
+                w.widget_clear(LIST_KIND_WIDGET_ID);
+                w.widget_clear(LIST_WIDGET_ID);
             }
 
             EventResult::Nop
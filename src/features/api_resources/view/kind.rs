@@ -0,0 +1,29 @@
+use crate::{
+    features::component_id::{LIST_KIND_WIDGET_ID, LIST_WIDGET_ID},
+    ui::{
+        event::EventResult,
+        widget::{List, LiteralItem, Widget, WidgetBase},
+        Window,
+    },
+};
+
+/// Listタブで表示するリソースの種類
+///
+/// カーソルのある種類のテーブルを右側に表示し、Enterでテーブルに移動する
+pub fn kind_widget() -> Widget<'static> {
+    List::builder()
+        .id(LIST_KIND_WIDGET_ID)
+        .widget_base(WidgetBase::builder().title("Kind").build())
+        .on_select(on_select())
+        .build()
+        .into()
+}
+
+fn on_select() -> impl Fn(&mut Window, &LiteralItem) -> EventResult {
+    |w: &mut Window, _: &LiteralItem| {
+        w.widget_clear(LIST_WIDGET_ID);
+        w.activate_widget_by_id(LIST_WIDGET_ID);
+
+        EventResult::Nop
+    }
+}
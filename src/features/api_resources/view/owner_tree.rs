@@ -0,0 +1,90 @@
+use crossbeam::channel::Sender;
+
+use crate::{
+    features::{
+        api_resources::kube::LOG_QUERY_KEY,
+        component_id::{
+            LIST_OWNER_TREE_DIALOG_ID, POD_LOG_QUERY_WIDGET_ID, POD_LOG_WIDGET_ID, POD_TAB_ID,
+            YAML_DIALOG_ID,
+        },
+        get::message::GetRequest,
+        pod::{
+            kube::{LogConfig, LogPrefixType},
+            message::LogMessage,
+        },
+    },
+    kube::context::Namespace,
+    message::Message,
+    ui::{
+        event::EventResult,
+        widget::{Item, SelectedItem, Table, TableItem, Widget, WidgetBase, WidgetTrait as _},
+        Window,
+    },
+};
+
+/// 所有者と従属するオブジェクトをツリーで表示するダイアログ
+///
+/// Enterでyamlを表示し、lでPodタブにログを表示する
+pub fn owner_tree_dialog(tx: &Sender<Message>) -> Widget<'static> {
+    Table::builder()
+        .id(LIST_OWNER_TREE_DIALOG_ID)
+        .widget_base(WidgetBase::builder().title("Owner Tree").build())
+        .on_select(on_select(tx.clone()))
+        .action('l', open_logs(tx.clone()))
+        .build()
+        .into()
+}
+
+fn on_select(tx: Sender<Message>) -> impl Fn(&mut Window, &TableItem) -> EventResult {
+    move |w: &mut Window, v: &TableItem| {
+        let Some(req) = v.metadata.as_ref().and_then(GetRequest::from_metadata) else {
+            return EventResult::Ignore;
+        };
+
+        tx.send(req.into()).expect("Failed to send GetRequest");
+
+        w.widget_clear(YAML_DIALOG_ID);
+        w.open_dialog(YAML_DIALOG_ID);
+
+        EventResult::Nop
+    }
+}
+
+fn open_logs(tx: Sender<Message>) -> impl Fn(&mut Window) -> EventResult {
+    move |w: &mut Window| {
+        let widget = w.find_widget(LIST_OWNER_TREE_DIALOG_ID);
+
+        let Some(SelectedItem::TableRow {
+            metadata: Some(metadata),
+            ..
+        }) = widget.widget_item()
+        else {
+            return EventResult::Ignore;
+        };
+
+        let (Some(query), Some(namespace)) =
+            (metadata.get(LOG_QUERY_KEY), metadata.get("namespace"))
+        else {
+            return EventResult::Ignore;
+        };
+
+        w.find_widget_mut(POD_LOG_QUERY_WIDGET_ID)
+            .update_widget_item(Item::Single(query.to_string().into()));
+
+        w.widget_clear(POD_LOG_WIDGET_ID);
+
+        let config = LogConfig::new(
+            query.to_string(),
+            Namespace(vec![namespace.to_string()]),
+            LogPrefixType::PodAndContainer,
+        );
+
+        tx.send(LogMessage::Request(config).into())
+            .expect("Failed to send LogMessage::Request");
+
+        w.close_dialog();
+        w.activate_tab_by_id(POD_TAB_ID);
+
+        EventResult::Nop
+    }
+}
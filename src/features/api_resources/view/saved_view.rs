@@ -0,0 +1,137 @@
+use std::collections::BTreeMap;
+
+use crossbeam::channel::Sender;
+use ratatui::crossterm::event::KeyCode;
+
+use crate::{
+    features::{
+        api_resources::{kube::SavedView, message::ApiRequest},
+        component_id::{
+            LIST_SAVE_VIEW_DIALOG_ID, LIST_SAVE_VIEW_RESULT_DIALOG_ID, LIST_TAB_ID,
+            LIST_VIEW_DIALOG_ID,
+        },
+    },
+    logger,
+    message::{Message, UserEvent},
+    ui::{
+        event::EventResult,
+        widget::{
+            InputFormBuilder, Item, LiteralItem, SelectedItem, SingleSelect, Text, Widget,
+            WidgetBase, WidgetTrait as _,
+        },
+        Window,
+    },
+};
+
+/// 設定ファイルで定義したビューを選択するダイアログ
+pub fn view_dialog(tx: &Sender<Message>, views: &[SavedView]) -> Widget<'static> {
+    let mut widget: Widget = SingleSelect::builder()
+        .id(LIST_VIEW_DIALOG_ID)
+        .widget_base(WidgetBase::builder().title("View").build())
+        .on_select(on_select(tx.clone()))
+        .build()
+        .into();
+
+    widget.update_widget_item(Item::Array(view_items(views)));
+
+    widget
+}
+
+/// ビューを選択するダイアログの項目
+pub fn view_items(views: &[SavedView]) -> Vec<LiteralItem> {
+    views
+        .iter()
+        .map(|view| {
+            let Ok(json) = serde_json::to_string(&view.resources) else {
+                unreachable!()
+            };
+
+            let item = match view.key_char() {
+                Some(key) => format!("{} (Alt+{})", view.name, key),
+                None => view.name.to_string(),
+            };
+
+            LiteralItem::new(item, Some(BTreeMap::from([("resources".into(), json)])))
+        })
+        .collect()
+}
+
+/// 選択中のリソースをビューとして保存するときの名前を入力するダイアログ
+pub fn save_view_dialog(tx: &Sender<Message>) -> Widget<'static> {
+    InputFormBuilder::default()
+        .id(LIST_SAVE_VIEW_DIALOG_ID)
+        .widget_base(
+            WidgetBase::builder()
+                .title("Save selected resources as view (name)")
+                .build(),
+        )
+        .actions(UserEvent::from(KeyCode::Enter), exec_save_view(tx.clone()))
+        .build()
+        .into()
+}
+
+/// ビューを保存した結果を表示するダイアログ
+pub fn save_view_result_dialog() -> Widget<'static> {
+    Text::builder()
+        .id(LIST_SAVE_VIEW_RESULT_DIALOG_ID)
+        .widget_base(WidgetBase::builder().title("Save View").build())
+        .build()
+        .into()
+}
+
+fn exec_save_view(tx: Sender<Message>) -> impl Fn(&mut Window) -> EventResult {
+    move |w: &mut Window| {
+        let widget = w.find_widget_mut(LIST_SAVE_VIEW_DIALOG_ID);
+
+        let Some(SelectedItem::Literal { metadata: _, item }) = widget.widget_item() else {
+            return EventResult::Ignore;
+        };
+
+        let name = item.trim();
+
+        if name.is_empty() {
+            return EventResult::Ignore;
+        }
+
+        tx.send(ApiRequest::SaveView(name.to_string()).into())
+            .expect("Failed to send ApiRequest::SaveView");
+
+        w.widget_clear(LIST_SAVE_VIEW_RESULT_DIALOG_ID);
+        w.open_dialog(LIST_SAVE_VIEW_RESULT_DIALOG_ID);
+
+        EventResult::Nop
+    }
+}
+
+fn on_select(tx: Sender<Message>) -> impl Fn(&mut Window, &LiteralItem) -> EventResult {
+    move |w, v| {
+        logger!(info, "Select Item: {:?}", v);
+
+        w.close_dialog();
+
+        let Some(resources) = v.metadata.as_ref().and_then(|m| m.get("resources")) else {
+            unreachable!()
+        };
+
+        let Ok(resources) = serde_json::from_str(resources) else {
+            unreachable!()
+        };
+
+        switch_view(tx.clone(), resources)(w)
+    }
+}
+
+/// ビューのリソースをListタブに表示する
+pub fn switch_view(
+    tx: Sender<Message>,
+    resources: Vec<String>,
+) -> impl Fn(&mut Window) -> EventResult {
+    move |w: &mut Window| {
+        tx.send(ApiRequest::SetView(resources.clone()).into())
+            .expect("Failed to send ApiRequest::SetView");
+
+        w.activate_tab_by_id(LIST_TAB_ID);
+
+        EventResult::Nop
+    }
+}
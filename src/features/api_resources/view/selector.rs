@@ -0,0 +1,42 @@
+use crossbeam::channel::Sender;
+use ratatui::crossterm::event::KeyCode;
+
+use crate::{
+    features::{
+        api_resources::message::ApiRequest,
+        component_id::{LIST_SELECTOR_WIDGET_ID, LIST_WIDGET_ID},
+    },
+    message::{Message, UserEvent},
+    ui::{
+        event::EventResult,
+        widget::{InputFormBuilder, SelectedItem, Widget, WidgetBase, WidgetTrait as _},
+        Window,
+    },
+};
+
+/// ラベルセレクタとフィールドセレクタの入力欄（例: `labels:app=checkout fields:status.phase=Running`）
+pub fn selector_widget(tx: &Sender<Message>) -> Widget<'static> {
+    InputFormBuilder::default()
+        .id(LIST_SELECTOR_WIDGET_ID)
+        .widget_base(WidgetBase::builder().title("Selector").build())
+        .actions(UserEvent::from(KeyCode::Enter), exec_query(tx.clone()))
+        .build()
+        .into()
+}
+
+fn exec_query(tx: Sender<Message>) -> impl Fn(&mut Window) -> EventResult {
+    move |w: &mut Window| {
+        let Some(SelectedItem::Literal { item, .. }) =
+            w.find_widget(LIST_SELECTOR_WIDGET_ID).widget_item()
+        else {
+            return EventResult::Ignore;
+        };
+
+        w.widget_clear(LIST_WIDGET_ID);
+
+        tx.send(ApiRequest::Query(item).into())
+            .expect("Failed to send ApiRequest::Query");
+
+        EventResult::Ignore
+    }
+}
@@ -1,11 +1,11 @@
-use crossbeam::channel::Sender;
-use ratatui::prelude::Constraint;
-
 use std::{cell::RefCell, rc::Rc};
 
+use crossbeam::channel::Sender;
+use ratatui::prelude::{Constraint, Direction};
+
 use crate::{
     clipboard::Clipboard,
-    features::component_id::LIST_TAB_ID,
+    features::{api_resources::kube::SavedView, component_id::LIST_TAB_ID},
     message::Message,
     ui::{
         tab::{LayoutElement, NestedLayoutElement, NestedWidgetLayout, TabLayout},
@@ -14,11 +14,24 @@ use crate::{
     },
 };
 
-use super::{dialog::dialog_widget, widget::list_widget};
+use super::{
+    conditions::conditions_dialog,
+    dialog::dialog_widget,
+    kind::kind_widget,
+    owner_tree::owner_tree_dialog,
+    saved_view::{save_view_dialog, save_view_result_dialog, view_dialog},
+    selector::selector_widget,
+    widget::list_widget,
+};
 
 pub struct ListTab {
     pub tab: Tab<'static>,
     pub dialog: Widget<'static>,
+    pub view_dialog: Widget<'static>,
+    pub save_view_dialog: Widget<'static>,
+    pub save_view_result_dialog: Widget<'static>,
+    pub conditions_dialog: Widget<'static>,
+    pub owner_tree_dialog: Widget<'static>,
 }
 
 impl ListTab {
@@ -26,22 +39,50 @@ impl ListTab {
         title: &'static str,
         tx: &Sender<Message>,
         clipboard: &Option<Rc<RefCell<Clipboard>>>,
+        split_direction: Direction,
+        saved_views: &[SavedView],
     ) -> Self {
-        let list_widget = list_widget(tx, clipboard);
-
-        let layout = TabLayout::new(
-            |_| {
-                NestedWidgetLayout::default().nested_widget_layout([NestedLayoutElement(
-                    Constraint::Percentage(100),
-                    LayoutElement::WidgetIndex(0),
-                )])
-            },
-            Default::default(),
-        );
+        let kind_widget = kind_widget();
+        let selector_widget = selector_widget(tx);
+        let list_widget = list_widget(tx);
+
+        let layout = TabLayout::new(layout, split_direction);
 
         ListTab {
-            tab: Tab::new(LIST_TAB_ID, title, [list_widget], layout),
+            tab: Tab::new(
+                LIST_TAB_ID,
+                title,
+                [kind_widget, selector_widget, list_widget],
+                layout,
+            ),
             dialog: dialog_widget(tx),
+            view_dialog: view_dialog(tx, saved_views),
+            save_view_dialog: save_view_dialog(tx),
+            save_view_result_dialog: save_view_result_dialog(),
+            conditions_dialog: conditions_dialog(clipboard),
+            owner_tree_dialog: owner_tree_dialog(tx),
         }
     }
 }
+
+fn layout(split_direction: Direction) -> NestedWidgetLayout {
+    NestedWidgetLayout::default()
+        .direction(split_direction)
+        .nested_widget_layout([
+            NestedLayoutElement(Constraint::Percentage(25), LayoutElement::WidgetIndex(0)),
+            NestedLayoutElement(
+                Constraint::Percentage(75),
+                LayoutElement::NestedElement(
+                    NestedWidgetLayout::default()
+                        .direction(Direction::Vertical)
+                        .nested_widget_layout([
+                            NestedLayoutElement(
+                                Constraint::Length(3),
+                                LayoutElement::WidgetIndex(1),
+                            ),
+                            NestedLayoutElement(Constraint::Min(3), LayoutElement::WidgetIndex(2)),
+                        ]),
+                ),
+            ),
+        ])
+}
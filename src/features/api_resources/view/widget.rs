@@ -1,53 +1,158 @@
-use std::{cell::RefCell, rc::Rc};
+use std::collections::BTreeMap;
 
 use crossbeam::channel::Sender;
 
 use crate::{
-    clipboard::Clipboard,
     features::{
-        api_resources::message::ApiRequest,
-        component_id::{LIST_DIALOG_ID, LIST_WIDGET_ID},
+        api_resources::{
+            kube::{ApiResource, API_RESOURCE_KEY},
+            message::ApiRequest,
+        },
+        component_id::{
+            LIST_CONDITIONS_DIALOG_ID, LIST_DIALOG_ID, LIST_OWNER_TREE_DIALOG_ID,
+            LIST_SAVE_VIEW_DIALOG_ID, LIST_VIEW_DIALOG_ID, LIST_WIDGET_ID, YAML_DIALOG_ID,
+        },
+        get::message::GetRequest,
     },
     message::Message,
     ui::{
         event::EventResult,
-        widget::{Text, Widget, WidgetBase, WidgetTrait as _},
+        widget::{SelectedItem, Table, TableItem, Widget, WidgetBase, WidgetTrait as _},
         Window,
     },
 };
 
-pub fn list_widget(
-    tx: &Sender<Message>,
-    clipboard: &Option<Rc<RefCell<Clipboard>>>,
-) -> Widget<'static> {
-    let tx = tx.clone();
+pub fn list_widget(tx: &Sender<Message>) -> Widget<'static> {
+    Table::builder()
+        .id(LIST_WIDGET_ID)
+        .widget_base(WidgetBase::builder().title("List").build())
+        .filtered_key("NAME")
+        .block_injection(block_injection())
+        .on_select(on_select(tx.clone()))
+        .action('f', open_subwin(tx.clone()))
+        .action('v', open_view_dialog())
+        .action('V', open_save_view_dialog())
+        .action(
+            'c',
+            open_object_dialog(
+                tx.clone(),
+                LIST_CONDITIONS_DIALOG_ID,
+                |resource, name, namespace| ApiRequest::Conditions {
+                    resource,
+                    name,
+                    namespace,
+                },
+            ),
+        )
+        .action(
+            't',
+            open_object_dialog(
+                tx.clone(),
+                LIST_OWNER_TREE_DIALOG_ID,
+                |resource, name, namespace| ApiRequest::OwnerTree {
+                    resource,
+                    name,
+                    namespace,
+                },
+            ),
+        )
+        .build()
+        .into()
+}
 
-    let open_subwin = move |w: &mut Window| {
+fn open_subwin(tx: Sender<Message>) -> impl Fn(&mut Window) -> EventResult {
+    move |w: &mut Window| {
         tx.send(ApiRequest::Get.into())
             .expect("Failed to send ApiRequest::Get");
         w.open_dialog(LIST_DIALOG_ID);
         EventResult::Nop
-    };
+    }
+}
 
-    let builder = Text::builder()
-        .id(LIST_WIDGET_ID)
-        .widget_base(WidgetBase::builder().title("List").build())
-        .block_injection(|text: &Text, is_active: bool, is_mouse_over: bool| {
-            let (index, size) = text.state();
+fn open_view_dialog() -> impl Fn(&mut Window) -> EventResult {
+    |w: &mut Window| {
+        w.open_dialog(LIST_VIEW_DIALOG_ID);
+        EventResult::Nop
+    }
+}
+
+fn open_save_view_dialog() -> impl Fn(&mut Window) -> EventResult {
+    |w: &mut Window| {
+        w.widget_clear(LIST_SAVE_VIEW_DIALOG_ID);
+        w.open_dialog(LIST_SAVE_VIEW_DIALOG_ID);
+        EventResult::Nop
+    }
+}
+
+/// 選択したリソースについての情報をダイアログで表示する
+fn open_object_dialog(
+    tx: Sender<Message>,
+    dialog_id: &'static str,
+    request: fn(ApiResource, String, String) -> ApiRequest,
+) -> impl Fn(&mut Window) -> EventResult {
+    move |w: &mut Window| {
+        let widget = w.find_widget(LIST_WIDGET_ID);
+
+        let Some(SelectedItem::TableRow {
+            metadata: Some(metadata),
+            ..
+        }) = widget.widget_item()
+        else {
+            return EventResult::Ignore;
+        };
 
-            let mut base = text.widget_base().clone();
+        let Some((resource, name, namespace)) = object_from_metadata(&metadata) else {
+            return EventResult::Ignore;
+        };
 
-            *base.append_title_mut() = Some(format!(" [{}/{}]", index, size).into());
+        tx.send(request(resource, name, namespace).into())
+            .expect("Failed to send ApiRequest");
 
-            base.render_block(text.can_activate() && is_active, is_mouse_over)
-        })
-        .action('f', open_subwin);
+        w.widget_clear(dialog_id);
+        w.open_dialog(dialog_id);
 
-    if let Some(cb) = clipboard {
-        builder.clipboard(cb.clone())
-    } else {
-        builder
+        EventResult::Nop
+    }
+}
+
+fn object_from_metadata(
+    metadata: &BTreeMap<String, String>,
+) -> Option<(ApiResource, String, String)> {
+    Some((
+        serde_json::from_str(metadata.get(API_RESOURCE_KEY)?).ok()?,
+        metadata.get("name")?.to_string(),
+        metadata.get("namespace").cloned().unwrap_or_default(),
+    ))
+}
+
+fn block_injection() -> impl Fn(&Table) -> WidgetBase {
+    |table: &Table| {
+        let index = if let Some(index) = table.state().selected() {
+            index + 1
+        } else {
+            0
+        };
+
+        let mut base = table.widget_base().clone();
+
+        *base.append_title_mut() = Some(format!(" [{}/{}]", index, table.items().len()).into());
+
+        base
+    }
+}
+
+/// 選択したリソースのyamlをダイアログで表示する
+fn on_select(tx: Sender<Message>) -> impl Fn(&mut Window, &TableItem) -> EventResult {
+    move |w: &mut Window, v: &TableItem| {
+        let Some(req) = v.metadata.as_ref().and_then(GetRequest::from_metadata) else {
+            return EventResult::Ignore;
+        };
+
+        tx.send(req.into()).expect("Failed to send GetRequest");
+
+        w.widget_clear(YAML_DIALOG_ID);
+        w.open_dialog(YAML_DIALOG_ID);
+
+        EventResult::Nop
     }
-    .build()
-    .into()
 }
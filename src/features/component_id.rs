@@ -18,6 +18,9 @@ component_id!(
     list_tab,
     network_tab,
     yaml_tab,
+    helm_tab,
+    workload_tab,
+    rbac_tab,
     // widgets
     pod_widget,
     pod_log_widget,
@@ -27,17 +30,43 @@ component_id!(
     network_widget,
     network_description_widget,
     event_widget,
+    list_kind_widget,
+    list_selector_widget,
     list_widget,
     yaml_widget,
+    helm_widget,
+    helm_description_widget,
+    helm_history_widget,
+    workload_widget,
+    workload_description_widget,
+    workload_rollout_widget,
+    rbac_subject_widget,
+    rbac_permission_widget,
+    rbac_can_i_widget,
     // dialogs
     pod_log_query_help_dialog,
     context_dialog,
     single_namespace_dialog,
     multiple_namespaces_dialog,
     list_dialog,
+    list_view_dialog,
+    list_save_view_dialog,
+    list_save_view_result_dialog,
+    list_conditions_dialog,
+    list_owner_tree_dialog,
     yaml_kind_dialog,
     yaml_name_dialog,
     yaml_not_found_dialog,
+    yaml_compare_context_dialog,
+    yaml_compare_namespace_dialog,
+    yaml_compare_dialog,
     help_dialog,
-    yaml_dialog
+    yaml_dialog,
+    copy_dialog,
+    network_simulation_query_dialog,
+    network_simulation_dialog,
+    config_secret_reveal_dialog,
+    helm_diff_dialog,
+    workload_diff_dialog,
+    workload_trigger_dialog
 );
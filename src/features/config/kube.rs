@@ -1,5 +1,9 @@
 mod config;
+mod mask;
+mod options;
 mod raw_data;
 
 pub use config::*;
+pub use mask::*;
+pub use options::*;
 pub use raw_data::*;
@@ -4,7 +4,9 @@ use crate::{
     features::config::message::ConfigResponse,
     kube::{
         apis::v1_table::TableRow,
-        table::{get_resource_per_namespace, insert_ns, KubeTable, KubeTableRow},
+        table::{
+            get_resource_per_namespace, insert_ns, partition_namespaces, KubeTable, KubeTableRow,
+        },
         KubeClient,
     },
     workers::kube::{
@@ -14,7 +16,7 @@ use crate::{
 
 use anyhow::Result;
 use async_trait::async_trait;
-use futures::future::try_join_all;
+use futures::future::{join_all, try_join};
 
 #[derive(Clone)]
 pub struct ConfigPoller {
@@ -83,45 +85,55 @@ impl Configs {
 
 async fn fetch_configs_per_namespace(
     client: &KubeClient,
-    namespaces: &[String],
+    ns: &str,
+    insert_ns: bool,
     ty: Configs,
 ) -> Result<Vec<KubeTableRow>> {
+    get_resource_per_namespace(
+        client,
+        format!("api/v1/namespaces/{}/{}", ns, ty.kind()),
+        &["Name", r#"Data"#, "Age"],
+        move |row: &TableRow, indexes: &[usize]| {
+            let mut row = vec![
+                ty.resource().to_string(),
+                row.cells[indexes[0]].to_string(),
+                row.cells[indexes[1]].to_string(),
+                row.cells[indexes[2]].to_string(),
+            ];
+
+            let kind = row[0].clone();
+            let name = row[1].clone();
+
+            if insert_ns {
+                row.insert(0, ns.to_string())
+            }
+
+            KubeTableRow {
+                namespace: ns.to_string(),
+                name,
+                row,
+                metadata: Some(BTreeMap::from([("kind".to_string(), kind)])),
+            }
+        },
+    )
+    .await
+}
+
+/// ネームスペースごとにConfigMapとSecretを取得する（どちらかを取得できないネームスペースは除く）
+async fn fetch_configs(client: &KubeClient, namespaces: &[String]) -> Result<KubeTable> {
     let insert_ns = insert_ns(namespaces);
-    let jobs = try_join_all(namespaces.iter().map(|ns| {
-        get_resource_per_namespace(
-            client,
-            format!("api/v1/namespaces/{}/{}", ns, ty.kind()),
-            &["Name", r#"Data"#, "Age"],
-            move |row: &TableRow, indexes: &[usize]| {
-                let mut row = vec![
-                    ty.resource().to_string(),
-                    row.cells[indexes[0]].to_string(),
-                    row.cells[indexes[1]].to_string(),
-                    row.cells[indexes[2]].to_string(),
-                ];
-
-                let kind = row[0].clone();
-                let name = row[1].clone();
-
-                if insert_ns {
-                    row.insert(0, ns.to_string())
-                }
-
-                KubeTableRow {
-                    namespace: ns.to_string(),
-                    name,
-                    row,
-                    metadata: Some(BTreeMap::from([("kind".to_string(), kind)])),
-                }
-            },
+
+    let jobs = join_all(namespaces.iter().map(|ns| async move {
+        try_join(
+            fetch_configs_per_namespace(client, ns, insert_ns, Configs::ConfigMap),
+            fetch_configs_per_namespace(client, ns, insert_ns, Configs::Secret),
         )
+        .await
     }))
-    .await?;
+    .await;
 
-    Ok(jobs.into_iter().flatten().collect())
-}
+    let (rows, skipped) = partition_namespaces(namespaces.iter().cloned().zip(jobs))?;
 
-async fn fetch_configs(client: &KubeClient, namespaces: &[String]) -> Result<KubeTable> {
     let mut table = KubeTable {
         header: if namespaces.len() == 1 {
             ["KIND", "NAME", "DATA", "AGE"]
@@ -134,16 +146,19 @@ async fn fetch_configs(client: &KubeClient, namespaces: &[String]) -> Result<Kub
                 .map(ToString::to_string)
                 .collect()
         },
+        skipped,
         ..Default::default()
     };
 
-    let jobs = try_join_all([
-        fetch_configs_per_namespace(client, namespaces, Configs::ConfigMap),
-        fetch_configs_per_namespace(client, namespaces, Configs::Secret),
-    ])
-    .await?;
+    let (configmaps, secrets): (Vec<_>, Vec<_>) = rows.into_iter().unzip();
 
-    table.update_rows(jobs.into_iter().flatten().collect());
+    table.update_rows(
+        configmaps
+            .into_iter()
+            .flatten()
+            .chain(secrets.into_iter().flatten())
+            .collect(),
+    );
 
     Ok(table)
 }
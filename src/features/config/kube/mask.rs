@@ -0,0 +1,167 @@
+use std::{collections::BTreeSet, sync::Arc};
+
+use serde::Deserialize;
+use tokio::sync::RwLock;
+
+/// 伏せ字にした値の表示
+pub const MASKED_VALUE: &str = "********";
+
+pub type SharedSecretMask = Arc<RwLock<SecretMask>>;
+
+/// Secretの値を伏せ字で表示する設定
+///
+/// - mask: Secretの値を伏せ字で表示する。falseのときは常に値を表示する
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default)]
+pub struct SecretMask {
+    pub mask: bool,
+
+    /// すべての値を表示しているとき
+    #[serde(skip)]
+    reveal_all: bool,
+
+    /// 値を表示しているキー
+    #[serde(skip)]
+    revealed_keys: BTreeSet<String>,
+
+    /// 値を表示しているSecretのnamespaceと名前
+    #[serde(skip)]
+    secret: Option<(String, String)>,
+}
+
+impl Default for SecretMask {
+    fn default() -> Self {
+        Self {
+            mask: true,
+            reveal_all: false,
+            revealed_keys: BTreeSet::default(),
+            secret: None,
+        }
+    }
+}
+
+impl SecretMask {
+    pub fn shared(self) -> SharedSecretMask {
+        Arc::new(RwLock::new(self))
+    }
+
+    pub fn is_masked(&self, key: &str) -> bool {
+        self.mask && !self.reveal_all && !self.revealed_keys.contains(key)
+    }
+
+    pub fn toggle_all(&mut self) {
+        self.reveal_all = !self.reveal_all;
+        self.revealed_keys.clear();
+    }
+
+    pub fn toggle_key(&mut self, key: &str) {
+        if !self.revealed_keys.remove(key) {
+            self.revealed_keys.insert(key.to_string());
+        }
+    }
+
+    /// Config タブで表示しているSecretを設定する
+    ///
+    /// 表示しているSecretが変わったときは、表示中の値を伏せ字に戻す
+    pub fn set_secret(&mut self, secret: Option<(String, String)>) {
+        if self.secret != secret {
+            self.reset();
+            self.secret = secret;
+        }
+    }
+
+    /// 指定したSecretの値に適用する設定を返す
+    ///
+    /// 表示の切り替えはConfigタブで表示しているSecretにのみ適用し、それ以外は伏せ字にする
+    pub fn for_secret(&self, namespace: &str, name: &str) -> Self {
+        let mut mask = self.clone();
+
+        let is_target = self
+            .secret
+            .as_ref()
+            .is_some_and(|(ns, n)| ns == namespace && n == name);
+
+        if !is_target {
+            mask.reset();
+        }
+
+        mask
+    }
+
+    fn reset(&mut self) {
+        self.reveal_all = false;
+        self.revealed_keys.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn キーごとに表示を切り替える() {
+        let mut mask = SecretMask::default();
+
+        assert!(mask.is_masked("password"));
+
+        mask.toggle_key("password");
+
+        assert!(!mask.is_masked("password"));
+        assert!(mask.is_masked("username"));
+
+        mask.toggle_key("password");
+
+        assert!(mask.is_masked("password"));
+    }
+
+    #[test]
+    fn すべての値の表示を切り替える() {
+        let mut mask = SecretMask::default();
+
+        mask.toggle_all();
+
+        assert!(!mask.is_masked("password"));
+
+        mask.toggle_all();
+
+        assert!(mask.is_masked("password"));
+    }
+
+    #[test]
+    fn 表示しているsecretが変わったとき伏せ字に戻す() {
+        let mut mask = SecretMask::default();
+
+        mask.set_secret(Some(("default".into(), "a".into())));
+        mask.toggle_key("password");
+
+        mask.set_secret(Some(("default".into(), "a".into())));
+
+        assert!(!mask.is_masked("password"));
+
+        mask.set_secret(Some(("default".into(), "b".into())));
+
+        assert!(mask.is_masked("password"));
+    }
+
+    #[test]
+    fn 表示の切り替えは対象のsecretにのみ適用する() {
+        let mut mask = SecretMask::default();
+
+        mask.set_secret(Some(("default".into(), "a".into())));
+        mask.toggle_all();
+
+        assert!(!mask.for_secret("default", "a").is_masked("password"));
+        assert!(mask.for_secret("default", "b").is_masked("password"));
+        assert!(mask.for_secret("other", "a").is_masked("password"));
+    }
+
+    #[test]
+    fn 無効なときは伏せ字にしない() {
+        let mask = SecretMask {
+            mask: false,
+            ..Default::default()
+        };
+
+        assert!(!mask.is_masked("password"));
+    }
+}
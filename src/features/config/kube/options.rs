@@ -0,0 +1,34 @@
+use std::sync::Arc;
+
+use tokio::sync::RwLock;
+
+pub type SharedRawDataOptions = Arc<RwLock<RawDataOptions>>;
+
+/// Raw Dataの値の表示方法
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct RawDataOptions {
+    /// サイズの大きい値を省略せずに表示する
+    pub expand: bool,
+
+    /// base64エンコードやgzip圧縮された値を展開して表示する
+    pub decode: bool,
+}
+
+impl RawDataOptions {
+    pub fn shared(self) -> SharedRawDataOptions {
+        Arc::new(RwLock::new(self))
+    }
+
+    pub fn toggle_expand(&mut self) {
+        self.expand = !self.expand;
+    }
+
+    pub fn toggle_decode(&mut self) {
+        self.decode = !self.decode;
+    }
+
+    /// 表示しているConfigMapやSecretが変わったときに、表示方法を元に戻す
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
@@ -1,26 +1,38 @@
 mod configmap;
+mod format;
 mod secret;
+mod used_by;
 
-use std::sync::{atomic::AtomicBool, Arc};
+use std::{
+    sync::{atomic::AtomicBool, Arc},
+    time::{Duration, Instant},
+};
 
 use anyhow::Result;
 use async_trait::async_trait;
 use crossbeam::channel::Sender;
 
 use crate::{
-    features::config::message::{ConfigData, ConfigRequest, ConfigResponse, RequestData},
+    features::{
+        config::message::{ConfigData, ConfigRequest, ConfigResponse, RequestData},
+        network::kube::related_resources::config::{ConfigKind, ConfigTarget},
+    },
     kube::KubeClient,
     message::Message,
     workers::kube::AbortWorker,
 };
 
-use self::{configmap::ConfigMapDataWorker, secret::SecretDataWorker};
+use self::{configmap::ConfigMapDataWorker, secret::SecretDataWorker, used_by::fetch_used_by};
+
+use super::{SharedRawDataOptions, SharedSecretMask};
 
 #[derive(Clone)]
 pub struct ConfigsDataWorker {
     is_terminated: Arc<AtomicBool>,
     tx: Sender<Message>,
     client: KubeClient,
+    secret_mask: SharedSecretMask,
+    options: SharedRawDataOptions,
     req: ConfigRequest,
 }
 
@@ -29,12 +41,16 @@ impl ConfigsDataWorker {
         is_terminated: Arc<AtomicBool>,
         tx: Sender<Message>,
         client: KubeClient,
+        secret_mask: SharedSecretMask,
+        options: SharedRawDataOptions,
         req: ConfigRequest,
     ) -> Self {
         Self {
             is_terminated,
             tx,
             client,
+            secret_mask,
+            options,
             req,
         }
     }
@@ -58,13 +74,40 @@ impl AbortWorker for ConfigsDataWorker {
 
 #[async_trait]
 trait Fetch<'a> {
-    fn new(client: &'a KubeClient, namespace: String, name: String) -> Self;
+    fn new(
+        client: &'a KubeClient,
+        namespace: String,
+        name: String,
+        secret_mask: SharedSecretMask,
+        options: SharedRawDataOptions,
+    ) -> Self;
+
+    async fn fetch(&self) -> Result<FetchedData>;
+}
 
-    async fn fetch(&self) -> Result<ConfigData>;
+struct FetchedData {
+    data: ConfigData,
+
+    /// Secretのキー。値の表示を切り替えるダイアログで使う
+    secret_keys: Option<Vec<String>>,
+}
+
+impl From<ConfigData> for FetchedData {
+    fn from(data: ConfigData) -> Self {
+        Self {
+            data,
+            secret_keys: None,
+        }
+    }
 }
 
 const INTERVAL: u64 = 3;
 
+/// 参照しているPodを取得する間隔
+///
+/// Podの一覧の取得は重いため、データよりも長い間隔で取得する
+const USED_BY_INTERVAL: Duration = Duration::from_secs(30);
+
 impl ConfigsDataWorker {
     async fn fetch_description<'a, Worker>(&'a self) -> Result<()>
     where
@@ -74,7 +117,23 @@ impl ConfigsDataWorker {
 
         let RequestData { name, namespace } = self.req.data();
 
-        let worker = Worker::new(&self.client, namespace.to_string(), name.to_string());
+        let worker = Worker::new(
+            &self.client,
+            namespace.to_string(),
+            name.to_string(),
+            self.secret_mask.clone(),
+            self.options.clone(),
+        );
+
+        let target = ConfigTarget {
+            kind: match self.req {
+                ConfigRequest::ConfigMap(_) => ConfigKind::ConfigMap,
+                ConfigRequest::Secret(_) => ConfigKind::Secret,
+            },
+            name: name.to_string(),
+        };
+
+        let mut used_by: Option<(Instant, Vec<String>)> = None;
 
         while !self
             .is_terminated
@@ -82,11 +141,39 @@ impl ConfigsDataWorker {
         {
             interval.tick().await;
 
-            let fetched_data = worker.fetch().await;
+            let (fetched_data, secret_keys) = match worker.fetch().await {
+                Ok(FetchedData { data, secret_keys }) => (Ok(data), secret_keys),
+                Err(e) => (Err(e), None),
+            };
+
+            let needs_used_by = used_by
+                .as_ref()
+                .is_none_or(|(fetched_at, _)| fetched_at.elapsed() >= USED_BY_INTERVAL);
+
+            if fetched_data.is_ok() && needs_used_by {
+                let lines = fetch_used_by(&self.client, namespace, &target).await;
+
+                used_by = Some((Instant::now(), lines));
+            }
+
+            let fetched_data = fetched_data.map(|mut data| {
+                if let Some((_, lines)) = &used_by {
+                    data.push(String::default());
+                    data.extend(lines.iter().cloned());
+                }
+
+                data
+            });
 
             self.tx
                 .send(ConfigResponse::Data(fetched_data).into())
                 .expect("Failed to send ConfigResponse::Data");
+
+            if let Some(secret_keys) = secret_keys {
+                self.tx
+                    .send(ConfigResponse::SecretKeys(secret_keys).into())
+                    .expect("Failed to send ConfigResponse::SecretKeys");
+            }
         }
 
         Ok(())
@@ -2,78 +2,89 @@ use std::collections::BTreeMap;
 
 use anyhow::Result;
 use async_trait::async_trait;
-use k8s_openapi::api::core::v1::ConfigMap;
+use k8s_openapi::{api::core::v1::ConfigMap, ByteString};
 use kube::Api;
 
-use crate::{features::config::message::ConfigData, kube::KubeClient, workers::kube::color::Color};
+use crate::{
+    features::config::kube::{RawDataOptions, SharedRawDataOptions, SharedSecretMask},
+    kube::KubeClient,
+    workers::kube::color::Color,
+};
 
-use super::Fetch;
+use super::{format::format_value, Fetch, FetchedData};
 
 pub(super) struct ConfigMapDataWorker<'a> {
     client: &'a KubeClient,
     namespace: String,
     name: String,
+    options: SharedRawDataOptions,
 }
 
 #[async_trait()]
 impl<'a> Fetch<'a> for ConfigMapDataWorker<'a> {
-    fn new(client: &'a KubeClient, namespace: String, name: String) -> Self {
+    fn new(
+        client: &'a KubeClient,
+        namespace: String,
+        name: String,
+        _secret_mask: SharedSecretMask,
+        options: SharedRawDataOptions,
+    ) -> Self {
         Self {
             client,
             namespace,
             name,
+            options,
         }
     }
 
-    async fn fetch(&self) -> Result<ConfigData> {
+    async fn fetch(&self) -> Result<FetchedData> {
         let list: Api<ConfigMap> =
             Api::namespaced(self.client.as_client().clone(), &self.namespace);
 
         let target = list.get(&self.name).await?;
 
-        if let Some(data) = target.data {
-            let data = ConfigMapData(data);
-            Ok(data.to_vec_string_with_color())
+        let options = self.options.read().await.clone();
+
+        let data = if target.data.is_some() || target.binary_data.is_some() {
+            ConfigMapData {
+                data: target.data.unwrap_or_default(),
+                binary_data: target.binary_data.unwrap_or_default(),
+            }
+            .to_vec_string_with_color(&options)
         } else {
-            Ok(vec!["no data".into()])
-        }
+            vec!["no data".to_string()]
+        };
+
+        Ok(data.into())
     }
 }
 
-struct ConfigMapData(BTreeMap<String, String>);
+struct ConfigMapData {
+    data: BTreeMap<String, String>,
+    binary_data: BTreeMap<String, ByteString>,
+}
 
 impl ConfigMapData {
-    fn to_vec_string_with_color(&self) -> Vec<String> {
-        let ret = self
-            .0
+    fn to_vec_string_with_color(&self, options: &RawDataOptions) -> Vec<String> {
+        let data = self.data.iter().map(|(key, value)| (key, value.as_bytes()));
+
+        let binary_data = self
+            .binary_data
             .iter()
+            .map(|(key, ByteString(value))| (key, value.as_slice()));
+
+        data.chain(binary_data)
             .scan(Color::new(), |color, (key, value)| {
                 let color = color.next_color();
 
-                if value.contains('\n') {
-                    let mut ret = vec![format!(
-                        "\x1b[{color}m{key}:\x1b[39m |",
-                        color = color,
-                        key = key
-                    )];
-
-                    let value: Vec<String> = value.lines().map(|l| format!("  {}\n", l)).collect();
-
-                    ret.extend(value);
-
-                    Some(ret)
-                } else {
-                    Some(vec![format!(
-                        "\x1b[{color}m{key}:\x1b[39m {value}",
-                        color = color,
-                        key = key,
-                        value = value,
-                    )])
-                }
+                Some(format_value(key, value, color, options))
             })
-            .flatten()
-            .collect();
-
-        ret
+            .flat_map(|value| {
+                value
+                    .lines()
+                    .map(ToString::to_string)
+                    .collect::<Vec<String>>()
+            })
+            .collect()
     }
 }
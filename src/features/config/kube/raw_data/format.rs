@@ -0,0 +1,416 @@
+use std::{borrow::Cow, io::prelude::*};
+
+use base64::{engine::general_purpose, Engine};
+
+use crate::{
+    features::config::kube::{RawDataOptions, SecretMask, MASKED_VALUE},
+    workers::kube::color::fg::Color,
+};
+
+/// 省略せずに表示するテキストの最大サイズ
+const TRUNCATE_BYTES: usize = 16 * 1024;
+
+/// 省略せずに表示するテキストの最大行数
+const TRUNCATE_LINES: usize = 200;
+
+/// 省略せずに表示するバイナリの最大サイズ
+const TRUNCATE_BINARY_BYTES: usize = 512;
+
+/// 入れ子になったエンコードを展開する最大の回数
+const MAX_DECODE_DEPTH: usize = 4;
+
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+/// 伏せ字にするキーのときは値を表示しない
+pub(super) fn format_secret_value(
+    key: &str,
+    value: &[u8],
+    color: u8,
+    mask: &SecretMask,
+    options: &RawDataOptions,
+) -> String {
+    if mask.is_masked(key) {
+        format!(
+            "{} {}",
+            format_masked(key, color),
+            format_comment(&[format_size(value.len())])
+        )
+    } else {
+        format_value(key, value, color, options)
+    }
+}
+
+pub(super) fn format_masked(key: &str, color: u8) -> String {
+    format!(
+        "\x1b[{color}m{key}:\x1b[39m {masked}",
+        color = color,
+        key = key,
+        masked = MASKED_VALUE,
+    )
+}
+
+/// サイズを付けて値を表示する
+///
+/// - base64エンコードやgzip圧縮された値は、decodeが有効なときに展開して表示する
+/// - UTF-8として表示できない値は16進ダンプで表示する
+/// - サイズの大きい値は、expandが有効なとき以外は省略して表示する
+pub(super) fn format_value(key: &str, value: &[u8], color: u8, options: &RawDataOptions) -> String {
+    let mut notes = vec![format_size(value.len())];
+
+    let (key, value) = match decode_nested(value) {
+        Some((encodings, decoded)) if options.decode => {
+            notes.push(format!("decoded from {}", encodings.join(" > ")));
+
+            (
+                Cow::Owned(format!("{} (decoded)", key)),
+                Cow::Owned(decoded),
+            )
+        }
+        Some((encodings, _)) => {
+            notes.push(format!("{} encoded", encodings.join(" > ")));
+
+            (Cow::Borrowed(key), Cow::Borrowed(value))
+        }
+        None => (Cow::Borrowed(key), Cow::Borrowed(value)),
+    };
+
+    match std::str::from_utf8(&value) {
+        Ok(text) => format_text(&key, text, color, &notes, options.expand),
+        Err(_) => {
+            notes.push("binary".into());
+
+            format_binary(&key, &value, color, &notes, options.expand)
+        }
+    }
+}
+
+pub(super) fn format_utf8(key: &str, value: &str, color: u8) -> String {
+    if value.contains('\n') {
+        let mut ret = format!("\x1b[{color}m{key}:\x1b[39m |\n", color = color, key = key);
+
+        value.lines().for_each(|l| {
+            ret += &format!("  {}\n", l);
+        });
+
+        ret.trim_end().to_string()
+    } else {
+        format!(
+            "\x1b[{color}m{key}:\x1b[39m {value}",
+            color = color,
+            key = key,
+            value = value,
+        )
+    }
+}
+
+pub(super) fn format_decode_error(key: &str, err: impl std::fmt::Display) -> String {
+    Color::Red.wrap(format!("# Failed to decode the '{}' value: {}", key, err))
+}
+
+fn format_text(key: &str, text: &str, color: u8, notes: &[String], expand: bool) -> String {
+    let comment = format_comment(notes);
+
+    let (shown, omitted) = if expand {
+        (text, 0)
+    } else {
+        truncate_text(text)
+    };
+
+    if !text.contains('\n') && omitted == 0 {
+        let value = [text, &comment]
+            .iter()
+            .filter(|s| !s.is_empty())
+            .copied()
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        return format!("\x1b[{color}m{key}:\x1b[39m {value}");
+    }
+
+    let mut ret = format!("\x1b[{color}m{key}:\x1b[39m | {comment}\n");
+
+    shown.lines().for_each(|l| {
+        ret += &format!("  {}\n", l);
+    });
+
+    if 0 < omitted {
+        ret += &format!("  {}", format_truncated(omitted));
+    }
+
+    ret.trim_end().to_string()
+}
+
+fn format_binary(key: &str, value: &[u8], color: u8, notes: &[String], expand: bool) -> String {
+    let shown = if expand {
+        value
+    } else {
+        &value[..value.len().min(TRUNCATE_BINARY_BYTES)]
+    };
+
+    let mut ret = format!(
+        "\x1b[{color}m{key}:\x1b[39m | {comment}\n",
+        comment = format_comment(notes)
+    );
+
+    hexdump(shown).iter().for_each(|l| {
+        ret += &format!("  {}\n", l);
+    });
+
+    if shown.len() < value.len() {
+        ret += &format!("  {}", format_truncated(value.len() - shown.len()));
+    }
+
+    ret.trim_end().to_string()
+}
+
+fn format_comment(notes: &[String]) -> String {
+    Color::DarkGray.wrap(format!("# {}", notes.join(", ")))
+}
+
+fn format_truncated(omitted: usize) -> String {
+    Color::DarkGray.wrap(format!(
+        "# ... {} truncated (press 'e' to expand)",
+        format_size(omitted)
+    ))
+}
+
+fn format_size(len: usize) -> String {
+    const KIB: f64 = 1024.0;
+    const MIB: f64 = KIB * 1024.0;
+
+    let size = len as f64;
+
+    if size < KIB {
+        format!("{} B", len)
+    } else if size < MIB {
+        format!("{:.1} KiB", size / KIB)
+    } else {
+        format!("{:.1} MiB", size / MIB)
+    }
+}
+
+/// 先頭から最大サイズ・最大行数までを返す。2つ目の値は省略したバイト数
+fn truncate_text(text: &str) -> (&str, usize) {
+    let mut cut = text.len().min(TRUNCATE_BYTES);
+
+    if let Some((index, _)) = text.match_indices('\n').nth(TRUNCATE_LINES - 1) {
+        cut = cut.min(index + 1);
+    }
+
+    while !text.is_char_boundary(cut) {
+        cut -= 1;
+    }
+
+    if text[cut..].trim().is_empty() {
+        (text, 0)
+    } else {
+        (&text[..cut], text.len() - cut)
+    }
+}
+
+/// `hexdump -C`と同じ形式
+fn hexdump(value: &[u8]) -> Vec<String> {
+    value
+        .chunks(16)
+        .enumerate()
+        .map(|(i, chunk)| {
+            let mut hex = String::new();
+
+            for j in 0..16 {
+                if j == 8 {
+                    hex.push(' ');
+                }
+
+                match chunk.get(j) {
+                    Some(b) => hex += &format!("{:02x} ", b),
+                    None => hex += "   ",
+                }
+            }
+
+            let ascii: String = chunk
+                .iter()
+                .map(|b| {
+                    if b.is_ascii_graphic() || *b == b' ' {
+                        *b as char
+                    } else {
+                        '.'
+                    }
+                })
+                .collect();
+
+            format!("{:08x}  {} |{}|", i * 16, hex, ascii)
+        })
+        .collect()
+}
+
+/// base64エンコードやgzip圧縮を繰り返し展開する。展開したエンコードの順番と展開後の値を返す
+fn decode_nested(value: &[u8]) -> Option<(Vec<&'static str>, Vec<u8>)> {
+    let mut encodings = Vec::new();
+    let mut current = value.to_vec();
+
+    for _ in 0..MAX_DECODE_DEPTH {
+        let (encoding, decoded) = if current.starts_with(&GZIP_MAGIC) {
+            let mut decoded = Vec::new();
+
+            if flate2::read::GzDecoder::new(&current[..])
+                .read_to_end(&mut decoded)
+                .is_err()
+            {
+                break;
+            }
+
+            ("gzip", decoded)
+        } else if let Some(decoded) = decode_base64(&current) {
+            ("base64", decoded)
+        } else {
+            break;
+        };
+
+        encodings.push(encoding);
+        current = decoded;
+    }
+
+    if encodings.is_empty() {
+        None
+    } else {
+        Some((encodings, current))
+    }
+}
+
+/// 偶然base64として解釈できる短い文字列を除くため、展開後の値がgzipまたは表示できるテキストのときのみ展開する
+fn decode_base64(value: &[u8]) -> Option<Vec<u8>> {
+    let value: Vec<u8> = value
+        .iter()
+        .filter(|b| !b.is_ascii_whitespace())
+        .copied()
+        .collect();
+
+    if value.len() < 8 || !value.len().is_multiple_of(4) {
+        return None;
+    }
+
+    let decoded = general_purpose::STANDARD.decode(&value).ok()?;
+
+    let is_text = std::str::from_utf8(&decoded).is_ok_and(|text| {
+        text.chars()
+            .all(|c| !c.is_control() || matches!(c, '\n' | '\r' | '\t'))
+    });
+
+    if decoded.starts_with(&GZIP_MAGIC) || is_text {
+        Some(decoded)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use flate2::{write::GzEncoder, Compression};
+    use indoc::formatdoc;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn gray(s: &str) -> String {
+        Color::DarkGray.wrap(s)
+    }
+
+    #[test]
+    fn サイズを付けて表示する() {
+        let actual = format_value("key", b"value", 32, &RawDataOptions::default());
+
+        assert_eq!(
+            actual,
+            format!("\x1b[32mkey:\x1b[39m value {}", gray("# 5 B"))
+        );
+    }
+
+    #[test]
+    fn バイナリは16進ダンプで表示する() {
+        let actual = format_value("key", b"\x00\xffhello", 32, &RawDataOptions::default());
+
+        let expected = formatdoc! {
+            "
+            \x1b[32mkey:\x1b[39m | {}
+              00000000  00 ff 68 65 6c 6c 6f                              |..hello|",
+            gray("# 7 B, binary")
+        };
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn 大きい値は省略して表示する() {
+        let value = "a\n".repeat(TRUNCATE_LINES + 10);
+
+        let actual = format_value("key", value.as_bytes(), 32, &RawDataOptions::default());
+
+        let lines: Vec<_> = actual.lines().collect();
+
+        assert_eq!(lines.len(), TRUNCATE_LINES + 2);
+        assert_eq!(
+            lines.last().unwrap(),
+            &format!("  {}", gray("# ... 20 B truncated (press 'e' to expand)"))
+        );
+
+        let options = RawDataOptions {
+            expand: true,
+            ..Default::default()
+        };
+
+        let actual = format_value("key", value.as_bytes(), 32, &options);
+
+        assert_eq!(actual.lines().count(), TRUNCATE_LINES + 11);
+    }
+
+    #[test]
+    fn base64エンコードしたgzipを展開して表示する() {
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(b"hello world").unwrap();
+
+        let value = general_purpose::STANDARD.encode(encoder.finish().unwrap());
+
+        let actual = format_value("key", value.as_bytes(), 32, &RawDataOptions::default());
+
+        assert!(actual.ends_with(&gray(&format!(
+            "# {} B, base64 > gzip encoded",
+            value.len()
+        ))));
+
+        let options = RawDataOptions {
+            decode: true,
+            ..Default::default()
+        };
+
+        let actual = format_value("key", value.as_bytes(), 32, &options);
+
+        assert_eq!(
+            actual,
+            format!(
+                "\x1b[32mkey (decoded):\x1b[39m hello world {}",
+                gray(&format!("# {} B, decoded from base64 > gzip", value.len()))
+            )
+        );
+    }
+
+    #[test]
+    fn base64として解釈できても展開後がテキストでないときは展開しない() {
+        assert_eq!(decode_nested(b"password"), None);
+    }
+
+    #[test]
+    fn 伏せ字のときもサイズを表示する() {
+        let actual = format_secret_value(
+            "key",
+            b"secret",
+            32,
+            &SecretMask::default(),
+            &RawDataOptions::default(),
+        );
+
+        assert_eq!(
+            actual,
+            format!("\x1b[32mkey:\x1b[39m ******** {}", gray("# 6 B"))
+        );
+    }
+}
@@ -1,6 +1,9 @@
 mod any;
-mod format;
+mod basic_auth;
+mod docker_config;
 mod helm;
+mod service_account_token;
+mod tls;
 
 use std::collections::BTreeMap;
 
@@ -9,44 +12,81 @@ use async_trait::async_trait;
 use k8s_openapi::{api::core::v1::Secret, ByteString};
 use kube::Api;
 
-use crate::{features::config::message::ConfigData, kube::KubeClient};
+use crate::{
+    features::config::{
+        kube::{RawDataOptions, SecretMask, SharedRawDataOptions, SharedSecretMask},
+        message::ConfigData,
+    },
+    kube::KubeClient,
+};
 
-use self::{any::Any, helm::Helm};
+use self::{
+    any::Any, basic_auth::BasicAuth, docker_config::DockerConfigJson, helm::Helm,
+    service_account_token::ServiceAccountToken, tls::Tls,
+};
 
-use super::Fetch;
+use super::{format, Fetch, FetchedData};
 
 pub(super) struct SecretDataWorker<'a> {
     client: &'a KubeClient,
     namespace: String,
     name: String,
+    secret_mask: SharedSecretMask,
+    options: SharedRawDataOptions,
 }
 #[async_trait()]
 impl<'a> Fetch<'a> for SecretDataWorker<'a> {
-    fn new(client: &'a KubeClient, namespace: String, name: String) -> Self {
+    fn new(
+        client: &'a KubeClient,
+        namespace: String,
+        name: String,
+        secret_mask: SharedSecretMask,
+        options: SharedRawDataOptions,
+    ) -> Self {
         Self {
             client,
             namespace,
             name,
+            secret_mask,
+            options,
         }
     }
-    async fn fetch(&self) -> Result<ConfigData> {
+    async fn fetch(&self) -> Result<FetchedData> {
         let list: Api<Secret> = Api::namespaced(self.client.as_client().clone(), &self.namespace);
         let target = list.get(&self.name).await?;
 
         let type_ = target.type_.as_deref().unwrap_or_default();
 
-        let Some(data) = target.data else {
-            return Ok(vec!["no data".into()]);
+        let (data, secret_keys) = match target.data {
+            Some(data) => {
+                let secret_keys = data.keys().cloned().collect();
+
+                let secret_mask = self.secret_mask.read().await.clone();
+
+                let options = self.options.read().await.clone();
+
+                let data =
+                    SecretData::new(type_, data)?.to_string_key_values(&secret_mask, &options);
+
+                (data, secret_keys)
+            }
+            None => (vec!["no data".into()], Vec::new()),
         };
 
-        let data = SecretData::new(type_, data)?;
-        Ok(data.to_string_key_values())
+        Ok(FetchedData {
+            data,
+            secret_keys: Some(secret_keys),
+        })
     }
 }
 
 #[derive(Debug)]
 enum SecretData {
     Helm(Helm),
+    Tls(Tls),
+    DockerConfigJson(DockerConfigJson),
+    ServiceAccountToken(ServiceAccountToken),
+    BasicAuth(BasicAuth),
     Any(Any),
 }
 
@@ -54,14 +94,29 @@ impl SecretData {
     fn new(type_: &str, data: BTreeMap<String, ByteString>) -> Result<Self> {
         match type_ {
             "helm.sh/release.v1" => Ok(Self::Helm(Helm::new(data))),
+            "kubernetes.io/tls" => Ok(Self::Tls(Tls::new(data))),
+            "kubernetes.io/dockerconfigjson" => {
+                Ok(Self::DockerConfigJson(DockerConfigJson::new(data)))
+            }
+            "kubernetes.io/service-account-token" => {
+                Ok(Self::ServiceAccountToken(ServiceAccountToken::new(data)))
+            }
+            "kubernetes.io/basic-auth" => Ok(Self::BasicAuth(BasicAuth::new(data))),
+            _ if Tls::contains_certificate(&data) => Ok(Self::Tls(Tls::new(data))),
             _ => Ok(Self::Any(Any::new(data))),
         }
     }
 
-    fn to_string_key_values(&self) -> ConfigData {
+    fn to_string_key_values(&self, mask: &SecretMask, options: &RawDataOptions) -> ConfigData {
         match self {
-            Self::Helm(helm) => helm.to_string_key_values(),
-            Self::Any(any) => any.to_string_key_values(),
+            Self::Helm(helm) => helm.to_string_key_values(mask, options),
+            Self::Tls(tls) => tls.to_string_key_values(mask, options),
+            Self::DockerConfigJson(docker_config) => {
+                docker_config.to_string_key_values(mask, options)
+            }
+            Self::ServiceAccountToken(token) => token.to_string_key_values(mask, options),
+            Self::BasicAuth(basic_auth) => basic_auth.to_string_key_values(mask, options),
+            Self::Any(any) => any.to_string_key_values(mask, options),
         }
     }
 }
@@ -1,11 +1,13 @@
 use std::collections::{btree_map, BTreeMap};
 
-use base64::{engine::general_purpose, Engine};
 use k8s_openapi::ByteString;
 
-use crate::workers::kube::color::Color;
+use crate::{
+    features::config::kube::{RawDataOptions, SecretMask},
+    workers::kube::color::Color,
+};
 
-use super::format::{format_error, format_utf8};
+use super::format::format_secret_value;
 
 /// any type secret
 #[derive(Debug, Default)]
@@ -18,8 +20,8 @@ impl Any {
         Self { data }
     }
 
-    pub fn to_string_key_values(&self) -> Vec<String> {
-        self.iter()
+    pub fn to_string_key_values(&self, mask: &SecretMask, options: &RawDataOptions) -> Vec<String> {
+        self.iter(mask, options)
             .flat_map(|key_value| {
                 key_value
                     .lines()
@@ -29,10 +31,12 @@ impl Any {
             .collect()
     }
 
-    fn iter(&self) -> Iter {
+    fn iter<'a>(&'a self, mask: &'a SecretMask, options: &'a RawDataOptions) -> Iter<'a> {
         Iter {
             iter: self.data.iter(),
             color: Color::new(),
+            mask,
+            options,
         }
     }
 }
@@ -40,6 +44,8 @@ impl Any {
 struct Iter<'a> {
     iter: btree_map::Iter<'a, String, ByteString>,
     color: Color,
+    mask: &'a SecretMask,
+    options: &'a RawDataOptions,
 }
 
 impl Iterator for Iter<'_> {
@@ -49,13 +55,12 @@ impl Iterator for Iter<'_> {
 
         let color = self.color.next_color();
 
-        match String::from_utf8(value.to_vec()) {
-            Ok(utf8_data) => Some(format_utf8(key, &utf8_data, color)),
-            Err(err) => {
-                let base64_encoded = general_purpose::STANDARD.encode(value);
-
-                Some(format_error(key, &base64_encoded, &err.to_string(), color))
-            }
-        }
+        Some(format_secret_value(
+            key,
+            value,
+            color,
+            self.mask,
+            self.options,
+        ))
     }
 }
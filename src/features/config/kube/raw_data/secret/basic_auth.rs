@@ -0,0 +1,125 @@
+use std::collections::BTreeMap;
+
+use base64::{engine::general_purpose, Engine};
+use k8s_openapi::ByteString;
+
+use crate::{
+    features::config::kube::{RawDataOptions, SecretMask},
+    workers::kube::color::{self, Color},
+};
+
+use super::format::{format_masked, format_secret_value, format_utf8};
+
+const USERNAME_KEY: &str = "username";
+
+const PASSWORD_KEY: &str = "password";
+
+const AUTHORIZATION_HEADER: &str = "Authorization header";
+
+/// kubernetes.io/basic-auth type secret
+#[derive(Debug, Default)]
+pub struct BasicAuth {
+    data: BTreeMap<String, ByteString>,
+}
+
+impl BasicAuth {
+    pub fn new(data: BTreeMap<String, ByteString>) -> Self {
+        Self { data }
+    }
+
+    pub fn to_string_key_values(&self, mask: &SecretMask, options: &RawDataOptions) -> Vec<String> {
+        let mut color = Color::new();
+
+        let mut lines = Vec::new();
+
+        let username = self.data.get(USERNAME_KEY);
+        let password = self.data.get(PASSWORD_KEY);
+
+        if username.is_none() && password.is_none() {
+            lines.push(color::fg::Color::Red.wrap(format!(
+                "# WARNING: basic-auth secret requires '{}' or '{}'",
+                USERNAME_KEY, PASSWORD_KEY
+            )));
+        } else if mask.is_masked(USERNAME_KEY) || mask.is_masked(PASSWORD_KEY) {
+            lines.push(format_masked(AUTHORIZATION_HEADER, color.next_color()));
+        } else {
+            // Authorizationヘッダーの値（`username:password`をbase64エンコードしたもの）
+            let credentials = [username, password]
+                .map(|value| value.map(|ByteString(v)| v.as_slice()).unwrap_or_default())
+                .join(&b':');
+
+            lines.push(format_utf8(
+                AUTHORIZATION_HEADER,
+                &format!("Basic {}", general_purpose::STANDARD.encode(credentials)),
+                color.next_color(),
+            ));
+        }
+
+        for (key, ByteString(value)) in &self.data {
+            let color = color.next_color();
+
+            lines.extend(
+                format_secret_value(key, value, color, mask, options)
+                    .lines()
+                    .map(ToString::to_string),
+            );
+        }
+
+        lines
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn revealed() -> SecretMask {
+        let mut mask = SecretMask::default();
+        mask.toggle_all();
+        mask
+    }
+
+    #[test]
+    fn authorizationヘッダーを表示する() {
+        let data = BTreeMap::from([
+            ("username".to_string(), ByteString(b"admin".to_vec())),
+            ("password".to_string(), ByteString(b"secret".to_vec())),
+        ]);
+
+        let actual = BasicAuth::new(data.clone())
+            .to_string_key_values(&revealed(), &RawDataOptions::default());
+
+        let expected = vec![
+            "\x1b[32mAuthorization header:\x1b[39m Basic YWRtaW46c2VjcmV0".to_string(),
+            "\x1b[33mpassword:\x1b[39m secret \x1b[90m# 6 B\x1b[39m".to_string(),
+            "\x1b[34musername:\x1b[39m admin \x1b[90m# 5 B\x1b[39m".to_string(),
+        ];
+
+        assert_eq!(actual, expected);
+
+        let actual = BasicAuth::new(data)
+            .to_string_key_values(&SecretMask::default(), &RawDataOptions::default());
+
+        let expected = vec![
+            "\x1b[32mAuthorization header:\x1b[39m ********".to_string(),
+            "\x1b[33mpassword:\x1b[39m ******** \x1b[90m# 6 B\x1b[39m".to_string(),
+            "\x1b[34musername:\x1b[39m ******** \x1b[90m# 5 B\x1b[39m".to_string(),
+        ];
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn usernameとpasswordがないとき警告する() {
+        let actual = BasicAuth::new(BTreeMap::new())
+            .to_string_key_values(&SecretMask::default(), &RawDataOptions::default());
+
+        assert_eq!(
+            actual,
+            vec![color::fg::Color::Red
+                .wrap("# WARNING: basic-auth secret requires 'username' or 'password'")]
+        );
+    }
+}
@@ -0,0 +1,179 @@
+use std::collections::BTreeMap;
+
+use anyhow::Result;
+use base64::{engine::general_purpose, Engine};
+use k8s_openapi::ByteString;
+use serde::Deserialize;
+
+use crate::{
+    features::config::kube::{RawDataOptions, SecretMask},
+    workers::kube::color::Color,
+};
+
+use super::format::{format_decode_error, format_secret_value, format_utf8};
+
+const DOCKER_CONFIG_JSON_KEY: &str = ".dockerconfigjson";
+
+/// kubernetes.io/dockerconfigjson type secret
+#[derive(Debug, Default)]
+pub struct DockerConfigJson {
+    data: BTreeMap<String, ByteString>,
+}
+
+#[derive(Debug, Deserialize)]
+struct DockerConfig {
+    #[serde(default)]
+    auths: BTreeMap<String, DockerAuth>,
+}
+
+#[derive(Debug, Deserialize)]
+struct DockerAuth {
+    username: Option<String>,
+    auth: Option<String>,
+    email: Option<String>,
+}
+
+impl DockerConfigJson {
+    pub fn new(data: BTreeMap<String, ByteString>) -> Self {
+        Self { data }
+    }
+
+    /// パスワードを含まないため、伏せ字にするときもレジストリとユーザー名は表示する
+    pub fn to_string_key_values(&self, mask: &SecretMask, options: &RawDataOptions) -> Vec<String> {
+        let mut color = Color::new();
+
+        let mut lines = Vec::new();
+
+        if let Some(ByteString(value)) = self.data.get(DOCKER_CONFIG_JSON_KEY) {
+            let decoded = match decode_registries(value) {
+                Ok(decoded) => format_utf8(
+                    &format!("{} (decoded)", DOCKER_CONFIG_JSON_KEY),
+                    &decoded,
+                    color.next_color(),
+                ),
+                Err(err) => format_decode_error(DOCKER_CONFIG_JSON_KEY, err),
+            };
+
+            lines.extend(decoded.lines().map(ToString::to_string));
+        }
+
+        for (key, ByteString(value)) in &self.data {
+            let color = color.next_color();
+
+            lines.extend(
+                format_secret_value(key, value, color, mask, options)
+                    .lines()
+                    .map(ToString::to_string),
+            );
+        }
+
+        lines
+    }
+}
+
+/// レジストリごとのユーザー名を表示する。パスワードは表示しない
+fn decode_registries(data: &[u8]) -> Result<String> {
+    let config: DockerConfig = serde_json::from_slice(data)?;
+
+    if config.auths.is_empty() {
+        return Ok("no registries\n".into());
+    }
+
+    let mut ret = String::new();
+
+    for (registry, auth) in &config.auths {
+        ret += &format!("- registry: {}\n", registry);
+
+        let username = match (&auth.username, &auth.auth) {
+            (Some(username), _) => Some(username.clone()),
+            (None, Some(auth)) => Some(decode_auth_username(auth)?),
+            (None, None) => None,
+        };
+
+        if let Some(username) = username {
+            ret += &format!("  username: {}\n", username);
+        }
+
+        if let Some(email) = &auth.email {
+            ret += &format!("  email: {}\n", email);
+        }
+    }
+
+    Ok(ret)
+}
+
+/// `auth`は`username:password`をbase64エンコードした値
+fn decode_auth_username(auth: &str) -> Result<String> {
+    let decoded = String::from_utf8(general_purpose::STANDARD.decode(auth)?)?;
+
+    let username = decoded
+        .split_once(':')
+        .map_or(decoded.as_str(), |(username, _)| username);
+
+    Ok(username.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn revealed() -> SecretMask {
+        let mut mask = SecretMask::default();
+        mask.toggle_all();
+        mask
+    }
+
+    #[test]
+    fn レジストリとユーザー名を表示する() {
+        let data = indoc! {
+            r#"
+            {
+              "auths": {
+                "ghcr.io": {
+                  "auth": "Z2hjci11c2VyOnNlY3JldA=="
+                },
+                "registry.example.com": {
+                  "username": "example",
+                  "password": "secret",
+                  "email": "example@example.com"
+                }
+              }
+            }
+            "#
+        };
+
+        let actual = decode_registries(data.as_bytes()).unwrap();
+
+        let expected = indoc! {
+            "
+            - registry: ghcr.io
+              username: ghcr-user
+            - registry: registry.example.com
+              username: example
+              email: example@example.com
+            "
+        };
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn 不正なjsonのときエラーを表示する() {
+        let data = BTreeMap::from([(
+            DOCKER_CONFIG_JSON_KEY.to_string(),
+            ByteString(b"invalid".to_vec()),
+        )]);
+
+        let actual = DockerConfigJson::new(data)
+            .to_string_key_values(&revealed(), &RawDataOptions::default());
+
+        assert!(actual[0].contains("# Failed to decode the '.dockerconfigjson' value"));
+        assert_eq!(
+            actual[1],
+            "\x1b[32m.dockerconfigjson:\x1b[39m invalid \x1b[90m# 7 B\x1b[39m"
+        );
+    }
+}
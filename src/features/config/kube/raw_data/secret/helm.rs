@@ -1,12 +1,17 @@
-use std::{collections::BTreeMap, io::prelude::*};
+use std::collections::BTreeMap;
 
 use anyhow::Result;
-use base64::{engine::general_purpose, Engine};
 use k8s_openapi::ByteString;
 
-use crate::workers::kube::color::{self, Color};
+use crate::{
+    features::{
+        config::kube::{RawDataOptions, SecretMask},
+        helm::kube::{decompress_release, RELEASE_KEY},
+    },
+    workers::kube::color::Color,
+};
 
-use super::format::{format_error, format_utf8};
+use super::format::{format_decode_error, format_masked, format_secret_value, format_utf8};
 
 #[derive(Debug, Default)]
 pub struct Helm {
@@ -18,36 +23,31 @@ impl Helm {
         Self { data }
     }
 
-    pub fn to_string_key_values(&self) -> Vec<String> {
-        let Some(ByteString(value)) = self.data.get("release") else {
+    /// リリースにはvaluesが含まれるため、伏せ字にするときはデコードした内容も表示しない
+    pub fn to_string_key_values(&self, mask: &SecretMask, options: &RawDataOptions) -> Vec<String> {
+        let Some(ByteString(value)) = self.data.get(RELEASE_KEY) else {
             return vec!["no release data".into()];
         };
 
         let mut color = Color::new();
 
-        let decoded_release = match decode_release(value) {
-            Ok(decoded) => {
-                let color = color.next_color();
-                format_utf8("release (decoded)", &decoded, color)
-            }
-            Err(err) => {
-                format!(
-                    "\x1b[{red}m# Failed to decode the 'release' value: {err}\x1b[39m",
-                    red = color::fg::Color::Red as u8,
-                    err = err
-                )
+        let decoded_key = format!("{} (decoded)", RELEASE_KEY);
+
+        let decoded_release = if mask.is_masked(RELEASE_KEY) {
+            format_masked(&decoded_key, color.next_color())
+        } else {
+            match decode_release(value) {
+                Ok(decoded) => {
+                    let color = color.next_color();
+                    format_utf8(&decoded_key, &decoded, color)
+                }
+                Err(err) => format_decode_error(RELEASE_KEY, err),
             }
         };
 
         let color = color.next_color();
 
-        let release = match String::from_utf8(value.to_vec()) {
-            Ok(utf8_data) => format_utf8("release", &utf8_data, color),
-            Err(err) => {
-                let base64_encoded = general_purpose::STANDARD.encode(value);
-                format_error("release", &base64_encoded, &err.to_string(), color)
-            }
-        };
+        let release = format_secret_value(RELEASE_KEY, value, color, mask, options);
 
         decoded_release
             .lines()
@@ -58,12 +58,7 @@ impl Helm {
 }
 
 fn decode_release(data: &[u8]) -> Result<String> {
-    let gzip = general_purpose::STANDARD.decode(data)?;
-
-    // decode gzip
-    let mut decoder = flate2::read::GzDecoder::new(&gzip[..]);
-    let mut decoded = String::new();
-    decoder.read_to_string(&mut decoded)?;
+    let decoded = decompress_release(data)?;
 
     let yaml = serde_yaml::from_str::<serde_yaml::Value>(&decoded)?;
 
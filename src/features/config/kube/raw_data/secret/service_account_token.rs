@@ -0,0 +1,177 @@
+use std::collections::BTreeMap;
+
+use anyhow::{bail, Result};
+use base64::{engine::general_purpose, Engine};
+use chrono::{DateTime, SecondsFormat, Utc};
+use k8s_openapi::ByteString;
+use serde_json::Value;
+
+use crate::{
+    certificate::expiry_countdown,
+    features::config::kube::{RawDataOptions, SecretMask},
+    workers::kube::color::Color,
+};
+
+use super::format::{format_decode_error, format_secret_value, format_utf8};
+
+const TOKEN_KEY: &str = "token";
+
+/// kubernetes.io/service-account-token type secret
+#[derive(Debug, Default)]
+pub struct ServiceAccountToken {
+    data: BTreeMap<String, ByteString>,
+}
+
+impl ServiceAccountToken {
+    pub fn new(data: BTreeMap<String, ByteString>) -> Self {
+        Self { data }
+    }
+
+    /// 署名を含まないため、伏せ字にするときもヘッダーとクレームは表示する
+    pub fn to_string_key_values(&self, mask: &SecretMask, options: &RawDataOptions) -> Vec<String> {
+        let now = Utc::now();
+
+        let mut color = Color::new();
+
+        let mut lines = Vec::new();
+
+        if let Some(ByteString(value)) = self.data.get(TOKEN_KEY) {
+            let decoded = match decode_token(value, now) {
+                Ok(decoded) => format_utf8(
+                    &format!("{} (decoded)", TOKEN_KEY),
+                    &decoded,
+                    color.next_color(),
+                ),
+                Err(err) => format_decode_error(TOKEN_KEY, err),
+            };
+
+            lines.extend(decoded.lines().map(ToString::to_string));
+        }
+
+        for (key, ByteString(value)) in &self.data {
+            let color = color.next_color();
+
+            lines.extend(
+                format_secret_value(key, value, color, mask, options)
+                    .lines()
+                    .map(ToString::to_string),
+            );
+        }
+
+        lines
+    }
+}
+
+/// JWTのヘッダーとクレームをYAML形式で表示する。署名は検証しない
+fn decode_token(token: &[u8], now: DateTime<Utc>) -> Result<String> {
+    let token = std::str::from_utf8(token)?.trim();
+
+    let [header, claims, _signature] = token.split('.').collect::<Vec<_>>()[..] else {
+        bail!("Invalid JWT format");
+    };
+
+    let header = decode_segment(header)?;
+    let claims = decode_segment(claims)?;
+
+    let mut ret = String::new();
+
+    ret += "header:\n";
+    ret += &indent(&serde_yaml::to_string(&header)?);
+
+    ret += "claims:\n";
+    ret += &indent(&serde_yaml::to_string(&claims)?);
+
+    let expiry = claims
+        .get("exp")
+        .and_then(Value::as_i64)
+        .and_then(|exp| DateTime::from_timestamp(exp, 0));
+
+    match expiry {
+        Some(exp) => {
+            ret += &format!(
+                "expiry: {} {}\n",
+                exp.to_rfc3339_opts(SecondsFormat::Secs, true),
+                expiry_countdown(exp, now)
+            );
+        }
+        None => ret += "expiry: never\n",
+    }
+
+    Ok(ret)
+}
+
+fn decode_segment(segment: &str) -> Result<Value> {
+    let decoded = general_purpose::URL_SAFE_NO_PAD.decode(segment.trim_end_matches('='))?;
+
+    Ok(serde_json::from_slice(&decoded)?)
+}
+
+fn indent(s: &str) -> String {
+    s.lines().map(|l| format!("  {}\n", l)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+
+    use crate::workers::kube::color::fg;
+
+    use super::*;
+
+    fn encode(value: &str) -> String {
+        general_purpose::URL_SAFE_NO_PAD.encode(value)
+    }
+
+    #[test]
+    fn jwtのヘッダーとクレームを表示する() {
+        let token = format!(
+            "{}.{}.signature",
+            encode(r#"{"alg":"RS256","kid":"key"}"#),
+            encode(
+                r#"{"iss":"kubernetes/serviceaccount","sub":"system:serviceaccount:default:sa","exp":1800000000}"#
+            )
+        );
+
+        let now = "2027-01-05T00:00:00Z".parse().unwrap();
+
+        let actual = decode_token(token.as_bytes(), now).unwrap();
+
+        let expected = indoc! {
+            "
+            header:
+              alg: RS256
+              kid: key
+            claims:
+              exp: 1800000000
+              iss: kubernetes/serviceaccount
+              sub: system:serviceaccount:default:sa
+            "
+        }
+        .to_string()
+            + &format!(
+                "expiry: 2027-01-15T08:00:00Z {}\n",
+                fg::Color::Yellow.wrap("(in 10d)")
+            );
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn 有効期限がないときneverと表示する() {
+        let token = format!(
+            "{}.{}.signature",
+            encode(r#"{"alg":"RS256"}"#),
+            encode(r#"{"sub":"system:serviceaccount:default:sa"}"#)
+        );
+
+        let actual = decode_token(token.as_bytes(), Utc::now()).unwrap();
+
+        assert!(actual.ends_with("expiry: never\n"));
+    }
+
+    #[test]
+    fn jwtでないときエラーを返す() {
+        assert!(decode_token(b"invalid", Utc::now()).is_err());
+    }
+}
@@ -0,0 +1,248 @@
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, SecondsFormat, Utc};
+use k8s_openapi::ByteString;
+
+use crate::{
+    certificate::{public_key_from_private_key_pem, Certificate},
+    features::config::kube::{RawDataOptions, SecretMask},
+    workers::kube::color::{self, Color},
+};
+
+use super::format::{format_decode_error, format_secret_value, format_utf8};
+
+const CERTIFICATE_KEY: &str = "tls.crt";
+
+const PRIVATE_KEY_KEY: &str = "tls.key";
+
+/// kubernetes.io/tls type secret、または証明書（.crt, .pem）を含むsecret
+#[derive(Debug, Default)]
+pub struct Tls {
+    data: BTreeMap<String, ByteString>,
+}
+
+impl Tls {
+    pub fn new(data: BTreeMap<String, ByteString>) -> Self {
+        Self { data }
+    }
+
+    /// 証明書を含む値があるときにtrueを返す
+    pub fn contains_certificate(data: &BTreeMap<String, ByteString>) -> bool {
+        data.iter()
+            .any(|(key, ByteString(value))| is_certificate(key, value))
+    }
+
+    /// 証明書は公開情報のため、伏せ字にするときもデコードした内容は表示する
+    pub fn to_string_key_values(&self, mask: &SecretMask, options: &RawDataOptions) -> Vec<String> {
+        self.to_string_key_values_at(mask, options, Utc::now())
+    }
+
+    fn to_string_key_values_at(
+        &self,
+        mask: &SecretMask,
+        options: &RawDataOptions,
+        now: DateTime<Utc>,
+    ) -> Vec<String> {
+        let mut lines: Vec<String> = self.key_pair_warning().into_iter().collect();
+
+        let mut color = Color::new();
+
+        for (key, ByteString(value)) in &self.data {
+            if is_certificate(key, value) {
+                let decoded = match Certificate::parse_pem_chain(value) {
+                    Ok(chain) => format_utf8(
+                        &format!("{} (decoded)", key),
+                        &format_chain(&chain, now),
+                        color.next_color(),
+                    ),
+                    Err(err) => format_decode_error(key, err),
+                };
+
+                lines.extend(decoded.lines().map(ToString::to_string));
+            }
+
+            let color = color.next_color();
+
+            lines.extend(
+                format_secret_value(key, value, color, mask, options)
+                    .lines()
+                    .map(ToString::to_string),
+            );
+        }
+
+        lines
+    }
+
+    /// tls.keyがtls.crtの証明書と一致しないときの警告
+    fn key_pair_warning(&self) -> Option<String> {
+        let ByteString(crt) = self.data.get(CERTIFICATE_KEY)?;
+        let ByteString(key) = self.data.get(PRIVATE_KEY_KEY)?;
+
+        let certificate = Certificate::parse_pem_chain(crt).ok()?.into_iter().next()?;
+
+        match public_key_from_private_key_pem(key) {
+            Ok(public_key) if certificate.matches_private_key(&public_key) => None,
+            Ok(_) => Some(color::fg::Color::Red.wrap(format!(
+                "# WARNING: '{}' does not match the certificate in '{}'",
+                PRIVATE_KEY_KEY, CERTIFICATE_KEY
+            ))),
+            Err(err) => Some(format_decode_error(PRIVATE_KEY_KEY, err)),
+        }
+    }
+}
+
+fn is_certificate(key: &str, value: &[u8]) -> bool {
+    (key.ends_with(".crt") || key.ends_with(".pem"))
+        && std::str::from_utf8(value).is_ok_and(|v| v.contains("-----BEGIN CERTIFICATE-----"))
+}
+
+/// 証明書チェーンをYAML形式で表示する
+fn format_chain(chain: &[Certificate], now: DateTime<Utc>) -> String {
+    let mut ret = String::new();
+
+    for certificate in chain {
+        ret += &format!("- subject: {}\n", certificate.subject);
+        ret += &format!("  issuer: {}\n", certificate.issuer);
+        ret += &format!("  serial: {}\n", certificate.serial);
+
+        if !certificate.subject_alt_names.is_empty() {
+            ret += "  subjectAltNames:\n";
+
+            for name in &certificate.subject_alt_names {
+                ret += &format!("  - {}\n", name);
+            }
+        }
+
+        ret += &format!(
+            "  notBefore: {}\n",
+            certificate
+                .not_before
+                .to_rfc3339_opts(SecondsFormat::Secs, true)
+        );
+        ret += &format!(
+            "  notAfter: {} {}\n",
+            certificate
+                .not_after
+                .to_rfc3339_opts(SecondsFormat::Secs, true),
+            certificate.expiry_countdown(now)
+        );
+    }
+
+    ret
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::formatdoc;
+    use pretty_assertions::assert_eq;
+
+    use crate::certificate::tests::{CERTIFICATE, OTHER_PRIVATE_KEY, PRIVATE_KEY};
+
+    use super::*;
+
+    fn revealed() -> SecretMask {
+        let mut mask = SecretMask::default();
+        mask.toggle_all();
+        mask
+    }
+
+    fn data(items: &[(&str, &str)]) -> BTreeMap<String, ByteString> {
+        items
+            .iter()
+            .map(|(k, v)| (k.to_string(), ByteString(v.as_bytes().to_vec())))
+            .collect()
+    }
+
+    fn size(s: &str) -> String {
+        color::fg::Color::DarkGray.wrap(format!("# {} B", s.len()))
+    }
+
+    fn indent(s: &str) -> String {
+        s.lines().map(|l| format!("  {}\n", l)).collect()
+    }
+
+    #[test]
+    fn 証明書をデコードして表示する() {
+        let tls = Tls::new(data(&[("tls.crt", CERTIFICATE), ("tls.key", PRIVATE_KEY)]));
+
+        let actual = tls
+            .to_string_key_values_at(
+                &revealed(),
+                &RawDataOptions::default(),
+                "2126-09-15T00:00:00Z".parse().unwrap(),
+            )
+            .join("\n");
+
+        let expected = formatdoc! {
+            "
+            \x1b[32mtls.crt (decoded):\x1b[39m |
+              - subject: CN=example.com
+                issuer: CN=example.com
+                serial: 12:34
+                subjectAltNames:
+                - DNS:example.com
+                - DNS:www.example.com
+                notBefore: 2026-10-19T08:49:55Z
+                notAfter: 2126-09-25T08:49:55Z {countdown}
+            \x1b[33mtls.crt:\x1b[39m | {crt_size}
+            {crt}\x1b[34mtls.key:\x1b[39m | {key_size}
+            {key}",
+            countdown = color::fg::Color::Yellow.wrap("(in 10d)"),
+            crt_size = size(CERTIFICATE),
+            key_size = size(PRIVATE_KEY),
+            crt = indent(CERTIFICATE),
+            key = indent(PRIVATE_KEY),
+        };
+
+        assert_eq!(actual, expected.trim_end());
+    }
+
+    #[test]
+    fn 伏せ字にするときも証明書をデコードして表示する() {
+        let tls = Tls::new(data(&[("tls.crt", CERTIFICATE), ("tls.key", PRIVATE_KEY)]));
+
+        let mut mask = SecretMask::default();
+        mask.toggle_key("tls.crt");
+
+        let actual = tls.to_string_key_values_at(&mask, &RawDataOptions::default(), Utc::now());
+
+        assert!(actual[0].starts_with("\x1b[32mtls.crt (decoded):\x1b[39m |"));
+        assert_eq!(
+            actual[actual.len() - 1],
+            format!("\x1b[34mtls.key:\x1b[39m ******** {}", size(PRIVATE_KEY))
+        );
+        assert!(actual
+            .iter()
+            .any(|l| l == &format!("\x1b[33mtls.crt:\x1b[39m | {}", size(CERTIFICATE))));
+    }
+
+    #[test]
+    fn 秘密鍵が証明書と一致しないとき警告する() {
+        let tls = Tls::new(data(&[
+            ("tls.crt", CERTIFICATE),
+            ("tls.key", OTHER_PRIVATE_KEY),
+        ]));
+
+        let actual = tls.to_string_key_values_at(
+            &SecretMask::default(),
+            &RawDataOptions::default(),
+            Utc::now(),
+        );
+
+        assert_eq!(
+            actual[0],
+            color::fg::Color::Red
+                .wrap("# WARNING: 'tls.key' does not match the certificate in 'tls.crt'")
+        );
+    }
+
+    #[test]
+    fn 証明書を含むキーを判定する() {
+        assert!(Tls::contains_certificate(&data(&[("ca.pem", CERTIFICATE)])));
+        assert!(!Tls::contains_certificate(&data(&[(
+            "key.pem",
+            PRIVATE_KEY
+        )])));
+        assert!(!Tls::contains_certificate(&data(&[("ca", CERTIFICATE)])));
+    }
+}
@@ -0,0 +1,381 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use anyhow::Result;
+use futures::future::join_all;
+use k8s_openapi::{
+    api::{
+        apps::v1::ReplicaSet,
+        batch::v1::Job,
+        core::v1::{ConfigMap, Pod, Secret},
+    },
+    apimachinery::pkg::apis::meta::v1::OwnerReference,
+    List, NamespaceResourceScope,
+};
+use kube::{Resource, ResourceExt as _};
+use serde::de::DeserializeOwned;
+
+use crate::{
+    features::network::kube::related_resources::{
+        config::{config_references, ConfigKind, ConfigTarget},
+        Filter as _,
+    },
+    kube::{apis::v1_table::Table, KubeClientRequest},
+    workers::kube::color::fg::Color,
+};
+
+/// Podを管理しているコントローラーから、ワークロード（kind, name）への対応
+type Workloads = BTreeMap<(String, String), (String, String)>;
+
+/// ConfigMapまたはSecretを参照しているPodと、それらのPodが参照している存在しないConfigMapやSecret
+///
+/// 取得に失敗したときもConfigMapやSecretのデータは表示したいため、エラーは行として返す
+pub(super) async fn fetch_used_by<C: KubeClientRequest>(
+    client: &C,
+    namespace: &str,
+    target: &ConfigTarget,
+) -> Vec<String> {
+    fetch(client, namespace, target)
+        .await
+        .unwrap_or_else(|err| {
+            vec![Color::Red.wrap(format!(
+                "# Failed to fetch the pods referencing this config: {}",
+                err
+            ))]
+        })
+}
+
+async fn fetch<C: KubeClientRequest>(
+    client: &C,
+    namespace: &str,
+    target: &ConfigTarget,
+) -> Result<Vec<String>> {
+    let pods: List<Pod> = client.request(&Pod::url_path(&(), Some(namespace))).await?;
+
+    let users = pods.filter_by_item(target).unwrap_or_default();
+
+    // 参照しているPodがないときは、存在しない参照を確認する必要がない
+    let mut existing = BTreeSet::new();
+
+    if !users.items.is_empty() {
+        existing
+            .extend(fetch_names::<C, ConfigMap>(client, namespace, ConfigKind::ConfigMap).await?);
+        existing.extend(fetch_names::<C, Secret>(client, namespace, ConfigKind::Secret).await?);
+    }
+
+    let workloads = resolve_workloads(client, namespace, &users).await;
+
+    Ok(used_by(&users, target, &existing, &workloads))
+}
+
+async fn fetch_names<C, K>(
+    client: &C,
+    namespace: &str,
+    kind: ConfigKind,
+) -> Result<BTreeSet<ConfigTarget>>
+where
+    C: KubeClientRequest,
+    K: Resource<DynamicType = (), Scope = NamespaceResourceScope> + 'static,
+{
+    let table: Table = client.table_namespaced::<K>(namespace).await?;
+
+    let Some(index) = table.find_index("Name") else {
+        return Ok(BTreeSet::new());
+    };
+
+    Ok(table
+        .rows
+        .iter()
+        .map(|row| ConfigTarget {
+            kind,
+            name: row.cells[index].to_string(),
+        })
+        .collect())
+}
+
+/// Podを管理しているコントローラー
+fn controller(pod: &Pod) -> Option<&OwnerReference> {
+    pod.owner_references()
+        .iter()
+        .find(|owner| owner.controller.unwrap_or_default())
+}
+
+/// Podのコントローラーからワークロードを解決する
+async fn resolve_workloads<C: KubeClientRequest>(
+    client: &C,
+    namespace: &str,
+    pods: &List<Pod>,
+) -> Workloads {
+    let controllers: BTreeSet<(String, String)> = pods
+        .items
+        .iter()
+        .filter_map(controller)
+        .map(|owner| (owner.kind.clone(), owner.name.clone()))
+        .collect();
+
+    join_all(controllers.into_iter().map(|(kind, name)| async move {
+        let workload = resolve_workload(client, namespace, &kind, &name).await;
+
+        ((kind, name), workload)
+    }))
+    .await
+    .into_iter()
+    .collect()
+}
+
+/// ReplicaSetはDeploymentまで、JobはCronJobまでたどる
+///
+/// 所有者がいないときや取得できないときは、コントローラーをそのまま返す
+async fn resolve_workload<C: KubeClientRequest>(
+    client: &C,
+    namespace: &str,
+    kind: &str,
+    name: &str,
+) -> (String, String) {
+    let owner = match kind {
+        "ReplicaSet" => fetch_controller::<C, ReplicaSet>(client, namespace, name).await,
+        "Job" => fetch_controller::<C, Job>(client, namespace, name).await,
+        _ => None,
+    };
+
+    owner
+        .map(|owner| (owner.kind, owner.name))
+        .unwrap_or_else(|| (kind.to_string(), name.to_string()))
+}
+
+async fn fetch_controller<C, K>(client: &C, namespace: &str, name: &str) -> Option<OwnerReference>
+where
+    C: KubeClientRequest,
+    K: Resource<DynamicType = (), Scope = NamespaceResourceScope> + DeserializeOwned + 'static,
+{
+    let path = format!("{}/{}", K::url_path(&(), Some(namespace)), name);
+
+    let object: K = client.request(&path).await.ok()?;
+
+    object
+        .meta()
+        .owner_references
+        .iter()
+        .flatten()
+        .find(|owner| owner.controller.unwrap_or_default())
+        .cloned()
+}
+
+/// `users` は対象を参照しているPod
+fn used_by(
+    users: &List<Pod>,
+    target: &ConfigTarget,
+    existing: &BTreeSet<ConfigTarget>,
+    workloads: &Workloads,
+) -> Vec<String> {
+    let mut lines = vec![Color::Cyan.wrap("used by:")];
+
+    if users.items.is_empty() {
+        lines.push(format!("  {}", Color::DarkGray.wrap("<none>")));
+    }
+
+    for pod in &users.items {
+        let usages: BTreeSet<_> = config_references(pod)
+            .into_iter()
+            .filter(|reference| &reference.target == target)
+            .map(|reference| reference.usage)
+            .collect();
+
+        lines.push(format!(
+            "  {}{}: {}",
+            pod.name_any(),
+            workload(pod, workloads),
+            usages.into_iter().collect::<Vec<_>>().join(", ")
+        ));
+    }
+
+    let missing: Vec<String> = users
+        .items
+        .iter()
+        .flat_map(|pod| {
+            config_references(pod)
+                .into_iter()
+                .filter(|reference| !reference.optional && !existing.contains(&reference.target))
+                .map(move |reference| {
+                    Color::Red.wrap(format!(
+                        "  {}: {}/{} ({})",
+                        pod.name_any(),
+                        reference.target.kind,
+                        reference.target.name,
+                        reference.usage
+                    ))
+                })
+        })
+        .collect();
+
+    if !missing.is_empty() {
+        lines.push(String::default());
+        lines.push(
+            Color::Red
+                .wrap("# WARNING: pods using this config reference missing ConfigMaps/Secrets"),
+        );
+        lines.extend(missing);
+    }
+
+    lines
+}
+
+/// Podを管理しているワークロード
+fn workload(pod: &Pod, workloads: &Workloads) -> String {
+    let Some(owner) = controller(pod) else {
+        return String::default();
+    };
+
+    let key = (owner.kind.clone(), owner.name.clone());
+
+    let (kind, name) = workloads.get(&key).unwrap_or(&key);
+
+    format!(" ({}/{})", kind, name)
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+    use mockall::predicate::eq;
+    use pretty_assertions::assert_eq;
+
+    use crate::{kube::mock::MockTestKubeClient, mock_expect};
+
+    use super::*;
+
+    fn pods() -> List<Pod> {
+        let yaml = indoc! {
+            "
+            items:
+              - metadata:
+                  name: app-5d8f-abcde
+                  ownerReferences:
+                    - apiVersion: apps/v1
+                      kind: ReplicaSet
+                      name: app-5d8f
+                      uid: uid
+                      controller: true
+                spec:
+                  containers:
+                    - name: app
+                      envFrom:
+                        - configMapRef:
+                            name: app-config
+                        - secretRef:
+                            name: app-secret
+                  volumes:
+                    - name: config
+                      configMap:
+                        name: app-config
+              - metadata:
+                  name: job
+                spec:
+                  containers:
+                    - name: job
+                      env:
+                        - name: TOKEN
+                          valueFrom:
+                            secretKeyRef:
+                              name: missing
+                              key: token
+                        - name: OPTIONAL
+                          valueFrom:
+                            secretKeyRef:
+                              name: optional
+                              key: token
+                              optional: true
+            "
+        };
+
+        serde_yaml::from_str(yaml).unwrap()
+    }
+
+    fn existing() -> BTreeSet<ConfigTarget> {
+        BTreeSet::from([ConfigTarget {
+            kind: ConfigKind::ConfigMap,
+            name: "app-config".into(),
+        }])
+    }
+
+    fn users(target: &ConfigTarget) -> List<Pod> {
+        pods().filter_by_item(target).unwrap_or_default()
+    }
+
+    #[test]
+    fn 参照しているpodとそのpodの存在しない参照を返す() {
+        let target = ConfigTarget {
+            kind: ConfigKind::ConfigMap,
+            name: "app-config".into(),
+        };
+
+        let workloads = Workloads::from([(
+            ("ReplicaSet".into(), "app-5d8f".into()),
+            ("Deployment".into(), "app".into()),
+        )]);
+
+        let actual = used_by(&users(&target), &target, &existing(), &workloads);
+
+        let expected = vec![
+            Color::Cyan.wrap("used by:"),
+            "  app-5d8f-abcde (Deployment/app): envFrom, volume".to_string(),
+            String::default(),
+            Color::Red
+                .wrap("# WARNING: pods using this config reference missing ConfigMaps/Secrets"),
+            Color::Red.wrap("  app-5d8f-abcde: Secret/app-secret (envFrom)"),
+        ];
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn 参照しているpodがないときnoneを返す() {
+        let target = ConfigTarget {
+            kind: ConfigKind::Secret,
+            name: "unused".into(),
+        };
+
+        let actual = used_by(&users(&target), &target, &existing(), &Workloads::new());
+
+        assert_eq!(
+            actual,
+            vec![
+                Color::Cyan.wrap("used by:"),
+                format!("  {}", Color::DarkGray.wrap("<none>"))
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn replicasetの所有者をワークロードとして返す() {
+        let mut client = MockTestKubeClient::new();
+
+        mock_expect!(
+            client,
+            request,
+            [(
+                ReplicaSet,
+                eq("/apis/apps/v1/namespaces/default/replicasets/app-5d8f"),
+                Ok(serde_yaml::from_str(indoc! {
+                    "
+                    metadata:
+                      name: app-5d8f
+                      ownerReferences:
+                        - apiVersion: apps/v1
+                          kind: Deployment
+                          name: app
+                          uid: uid
+                          controller: true
+                    "
+                })
+                .unwrap())
+            )]
+        );
+
+        let actual = resolve_workload(&client, "default", "ReplicaSet", "app-5d8f").await;
+
+        assert_eq!(actual, ("Deployment".to_string(), "app".to_string()));
+
+        let actual = resolve_workload(&client, "default", "StatefulSet", "db").await;
+
+        assert_eq!(actual, ("StatefulSet".to_string(), "db".to_string()));
+    }
+}
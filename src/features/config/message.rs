@@ -4,7 +4,7 @@ use crate::{kube::table::KubeTable, message::Message, workers::kube::message::Ku
 
 pub type ConfigData = Vec<String>;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct RequestData {
     pub name: String,
     pub namespace: String,
@@ -14,9 +14,27 @@ pub struct RequestData {
 pub enum ConfigMessage {
     Request(ConfigRequest),
     Response(ConfigResponse),
+    ToggleSecretReveal(SecretReveal),
+    ToggleRawDataOption(RawDataOption),
 }
 
+/// Secretの値の表示を切り替える対象
 #[derive(Debug, Clone)]
+pub enum SecretReveal {
+    All,
+    Key(String),
+}
+
+/// Raw Dataの表示方法の切り替え
+#[derive(Debug, Clone, Copy)]
+pub enum RawDataOption {
+    /// サイズの大きい値を省略せずに表示する
+    Expand,
+    /// base64エンコードやgzip圧縮された値を展開する
+    Decode,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ConfigRequest {
     ConfigMap(RequestData),
     Secret(RequestData),
@@ -26,6 +44,8 @@ pub enum ConfigRequest {
 pub enum ConfigResponse {
     Table(Result<KubeTable>),
     Data(Result<ConfigData>),
+    /// 表示しているSecretのキー
+    SecretKeys(Vec<String>),
 }
 
 impl ConfigRequest {
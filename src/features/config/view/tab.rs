@@ -9,14 +9,16 @@ use crate::{
     message::Message,
     ui::{
         tab::{LayoutElement, NestedLayoutElement, NestedWidgetLayout, TabLayout},
+        widget::Widget,
         Tab,
     },
 };
 
-use super::widgets::{config_widget, raw_data_widget};
+use super::widgets::{config_widget, raw_data_widget, secret_reveal_widget};
 
 pub struct ConfigTab {
     pub tab: Tab<'static>,
+    pub secret_reveal_dialog: Widget<'static>,
 }
 
 impl ConfigTab {
@@ -27,7 +29,7 @@ impl ConfigTab {
         split_direction: Direction,
     ) -> Self {
         let config_widget = config_widget(tx);
-        let raw_data_widget = raw_data_widget(clipboard);
+        let raw_data_widget = raw_data_widget(tx, clipboard);
 
         let layout = TabLayout::new(layout, split_direction);
 
@@ -38,6 +40,7 @@ impl ConfigTab {
                 [config_widget, raw_data_widget],
                 layout,
             ),
+            secret_reveal_dialog: secret_reveal_widget(tx),
         }
     }
 }
@@ -1,5 +1,7 @@
 mod config;
 mod raw_data;
+mod secret_reveal;
 
 pub(super) use config::*;
 pub(super) use raw_data::*;
+pub(super) use secret_reveal::*;
@@ -2,7 +2,9 @@ use crossbeam::channel::Sender;
 
 use crate::{
     features::{
-        component_id::{CONFIG_RAW_DATA_WIDGET_ID, CONFIG_WIDGET_ID},
+        component_id::{
+            CONFIG_RAW_DATA_WIDGET_ID, CONFIG_SECRET_REVEAL_DIALOG_ID, CONFIG_WIDGET_ID,
+        },
         config::message::{ConfigRequest, RequestData},
     },
     message::Message,
@@ -45,6 +47,7 @@ fn block_injection() -> impl Fn(&Table) -> WidgetBase {
 fn on_select(tx: Sender<Message>) -> impl Fn(&mut Window, &TableItem) -> EventResult {
     move |w, v| {
         w.widget_clear(CONFIG_RAW_DATA_WIDGET_ID);
+        w.widget_clear(CONFIG_SECRET_REVEAL_DIALOG_ID);
 
         let Some(metadata) = v.metadata.as_ref() else {
             return EventResult::Ignore;
@@ -1,19 +1,47 @@
 use std::{cell::RefCell, rc::Rc};
 
+use crossbeam::channel::Sender;
 use ratatui::widgets::Block;
 
 use crate::{
     clipboard::Clipboard,
-    features::component_id::CONFIG_RAW_DATA_WIDGET_ID,
-    ui::widget::{Text, Widget, WidgetBase, WidgetTrait as _},
+    features::{
+        component_id::{CONFIG_RAW_DATA_WIDGET_ID, CONFIG_SECRET_REVEAL_DIALOG_ID},
+        config::message::{ConfigMessage, RawDataOption, SecretReveal},
+    },
+    message::Message,
+    ui::{
+        event::EventResult,
+        widget::{Text, Widget, WidgetBase, WidgetTrait as _},
+        Window,
+    },
 };
 
-pub fn raw_data_widget(clipboard: &Option<Rc<RefCell<Clipboard>>>) -> Widget<'static> {
+/// Secretの値は伏せ字で表示し、クリップボードにも伏せ字のままコピーする
+///
+/// - r: キーを選択して値の表示を切り替える
+/// - R: すべての値の表示を切り替える
+/// - e: サイズの大きい値の省略を切り替える
+/// - d: base64エンコードやgzip圧縮された値の展開を切り替える
+pub fn raw_data_widget(
+    tx: &Sender<Message>,
+    clipboard: &Option<Rc<RefCell<Clipboard>>>,
+) -> Widget<'static> {
     let builder = Text::builder()
         .id(CONFIG_RAW_DATA_WIDGET_ID)
         .widget_base(WidgetBase::builder().title("Raw Data").build())
         .wrap()
-        .block_injection(block_injection());
+        .block_injection(block_injection())
+        .action('r', open_secret_reveal_dialog())
+        .action('R', toggle_reveal_all(tx.clone()))
+        .action(
+            'e',
+            toggle_raw_data_option(tx.clone(), RawDataOption::Expand),
+        )
+        .action(
+            'd',
+            toggle_raw_data_option(tx.clone(), RawDataOption::Decode),
+        );
 
     if let Some(cb) = clipboard {
         builder.clipboard(cb.clone())
@@ -24,6 +52,35 @@ pub fn raw_data_widget(clipboard: &Option<Rc<RefCell<Clipboard>>>) -> Widget<'st
     .into()
 }
 
+fn open_secret_reveal_dialog() -> impl Fn(&mut Window) -> EventResult {
+    |w: &mut Window| {
+        w.open_dialog(CONFIG_SECRET_REVEAL_DIALOG_ID);
+
+        EventResult::Nop
+    }
+}
+
+fn toggle_reveal_all(tx: Sender<Message>) -> impl Fn(&mut Window) -> EventResult {
+    move |_: &mut Window| {
+        tx.send(ConfigMessage::ToggleSecretReveal(SecretReveal::All).into())
+            .expect("Failed to send ConfigMessage::ToggleSecretReveal");
+
+        EventResult::Nop
+    }
+}
+
+fn toggle_raw_data_option(
+    tx: Sender<Message>,
+    option: RawDataOption,
+) -> impl Fn(&mut Window) -> EventResult {
+    move |_: &mut Window| {
+        tx.send(ConfigMessage::ToggleRawDataOption(option).into())
+            .expect("Failed to send ConfigMessage::ToggleRawDataOption");
+
+        EventResult::Nop
+    }
+}
+
 fn block_injection() -> impl Fn(&Text, bool, bool) -> Block<'static> {
     |text: &Text, is_active: bool, is_mouse_over: bool| {
         let (index, size) = text.state();
@@ -0,0 +1,35 @@
+use crossbeam::channel::Sender;
+
+use crate::{
+    features::{
+        component_id::CONFIG_SECRET_REVEAL_DIALOG_ID,
+        config::message::{ConfigMessage, SecretReveal},
+    },
+    message::Message,
+    ui::{
+        event::EventResult,
+        widget::{LiteralItem, SingleSelect, Widget, WidgetBase},
+        Window,
+    },
+};
+
+/// 値の表示を切り替えるSecretのキーを選択する
+pub fn secret_reveal_widget(tx: &Sender<Message>) -> Widget<'static> {
+    SingleSelect::builder()
+        .id(CONFIG_SECRET_REVEAL_DIALOG_ID)
+        .widget_base(WidgetBase::builder().title("Reveal Secret Value").build())
+        .on_select(on_select(tx.clone()))
+        .build()
+        .into()
+}
+
+fn on_select(tx: Sender<Message>) -> impl Fn(&mut Window, &LiteralItem) -> EventResult {
+    move |w, v| {
+        tx.send(ConfigMessage::ToggleSecretReveal(SecretReveal::Key(v.item.to_string())).into())
+            .expect("Failed to send ConfigMessage::ToggleSecretReveal");
+
+        w.close_dialog();
+
+        EventResult::Nop
+    }
+}
@@ -2,12 +2,15 @@ use std::{sync::atomic::Ordering, time};
 
 use anyhow::Result;
 use async_trait::async_trait;
-use futures::future::try_join_all;
+use futures::future::join_all;
 
 use crate::{
     kube::{
         apis::v1_table::{TableRow, ToTime as _},
-        table::{get_resource_per_namespace, insert_ns, KubeTableRow},
+        table::{
+            get_resource_per_namespace, insert_ns, partition_namespaces, KubeTableRow,
+            SkippedNamespace,
+        },
         KubeClient,
     },
     message::Message,
@@ -57,10 +60,17 @@ impl Worker for EventPoller {
 const TARGET_LEN: usize = 4;
 const TARGET: [&str; TARGET_LEN] = ["Last Seen", "Object", "Reason", "Message"];
 
-async fn get_event_table(client: &KubeClient, namespaces: &[String]) -> Result<Vec<String>> {
+/// イベントの行と、取得できなかったネームスペース
+#[derive(Debug, Default)]
+pub struct EventList {
+    pub lines: Vec<String>,
+    pub skipped: Vec<SkippedNamespace>,
+}
+
+async fn get_event_table(client: &KubeClient, namespaces: &[String]) -> Result<EventList> {
     let insert_ns = insert_ns(namespaces);
 
-    let jobs = try_join_all(namespaces.iter().map(|ns| {
+    let jobs = join_all(namespaces.iter().map(|ns| {
         get_resource_per_namespace(
             client,
             format!("api/v1/namespaces/{}/{}", ns, "events"),
@@ -84,13 +94,15 @@ async fn get_event_table(client: &KubeClient, namespaces: &[String]) -> Result<V
             },
         )
     }))
-    .await?;
+    .await;
 
-    let mut ok_only: Vec<KubeTableRow> = jobs.into_iter().flatten().collect();
+    let (rows, skipped) = partition_namespaces(namespaces.iter().cloned().zip(jobs))?;
+
+    let mut ok_only: Vec<KubeTableRow> = rows.into_iter().flatten().collect();
 
     ok_only.sort_by_key(|row| row.row[0].to_time());
 
-    Ok(ok_only
+    let lines = ok_only
         .iter()
         .flat_map(|v| {
             v.row
@@ -112,5 +124,7 @@ async fn get_event_table(client: &KubeClient, namespaces: &[String]) -> Result<V
                 .map(ToString::to_string)
                 .collect::<Vec<_>>()
         })
-        .collect())
+        .collect();
+
+    Ok(EventList { lines, skipped })
 }
@@ -11,11 +11,18 @@ use k8s_openapi::{
 };
 use kube::Api;
 use serde::{de::DeserializeOwned, Serialize};
+use serde_yaml::Value;
 
 use crate::{
     features::{
+        api_resources::kube::ApiResource,
+        config::kube::{SecretMask, MASKED_VALUE},
         get::message::{GetRequest, GetResponse},
         network::message::{GatewayVersion, HTTPRouteVersion},
+        yaml::{
+            self,
+            kube::{highlight_yaml, SharedYamlFilter, YamlFilter, LAST_APPLIED_CONFIGURATION},
+        },
     },
     kube::{
         apis::networking::gateway::{v1, v1beta1},
@@ -36,6 +43,8 @@ pub enum GetYamlKind {
     NetworkPolicy,
     Gateway(GatewayVersion),
     HTTPRoute(HTTPRouteVersion),
+    /// Listタブで表示している任意のリソース
+    ApiResource(ApiResource),
 }
 
 impl std::fmt::Display for GetYamlKind {
@@ -55,6 +64,45 @@ impl std::fmt::Display for GetYamlKind {
                 HTTPRouteVersion::V1 => write!(f, "{}", v1::HTTPRoute::URL_PATH_SEGMENT),
                 HTTPRouteVersion::V1Beta1 => write!(f, "{}", v1beta1::HTTPRoute::URL_PATH_SEGMENT),
             },
+            Self::ApiResource(api_resource) => write!(f, "{}", api_resource.name()),
+        }
+    }
+}
+
+impl GetYamlKind {
+    /// テーブルの行に設定されているkindとversionから変換する
+    pub fn from_kind(kind: &str, version: Option<&str>) -> Option<Self> {
+        let kind = match kind {
+            Pod::KIND => Self::Pod,
+            ConfigMap::KIND => Self::ConfigMap,
+            Secret::KIND => Self::Secret,
+            Ingress::KIND => Self::Ingress,
+            Service::KIND => Self::Service,
+            NetworkPolicy::KIND => Self::NetworkPolicy,
+            v1::Gateway::KIND => match version? {
+                "v1" => Self::Gateway(GatewayVersion::V1),
+                "v1beta1" => Self::Gateway(GatewayVersion::V1Beta1),
+                _ => return None,
+            },
+            v1::HTTPRoute::KIND => match version? {
+                "v1" => Self::HTTPRoute(HTTPRouteVersion::V1),
+                "v1beta1" => Self::HTTPRoute(HTTPRouteVersion::V1Beta1),
+                _ => return None,
+            },
+            _ => return None,
+        };
+
+        Some(kind)
+    }
+
+    /// CoreグループのSecretかどうか（Listタブで選択したsecretsを含む）
+    fn is_secret(&self) -> bool {
+        match self {
+            Self::Secret => true,
+            Self::ApiResource(api_resource) => {
+                api_resource.is_api() && api_resource.name() == Secret::URL_PATH_SEGMENT
+            }
+            _ => false,
         }
     }
 }
@@ -64,6 +112,7 @@ pub struct GetYamlWorker {
     is_terminated: Arc<AtomicBool>,
     tx: Sender<Message>,
     client: KubeClient,
+    shared_yaml_filter: SharedYamlFilter,
     req: GetRequest,
 }
 
@@ -72,12 +121,14 @@ impl GetYamlWorker {
         is_terminated: Arc<AtomicBool>,
         tx: Sender<Message>,
         client: KubeClient,
+        shared_yaml_filter: SharedYamlFilter,
         req: GetRequest,
     ) -> Self {
         Self {
             is_terminated,
             tx,
             client,
+            shared_yaml_filter,
             req,
         }
     }
@@ -88,11 +139,7 @@ impl AbortWorker for GetYamlWorker {
     async fn run(&self) {
         let mut interval = tokio::time::interval(std::time::Duration::from_secs(3));
 
-        let GetRequest {
-            kind,
-            name,
-            namespace,
-        } = &self.req;
+        let GetRequest { kind, name, .. } = &self.req;
 
         while !self
             .is_terminated
@@ -100,41 +147,17 @@ impl AbortWorker for GetYamlWorker {
         {
             interval.tick().await;
 
-            let yaml = match kind {
-                GetYamlKind::Pod => fetch_resource_yaml::<Pod>(&self.client, name, namespace).await,
-                GetYamlKind::ConfigMap => {
-                    fetch_resource_yaml::<ConfigMap>(&self.client, name, namespace).await
-                }
-                GetYamlKind::Secret => {
-                    fetch_resource_yaml::<Secret>(&self.client, name, namespace).await
-                }
-                GetYamlKind::Ingress => {
-                    fetch_resource_yaml::<Ingress>(&self.client, name, namespace).await
-                }
-                GetYamlKind::Service => {
-                    fetch_resource_yaml::<Service>(&self.client, name, namespace).await
-                }
-                GetYamlKind::NetworkPolicy => {
-                    fetch_resource_yaml::<NetworkPolicy>(&self.client, name, namespace).await
-                }
-                GetYamlKind::Gateway(version) => match version {
-                    GatewayVersion::V1 => {
-                        fetch_resource_yaml::<v1::Gateway>(&self.client, name, namespace).await
-                    }
-                    GatewayVersion::V1Beta1 => {
-                        fetch_resource_yaml::<v1beta1::Gateway>(&self.client, name, namespace).await
-                    }
-                },
-                GetYamlKind::HTTPRoute(version) => match version {
-                    HTTPRouteVersion::V1 => {
-                        fetch_resource_yaml::<v1::HTTPRoute>(&self.client, name, namespace).await
-                    }
-                    HTTPRouteVersion::V1Beta1 => {
-                        fetch_resource_yaml::<v1beta1::HTTPRoute>(&self.client, name, namespace)
-                            .await
-                    }
-                },
-            };
+            let value = fetch_value(&self.client, &self.req).await;
+
+            let yaml_filter = *self.shared_yaml_filter.read().await;
+
+            let yaml = value.and_then(|mut value| {
+                yaml_filter.apply(&mut value);
+
+                let yaml_string = highlight_yaml(&serde_yaml::to_string(&value)?);
+
+                Ok(yaml_string)
+            });
 
             self.tx
                 .send(
@@ -150,8 +173,123 @@ impl AbortWorker for GetYamlWorker {
     }
 }
 
-/// 選択されているリソースのyamlを取得する
-async fn fetch_resource_yaml<K>(client: &KubeClient, name: &str, ns: &str) -> Result<Vec<String>>
+/// リソースを取得してyamlのValueに変換する
+async fn fetch_value(client: &KubeClient, req: &GetRequest) -> Result<Value> {
+    let GetRequest {
+        kind,
+        name,
+        namespace,
+    } = req;
+
+    match kind {
+        GetYamlKind::Pod => fetch_resource_value::<Pod>(client, name, namespace).await,
+        GetYamlKind::ConfigMap => fetch_resource_value::<ConfigMap>(client, name, namespace).await,
+        GetYamlKind::Secret => fetch_resource_value::<Secret>(client, name, namespace).await,
+        GetYamlKind::Ingress => fetch_resource_value::<Ingress>(client, name, namespace).await,
+        GetYamlKind::Service => fetch_resource_value::<Service>(client, name, namespace).await,
+        GetYamlKind::NetworkPolicy => {
+            fetch_resource_value::<NetworkPolicy>(client, name, namespace).await
+        }
+        GetYamlKind::Gateway(version) => match version {
+            GatewayVersion::V1 => {
+                fetch_resource_value::<v1::Gateway>(client, name, namespace).await
+            }
+            GatewayVersion::V1Beta1 => {
+                fetch_resource_value::<v1beta1::Gateway>(client, name, namespace).await
+            }
+        },
+        GetYamlKind::HTTPRoute(version) => match version {
+            HTTPRouteVersion::V1 => {
+                fetch_resource_value::<v1::HTTPRoute>(client, name, namespace).await
+            }
+            HTTPRouteVersion::V1Beta1 => {
+                fetch_resource_value::<v1beta1::HTTPRoute>(client, name, namespace).await
+            }
+        },
+        GetYamlKind::ApiResource(api_resource) => {
+            yaml::kube::fetch_resource_value(client, api_resource, name, namespace).await
+        }
+    }
+}
+
+/// クリップボードにコピーするためのyamlを取得する
+///
+/// シンタックスハイライトは行わない
+pub async fn fetch_yaml_for_copy(
+    client: &KubeClient,
+    req: &GetRequest,
+    yaml_filter: &YamlFilter,
+    secret_mask: &SecretMask,
+) -> Result<String> {
+    let value = fetch_value(client, req).await?;
+
+    copy_yaml(req, value, yaml_filter, secret_mask)
+}
+
+fn copy_yaml(
+    req: &GetRequest,
+    mut value: Value,
+    yaml_filter: &YamlFilter,
+    secret_mask: &SecretMask,
+) -> Result<String> {
+    yaml_filter.apply(&mut value);
+
+    if req.kind.is_secret() {
+        // 値の表示はConfigタブで表示しているSecretにのみ適用する
+        let secret_mask = secret_mask.for_secret(&req.namespace, &req.name);
+
+        mask_secret_value(&mut value, &secret_mask);
+
+        mask_last_applied_configuration(&mut value, &secret_mask);
+    }
+
+    Ok(serde_yaml::to_string(&value)?)
+}
+
+/// last-applied-configurationのannotationに含まれるSecretの値を伏せ字にする
+///
+/// annotationを解析できないときはannotation全体を伏せ字にする
+fn mask_last_applied_configuration(value: &mut Value, secret_mask: &SecretMask) {
+    let Some(Value::Mapping(annotations)) = value
+        .get_mut("metadata")
+        .and_then(|metadata| metadata.get_mut("annotations"))
+    else {
+        return;
+    };
+
+    let Some(annotation) = annotations.get_mut(LAST_APPLIED_CONFIGURATION) else {
+        return;
+    };
+
+    let masked = annotation
+        .as_str()
+        .and_then(|json| serde_yaml::from_str::<Value>(json).ok())
+        .and_then(|mut applied| {
+            mask_secret_value(&mut applied, secret_mask);
+            serde_json::to_string(&applied).ok()
+        })
+        .unwrap_or_else(|| MASKED_VALUE.to_string());
+
+    *annotation = Value::String(masked);
+}
+
+/// Secretのdata・stringDataの値のうち、表示していないものを伏せ字にする
+fn mask_secret_value(value: &mut Value, secret_mask: &SecretMask) {
+    for field in ["data", "stringData"] {
+        let Some(Value::Mapping(data)) = value.get_mut(field) else {
+            continue;
+        };
+
+        for (key, value) in data.iter_mut() {
+            if key.as_str().is_some_and(|key| secret_mask.is_masked(key)) {
+                *value = Value::String(MASKED_VALUE.to_string());
+            }
+        }
+    }
+}
+
+/// 選択されているリソースを取得してyamlのValueに変換する
+async fn fetch_resource_value<K>(client: &KubeClient, name: &str, ns: &str) -> Result<Value>
 where
     K: kube::Resource<Scope = NamespaceResourceScope>,
     <K as kube::Resource>::DynamicType: Default,
@@ -168,15 +306,199 @@ where
 
     let api: Api<K> = Api::namespaced(client.to_client(), ns);
 
-    let mut data = api.get(name).await?;
+    let data = api.get(name).await?;
+
+    let value = serde_yaml::to_value(&data)?;
+
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+
+    fn request(kind: GetYamlKind) -> GetRequest {
+        GetRequest {
+            name: "db".into(),
+            namespace: "default".into(),
+            kind,
+        }
+    }
+
+    fn secret_value() -> Value {
+        serde_yaml::from_str(indoc! {"
+            apiVersion: v1
+            kind: Secret
+            metadata:
+              name: db
+              namespace: default
+            data:
+              password: cGFzc3dvcmQ=
+              username: YWRtaW4=
+            stringData:
+              token: token
+        "})
+        .unwrap()
+    }
+
+    #[test]
+    fn secretの値を伏せ字にしてコピーする() {
+        let actual = copy_yaml(
+            &request(GetYamlKind::Secret),
+            secret_value(),
+            &YamlFilter::default(),
+            &SecretMask::default(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            actual,
+            indoc! {"
+                apiVersion: v1
+                kind: Secret
+                metadata:
+                  name: db
+                  namespace: default
+                data:
+                  password: '********'
+                  username: '********'
+                stringData:
+                  token: '********'
+            "}
+        );
+    }
+
+    #[test]
+    fn 表示しているsecretの値はそのままコピーする() {
+        let mut secret_mask = SecretMask::default();
+
+        secret_mask.set_secret(Some(("default".into(), "db".into())));
+        secret_mask.toggle_key("password");
 
-    let metadata = data.meta_mut();
-    metadata.managed_fields = None;
+        let actual = copy_yaml(
+            &request(GetYamlKind::Secret),
+            secret_value(),
+            &YamlFilter::default(),
+            &secret_mask,
+        )
+        .unwrap();
 
-    let yaml_string = serde_yaml::to_string(&data)?
-        .lines()
-        .map(ToString::to_string)
-        .collect();
+        assert!(actual.contains("password: cGFzc3dvcmQ="));
+        assert!(actual.contains("username: '********'"));
+    }
+
+    #[test]
+    fn listタブのsecretの値を伏せ字にしてコピーする() {
+        let kind = GetYamlKind::ApiResource(ApiResource::Api {
+            name: "secrets".into(),
+            version: "v1".into(),
+            scope: kube::discovery::Scope::Namespaced,
+        });
+
+        let actual = copy_yaml(
+            &request(kind),
+            secret_value(),
+            &YamlFilter::default(),
+            &SecretMask::default(),
+        )
+        .unwrap();
+
+        assert!(actual.contains("password: '********'"));
+    }
 
-    Ok(yaml_string)
+    #[test]
+    fn secret以外は伏せ字にしない() {
+        let actual = copy_yaml(
+            &request(GetYamlKind::ConfigMap),
+            secret_value(),
+            &YamlFilter::default(),
+            &SecretMask::default(),
+        )
+        .unwrap();
+
+        assert!(actual.contains("password: cGFzc3dvcmQ="));
+    }
+
+    #[test]
+    fn 別のsecretで表示した値は伏せ字にしてコピーする() {
+        let mut secret_mask = SecretMask::default();
+
+        secret_mask.set_secret(Some(("default".into(), "other".into())));
+        secret_mask.toggle_all();
+
+        let actual = copy_yaml(
+            &request(GetYamlKind::Secret),
+            secret_value(),
+            &YamlFilter::default(),
+            &secret_mask,
+        )
+        .unwrap();
+
+        assert!(actual.contains("password: '********'"));
+        assert!(actual.contains("username: '********'"));
+    }
+
+    #[test]
+    fn last_applied_configurationのsecretの値を伏せ字にする() {
+        let mut value = secret_value();
+
+        value["metadata"]["annotations"] = serde_yaml::from_str(indoc! {r#"
+            kubectl.kubernetes.io/last-applied-configuration: '{"apiVersion":"v1","data":{"password":"cGFzc3dvcmQ="},"kind":"Secret"}'
+        "#})
+        .unwrap();
+
+        let yaml_filter = YamlFilter {
+            hide_noisy_fields: false,
+            hide_status: false,
+        };
+
+        let actual = copy_yaml(
+            &request(GetYamlKind::Secret),
+            value,
+            &yaml_filter,
+            &SecretMask::default(),
+        )
+        .unwrap();
+
+        let actual: Value = serde_yaml::from_str(&actual).unwrap();
+
+        assert_eq!(
+            actual["metadata"]["annotations"][LAST_APPLIED_CONFIGURATION],
+            Value::String(
+                r#"{"apiVersion":"v1","data":{"password":"********"},"kind":"Secret"}"#.into()
+            )
+        );
+    }
+
+    #[test]
+    fn 解析できないlast_applied_configurationは全体を伏せ字にする() {
+        let mut value = secret_value();
+
+        value["metadata"]["annotations"] = serde_yaml::from_str(indoc! {r#"
+            kubectl.kubernetes.io/last-applied-configuration: '{"data":'
+        "#})
+        .unwrap();
+
+        let yaml_filter = YamlFilter {
+            hide_noisy_fields: false,
+            hide_status: false,
+        };
+
+        let actual = copy_yaml(
+            &request(GetYamlKind::Secret),
+            value,
+            &yaml_filter,
+            &SecretMask::default(),
+        )
+        .unwrap();
+
+        let actual: Value = serde_yaml::from_str(&actual).unwrap();
+
+        assert_eq!(
+            actual["metadata"]["annotations"][LAST_APPLIED_CONFIGURATION],
+            Value::String(MASKED_VALUE.into())
+        );
+    }
 }
@@ -1,6 +1,10 @@
+use std::collections::BTreeMap;
+
 use anyhow::Result;
 
-use crate::{message::Message, workers::kube::message::Kube};
+use crate::{
+    features::api_resources::kube::API_RESOURCE_KEY, message::Message, workers::kube::message::Kube,
+};
 
 pub use super::kube::yaml::GetYamlKind;
 
@@ -8,6 +12,9 @@ pub use super::kube::yaml::GetYamlKind;
 pub enum GetMessage {
     Request(GetRequest),
     Response(GetResponse),
+    /// クリップボードにコピーするyamlを取得する
+    CopyYamlRequest(GetRequest),
+    CopyYamlResponse(Result<String>),
 }
 
 #[derive(Debug, Clone)]
@@ -17,6 +24,29 @@ pub struct GetRequest {
     pub kind: GetYamlKind,
 }
 
+impl GetRequest {
+    /// テーブルの行のmetadataから生成する
+    ///
+    /// Listタブの行はAPIリソースを、それ以外はkindとversionを使ってリソースの種類を決める
+    pub fn from_metadata(metadata: &BTreeMap<String, String>) -> Option<Self> {
+        let kind = match metadata.get(API_RESOURCE_KEY) {
+            Some(api_resource) => {
+                GetYamlKind::ApiResource(serde_json::from_str(api_resource).ok()?)
+            }
+            None => GetYamlKind::from_kind(
+                metadata.get("kind")?,
+                metadata.get("version").map(|v| v.as_str()),
+            )?,
+        };
+
+        Some(Self {
+            name: metadata.get("name")?.to_string(),
+            namespace: metadata.get("namespace")?.to_string(),
+            kind,
+        })
+    }
+}
+
 #[derive(Debug)]
 pub struct GetResponse {
     pub kind: String,
@@ -35,3 +65,9 @@ impl From<GetResponse> for Message {
         Self::Kube(Kube::Get(GetMessage::Response(res)))
     }
 }
+
+impl From<GetMessage> for Message {
+    fn from(m: GetMessage) -> Self {
+        Self::Kube(Kube::Get(m))
+    }
+}
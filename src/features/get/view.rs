@@ -1,3 +1,5 @@
+mod copy;
 mod yaml;
 
+pub use copy::*;
 pub use yaml::*;
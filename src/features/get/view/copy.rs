@@ -0,0 +1,197 @@
+use std::{cell::RefCell, collections::BTreeMap, rc::Rc};
+
+use crossbeam::channel::Sender;
+use k8s_openapi::{api::core::v1::Pod, Resource as _};
+
+use crate::{
+    clipboard::Clipboard,
+    features::{
+        component_id::COPY_DIALOG_ID,
+        get::message::{GetMessage, GetRequest},
+    },
+    logger,
+    message::Message,
+    ui::{
+        event::EventResult,
+        widget::{LiteralItem, SingleSelect, Widget, WidgetBase},
+        Window,
+    },
+};
+
+/// コピーする文字列を保持するmetadataのキー
+const VALUE_KEY: &str = "value";
+
+/// yamlをコピーする項目に設定するmetadataのキー
+const COPY_YAML_KEY: &str = "copy_yaml";
+
+pub struct CopyDialog {
+    pub widget: Widget<'static>,
+}
+
+impl CopyDialog {
+    pub fn new(tx: &Sender<Message>, clipboard: &Option<Rc<RefCell<Clipboard>>>) -> Self {
+        Self {
+            widget: widget(tx.clone(), clipboard.clone()),
+        }
+    }
+}
+
+fn widget(tx: Sender<Message>, clipboard: Option<Rc<RefCell<Clipboard>>>) -> Widget<'static> {
+    SingleSelect::builder()
+        .id(COPY_DIALOG_ID)
+        .widget_base(WidgetBase::builder().title("Copy").build())
+        .on_select(on_select(tx, clipboard))
+        .build()
+        .into()
+}
+
+fn on_select(
+    tx: Sender<Message>,
+    clipboard: Option<Rc<RefCell<Clipboard>>>,
+) -> impl Fn(&mut Window, &LiteralItem) -> EventResult {
+    move |w, v| {
+        w.close_dialog();
+
+        let Some(metadata) = v.metadata.as_ref() else {
+            return EventResult::Nop;
+        };
+
+        if let Some(value) = metadata.get(VALUE_KEY) {
+            if let Some(clipboard) = &clipboard {
+                logger!(info, "Clipboard saved '{}'", value);
+
+                if let Err(e) = clipboard.borrow_mut().set_contents(value.to_string()) {
+                    logger!(error, "Clipboard Error '{}'", e);
+                }
+            }
+
+            return EventResult::Nop;
+        }
+
+        if metadata.contains_key(COPY_YAML_KEY) {
+            if let Some(req) = GetRequest::from_metadata(metadata) {
+                tx.send(GetMessage::CopyYamlRequest(req).into())
+                    .expect("Failed to send GetMessage::CopyYamlRequest");
+            }
+        }
+
+        EventResult::Nop
+    }
+}
+
+fn value_item(value: String) -> LiteralItem {
+    LiteralItem::new(
+        value.clone(),
+        Some(BTreeMap::from([(VALUE_KEY.to_string(), value)])),
+    )
+}
+
+/// テーブルの行のmetadataからコピーする項目を生成する
+///
+/// - name
+/// - namespace/name
+/// - yaml（yamlダイアログで表示できるリソースのみ）
+/// - kubectl get コマンド
+/// - kubectl logs コマンド（Podのみ）
+pub fn copy_items(metadata: &BTreeMap<String, String>) -> Vec<LiteralItem> {
+    let (Some(name), Some(kind)) = (metadata.get("name"), metadata.get("kind")) else {
+        return Vec::default();
+    };
+
+    // クラスタスコープのリソースはnamespaceが空になる
+    let namespace = metadata.get("namespace").filter(|ns| !ns.is_empty());
+
+    let namespace_option = namespace
+        .map(|ns| format!(" -n {}", ns))
+        .unwrap_or_default();
+
+    let mut items = vec![value_item(name.to_string())];
+
+    if let Some(namespace) = namespace {
+        items.push(value_item(format!("{}/{}", namespace, name)));
+    }
+
+    if GetRequest::from_metadata(metadata).is_some() {
+        let mut metadata = metadata.clone();
+        metadata.insert(COPY_YAML_KEY.to_string(), String::default());
+
+        items.push(LiteralItem::new("YAML", Some(metadata)));
+    }
+
+    items.push(value_item(format!(
+        "kubectl{} get {} {}",
+        namespace_option,
+        kind.to_lowercase(),
+        name
+    )));
+
+    if kind == Pod::KIND {
+        items.push(value_item(format!(
+            "kubectl{} logs {} --all-containers",
+            namespace_option, name
+        )));
+    }
+
+    items
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn items(metadata: &[(&str, &str)]) -> Vec<String> {
+        let metadata = metadata
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+
+        copy_items(&metadata).into_iter().map(|i| i.item).collect()
+    }
+
+    #[test]
+    fn podのときlogsコマンドを含む() {
+        let actual = items(&[("kind", "Pod"), ("name", "app"), ("namespace", "default")]);
+
+        assert_eq!(
+            actual,
+            vec![
+                "app",
+                "default/app",
+                "YAML",
+                "kubectl -n default get pod app",
+                "kubectl -n default logs app --all-containers",
+            ]
+        );
+    }
+
+    #[test]
+    fn yamlダイアログで表示できないリソースのときyamlを含まない() {
+        let actual = items(&[("kind", "Node"), ("name", "node-1")]);
+
+        assert_eq!(actual, vec!["node-1", "kubectl get node node-1"]);
+    }
+
+    #[test]
+    fn namespaceが空のときnamespaceを含めない() {
+        let actual = items(&[
+            ("kind", "clusterroles.rbac.authorization.k8s.io"),
+            ("name", "admin"),
+            ("namespace", ""),
+            (
+                "api_resource",
+                r#"{"Apis":{"name":"clusterroles","group":"rbac.authorization.k8s.io","version":"v1","preferred_version":true,"scope":"Cluster"}}"#,
+            ),
+        ]);
+
+        assert_eq!(
+            actual,
+            vec![
+                "admin",
+                "YAML",
+                "kubectl get clusterroles.rbac.authorization.k8s.io admin",
+            ]
+        );
+    }
+}
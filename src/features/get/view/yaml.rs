@@ -1,9 +1,16 @@
 use std::{cell::RefCell, rc::Rc};
 
+use crossbeam::channel::Sender;
+
 use crate::{
     clipboard::Clipboard,
-    features::component_id::YAML_DIALOG_ID,
-    ui::widget::{Text, Widget, WidgetBase, WidgetTrait},
+    features::{component_id::YAML_DIALOG_ID, yaml::message::YamlRequest},
+    message::Message,
+    ui::{
+        event::EventResult,
+        widget::{Text, Widget, WidgetBase, WidgetTrait},
+        Window,
+    },
 };
 
 pub struct YamlDialog {
@@ -11,14 +18,14 @@ pub struct YamlDialog {
 }
 
 impl YamlDialog {
-    pub fn new(clipboard: &Option<Rc<RefCell<Clipboard>>>) -> Self {
+    pub fn new(tx: &Sender<Message>, clipboard: &Option<Rc<RefCell<Clipboard>>>) -> Self {
         Self {
-            widget: widget(clipboard),
+            widget: widget(tx, clipboard),
         }
     }
 }
 
-fn widget(clipboard: &Option<Rc<RefCell<Clipboard>>>) -> Widget<'static> {
+fn widget(tx: &Sender<Message>, clipboard: &Option<Rc<RefCell<Clipboard>>>) -> Widget<'static> {
     let mut builder = Text::builder()
         .id(YAML_DIALOG_ID)
         .widget_base(WidgetBase::builder().title("Yaml").build())
@@ -31,7 +38,9 @@ fn widget(clipboard: &Option<Rc<RefCell<Clipboard>>>) -> Widget<'static> {
 
             base.render_block(text.can_activate() && is_active, is_mouse_over)
         })
-        .wrap();
+        .action('m', toggle_noisy_fields(tx.clone()))
+        .wrap()
+        .foldable();
 
     if let Some(clipboard) = clipboard {
         builder = builder.clipboard(clipboard.clone());
@@ -39,3 +48,12 @@ fn widget(clipboard: &Option<Rc<RefCell<Clipboard>>>) -> Widget<'static> {
 
     builder.build().into()
 }
+
+fn toggle_noisy_fields(tx: Sender<Message>) -> impl Fn(&mut Window) -> EventResult {
+    move |_: &mut Window| {
+        tx.send(YamlRequest::ToggleNoisyFields.into())
+            .expect("Failed to send YamlRequest::ToggleNoisyFields");
+
+        EventResult::Nop
+    }
+}
@@ -0,0 +1,7 @@
+mod description;
+mod helm;
+mod release;
+
+pub use description::*;
+pub use helm::*;
+pub use release::{decompress_release, RELEASE_KEY};
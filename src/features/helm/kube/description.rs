@@ -0,0 +1,339 @@
+use std::{
+    collections::BTreeMap,
+    sync::{atomic::AtomicBool, Arc},
+};
+
+use anyhow::{Context as _, Result};
+use async_trait::async_trait;
+use crossbeam::channel::Sender;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::{
+    features::{
+        helm::message::{HelmReleaseTarget, HelmResponse},
+        yaml::kube::{highlight_yaml, unified_diff},
+    },
+    kube::{
+        table::{KubeTable, KubeTableRow},
+        KubeClientRequest,
+    },
+    message::Message,
+    workers::kube::AbortWorker,
+};
+
+use super::release::{fetch_release_history, Release};
+
+const INTERVAL: u64 = 3;
+
+#[derive(Clone)]
+pub struct HelmDescriptionWorker<C>
+where
+    C: KubeClientRequest,
+{
+    is_terminated: Arc<AtomicBool>,
+    tx: Sender<Message>,
+    client: C,
+    target: HelmReleaseTarget,
+}
+
+impl<C> HelmDescriptionWorker<C>
+where
+    C: KubeClientRequest,
+{
+    pub fn new(
+        is_terminated: Arc<AtomicBool>,
+        tx: Sender<Message>,
+        client: C,
+        target: HelmReleaseTarget,
+    ) -> Self {
+        Self {
+            is_terminated,
+            tx,
+            client,
+            target,
+        }
+    }
+}
+
+#[async_trait]
+impl<C> AbortWorker for HelmDescriptionWorker<C>
+where
+    C: KubeClientRequest,
+{
+    async fn run(&self) {
+        let mut interval = tokio::time::interval(std::time::Duration::from_secs(INTERVAL));
+
+        let HelmReleaseTarget { namespace, name } = &self.target;
+
+        while !self
+            .is_terminated
+            .load(std::sync::atomic::Ordering::Relaxed)
+        {
+            interval.tick().await;
+
+            let (description, history) =
+                match fetch_release_history(&self.client, namespace, name).await {
+                    Ok(releases) => (description(&releases), Ok(history_table(&releases))),
+                    Err(err) => (Err(anyhow::anyhow!("{}", err)), Err(err)),
+                };
+
+            self.tx
+                .send(HelmResponse::Description(description).into())
+                .expect("Failed to send HelmResponse::Description");
+
+            self.tx
+                .send(HelmResponse::History(history).into())
+                .expect("Failed to send HelmResponse::History");
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct Description<'a> {
+    release: Summary<'a>,
+    values: Value,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    notes: Option<&'a str>,
+    manifest: &'a str,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Summary<'a> {
+    name: &'a str,
+    namespace: &'a str,
+    revision: u32,
+    status: &'a str,
+    chart: String,
+    app_version: &'a str,
+    updated: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<&'a str>,
+}
+
+fn description(releases: &[Release]) -> Result<Vec<String>> {
+    Ok(highlight_yaml(&description_yaml(releases)?))
+}
+
+/// 最新リビジョンのcomputed values、notes、マニフェスト
+fn description_yaml(releases: &[Release]) -> Result<String> {
+    let release = releases.last().context("release not found")?;
+
+    let description = Description {
+        release: Summary {
+            name: &release.name,
+            namespace: &release.namespace,
+            revision: release.version,
+            status: release.status(),
+            chart: release.chart(),
+            app_version: release.app_version(),
+            updated: release.updated(),
+            description: release.info.description.as_deref(),
+        },
+        values: release.computed_values(),
+        notes: release.info.notes.as_deref().filter(|n| !n.is_empty()),
+        manifest: &release.manifest,
+    };
+
+    Ok(serde_yaml::to_string(&description)?)
+}
+
+fn history_table(releases: &[Release]) -> KubeTable {
+    let mut table = KubeTable {
+        header: [
+            "REVISION",
+            "UPDATED",
+            "STATUS",
+            "CHART",
+            "APP VERSION",
+            "DESCRIPTION",
+        ]
+        .iter()
+        .map(ToString::to_string)
+        .collect(),
+        ..Default::default()
+    };
+
+    table.update_rows(
+        releases
+            .iter()
+            .rev()
+            .map(|release| KubeTableRow {
+                namespace: release.namespace.clone(),
+                name: release.name.clone(),
+                metadata: Some(BTreeMap::from([(
+                    "revision".to_string(),
+                    release.version.to_string(),
+                )])),
+                row: vec![
+                    release.version.to_string(),
+                    release.updated(),
+                    release.status().to_string(),
+                    release.chart(),
+                    release.app_version().to_string(),
+                    release.info.description.clone().unwrap_or_default(),
+                ],
+            })
+            .collect(),
+    );
+
+    table
+}
+
+/// 指定したリビジョンのマニフェストと、その前のリビジョンのマニフェストの差分
+pub async fn fetch_revision_diff<C: KubeClientRequest>(
+    client: &C,
+    target: &HelmReleaseTarget,
+    revision: u32,
+) -> Result<Vec<String>> {
+    let releases = fetch_release_history(client, &target.namespace, &target.name).await?;
+
+    revision_diff(&releases, revision)
+}
+
+fn revision_diff(releases: &[Release], revision: u32) -> Result<Vec<String>> {
+    let new = releases
+        .iter()
+        .find(|release| release.version == revision)
+        .with_context(|| format!("revision {} not found", revision))?;
+
+    let new_header = format!("{} (revision {})", new.name, new.version);
+
+    let diff = match releases
+        .iter()
+        .filter(|release| release.version < revision)
+        .max_by_key(|release| release.version)
+    {
+        Some(old) => unified_diff(
+            &old.manifest,
+            &new.manifest,
+            &format!("{} (revision {})", old.name, old.version),
+            &new_header,
+        ),
+        None => unified_diff("", &new.manifest, "(no previous revision)", &new_header),
+    };
+
+    Ok(diff)
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use crate::workers::kube::color::fg::Color;
+
+    use super::*;
+
+    fn release(version: u32, manifest: &str) -> Release {
+        serde_json::from_value(json!({
+            "name": "app",
+            "namespace": "default",
+            "version": version,
+            "info": {
+                "last_deployed": "2024-01-01T00:00:00Z",
+                "status": "deployed",
+                "description": "Upgrade complete",
+                "notes": "Thank you\n"
+            },
+            "chart": {
+                "metadata": {"name": "nginx", "version": "1.2.3", "appVersion": "1.25.0"},
+                "values": {"replicaCount": 1}
+            },
+            "config": {"replicaCount": 2},
+            "manifest": manifest
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn 最新リビジョンのvaluesとnotesとマニフェストを返す() {
+        let releases = vec![
+            release(1, "kind: Service\n"),
+            release(2, "kind: Deployment\n"),
+        ];
+
+        let actual = description_yaml(&releases).unwrap();
+
+        let expected = indoc! {"
+            release:
+              name: app
+              namespace: default
+              revision: 2
+              status: deployed
+              chart: nginx-1.2.3
+              appVersion: 1.25.0
+              updated: 2024-01-01T00:00:00Z
+              description: Upgrade complete
+            values:
+              replicaCount: 2
+            notes: |
+              Thank you
+            manifest: |
+              kind: Deployment
+        "};
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn 新しいリビジョンから順に履歴を返す() {
+        let releases = vec![release(1, ""), release(2, "")];
+
+        let actual = history_table(&releases);
+
+        assert_eq!(
+            actual
+                .rows
+                .iter()
+                .map(|r| r.row[0].clone())
+                .collect::<Vec<_>>(),
+            vec!["2", "1"]
+        );
+        assert_eq!(
+            actual.rows[0].metadata.as_ref().unwrap()["revision"],
+            "2".to_string()
+        );
+    }
+
+    #[test]
+    fn 前のリビジョンとの差分を返す() {
+        let releases = vec![
+            release(
+                1,
+                indoc! {"
+                kind: Service
+                port: 80
+            "},
+            ),
+            release(
+                3,
+                indoc! {"
+                kind: Service
+                port: 8080
+            "},
+            ),
+        ];
+
+        let actual = revision_diff(&releases, 3).unwrap();
+
+        assert_eq!(
+            actual,
+            vec![
+                Color::Red.wrap("--- app (revision 1)"),
+                Color::Green.wrap("+++ app (revision 3)"),
+                Color::Cyan.wrap("@@ -1,2 +1,2 @@"),
+                " kind: Service".to_string(),
+                Color::Red.wrap("-port: 80"),
+                Color::Green.wrap("+port: 8080"),
+            ]
+        );
+    }
+
+    #[test]
+    fn 存在しないリビジョンのときエラーを返す() {
+        assert!(revision_diff(&[release(1, "")], 2).is_err());
+    }
+}
@@ -0,0 +1,456 @@
+use std::{collections::BTreeMap, time};
+
+use anyhow::Result;
+use async_trait::async_trait;
+use futures::future::{join_all, try_join_all};
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
+
+use crate::{
+    features::{component_id::HELM_TAB_ID, helm::message::HelmResponse},
+    kube::{
+        table::{insert_ns, partition_namespaces, KubeTable, KubeTableRow},
+        KubeClientRequest,
+    },
+    workers::kube::{PollerBase, SharedActiveTab, Worker, WorkerResult},
+};
+
+use super::release::{fetch_release, fetch_release_secret_metadata, secret_revision};
+
+const INTERVAL: u64 = 3;
+
+#[derive(Clone)]
+pub struct HelmPoller {
+    base: PollerBase,
+    shared_active_tab: SharedActiveTab,
+}
+
+impl HelmPoller {
+    pub fn new(base: PollerBase, shared_active_tab: SharedActiveTab) -> Self {
+        Self {
+            base,
+            shared_active_tab,
+        }
+    }
+}
+
+#[async_trait]
+impl Worker for HelmPoller {
+    type Output = WorkerResult;
+
+    async fn run(&self) -> Self::Output {
+        let mut interval = tokio::time::interval(time::Duration::from_secs(INTERVAL));
+
+        let Self {
+            base:
+                PollerBase {
+                    is_terminated,
+                    tx,
+                    shared_target_namespaces,
+                    kube_client,
+                },
+            shared_active_tab,
+        } = self;
+
+        let mut cache = ReleaseCache::default();
+
+        while !is_terminated.load(std::sync::atomic::Ordering::Relaxed) {
+            interval.tick().await;
+
+            // Helmタブを表示していないときは取得しない
+            if shared_active_tab.wait_until_active(HELM_TAB_ID).await {
+                interval.reset();
+            }
+
+            let target_namespaces = shared_target_namespaces.read().await;
+
+            let table = fetch_releases(kube_client, &target_namespaces, &mut cache).await;
+
+            tx.send(HelmResponse::Table(table).into())
+                .expect("Failed to send HelmResponse::Table");
+        }
+
+        WorkerResult::Terminated
+    }
+}
+
+/// デコードしたリリースの表示する値
+#[derive(Debug, Clone)]
+struct CachedRelease {
+    resource_version: Option<String>,
+    name: String,
+    row: Vec<String>,
+}
+
+/// リリースを保存するSecretのネームスペースと名前をキーにした、デコード済みのリリース
+///
+/// resourceVersionが変わっていないSecretは再取得しない
+type ReleaseCache = BTreeMap<(String, String), CachedRelease>;
+
+/// ラベルからリリースごとの最新リビジョンを選び、キャッシュにないSecretのみ取得してデコードする
+async fn fetch_releases_per_namespace<C: KubeClientRequest>(
+    client: &C,
+    namespace: &str,
+    cache: &ReleaseCache,
+) -> Result<Vec<((String, String), CachedRelease)>> {
+    let metadata = fetch_release_secret_metadata(client, namespace).await?;
+
+    let mut latest: BTreeMap<String, ObjectMeta> = BTreeMap::new();
+
+    for metadata in metadata {
+        let Some(name) = metadata
+            .labels
+            .as_ref()
+            .and_then(|labels| labels.get("name"))
+            .cloned()
+        else {
+            continue;
+        };
+
+        match latest.get(&name) {
+            Some(current) if secret_revision(current) >= secret_revision(&metadata) => {}
+            _ => {
+                latest.insert(name, metadata);
+            }
+        }
+    }
+
+    try_join_all(latest.into_values().filter_map(|metadata| {
+        let secret_name = metadata.name?;
+        let key = (namespace.to_string(), secret_name);
+        let resource_version = metadata.resource_version;
+
+        Some(async move {
+            if let Some(cached) = cache.get(&key).filter(|cached| {
+                resource_version.is_some() && cached.resource_version == resource_version
+            }) {
+                return Ok((key, cached.clone()));
+            }
+
+            let release = fetch_release(client, namespace, &key.1).await?;
+
+            let cached = CachedRelease {
+                resource_version,
+                row: vec![
+                    release.name.clone(),
+                    release.version.to_string(),
+                    release.status().to_string(),
+                    release.chart(),
+                    release.app_version().to_string(),
+                    release.updated(),
+                ],
+                name: release.name,
+            };
+
+            Ok((key, cached))
+        })
+    }))
+    .await
+}
+
+async fn fetch_releases<C: KubeClientRequest>(
+    client: &C,
+    namespaces: &[String],
+    cache: &mut ReleaseCache,
+) -> Result<KubeTable> {
+    let insert_ns = insert_ns(namespaces);
+
+    let mut table = KubeTable {
+        header: if insert_ns {
+            [
+                "NAMESPACE",
+                "NAME",
+                "REVISION",
+                "STATUS",
+                "CHART",
+                "APP VERSION",
+                "UPDATED",
+            ]
+            .iter()
+            .map(ToString::to_string)
+            .collect()
+        } else {
+            [
+                "NAME",
+                "REVISION",
+                "STATUS",
+                "CHART",
+                "APP VERSION",
+                "UPDATED",
+            ]
+            .iter()
+            .map(ToString::to_string)
+            .collect()
+        },
+        ..Default::default()
+    };
+
+    let jobs = join_all(
+        namespaces
+            .iter()
+            .map(|ns| fetch_releases_per_namespace(client, ns, cache)),
+    )
+    .await;
+
+    let (releases, skipped) = partition_namespaces(namespaces.iter().cloned().zip(jobs))?;
+
+    table.skipped = skipped;
+
+    // 今回取得したリリースのみ残す
+    *cache = releases.into_iter().flatten().collect();
+
+    table.update_rows(
+        cache
+            .iter()
+            .map(|((namespace, _), release)| {
+                let mut row = release.row.clone();
+
+                if insert_ns {
+                    row.insert(0, namespace.clone());
+                }
+
+                KubeTableRow {
+                    namespace: namespace.clone(),
+                    name: release.name.clone(),
+                    row,
+                    ..Default::default()
+                }
+            })
+            .collect(),
+    );
+
+    Ok(table)
+}
+
+#[cfg(test)]
+mod tests {
+    use k8s_openapi::api::core::v1::Secret;
+    use kube::core::ErrorResponse;
+    use mockall::predicate::eq;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use crate::{
+        features::helm::kube::release::tests::release_secret,
+        kube::{apis::v1_table::Table, mock::MockTestKubeClient},
+        mock_expect,
+    };
+
+    use super::*;
+
+    fn release(name: &str, version: u32, status: &str) -> Secret {
+        release_secret(json!({
+            "name": name,
+            "namespace": "default",
+            "version": version,
+            "info": {
+                "last_deployed": "2024-01-01T00:00:00Z",
+                "status": status
+            },
+            "chart": {
+                "metadata": {"name": "nginx", "version": format!("1.0.{}", version), "appVersion": "1.25.0"}
+            }
+        }))
+    }
+
+    /// dataを含まないTable形式のSecretの一覧
+    fn release_table(releases: &[(&str, u32)]) -> Table {
+        let rows: Vec<_> = releases
+            .iter()
+            .map(|(name, version)| {
+                json!({
+                    "cells": [format!("sh.helm.release.v1.{}.v{}", name, version), "helm.sh/release.v1", 1, "1d"],
+                    "object": {
+                        "kind": "PartialObjectMetadata",
+                        "apiVersion": "meta.k8s.io/v1",
+                        "metadata": {
+                            "name": format!("sh.helm.release.v1.{}.v{}", name, version),
+                            "namespace": "default",
+                            "resourceVersion": format!("{}", version),
+                            "labels": {"name": name, "owner": "helm", "version": version.to_string()}
+                        }
+                    }
+                })
+            })
+            .collect();
+
+        serde_json::from_value(json!({
+            "kind": "Table",
+            "apiVersion": "meta.k8s.io/v1",
+            "metadata": {},
+            "columnDefinitions": [
+                {"name": "Name", "type": "string", "format": "name", "description": "", "priority": 0},
+                {"name": "Type", "type": "string", "format": "", "description": "", "priority": 0},
+                {"name": "Data", "type": "string", "format": "", "description": "", "priority": 0},
+                {"name": "Age", "type": "string", "format": "", "description": "", "priority": 0}
+            ],
+            "rows": rows
+        }))
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn リリースごとに最新のリビジョンのみ取得する() {
+        let mut client = MockTestKubeClient::new();
+
+        mock_expect!(
+            client,
+            table_request,
+            [(
+                Table,
+                eq("/api/v1/namespaces/default/secrets?labelSelector=owner=helm"),
+                Ok(release_table(&[("app", 1), ("app", 2), ("db", 1)]))
+            )]
+        );
+
+        mock_expect!(
+            client,
+            request,
+            [
+                (
+                    Secret,
+                    eq("/api/v1/namespaces/default/secrets/sh.helm.release.v1.app.v2"),
+                    Ok(release("app", 2, "deployed"))
+                ),
+                (
+                    Secret,
+                    eq("/api/v1/namespaces/default/secrets/sh.helm.release.v1.db.v1"),
+                    Ok(release("db", 1, "failed"))
+                )
+            ]
+        );
+
+        let actual = fetch_releases(
+            &client,
+            &["default".to_string()],
+            &mut ReleaseCache::default(),
+        )
+        .await
+        .unwrap();
+
+        let expected = vec![
+            vec![
+                "app",
+                "2",
+                "deployed",
+                "nginx-1.0.2",
+                "1.25.0",
+                "2024-01-01T00:00:00Z",
+            ],
+            vec![
+                "db",
+                "1",
+                "failed",
+                "nginx-1.0.1",
+                "1.25.0",
+                "2024-01-01T00:00:00Z",
+            ],
+        ];
+
+        assert_eq!(
+            actual
+                .rows
+                .iter()
+                .map(|r| r.row.clone())
+                .collect::<Vec<_>>(),
+            expected
+        );
+    }
+
+    #[tokio::test]
+    async fn 取得できないネームスペースを除外する() {
+        let mut client = MockTestKubeClient::new();
+
+        mock_expect!(
+            client,
+            table_request,
+            [
+                (
+                    Table,
+                    eq("/api/v1/namespaces/default/secrets?labelSelector=owner=helm"),
+                    Ok(release_table(&[("app", 1)]))
+                ),
+                (
+                    Table,
+                    eq("/api/v1/namespaces/kube-system/secrets?labelSelector=owner=helm"),
+                    Err(kube::Error::Api(ErrorResponse {
+                        status: "Failure".into(),
+                        message: "forbidden".into(),
+                        reason: "Forbidden".into(),
+                        code: 403,
+                    })
+                    .into())
+                )
+            ]
+        );
+
+        mock_expect!(
+            client,
+            request,
+            [(
+                Secret,
+                eq("/api/v1/namespaces/default/secrets/sh.helm.release.v1.app.v1"),
+                Ok(release("app", 1, "deployed"))
+            )]
+        );
+
+        let actual = fetch_releases(
+            &client,
+            &["default".to_string(), "kube-system".to_string()],
+            &mut ReleaseCache::default(),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(actual.rows.len(), 1);
+        assert_eq!(
+            actual
+                .skipped
+                .iter()
+                .map(|skipped| skipped.namespace.as_str())
+                .collect::<Vec<_>>(),
+            vec!["kube-system"]
+        );
+    }
+
+    #[tokio::test]
+    async fn resource_versionが変わっていないリリースは再取得しない() {
+        let mut client = MockTestKubeClient::new();
+
+        mock_expect!(
+            client,
+            table_request,
+            [(
+                Table,
+                eq("/api/v1/namespaces/default/secrets?labelSelector=owner=helm"),
+                Ok(release_table(&[("app", 1)]))
+            )]
+        );
+
+        client
+            .expect_request::<Secret>()
+            .with(eq(
+                "/api/v1/namespaces/default/secrets/sh.helm.release.v1.app.v1",
+            ))
+            .times(1)
+            .returning(|_| Ok(release("app", 1, "deployed")));
+
+        let mut cache = ReleaseCache::default();
+
+        let first = fetch_releases(&client, &["default".to_string()], &mut cache)
+            .await
+            .unwrap();
+
+        let second = fetch_releases(&client, &["default".to_string()], &mut cache)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            first.rows.iter().map(|r| r.row.clone()).collect::<Vec<_>>(),
+            second
+                .rows
+                .iter()
+                .map(|r| r.row.clone())
+                .collect::<Vec<_>>()
+        );
+    }
+}
@@ -0,0 +1,345 @@
+use std::io::prelude::*;
+
+use anyhow::{Context as _, Result};
+use base64::{engine::general_purpose, Engine};
+use chrono::{DateTime, SecondsFormat, Utc};
+use k8s_openapi::{api::core::v1::Secret, apimachinery::pkg::apis::meta::v1::ObjectMeta, List};
+use kube::Resource as _;
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::kube::{apis::v1_table::Table, KubeClientRequest};
+
+/// Helmがリリースを保存するSecretのラベル
+const HELM_OWNER_LABEL_SELECTOR: &str = "owner=helm";
+
+/// リリースを保存するSecretのtype
+const RELEASE_SECRET_TYPE: &str = "helm.sh/release.v1";
+
+/// リリースを保存するSecretのデータのキー
+pub const RELEASE_KEY: &str = "release";
+
+/// Helmのリリース（helm.sh/release.v1 type secret）
+#[derive(Debug, Default, Clone, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct Release {
+    pub name: String,
+    pub namespace: String,
+
+    /// リビジョン
+    pub version: u32,
+
+    pub info: ReleaseInfo,
+    pub chart: Chart,
+
+    /// ユーザーが指定したvalues
+    pub config: Option<Value>,
+
+    pub manifest: String,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct ReleaseInfo {
+    pub last_deployed: Option<String>,
+    pub description: Option<String>,
+    pub status: Option<String>,
+    pub notes: Option<String>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct Chart {
+    pub metadata: ChartMetadata,
+
+    /// チャートのデフォルトのvalues
+    pub values: Option<Value>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ChartMetadata {
+    pub name: String,
+    pub version: String,
+    pub app_version: Option<String>,
+}
+
+impl Release {
+    /// Secretのreleaseの値（base64エンコードしたgzip圧縮のJSON）をデコードする
+    pub fn decode(data: &[u8]) -> Result<Self> {
+        Ok(serde_json::from_str(&decompress_release(data)?)?)
+    }
+
+    pub fn from_secret(secret: &Secret) -> Result<Self> {
+        let data = secret
+            .data
+            .as_ref()
+            .and_then(|data| data.get(RELEASE_KEY))
+            .with_context(|| format!("'{}' not found in secret", RELEASE_KEY))?;
+
+        Self::decode(&data.0)
+    }
+
+    /// 例: `nginx-1.2.3`
+    pub fn chart(&self) -> String {
+        format!(
+            "{}-{}",
+            self.chart.metadata.name, self.chart.metadata.version
+        )
+    }
+
+    pub fn app_version(&self) -> &str {
+        self.chart
+            .metadata
+            .app_version
+            .as_deref()
+            .unwrap_or_default()
+    }
+
+    pub fn status(&self) -> &str {
+        self.info.status.as_deref().unwrap_or_default()
+    }
+
+    /// 最終デプロイ日時（UTC、秒単位）
+    pub fn updated(&self) -> String {
+        let Some(last_deployed) = self.info.last_deployed.as_deref() else {
+            return String::new();
+        };
+
+        DateTime::parse_from_rfc3339(last_deployed)
+            .map(|dt| {
+                dt.with_timezone(&Utc)
+                    .to_rfc3339_opts(SecondsFormat::Secs, true)
+            })
+            .unwrap_or_else(|_| last_deployed.to_string())
+    }
+
+    /// チャートのデフォルトのvaluesにユーザーが指定したvaluesをマージしたもの
+    ///
+    /// サブチャートのvaluesはマージしない
+    pub fn computed_values(&self) -> Value {
+        let mut values = self
+            .chart
+            .values
+            .clone()
+            .unwrap_or_else(|| Value::Object(Default::default()));
+
+        if let Some(config) = &self.config {
+            coalesce(&mut values, config);
+        }
+
+        values
+    }
+}
+
+/// base64エンコードしたgzip圧縮のJSONを展開する
+pub fn decompress_release(data: &[u8]) -> Result<String> {
+    let gzip = general_purpose::STANDARD.decode(data)?;
+
+    // decode gzip
+    let mut decoder = flate2::read::GzDecoder::new(&gzip[..]);
+    let mut decoded = String::new();
+    decoder.read_to_string(&mut decoded)?;
+
+    Ok(decoded)
+}
+
+/// ユーザーが指定したvaluesで上書きする。nullのときはキーを取り除く
+fn coalesce(base: &mut Value, overrides: &Value) {
+    match (base, overrides) {
+        (Value::Object(base), Value::Object(overrides)) => {
+            for (key, value) in overrides {
+                match (base.get_mut(key), value) {
+                    (_, Value::Null) => {
+                        base.remove(key);
+                    }
+                    (Some(base_value), _) => coalesce(base_value, value),
+                    (None, _) => {
+                        base.insert(key.clone(), value.clone());
+                    }
+                }
+            }
+        }
+        (base, overrides) => *base = overrides.clone(),
+    }
+}
+
+/// namespaceのリリースを保存するSecretを取得する。nameを指定したときはそのリリースのみ取得する
+pub async fn fetch_release_secrets<C: KubeClientRequest>(
+    client: &C,
+    namespace: &str,
+    name: Option<&str>,
+) -> Result<Vec<Secret>> {
+    let mut label_selector = HELM_OWNER_LABEL_SELECTOR.to_string();
+
+    if let Some(name) = name {
+        label_selector += &format!(",name={}", name);
+    }
+
+    let list: List<Secret> = client
+        .request(&format!(
+            "{}?labelSelector={}",
+            Secret::url_path(&(), Some(namespace)),
+            label_selector
+        ))
+        .await?;
+
+    Ok(list
+        .items
+        .into_iter()
+        .filter(|secret| secret.type_.as_deref() == Some(RELEASE_SECRET_TYPE))
+        .collect())
+}
+
+/// リリースの全リビジョンをリビジョンの昇順で取得する
+pub async fn fetch_release_history<C: KubeClientRequest>(
+    client: &C,
+    namespace: &str,
+    name: &str,
+) -> Result<Vec<Release>> {
+    let secrets = fetch_release_secrets(client, namespace, Some(name)).await?;
+
+    let mut releases = secrets
+        .iter()
+        .map(Release::from_secret)
+        .collect::<Result<Vec<_>>>()?;
+
+    releases.sort_by_key(|release| release.version);
+
+    Ok(releases)
+}
+
+/// namespaceのリリースを保存するSecretのメタデータを取得する
+///
+/// リビジョンごとのdataは大きいため、Table形式で取得してdataを含めない
+pub async fn fetch_release_secret_metadata<C: KubeClientRequest>(
+    client: &C,
+    namespace: &str,
+) -> Result<Vec<ObjectMeta>> {
+    let table: Table = client
+        .table_request(&format!(
+            "{}?labelSelector={}",
+            Secret::url_path(&(), Some(namespace)),
+            HELM_OWNER_LABEL_SELECTOR
+        ))
+        .await?;
+
+    let type_index = table.find_index("Type");
+
+    Ok(table
+        .rows
+        .iter()
+        .filter(|row| {
+            type_index
+                .and_then(|i| row.cells.get(i))
+                .is_none_or(|cell| cell.to_string() == RELEASE_SECRET_TYPE)
+        })
+        .filter_map(|row| {
+            let metadata = row.object.as_ref()?.0.get("metadata")?.clone();
+
+            serde_json::from_value(metadata).ok()
+        })
+        .collect())
+}
+
+/// リリースを保存するSecretを取得する
+pub async fn fetch_release<C: KubeClientRequest>(
+    client: &C,
+    namespace: &str,
+    secret_name: &str,
+) -> Result<Release> {
+    let secret: Secret = client
+        .request(&format!(
+            "{}/{}",
+            Secret::url_path(&(), Some(namespace)),
+            secret_name
+        ))
+        .await?;
+
+    Release::from_secret(&secret)
+}
+
+/// Secretのラベルからリビジョンを取得する
+pub fn secret_revision(metadata: &ObjectMeta) -> u32 {
+    metadata
+        .labels
+        .as_ref()
+        .and_then(|labels| labels.get("version"))
+        .and_then(|version| version.parse().ok())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+pub mod tests {
+    use std::collections::BTreeMap;
+
+    use flate2::{write::GzEncoder, Compression};
+    use k8s_openapi::ByteString;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use super::*;
+
+    /// テスト用のリリースを保存するSecretを作成する
+    pub fn release_secret(release: Value) -> Secret {
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(release.to_string().as_bytes()).unwrap();
+
+        let data = general_purpose::STANDARD.encode(encoder.finish().unwrap());
+
+        let name = release["name"].as_str().unwrap_or_default().to_string();
+        let version = release["version"].as_u64().unwrap_or_default();
+
+        Secret {
+            metadata: ObjectMeta {
+                name: Some(format!("sh.helm.release.v1.{}.v{}", name, version)),
+                labels: Some(BTreeMap::from([
+                    ("name".to_string(), name),
+                    ("owner".to_string(), "helm".to_string()),
+                    ("version".to_string(), version.to_string()),
+                ])),
+                ..Default::default()
+            },
+            type_: Some("helm.sh/release.v1".into()),
+            data: Some(BTreeMap::from([(
+                RELEASE_KEY.to_string(),
+                ByteString(data.into_bytes()),
+            )])),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn リリースをデコードする() {
+        let secret = release_secret(json!({
+            "name": "app",
+            "namespace": "default",
+            "version": 2,
+            "info": {
+                "last_deployed": "2024-01-01T09:00:00.123456789+09:00",
+                "status": "deployed",
+                "notes": "Thank you"
+            },
+            "chart": {
+                "metadata": {"name": "nginx", "version": "1.2.3", "appVersion": "1.25.0"},
+                "values": {"replicaCount": 1, "image": {"tag": "latest", "pullPolicy": "Always"}}
+            },
+            "config": {"image": {"tag": "1.25.0", "pullPolicy": null}},
+            "manifest": "---\nkind: Service\n"
+        }));
+
+        let actual = Release::from_secret(&secret).unwrap();
+
+        assert_eq!(actual.version, 2);
+        assert_eq!(actual.chart(), "nginx-1.2.3");
+        assert_eq!(actual.app_version(), "1.25.0");
+        assert_eq!(actual.status(), "deployed");
+        assert_eq!(actual.updated(), "2024-01-01T00:00:00Z");
+        assert_eq!(secret_revision(&secret.metadata), 2);
+
+        assert_eq!(
+            actual.computed_values(),
+            json!({"replicaCount": 1, "image": {"tag": "1.25.0"}})
+        );
+    }
+}
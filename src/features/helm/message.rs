@@ -0,0 +1,51 @@
+use anyhow::Result;
+
+use crate::{kube::table::KubeTable, message::Message, workers::kube::message::Kube};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HelmReleaseTarget {
+    pub namespace: String,
+    pub name: String,
+}
+
+#[derive(Debug, Clone)]
+pub enum HelmRequest {
+    Release(HelmReleaseTarget),
+    /// 指定したリビジョンとその前のリビジョンのマニフェストの差分
+    Diff {
+        target: HelmReleaseTarget,
+        revision: u32,
+    },
+}
+
+#[derive(Debug)]
+pub enum HelmResponse {
+    Table(Result<KubeTable>),
+    Description(Result<Vec<String>>),
+    History(Result<KubeTable>),
+    Diff(Result<Vec<String>>),
+}
+
+#[derive(Debug)]
+pub enum HelmMessage {
+    Request(HelmRequest),
+    Response(HelmResponse),
+}
+
+impl From<HelmMessage> for Message {
+    fn from(m: HelmMessage) -> Self {
+        Self::Kube(Kube::Helm(m))
+    }
+}
+
+impl From<HelmRequest> for Message {
+    fn from(req: HelmRequest) -> Self {
+        HelmMessage::Request(req).into()
+    }
+}
+
+impl From<HelmResponse> for Message {
+    fn from(res: HelmResponse) -> Self {
+        HelmMessage::Response(res).into()
+    }
+}
@@ -0,0 +1,4 @@
+mod tab;
+mod widgets;
+
+pub use tab::*;
@@ -0,0 +1,72 @@
+use std::{cell::RefCell, rc::Rc};
+
+use crossbeam::channel::Sender;
+use ratatui::layout::{Constraint, Direction};
+
+use crate::{
+    clipboard::Clipboard,
+    features::component_id::{HELM_TAB_ID, HELM_WIDGET_ID},
+    message::Message,
+    ui::{
+        tab::{LayoutElement, NestedLayoutElement, NestedWidgetLayout, TabLayout},
+        widget::Widget,
+        Tab,
+    },
+};
+
+use super::widgets::{description_widget, diff_widget, helm_widget, history_widget};
+
+pub struct HelmTab {
+    pub tab: Tab<'static>,
+    pub diff_dialog: Widget<'static>,
+}
+
+impl HelmTab {
+    pub fn new(
+        title: &'static str,
+        tx: &Sender<Message>,
+        clipboard: &Option<Rc<RefCell<Clipboard>>>,
+        split_direction: Direction,
+    ) -> Self {
+        let helm_widget = helm_widget(tx);
+        let description_widget = description_widget(clipboard);
+        let history_widget = history_widget(tx);
+
+        let layout = TabLayout::new(layout, split_direction);
+
+        let mut tab = Tab::new(
+            HELM_TAB_ID,
+            title,
+            [helm_widget, description_widget, history_widget],
+            layout,
+        );
+
+        tab.activate_widget_by_id(HELM_WIDGET_ID);
+
+        Self {
+            tab,
+            diff_dialog: diff_widget(clipboard),
+        }
+    }
+}
+
+fn layout(split_direction: Direction) -> NestedWidgetLayout {
+    let detail_layout = NestedLayoutElement(
+        Constraint::Percentage(50),
+        LayoutElement::NestedElement(
+            NestedWidgetLayout::default()
+                .direction(Direction::Vertical)
+                .nested_widget_layout([
+                    NestedLayoutElement(Constraint::Percentage(60), LayoutElement::WidgetIndex(1)),
+                    NestedLayoutElement(Constraint::Percentage(40), LayoutElement::WidgetIndex(2)),
+                ]),
+        ),
+    );
+
+    NestedWidgetLayout::default()
+        .direction(split_direction)
+        .nested_widget_layout([
+            NestedLayoutElement(Constraint::Percentage(50), LayoutElement::WidgetIndex(0)),
+            detail_layout,
+        ])
+}
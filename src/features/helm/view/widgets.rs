@@ -0,0 +1,9 @@
+mod description;
+mod diff;
+mod helm;
+mod history;
+
+pub(super) use description::*;
+pub(super) use diff::*;
+pub(super) use helm::*;
+pub(super) use history::*;
@@ -0,0 +1,21 @@
+use std::{cell::RefCell, rc::Rc};
+
+use crate::{
+    clipboard::Clipboard,
+    features::component_id::HELM_DIFF_DIALOG_ID,
+    ui::widget::{Text, Widget, WidgetBase},
+};
+
+pub fn diff_widget(clipboard: &Option<Rc<RefCell<Clipboard>>>) -> Widget<'static> {
+    let builder = Text::builder()
+        .id(HELM_DIFF_DIALOG_ID)
+        .widget_base(WidgetBase::builder().title("Manifest Diff").build());
+
+    if let Some(cb) = clipboard {
+        builder.clipboard(cb.clone())
+    } else {
+        builder
+    }
+    .build()
+    .into()
+}
@@ -0,0 +1,74 @@
+use crossbeam::channel::Sender;
+
+use crate::{
+    features::{
+        component_id::{HELM_DESCRIPTION_WIDGET_ID, HELM_HISTORY_WIDGET_ID, HELM_WIDGET_ID},
+        helm::message::{HelmReleaseTarget, HelmRequest},
+    },
+    message::Message,
+    ui::{
+        event::EventResult,
+        widget::{Table, TableItem, Widget, WidgetBase, WidgetTrait as _},
+        Window, WindowAction,
+    },
+};
+
+pub fn helm_widget(tx: &Sender<Message>) -> Widget<'static> {
+    let tx = tx.clone();
+
+    Table::builder()
+        .id(HELM_WIDGET_ID)
+        .widget_base(WidgetBase::builder().title("Release").build())
+        .filtered_key("NAME")
+        .block_injection(block_injection())
+        .on_select(on_select(tx))
+        .build()
+        .into()
+}
+
+fn block_injection() -> impl Fn(&Table) -> WidgetBase {
+    |table: &Table| {
+        let index = if let Some(index) = table.state().selected() {
+            index + 1
+        } else {
+            0
+        };
+
+        let mut base = table.widget_base().clone();
+
+        *base.append_title_mut() = Some(format!(" [{}/{}]", index, table.items().len()).into());
+
+        base
+    }
+}
+
+fn on_select(tx: Sender<Message>) -> impl Fn(&mut Window, &TableItem) -> EventResult {
+    move |w: &mut Window, v: &TableItem| {
+        w.widget_clear(HELM_DESCRIPTION_WIDGET_ID);
+        w.widget_clear(HELM_HISTORY_WIDGET_ID);
+
+        let Some(metadata) = v.metadata.as_ref() else {
+            return EventResult::Ignore;
+        };
+
+        let (Some(namespace), Some(name)) = (metadata.get("namespace"), metadata.get("name"))
+        else {
+            return EventResult::Ignore;
+        };
+
+        *(w.find_widget_mut(HELM_DESCRIPTION_WIDGET_ID)
+            .widget_base_mut()
+            .append_title_mut()) = Some((format!(" : {}", name)).into());
+
+        tx.send(
+            HelmRequest::Release(HelmReleaseTarget {
+                namespace: namespace.to_string(),
+                name: name.to_string(),
+            })
+            .into(),
+        )
+        .expect("Failed to send HelmRequest::Release");
+
+        EventResult::WindowAction(WindowAction::Continue)
+    }
+}
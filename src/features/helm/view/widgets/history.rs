@@ -0,0 +1,59 @@
+use crossbeam::channel::Sender;
+
+use crate::{
+    features::{
+        component_id::{HELM_DIFF_DIALOG_ID, HELM_HISTORY_WIDGET_ID},
+        helm::message::{HelmReleaseTarget, HelmRequest},
+    },
+    message::Message,
+    ui::{
+        event::EventResult,
+        widget::{Table, TableItem, Widget, WidgetBase},
+        Window,
+    },
+};
+
+pub fn history_widget(tx: &Sender<Message>) -> Widget<'static> {
+    let tx = tx.clone();
+
+    Table::builder()
+        .id(HELM_HISTORY_WIDGET_ID)
+        .widget_base(WidgetBase::builder().title("History").build())
+        .on_select(on_select(tx))
+        .build()
+        .into()
+}
+
+/// 選択したリビジョンとその前のリビジョンのマニフェストの差分を表示する
+fn on_select(tx: Sender<Message>) -> impl Fn(&mut Window, &TableItem) -> EventResult {
+    move |w: &mut Window, v: &TableItem| {
+        let Some(metadata) = v.metadata.as_ref() else {
+            return EventResult::Ignore;
+        };
+
+        let (Some(namespace), Some(name), Some(revision)) = (
+            metadata.get("namespace"),
+            metadata.get("name"),
+            metadata.get("revision").and_then(|r| r.parse().ok()),
+        ) else {
+            return EventResult::Ignore;
+        };
+
+        tx.send(
+            HelmRequest::Diff {
+                target: HelmReleaseTarget {
+                    namespace: namespace.to_string(),
+                    name: name.to_string(),
+                },
+                revision,
+            }
+            .into(),
+        )
+        .expect("Failed to send HelmRequest::Diff");
+
+        w.widget_clear(HELM_DIFF_DIALOG_ID);
+        w.open_dialog(HELM_DIFF_DIALOG_ID);
+
+        EventResult::Nop
+    }
+}
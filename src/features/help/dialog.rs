@@ -11,7 +11,7 @@ const LEFT_HELP_TEXT: &[HelpBlock] = &[
         title: "General",
         bindings: &[
             KeyBindings {
-                keys: &["0~6"],
+                keys: &["0~7"],
                 desc: "switch tab",
             },
             KeyBindings {
@@ -38,6 +38,10 @@ const LEFT_HELP_TEXT: &[HelpBlock] = &[
                 keys: &["y"],
                 desc: "open yaml dialog",
             },
+            KeyBindings {
+                keys: &["Y"],
+                desc: "copy name/yaml/kubectl command",
+            },
             KeyBindings {
                 keys: &["q", "Esc"],
                 desc: "quit",
@@ -161,6 +165,121 @@ const RIGHT_HELP_TEXT: &[HelpBlock] = &[
             desc: "open select dialog",
         }],
     },
+    HelpBlock {
+        title: "List Tab",
+        bindings: &[
+            KeyBindings {
+                keys: &["Enter"],
+                desc: "open the table of the kind / yaml of the object",
+            },
+            KeyBindings {
+                keys: &["Enter (Selector)"],
+                desc: "apply labels:<selector> fields:<selector>",
+            },
+            KeyBindings {
+                keys: &["v"],
+                desc: "switch saved views",
+            },
+            KeyBindings {
+                keys: &["V"],
+                desc: "save selected resources as a view",
+            },
+            KeyBindings {
+                keys: &["c"],
+                desc: "printer columns / status conditions",
+            },
+            KeyBindings {
+                keys: &["t"],
+                desc: "owner tree (Enter: yaml, l: logs)",
+            },
+            KeyBindings {
+                keys: &["Alt+1..9"],
+                desc: "switch to the view bound to the key",
+            },
+        ],
+    },
+    HelpBlock {
+        title: "Config Tab (Raw Data)",
+        bindings: &[
+            KeyBindings {
+                keys: &["r"],
+                desc: "reveal/mask the selected secret value",
+            },
+            KeyBindings {
+                keys: &["R"],
+                desc: "reveal/mask all secret values",
+            },
+            KeyBindings {
+                keys: &["e"],
+                desc: "expand/collapse large values",
+            },
+            KeyBindings {
+                keys: &["d"],
+                desc: "decode base64/gzip values in place",
+            },
+        ],
+    },
+    HelpBlock {
+        title: "Network Tab",
+        bindings: &[KeyBindings {
+            keys: &["p"],
+            desc: "simulate NetworkPolicy",
+        }],
+    },
+    HelpBlock {
+        title: "Helm Tab (History)",
+        bindings: &[KeyBindings {
+            keys: &["Enter"],
+            desc: "diff manifest with previous revision",
+        }],
+    },
+    HelpBlock {
+        title: "Workloads Tab",
+        bindings: &[
+            KeyBindings {
+                keys: &["l"],
+                desc: "show logs in the Pod tab",
+            },
+            KeyBindings {
+                keys: &["T"],
+                desc: "create a Job from the CronJob (confirm with y)",
+            },
+        ],
+    },
+    HelpBlock {
+        title: "Workloads Tab (Rollout)",
+        bindings: &[
+            KeyBindings {
+                keys: &["Enter"],
+                desc: "diff pod template with previous revision",
+            },
+            KeyBindings {
+                keys: &["d"],
+                desc: "diff pod template with current template",
+            },
+        ],
+    },
+    HelpBlock {
+        title: "Yaml Tab",
+        bindings: &[
+            KeyBindings {
+                keys: &["d"],
+                desc: "compare with another context/namespace",
+            },
+            KeyBindings {
+                keys: &["m"],
+                desc: "toggle managedFields/last-applied",
+            },
+            KeyBindings {
+                keys: &["z"],
+                desc: "fold/unfold the line under cursor",
+            },
+            KeyBindings {
+                keys: &["Z"],
+                desc: "unfold all",
+            },
+        ],
+    },
     HelpBlock {
         title: "Search (Only text view)",
         bindings: &[
@@ -5,7 +5,9 @@ use crate::{
         component_id::{
             CONFIG_RAW_DATA_WIDGET_ID, CONFIG_WIDGET_ID, EVENT_WIDGET_ID, LIST_WIDGET_ID,
             MULTIPLE_NAMESPACES_DIALOG_ID, NETWORK_DESCRIPTION_WIDGET_ID, NETWORK_WIDGET_ID,
-            POD_LOG_QUERY_WIDGET_ID, POD_LOG_WIDGET_ID, POD_WIDGET_ID, YAML_WIDGET_ID,
+            POD_LOG_QUERY_WIDGET_ID, POD_LOG_WIDGET_ID, POD_WIDGET_ID, RBAC_CAN_I_WIDGET_ID,
+            RBAC_PERMISSION_WIDGET_ID, RBAC_SUBJECT_WIDGET_ID, WORKLOAD_DESCRIPTION_WIDGET_ID,
+            WORKLOAD_ROLLOUT_WIDGET_ID, WORKLOAD_WIDGET_ID, YAML_WIDGET_ID,
         },
         namespace::message::NamespaceRequest,
     },
@@ -68,6 +70,12 @@ fn on_select(
         w.widget_clear(EVENT_WIDGET_ID);
         w.widget_clear(LIST_WIDGET_ID);
         w.widget_clear(YAML_WIDGET_ID);
+        w.widget_clear(WORKLOAD_WIDGET_ID);
+        w.widget_clear(WORKLOAD_DESCRIPTION_WIDGET_ID);
+        w.widget_clear(WORKLOAD_ROLLOUT_WIDGET_ID);
+        w.widget_clear(RBAC_SUBJECT_WIDGET_ID);
+        w.widget_clear(RBAC_PERMISSION_WIDGET_ID);
+        w.widget_clear(RBAC_CAN_I_WIDGET_ID);
 
         EventResult::Nop
     }
@@ -1,5 +1,7 @@
 mod description;
 mod network;
+mod simulation;
 
 pub use description::*;
 pub use network::*;
+pub use simulation::simulate;
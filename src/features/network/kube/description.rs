@@ -3,11 +3,13 @@ mod httproute;
 mod ingress;
 mod network_policy;
 mod pod;
+mod route;
+mod routing_table;
 mod service;
 mod utils;
 
 #[allow(dead_code)]
-mod related_resources;
+pub mod related_resources;
 
 use std::sync::{atomic::AtomicBool, Arc};
 
@@ -16,7 +18,10 @@ use crate::{
         api_resources::kube::SharedApiResources,
         network::message::{NetworkRequest, NetworkRequestTargetParams, NetworkResponse},
     },
-    kube::KubeClientRequest,
+    kube::{
+        apis::networking::gateway::{v1, v1alpha2},
+        KubeClientRequest,
+    },
     message::Message,
     workers::kube::AbortWorker,
 };
@@ -24,7 +29,7 @@ use crate::{
 use self::{
     gateway::GatewayDescriptionWorker, httproute::HTTPRouteDescriptionWorker,
     ingress::IngressDescriptionWorker, network_policy::NetworkPolicyDescriptionWorker,
-    pod::PodDescriptionWorker, service::ServiceDescriptionWorker,
+    pod::PodDescriptionWorker, route::RouteDescriptionWorker, service::ServiceDescriptionWorker,
 };
 
 use anyhow::Result;
@@ -108,6 +113,28 @@ where
                 self.fetch_description::<HTTPRouteDescriptionWorker<C>>()
                     .await
             }
+            NetworkRequest::GRPCRoute(params) => match params.version.as_str() {
+                "v1alpha2" => {
+                    self.fetch_description::<RouteDescriptionWorker<C, v1alpha2::GRPCRoute>>()
+                        .await
+                }
+                _ => {
+                    self.fetch_description::<RouteDescriptionWorker<C, v1::GRPCRoute>>()
+                        .await
+                }
+            },
+            NetworkRequest::TLSRoute(_) => {
+                self.fetch_description::<RouteDescriptionWorker<C, v1alpha2::TLSRoute>>()
+                    .await
+            }
+            NetworkRequest::TCPRoute(_) => {
+                self.fetch_description::<RouteDescriptionWorker<C, v1alpha2::TCPRoute>>()
+                    .await
+            }
+            NetworkRequest::UDPRoute(_) => {
+                self.fetch_description::<RouteDescriptionWorker<C, v1alpha2::UDPRoute>>()
+                    .await
+            }
         };
 
         if let Err(e) = ret {
@@ -2,7 +2,7 @@ mod v1;
 mod v1beta1;
 
 use anyhow::{Context as _, Result};
-use kube::{Api, Client};
+use kube::Api;
 
 use crate::{
     features::{
@@ -14,7 +14,10 @@ use crate::{
     },
 };
 
-use super::{Fetch, FetchedData};
+use super::{
+    routing_table::{convert, gateway_tls, httproute_routes, routing_table},
+    Fetch, FetchedData,
+};
 
 pub(super) struct GatewayDescriptionWorker<'a, C>
 where
@@ -48,11 +51,9 @@ where
 
     async fn fetch(&self) -> Result<FetchedData> {
         match self.version.as_str() {
-            "v1" => fetch_v1(self.client.client().clone(), &self.name, &self.namespace).await,
+            "v1" => fetch_v1(self.client, &self.name, &self.namespace).await,
 
-            "v1beta1" => {
-                fetch_v1beta1(self.client.client().clone(), &self.name, &self.namespace).await
-            }
+            "v1beta1" => fetch_v1beta1(self.client, &self.name, &self.namespace).await,
 
             _ => {
                 unreachable!()
@@ -61,7 +62,13 @@ where
     }
 }
 
-async fn fetch_v1(client: Client, name: &str, namespace: &str) -> Result<FetchedData> {
+async fn fetch_v1<C: KubeClientRequest>(
+    kube_client: &C,
+    name: &str,
+    namespace: &str,
+) -> Result<FetchedData> {
+    let client = kube_client.client().clone();
+
     let api = Api::<gateway::v1::Gateway>::namespaced(client.clone(), namespace);
 
     let gateway = api.get(name).await.context(format!(
@@ -74,6 +81,13 @@ async fn fetch_v1(client: Client, name: &str, namespace: &str) -> Result<Fetched
     let related_resources =
         v1::discover_releated_resources(client, name, namespace, &gateway).await?;
 
+    let routes: Vec<_> = related_resources
+        .httproutes()
+        .flat_map(|r| httproute_routes(&r.namespace, &r.resource))
+        .collect();
+
+    let tls = gateway_tls(namespace, &gateway);
+
     let mut yaml = serde_yaml::to_string(&description)?
         .lines()
         .map(ToString::to_string)
@@ -84,6 +98,14 @@ async fn fetch_v1(client: Client, name: &str, namespace: &str) -> Result<Fetched
         .map(ToString::to_string)
         .collect::<Vec<String>>();
 
+    let routing_table = routing_table(kube_client, &routes, &tls).await;
+
+    if !routing_table.is_empty() {
+        yaml.push("".into());
+
+        yaml.extend(routing_table);
+    }
+
     if !related_resources_yaml.is_empty() {
         yaml.push("".into());
 
@@ -93,7 +115,13 @@ async fn fetch_v1(client: Client, name: &str, namespace: &str) -> Result<Fetched
     Ok(yaml)
 }
 
-async fn fetch_v1beta1(client: Client, name: &str, namespace: &str) -> Result<FetchedData> {
+async fn fetch_v1beta1<C: KubeClientRequest>(
+    kube_client: &C,
+    name: &str,
+    namespace: &str,
+) -> Result<FetchedData> {
+    let client = kube_client.client().clone();
+
     let api = Api::<gateway::v1beta1::Gateway>::namespaced(client.clone(), namespace);
 
     let gateway = api.get(name).await.context(format!(
@@ -106,6 +134,14 @@ async fn fetch_v1beta1(client: Client, name: &str, namespace: &str) -> Result<Fe
     let related_resources =
         v1beta1::discover_releated_resources(client, name, namespace, &gateway).await?;
 
+    let mut routes = Vec::new();
+
+    for r in related_resources.httproutes() {
+        routes.extend(httproute_routes(&r.namespace, &convert(&r.resource)?));
+    }
+
+    let tls = gateway_tls(namespace, &convert(&gateway)?);
+
     let mut yaml = serde_yaml::to_string(&description)?
         .lines()
         .map(ToString::to_string)
@@ -116,6 +152,14 @@ async fn fetch_v1beta1(client: Client, name: &str, namespace: &str) -> Result<Fe
         .map(ToString::to_string)
         .collect::<Vec<String>>();
 
+    let routing_table = routing_table(kube_client, &routes, &tls).await;
+
+    if !routing_table.is_empty() {
+        yaml.push("".into());
+
+        yaml.extend(routing_table);
+    }
+
     if !related_resources_yaml.is_empty() {
         yaml.push("".into());
 
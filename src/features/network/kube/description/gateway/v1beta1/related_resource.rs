@@ -9,7 +9,7 @@ use serde::{Deserialize, Serialize};
 use crate::kube::apis::networking::gateway::v1beta1::Gateway;
 
 use self::{
-    httproute::{discover_httproutes, RelatedHTTPRoutes},
+    httproute::{discover_httproutes, RelatedHTTPRoute, RelatedHTTPRoutes},
     pod::{discover_pods, RelatedPods},
     service::{discover_services, RelatedServices},
 };
@@ -20,6 +20,13 @@ pub struct GatewayRelatedResources {
     related_resources: GatewayRelatedResourceItems,
 }
 
+impl GatewayRelatedResources {
+    /// Gatewayに紐づくHTTPRoute
+    pub fn httproutes(&self) -> impl Iterator<Item = &RelatedHTTPRoute> {
+        self.related_resources.httproutes.iter().flatten()
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct GatewayRelatedResourceItems {
     #[serde(skip_serializing_if = "Option::is_none")]
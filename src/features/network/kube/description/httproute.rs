@@ -2,7 +2,7 @@ mod v1;
 mod v1beta1;
 
 use anyhow::{Context as _, Result};
-use kube::{Api, Client};
+use kube::Api;
 
 use crate::{
     features::{
@@ -11,7 +11,10 @@ use crate::{
     kube::{apis::networking::gateway, KubeClientRequest},
 };
 
-use super::{Fetch, FetchedData};
+use super::{
+    routing_table::{convert, httproute_routes, routing_table},
+    Fetch, FetchedData,
+};
 
 pub(super) struct HTTPRouteDescriptionWorker<'a, C>
 where
@@ -45,11 +48,9 @@ where
 
     async fn fetch(&self) -> Result<FetchedData> {
         match self.version.as_str() {
-            "v1" => fetch_v1(self.client.client().clone(), &self.name, &self.namespace).await,
+            "v1" => fetch_v1(self.client, &self.name, &self.namespace).await,
 
-            "v1beta1" => {
-                fetch_v1beat1(self.client.client().clone(), &self.name, &self.namespace).await
-            }
+            "v1beta1" => fetch_v1beat1(self.client, &self.name, &self.namespace).await,
 
             _ => {
                 unreachable!()
@@ -58,7 +59,13 @@ where
     }
 }
 
-async fn fetch_v1(client: Client, name: &str, namespace: &str) -> Result<FetchedData> {
+async fn fetch_v1<C: KubeClientRequest>(
+    kube_client: &C,
+    name: &str,
+    namespace: &str,
+) -> Result<FetchedData> {
+    let client = kube_client.client().clone();
+
     let api = Api::<gateway::v1::HTTPRoute>::namespaced(client.clone(), namespace);
 
     let httproute = api.get(name).await.context(format!(
@@ -68,6 +75,8 @@ async fn fetch_v1(client: Client, name: &str, namespace: &str) -> Result<Fetched
 
     let description = v1::Description::new(httproute.clone());
 
+    let routes = httproute_routes(namespace, &httproute);
+
     let related_resources = v1::discover_releated_resources(client, namespace, &httproute).await?;
 
     let mut yaml = serde_yaml::to_string(&description)?
@@ -80,6 +89,14 @@ async fn fetch_v1(client: Client, name: &str, namespace: &str) -> Result<Fetched
         .map(ToString::to_string)
         .collect::<Vec<String>>();
 
+    let routing_table = routing_table(kube_client, &routes, &[]).await;
+
+    if !routing_table.is_empty() {
+        yaml.push("".into());
+
+        yaml.extend(routing_table);
+    }
+
     if !related_resources_yaml.is_empty() {
         yaml.push("".into());
 
@@ -89,7 +106,13 @@ async fn fetch_v1(client: Client, name: &str, namespace: &str) -> Result<Fetched
     Ok(yaml)
 }
 
-async fn fetch_v1beat1(client: Client, name: &str, namespace: &str) -> Result<FetchedData> {
+async fn fetch_v1beat1<C: KubeClientRequest>(
+    kube_client: &C,
+    name: &str,
+    namespace: &str,
+) -> Result<FetchedData> {
+    let client = kube_client.client().clone();
+
     let api = Api::<gateway::v1beta1::HTTPRoute>::namespaced(client.clone(), namespace);
 
     let httproute = api.get(name).await.context(format!(
@@ -99,6 +122,8 @@ async fn fetch_v1beat1(client: Client, name: &str, namespace: &str) -> Result<Fe
 
     let description = v1beta1::Description::new(httproute.clone());
 
+    let routes = httproute_routes(namespace, &convert(&httproute)?);
+
     let related_resources =
         v1beta1::discover_releated_resources(client, namespace, &httproute).await?;
 
@@ -112,6 +137,14 @@ async fn fetch_v1beat1(client: Client, name: &str, namespace: &str) -> Result<Fe
         .map(ToString::to_string)
         .collect::<Vec<String>>();
 
+    let routing_table = routing_table(kube_client, &routes, &[]).await;
+
+    if !routing_table.is_empty() {
+        yaml.push("".into());
+
+        yaml.extend(routing_table);
+    }
+
     if !related_resources_yaml.is_empty() {
         yaml.push("".into());
 
@@ -17,6 +17,7 @@ use crate::{
 
 use super::{
     related_resources::{to_list_value::ToListValue, RelatedClient},
+    routing_table::{ingress_routing, routing_table},
     Fetch, FetchedData,
 };
 
@@ -98,6 +99,8 @@ where
             }
         }
 
+        let (routes, tls) = ingress_routing(&self.namespace, &ingress);
+
         let ingress: Vec<String> = serde_yaml::to_string(&ingress.to_value()?)?
             .lines()
             .map(ToString::to_string)
@@ -105,6 +108,14 @@ where
 
         let mut value = ingress;
 
+        let routing_table = routing_table(self.client, &routes, &tls).await;
+
+        if !routing_table.is_empty() {
+            value.push(Default::default());
+
+            value.extend(routing_table);
+        }
+
         if !related_resources.is_empty() {
             let mut root = Mapping::new();
 
@@ -157,7 +168,8 @@ mod tests {
     use indoc::indoc;
     use k8s_openapi::{
         api::{
-            core::v1::{Pod, Service},
+            core::v1::{Pod, Secret, Service},
+            discovery::v1::EndpointSlice,
             networking::v1::Ingress,
         },
         List,
@@ -175,6 +187,16 @@ mod tests {
 
     use super::*;
 
+    fn not_found() -> anyhow::Error {
+        kube::Error::Api(kube::core::ErrorResponse {
+            status: "Failure".into(),
+            message: "not found".into(),
+            reason: "NotFound".into(),
+            code: 404,
+        })
+        .into()
+    }
+
     fn ingress() -> Ingress {
         serde_yaml::from_str(indoc! {
             r#"
@@ -281,6 +303,46 @@ mod tests {
         .unwrap()
     }
 
+    fn backend_service() -> Service {
+        serde_yaml::from_str(indoc! {
+            "
+            metadata:
+              name: service
+            spec:
+              ports:
+              - port: 80
+            "
+        })
+        .unwrap()
+    }
+
+    fn endpoint_slices() -> List<EndpointSlice> {
+        serde_yaml::from_str(indoc! {
+            "
+            items:
+            - metadata:
+                name: service-1-abc
+              addressType: IPv4
+              endpoints:
+              - addresses:
+                - 10.0.0.1
+                conditions:
+                  ready: true
+                targetRef:
+                  kind: Pod
+                  name: pod-1
+              - addresses:
+                - 10.0.0.2
+                conditions:
+                  ready: false
+                targetRef:
+                  kind: Pod
+                  name: pod-2
+            "
+        })
+        .unwrap()
+    }
+
     #[tokio::test]
     async fn yamlデータを返す() {
         let mut client = MockTestKubeClient::new();
@@ -302,6 +364,36 @@ mod tests {
                     List<Pod>,
                     eq("/api/v1/namespaces/default/pods"),
                     Ok(pods())
+                ),
+                (
+                    Service,
+                    eq("/api/v1/namespaces/default/services/service-1"),
+                    Ok(backend_service())
+                ),
+                (
+                    Service,
+                    eq("/api/v1/namespaces/default/services/service-2"),
+                    Ok(backend_service())
+                ),
+                (
+                    Service,
+                    eq("/api/v1/namespaces/default/services/service-3"),
+                    Err(not_found())
+                ),
+                (
+                    List<EndpointSlice>,
+                    eq("/apis/discovery.k8s.io/v1/namespaces/default/endpointslices?labelSelector=kubernetes.io/service-name=service-1"),
+                    Ok(endpoint_slices())
+                ),
+                (
+                    List<EndpointSlice>,
+                    eq("/apis/discovery.k8s.io/v1/namespaces/default/endpointslices?labelSelector=kubernetes.io/service-name=service-2"),
+                    Ok(List::default())
+                ),
+                (
+                    Secret,
+                    eq("/api/v1/namespaces/default/secrets/secret-name"),
+                    Err(not_found())
                 )
             ]
         );
@@ -357,6 +449,16 @@ mod tests {
               status:
                 loadBalancer: {}
 
+            routingTable:
+              HOST           PATH                            BACKEND       ENDPOINTS
+              example-0.com  /path (ImplementationSpecific)  service-1:80  \x1b[32mpod-1\x1b[39m, \x1b[31mpod-2 (not ready)\x1b[39m
+              example-0.com  /path (ImplementationSpecific)  service-2:80  \x1b[31mno endpoints\x1b[39m
+              example-1.com  /path (ImplementationSpecific)  service-3:80  \x1b[31mservice not found\x1b[39m
+
+            tls:
+              SECRET       HOSTS        STATUS
+              secret-name  example.com  \x1b[31msecret not found\x1b[39m
+
             relatedResources:
               services:
               - service-1
@@ -7,6 +7,7 @@ use crate::kube::KubeClientRequest;
 
 use self::{fetch::FetchClient, to_list_value::ToListValue};
 
+pub mod config;
 pub mod ingress;
 pub mod network_policy;
 pub mod pod;
@@ -0,0 +1,299 @@
+use std::fmt;
+
+use k8s_openapi::{
+    api::core::v1::{Container, Pod},
+    List,
+};
+
+use super::Filter;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ConfigKind {
+    ConfigMap,
+    Secret,
+}
+
+impl fmt::Display for ConfigKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ConfigMap => write!(f, "ConfigMap"),
+            Self::Secret => write!(f, "Secret"),
+        }
+    }
+}
+
+/// 参照先のConfigMapまたはSecret
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ConfigTarget {
+    pub kind: ConfigKind,
+    pub name: String,
+}
+
+/// PodからConfigMapまたはSecretへの参照
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigReference {
+    pub target: ConfigTarget,
+
+    /// 参照している箇所（volume, envFrom, env, imagePullSecrets）
+    pub usage: &'static str,
+
+    /// optional: trueのときは参照先が存在しなくてもPodは起動する
+    pub optional: bool,
+}
+
+impl ConfigReference {
+    fn new(kind: ConfigKind, name: &str, usage: &'static str, optional: Option<bool>) -> Self {
+        Self {
+            target: ConfigTarget {
+                kind,
+                name: name.to_string(),
+            },
+            usage,
+            optional: optional.unwrap_or_default(),
+        }
+    }
+}
+
+/// PodのvolumeとコンテナのenvFrom, env, imagePullSecretsからConfigMapとSecretの参照を集める
+pub fn config_references(pod: &Pod) -> Vec<ConfigReference> {
+    let Some(spec) = &pod.spec else {
+        return Vec::new();
+    };
+
+    let mut refs = Vec::new();
+
+    for volume in spec.volumes.iter().flatten() {
+        if let Some(cm) = &volume.config_map {
+            refs.push(ConfigReference::new(
+                ConfigKind::ConfigMap,
+                &cm.name,
+                "volume",
+                cm.optional,
+            ));
+        }
+
+        if let Some(secret) = &volume.secret {
+            if let Some(name) = &secret.secret_name {
+                refs.push(ConfigReference::new(
+                    ConfigKind::Secret,
+                    name,
+                    "volume",
+                    secret.optional,
+                ));
+            }
+        }
+
+        for source in volume
+            .projected
+            .iter()
+            .flat_map(|projected| projected.sources.iter().flatten())
+        {
+            if let Some(cm) = &source.config_map {
+                refs.push(ConfigReference::new(
+                    ConfigKind::ConfigMap,
+                    &cm.name,
+                    "volume",
+                    cm.optional,
+                ));
+            }
+
+            if let Some(secret) = &source.secret {
+                refs.push(ConfigReference::new(
+                    ConfigKind::Secret,
+                    &secret.name,
+                    "volume",
+                    secret.optional,
+                ));
+            }
+        }
+    }
+
+    let containers = spec
+        .init_containers
+        .iter()
+        .flatten()
+        .chain(spec.containers.iter());
+
+    for container in containers {
+        refs.extend(container_config_references(container));
+    }
+
+    for secret in spec.image_pull_secrets.iter().flatten() {
+        refs.push(ConfigReference::new(
+            ConfigKind::Secret,
+            &secret.name,
+            "imagePullSecrets",
+            None,
+        ));
+    }
+
+    refs
+}
+
+fn container_config_references(container: &Container) -> Vec<ConfigReference> {
+    let mut refs = Vec::new();
+
+    for env_from in container.env_from.iter().flatten() {
+        if let Some(cm) = &env_from.config_map_ref {
+            refs.push(ConfigReference::new(
+                ConfigKind::ConfigMap,
+                &cm.name,
+                "envFrom",
+                cm.optional,
+            ));
+        }
+
+        if let Some(secret) = &env_from.secret_ref {
+            refs.push(ConfigReference::new(
+                ConfigKind::Secret,
+                &secret.name,
+                "envFrom",
+                secret.optional,
+            ));
+        }
+    }
+
+    for value_from in container
+        .env
+        .iter()
+        .flatten()
+        .filter_map(|env| env.value_from.as_ref())
+    {
+        if let Some(cm) = &value_from.config_map_key_ref {
+            refs.push(ConfigReference::new(
+                ConfigKind::ConfigMap,
+                &cm.name,
+                "env",
+                cm.optional,
+            ));
+        }
+
+        if let Some(secret) = &value_from.secret_key_ref {
+            refs.push(ConfigReference::new(
+                ConfigKind::Secret,
+                &secret.name,
+                "env",
+                secret.optional,
+            ));
+        }
+    }
+
+    refs
+}
+
+impl Filter<ConfigTarget> for List<Pod> {
+    type Filtered = Pod;
+
+    fn filter_by_item(&self, arg: &ConfigTarget) -> Option<List<Self::Filtered>> {
+        let ret: Vec<Pod> = self
+            .items
+            .iter()
+            .filter(|pod| {
+                config_references(pod)
+                    .iter()
+                    .any(|reference| &reference.target == arg)
+            })
+            .cloned()
+            .collect();
+
+        if !ret.is_empty() {
+            Some(List {
+                items: ret,
+                ..Default::default()
+            })
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn pods() -> List<Pod> {
+        let yaml = indoc! {
+            "
+            items:
+              - metadata:
+                  name: pod-1
+                spec:
+                  containers:
+                    - name: app
+                      envFrom:
+                        - configMapRef:
+                            name: app-config
+                      env:
+                        - name: PASSWORD
+                          valueFrom:
+                            secretKeyRef:
+                              name: app-secret
+                              key: password
+                              optional: true
+                  imagePullSecrets:
+                    - name: registry
+              - metadata:
+                  name: pod-2
+                spec:
+                  containers:
+                    - name: app
+                  volumes:
+                    - name: config
+                      configMap:
+                        name: app-config
+                    - name: projected
+                      projected:
+                        sources:
+                          - secret:
+                              name: tls
+            "
+        };
+
+        serde_yaml::from_str(yaml).unwrap()
+    }
+
+    #[test]
+    fn podが参照するconfigmapとsecretを返す() {
+        let actual = config_references(&pods().items[0]);
+
+        let expected = vec![
+            ConfigReference::new(ConfigKind::ConfigMap, "app-config", "envFrom", None),
+            ConfigReference::new(ConfigKind::Secret, "app-secret", "env", Some(true)),
+            ConfigReference::new(ConfigKind::Secret, "registry", "imagePullSecrets", None),
+        ];
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn configmapを参照するpodを返す() {
+        let target = ConfigTarget {
+            kind: ConfigKind::ConfigMap,
+            name: "app-config".into(),
+        };
+
+        let actual = pods().filter_by_item(&target).unwrap();
+
+        assert_eq!(
+            actual
+                .items
+                .iter()
+                .map(|pod| pod.metadata.name.clone().unwrap())
+                .collect::<Vec<_>>(),
+            vec!["pod-1", "pod-2"]
+        );
+    }
+
+    #[test]
+    fn 同名でも種類が異なるときは含めない() {
+        let target = ConfigTarget {
+            kind: ConfigKind::Secret,
+            name: "app-config".into(),
+        };
+
+        assert_eq!(pods().filter_by_item(&target), None);
+    }
+}
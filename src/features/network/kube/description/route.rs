@@ -0,0 +1,305 @@
+//! GRPCRoute, TLSRoute, TCPRoute, UDPRouteの詳細
+//!
+//! HTTPRoute以外のRouteはparentRefsとbackendRefsの構造が同じため、Routeトレイトで共通化する
+mod related_resource;
+
+use std::{fmt::Debug, marker::PhantomData};
+
+use anyhow::{Context as _, Result};
+use k8s_openapi::NamespaceResourceScope;
+use kube::{Api, Client, ResourceExt as _};
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::{json, Value};
+
+use crate::{
+    features::{
+        api_resources::kube::SharedApiResources, network::message::NetworkRequestTargetParams,
+    },
+    kube::{
+        apis::networking::gateway::{v1, v1alpha2},
+        KubeClientRequest,
+    },
+};
+
+use self::related_resource::discover_releated_resources;
+
+use super::{Fetch, FetchedData};
+
+/// parentRefsまたはbackendRefsの参照先
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RouteRef {
+    pub group: Option<String>,
+    pub kind: Option<String>,
+    pub name: String,
+    pub namespace: Option<String>,
+}
+
+pub trait Route:
+    kube::Resource<DynamicType = (), Scope = NamespaceResourceScope>
+    + k8s_openapi::Resource
+    + Clone
+    + Debug
+    + Serialize
+    + DeserializeOwned
+    + Send
+    + Sync
+    + 'static
+{
+    fn parent_refs(&self) -> Vec<RouteRef>;
+
+    fn backend_refs(&self) -> Vec<RouteRef>;
+}
+
+macro_rules! route_ref {
+    ($r:expr) => {
+        RouteRef {
+            group: $r.group.clone(),
+            kind: $r.kind.clone(),
+            name: $r.name.clone(),
+            namespace: $r.namespace.clone(),
+        }
+    };
+}
+
+/// rulesがOption<Vec<_>>のときはoptional_rulesを指定する
+macro_rules! impl_route {
+    ($ty:ty) => {
+        impl_route!($ty, |route| route.spec.rules.iter());
+    };
+    ($ty:ty, optional_rules) => {
+        impl_route!($ty, |route| route.spec.rules.iter().flatten());
+    };
+    ($ty:ty, |$route:ident| $rules:expr) => {
+        impl Route for $ty {
+            fn parent_refs(&self) -> Vec<RouteRef> {
+                self.spec
+                    .parent_refs
+                    .iter()
+                    .flatten()
+                    .map(|r| route_ref!(r))
+                    .collect()
+            }
+
+            fn backend_refs(&self) -> Vec<RouteRef> {
+                let $route = self;
+
+                $rules
+                    .filter_map(|rule| rule.backend_refs.as_ref())
+                    .flatten()
+                    .map(|r| route_ref!(r))
+                    .collect()
+            }
+        }
+    };
+}
+
+impl_route!(v1::GRPCRoute, optional_rules);
+impl_route!(v1alpha2::GRPCRoute, optional_rules);
+impl_route!(v1alpha2::TLSRoute);
+impl_route!(v1alpha2::TCPRoute);
+impl_route!(v1alpha2::UDPRoute);
+
+pub(super) struct RouteDescriptionWorker<'a, C, R>
+where
+    C: KubeClientRequest,
+    R: Route,
+{
+    client: &'a C,
+    namespace: String,
+    name: String,
+    _route: PhantomData<R>,
+}
+
+#[async_trait::async_trait]
+impl<'a, C, R> Fetch<'a, C> for RouteDescriptionWorker<'a, C, R>
+where
+    C: KubeClientRequest,
+    R: Route,
+{
+    fn new(client: &'a C, params: NetworkRequestTargetParams, _: SharedApiResources) -> Self {
+        let NetworkRequestTargetParams {
+            namespace, name, ..
+        } = params;
+
+        Self {
+            client,
+            namespace,
+            name,
+            _route: PhantomData,
+        }
+    }
+
+    async fn fetch(&self) -> Result<FetchedData> {
+        fetch::<R>(self.client.client().clone(), &self.name, &self.namespace).await
+    }
+}
+
+async fn fetch<R: Route>(client: Client, name: &str, namespace: &str) -> Result<FetchedData> {
+    let api = Api::<R>::namespaced(client.clone(), namespace);
+
+    let route = api.get(name).await.context(format!(
+        "Failed to fetch {}: namespace={}, name={}",
+        R::KIND,
+        namespace,
+        name
+    ))?;
+
+    let description = description(&route)?;
+
+    let related_resources = discover_releated_resources(client, namespace, &route).await?;
+
+    let mut yaml = serde_yaml::to_string(&description)?
+        .lines()
+        .map(ToString::to_string)
+        .collect::<Vec<String>>();
+
+    let mut related_resources_yaml = serde_yaml::to_string(&related_resources)?
+        .lines()
+        .map(ToString::to_string)
+        .collect::<Vec<String>>();
+
+    if !related_resources_yaml.is_empty() {
+        yaml.push("".into());
+
+        yaml.append(&mut related_resources_yaml);
+    }
+
+    Ok(yaml)
+}
+
+/// metadata.name, spec, status.parents（conditionsを除く）を抽出する
+fn description<R: Route>(route: &R) -> Result<Value> {
+    let value = serde_json::to_value(route)?;
+
+    let mut description = json!({
+        "metadata": {
+            "name": route.name_any(),
+        },
+        "spec": value.get("spec").cloned().unwrap_or_default(),
+    });
+
+    if let Some(parents) = value.pointer("/status/parents").and_then(Value::as_array) {
+        let parents: Vec<Value> = parents
+            .iter()
+            .cloned()
+            .map(|mut parent| {
+                if let Some(parent) = parent.as_object_mut() {
+                    parent.remove("conditions");
+                }
+                parent
+            })
+            .collect();
+
+        description["status"] = json!({ "parents": parents });
+    }
+
+    Ok(json!({ R::KIND.to_lowercase(): description }))
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn tcproute() -> v1alpha2::TCPRoute {
+        serde_yaml::from_str(indoc! {
+            r#"
+            apiVersion: gateway.networking.k8s.io/v1alpha2
+            kind: TCPRoute
+            metadata:
+              name: tcp
+              namespace: default
+              resourceVersion: "1"
+            spec:
+              parentRefs:
+              - name: gateway
+                sectionName: tcp
+              rules:
+              - backendRefs:
+                - name: service-1
+                  port: 8080
+                - name: service-2
+                  namespace: other
+                  port: 8080
+            status:
+              parents:
+              - controllerName: example.com/controller
+                parentRef:
+                  name: gateway
+                conditions:
+                - type: Accepted
+                  status: "True"
+                  reason: Accepted
+                  message: ""
+                  lastTransitionTime: "2024-01-01T00:00:00Z"
+            "#
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn parent_refsとbackend_refsを抽出する() {
+        let route = tcproute();
+
+        assert_eq!(
+            route.parent_refs(),
+            vec![RouteRef {
+                group: None,
+                kind: None,
+                name: "gateway".into(),
+                namespace: None,
+            }]
+        );
+
+        assert_eq!(
+            route.backend_refs(),
+            vec![
+                RouteRef {
+                    group: None,
+                    kind: None,
+                    name: "service-1".into(),
+                    namespace: None,
+                },
+                RouteRef {
+                    group: None,
+                    kind: None,
+                    name: "service-2".into(),
+                    namespace: Some("other".into()),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn descriptionはname_spec_statusのparentsを含む() {
+        let actual = serde_yaml::to_string(&description(&tcproute()).unwrap()).unwrap();
+
+        let expected = indoc! {
+            r#"
+            tcproute:
+              metadata:
+                name: tcp
+              spec:
+                parentRefs:
+                - name: gateway
+                  sectionName: tcp
+                rules:
+                - backendRefs:
+                  - name: service-1
+                    port: 8080
+                  - name: service-2
+                    namespace: other
+                    port: 8080
+              status:
+                parents:
+                - controllerName: example.com/controller
+                  parentRef:
+                    name: gateway
+            "#
+        };
+
+        assert_eq!(actual, expected);
+    }
+}
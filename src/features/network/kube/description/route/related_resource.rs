@@ -0,0 +1,235 @@
+use std::collections::BTreeMap;
+
+use anyhow::{Context as _, Result};
+use futures::StreamExt as _;
+use k8s_openapi::{
+    api::core::v1::{Pod, Service},
+    Resource as _,
+};
+use kube::{api::ListParams, Api, Client, ResourceExt as _};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    features::network::kube::description::utils::{match_labels_to_query, ExtractNamespace as _},
+    kube::apis::networking::gateway::v1::Gateway,
+    logger,
+};
+
+use super::{Route, RouteRef};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RouteRelatedResources {
+    related_resources: RouteRelatedResourceItems,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct RouteRelatedResourceItems {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    gateways: Option<Vec<RelatedGateway>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    services: Option<Vec<RelatedService>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pods: Option<Vec<RelatedPod>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+struct RelatedGateway {
+    /// Gateway Name
+    name: String,
+
+    /// Gateway Namespace
+    namespace: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+struct RelatedService {
+    /// Service Name
+    name: String,
+
+    /// Service Namespace
+    namespace: String,
+
+    #[serde(skip)]
+    selector: Option<BTreeMap<String, String>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+struct RelatedPod {
+    /// Pod Name
+    name: String,
+
+    /// Pod Namespace
+    namespace: String,
+
+    /// Service Name
+    service: String,
+}
+
+pub async fn discover_releated_resources<R: Route>(
+    client: Client,
+    route_namespace: &str,
+    route: &R,
+) -> Result<RouteRelatedResources> {
+    let gateways = discover_gateways(route_namespace, route);
+
+    let services = discover_services(client.clone(), route_namespace, route)
+        .await
+        .with_context(|| format!("discover services for {}", R::KIND))?;
+
+    let pods = if let Some(services) = services.as_ref() {
+        discover_pods(client, services)
+            .await
+            .with_context(|| format!("discover pods for {}", R::KIND))?
+    } else {
+        None
+    };
+
+    Ok(RouteRelatedResources {
+        related_resources: RouteRelatedResourceItems {
+            gateways,
+            services,
+            pods,
+        },
+    })
+}
+
+fn non_empty<T: Ord>(mut items: Vec<T>) -> Option<Vec<T>> {
+    items.sort();
+
+    if items.is_empty() {
+        None
+    } else {
+        Some(items)
+    }
+}
+
+fn discover_gateways<R: Route>(route_namespace: &str, route: &R) -> Option<Vec<RelatedGateway>> {
+    let gateways = route
+        .parent_refs()
+        .into_iter()
+        .filter_map(|r| {
+            let RouteRef {
+                group,
+                kind,
+                name,
+                namespace,
+            } = r;
+
+            let group = group.unwrap_or_else(|| Gateway::GROUP.to_string());
+            let kind = kind.unwrap_or_else(|| Gateway::KIND.to_string());
+            let namespace = namespace.unwrap_or_else(|| route_namespace.to_string());
+
+            if group != Gateway::GROUP || kind != Gateway::KIND {
+                logger!(
+                    warn,
+                    "ParentRef is not a Gateway, skipping. Group: {group}, Kind: {kind} namespace: {namespace} name: {name}",
+                );
+
+                return None;
+            }
+
+            Some(RelatedGateway { name, namespace })
+        })
+        .collect();
+
+    non_empty(gateways)
+}
+
+async fn discover_services<R: Route>(
+    client: Client,
+    route_namespace: &str,
+    route: &R,
+) -> Result<Option<Vec<RelatedService>>> {
+    let refs: Vec<_> = route
+        .backend_refs()
+        .into_iter()
+        .filter(|r| {
+            !(r.group.as_ref().is_some_and(|g| !g.is_empty())
+                || r.kind.as_ref().is_some_and(|k| k != Service::KIND))
+        })
+        .map(|r| {
+            let namespace = r.namespace.unwrap_or_else(|| route_namespace.to_string());
+            (r.name, namespace)
+        })
+        .collect();
+
+    let task = tokio::spawn(async move {
+        futures::stream::iter(refs.into_iter().map(|(name, namespace)| {
+            let client = client.clone();
+            async move { fetch_service(client, name, namespace).await }
+        }))
+        .buffer_unordered(20)
+        .collect::<Vec<_>>()
+        .await
+    });
+
+    let mut services: Vec<_> = task.await?.into_iter().flatten().collect();
+
+    services.sort();
+    services.dedup();
+
+    Ok(non_empty(services))
+}
+
+async fn fetch_service(client: Client, name: String, namespace: String) -> Option<RelatedService> {
+    let api = Api::<Service>::namespaced(client, &namespace);
+
+    match api.get(&name).await {
+        Ok(service) => Some(RelatedService {
+            name: service.name_any(),
+            namespace: service.extract_namespace(),
+            selector: service.spec.and_then(|spec| spec.selector),
+        }),
+
+        Err(err) => {
+            logger!(error, "failed to get service {namespace}/{name}: {err}");
+
+            None
+        }
+    }
+}
+
+async fn discover_pods(
+    client: Client,
+    services: &[RelatedService],
+) -> Result<Option<Vec<RelatedPod>>> {
+    let services = services.to_vec();
+
+    let task = tokio::spawn(async move {
+        futures::stream::iter(services.into_iter().map(|svc| {
+            let client = client.clone();
+            async move { fetch_pods(client, svc).await }
+        }))
+        .buffer_unordered(20)
+        .collect::<Vec<_>>()
+        .await
+    });
+
+    let pods = task.await?.into_iter().flatten().flatten().collect();
+
+    Ok(non_empty(pods))
+}
+
+async fn fetch_pods(client: Client, svc: RelatedService) -> Option<Vec<RelatedPod>> {
+    let selector = svc.selector.as_ref()?;
+
+    let label_selector = match_labels_to_query(selector.clone()).join(",");
+
+    let lp = ListParams::default().labels(&label_selector);
+
+    let api = Api::<Pod>::namespaced(client, &svc.namespace);
+
+    match api.list(&lp).await {
+        Ok(pods) => Some(
+            pods.into_iter()
+                .map(|pod| RelatedPod {
+                    name: pod.name_any(),
+                    namespace: pod.extract_namespace(),
+                    service: svc.name.clone(),
+                })
+                .collect(),
+        ),
+        Err(_) => None,
+    }
+}
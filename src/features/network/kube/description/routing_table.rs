@@ -0,0 +1,603 @@
+//! Ingress、Gateway、HTTPRouteのルーティングテーブル
+//!
+//! host・pathごとにバックエンドのServiceとエンドポイントのPod、TLSのSecretの状態を1行にまとめる
+mod gateway;
+mod ingress;
+
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use k8s_openapi::{
+    api::{
+        core::v1::{Secret, Service},
+        discovery::v1::EndpointSlice,
+    },
+    apimachinery::pkg::util::intstr::IntOrString,
+    List,
+};
+use kube::Resource as _;
+use serde::{de::DeserializeOwned, Serialize};
+use unicode_width::UnicodeWidthStr as _;
+
+use crate::{certificate::Certificate, kube::KubeClientRequest, workers::kube::color::fg::Color};
+
+use super::utils::error_message;
+
+pub use self::{
+    gateway::{gateway_tls, httproute_routes},
+    ingress::ingress_routing,
+};
+
+/// v1beta1のリソースをv1に変換する
+///
+/// Gateway APIのv1beta1とv1は同じスキーマのため、シリアライズしたものをそのまま読み込む
+pub fn convert<T: Serialize, U: DeserializeOwned>(value: &T) -> Result<U> {
+    Ok(serde_json::from_value(serde_json::to_value(value)?)?)
+}
+
+/// ルーティングテーブルの1行
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Route {
+    pub host: String,
+
+    /// pathやheaderなどのマッチ条件
+    pub path: String,
+
+    pub backend: Backend,
+}
+
+/// バックエンドのService
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Backend {
+    pub namespace: String,
+    pub name: String,
+    pub port: Option<IntOrString>,
+}
+
+/// TLSのSecretと対象のホスト
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Tls {
+    pub namespace: String,
+    pub secret: String,
+    pub hosts: Vec<String>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum BackendStatus {
+    ServiceNotFound,
+    PortNotFound,
+    Endpoints(Vec<EndpointPod>),
+    /// 404以外の理由（権限がない、タイムアウトなど）で取得できなかった
+    Error(String),
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct EndpointPod {
+    name: String,
+    ready: bool,
+}
+
+/// Service、EndpointSlice、Secretを取得してルーティングテーブルを返す
+///
+/// 404のときは存在しないものとして扱い、それ以外のエラーはその理由を表示する
+pub async fn routing_table<C: KubeClientRequest>(
+    client: &C,
+    routes: &[Route],
+    tls: &[Tls],
+) -> Vec<String> {
+    // 同じバックエンドは1度だけ取得する
+    let mut statuses: Vec<(&Backend, BackendStatus)> = Vec::new();
+
+    for route in routes {
+        if statuses
+            .iter()
+            .any(|(backend, _)| *backend == &route.backend)
+        {
+            continue;
+        }
+
+        let status = fetch_backend_status(client, &route.backend).await;
+
+        statuses.push((&route.backend, status));
+    }
+
+    let now = Utc::now();
+
+    let mut tls_statuses = Vec::new();
+
+    for t in tls {
+        let secret = client
+            .request::<Secret>(&format!(
+                "{}/{}",
+                Secret::url_path(&(), Some(&t.namespace)),
+                t.secret
+            ))
+            .await;
+
+        let secret = match secret {
+            Ok(secret) => Ok(Some(secret)),
+            Err(err) if is_not_found(&err) => Ok(None),
+            Err(err) => Err(error_message(&err)),
+        };
+
+        tls_statuses.push(tls_status(
+            secret.as_ref().map(Option::as_ref).map_err(String::as_str),
+            &t.hosts,
+            now,
+        ));
+    }
+
+    render(
+        routes
+            .iter()
+            .filter_map(|route| {
+                statuses
+                    .iter()
+                    .find(|(backend, _)| *backend == &route.backend)
+                    .map(|(_, status)| (route, status))
+            })
+            .collect(),
+        tls.iter().zip(tls_statuses).collect(),
+    )
+}
+
+async fn fetch_backend_status<C: KubeClientRequest>(
+    client: &C,
+    backend: &Backend,
+) -> BackendStatus {
+    let service = client
+        .request::<Service>(&format!(
+            "{}/{}",
+            Service::url_path(&(), Some(&backend.namespace)),
+            backend.name
+        ))
+        .await;
+
+    let service = match service {
+        Ok(service) => service,
+        Err(err) if is_not_found(&err) => return BackendStatus::ServiceNotFound,
+        Err(err) => {
+            return BackendStatus::Error(format!("unable to get service: {}", error_message(&err)))
+        }
+    };
+
+    let slices = client
+        .request::<List<EndpointSlice>>(&format!(
+            "{}?labelSelector=kubernetes.io/service-name={}",
+            EndpointSlice::url_path(&(), Some(&backend.namespace)),
+            backend.name
+        ))
+        .await;
+
+    match slices {
+        Ok(slices) => backend_status(&service, backend.port.as_ref(), &slices.items),
+        Err(err) => BackendStatus::Error(format!(
+            "unable to list endpointslices: {}",
+            error_message(&err)
+        )),
+    }
+}
+
+fn is_not_found(err: &anyhow::Error) -> bool {
+    matches!(
+        err.downcast_ref::<kube::Error>(),
+        Some(kube::Error::Api(res)) if res.code == 404
+    )
+}
+
+fn backend_status(
+    service: &Service,
+    port: Option<&IntOrString>,
+    slices: &[EndpointSlice],
+) -> BackendStatus {
+    if let Some(port) = port {
+        let ports = service.spec.as_ref().and_then(|spec| spec.ports.as_ref());
+
+        let has_port = ports.is_some_and(|ports| {
+            ports.iter().any(|p| match port {
+                IntOrString::Int(number) => p.port == *number,
+                IntOrString::String(name) => p.name.as_ref() == Some(name),
+            })
+        });
+
+        if !has_port {
+            return BackendStatus::PortNotFound;
+        }
+    }
+
+    let endpoints = slices
+        .iter()
+        .flat_map(|slice| &slice.endpoints)
+        .map(|endpoint| {
+            let name = endpoint
+                .target_ref
+                .as_ref()
+                .and_then(|r| r.name.clone())
+                .unwrap_or_else(|| endpoint.addresses.join(","));
+
+            // readyが未設定のときはreadyとして扱う
+            let ready = endpoint
+                .conditions
+                .as_ref()
+                .and_then(|c| c.ready)
+                .unwrap_or(true);
+
+            EndpointPod { name, ready }
+        })
+        .collect();
+
+    BackendStatus::Endpoints(endpoints)
+}
+
+/// `secret` は存在しないときOk(None)、取得に失敗したときはErrにエラーの理由
+fn tls_status(
+    secret: Result<Option<&Secret>, &str>,
+    hosts: &[String],
+    now: DateTime<Utc>,
+) -> String {
+    let secret = match secret {
+        Ok(Some(secret)) => secret,
+        Ok(None) => return Color::Red.wrap("secret not found"),
+        Err(err) => return Color::Red.wrap(format!("unable to read secret: {}", err)),
+    };
+
+    let Some(crt) = secret.data.as_ref().and_then(|data| data.get("tls.crt")) else {
+        return Color::Red.wrap("tls.crt not found");
+    };
+
+    let certificate = match Certificate::parse_pem_chain(&crt.0) {
+        Ok(chain) => chain.into_iter().next(),
+        Err(e) => return Color::Red.wrap(format!("invalid certificate: {}", e)),
+    };
+
+    let Some(certificate) = certificate else {
+        return Color::Red.wrap("certificate not found");
+    };
+
+    let mut status = format!(
+        "{}, expires {} {}",
+        certificate.subject,
+        certificate.not_after.format("%Y-%m-%d"),
+        certificate.expiry_countdown(now)
+    );
+
+    let unmatched: Vec<&str> = hosts
+        .iter()
+        .filter(|host| !certificate.matches_host(host))
+        .map(String::as_str)
+        .collect();
+
+    if !unmatched.is_empty() {
+        status += &Color::Red.wrap(format!(" host not in certificate: {}", unmatched.join(",")));
+    }
+
+    status
+}
+
+fn render(routes: Vec<(&Route, &BackendStatus)>, tls: Vec<(&Tls, String)>) -> Vec<String> {
+    let mut lines = Vec::new();
+
+    if !routes.is_empty() {
+        let rows = routes
+            .into_iter()
+            .map(|(route, status)| {
+                let backend = match &route.backend.port {
+                    Some(IntOrString::Int(port)) => format!("{}:{}", route.backend.name, port),
+                    Some(IntOrString::String(port)) => format!("{}:{}", route.backend.name, port),
+                    None => route.backend.name.clone(),
+                };
+
+                [
+                    route.host.clone(),
+                    route.path.clone(),
+                    backend,
+                    endpoints(status),
+                ]
+            })
+            .collect();
+
+        lines.push("routingTable:".into());
+        lines.extend(table(["HOST", "PATH", "BACKEND", "ENDPOINTS"], rows));
+    }
+
+    if !tls.is_empty() {
+        let rows = tls
+            .into_iter()
+            .map(|(tls, status)| [tls.secret.clone(), tls.hosts.join(","), status])
+            .collect();
+
+        if !lines.is_empty() {
+            lines.push("".into());
+        }
+
+        lines.push("tls:".into());
+        lines.extend(table(["SECRET", "HOSTS", "STATUS"], rows));
+    }
+
+    lines
+}
+
+fn endpoints(status: &BackendStatus) -> String {
+    match status {
+        BackendStatus::ServiceNotFound => Color::Red.wrap("service not found"),
+        BackendStatus::PortNotFound => Color::Red.wrap("port not found in service"),
+        BackendStatus::Error(err) => Color::Red.wrap(err),
+        BackendStatus::Endpoints(pods) if pods.is_empty() => Color::Red.wrap("no endpoints"),
+        BackendStatus::Endpoints(pods) => pods
+            .iter()
+            .map(|pod| {
+                if pod.ready {
+                    Color::Green.wrap(&pod.name)
+                } else {
+                    Color::Red.wrap(format!("{} (not ready)", pod.name))
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(", "),
+    }
+}
+
+/// 最後の列以外を揃えたテーブルを返す
+fn table<const N: usize>(header: [&str; N], rows: Vec<[String; N]>) -> Vec<String> {
+    let mut widths = header.map(|h| h.width());
+
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.width());
+        }
+    }
+
+    let format_row = |row: Vec<&str>| {
+        let mut line = String::from(" ");
+
+        for (i, cell) in row.iter().enumerate() {
+            line.push(' ');
+            line.push_str(cell);
+
+            if i + 1 < N {
+                line.push_str(&" ".repeat(widths[i] - cell.width() + 1));
+            }
+        }
+
+        line.trim_end().to_string()
+    };
+
+    std::iter::once(format_row(header.to_vec()))
+        .chain(
+            rows.iter()
+                .map(|row| format_row(row.iter().map(String::as_str).collect())),
+        )
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use indoc::indoc;
+    use k8s_openapi::ByteString;
+    use kube::core::ErrorResponse;
+    use mockall::predicate::eq;
+    use pretty_assertions::assert_eq;
+
+    use crate::{certificate::tests::CERTIFICATE, kube::mock::MockTestKubeClient, mock_expect};
+
+    use super::*;
+
+    fn service() -> Service {
+        serde_yaml::from_str(indoc! {
+            "
+            metadata:
+              name: service
+            spec:
+              ports:
+              - name: http
+                port: 80
+            "
+        })
+        .unwrap()
+    }
+
+    fn slices() -> Vec<EndpointSlice> {
+        serde_yaml::from_str(indoc! {
+            "
+            - metadata:
+                name: service-abc
+              addressType: IPv4
+              endpoints:
+              - addresses:
+                - 10.0.0.1
+                conditions:
+                  ready: true
+                targetRef:
+                  kind: Pod
+                  name: pod-1
+              - addresses:
+                - 10.0.0.2
+                conditions:
+                  ready: false
+                targetRef:
+                  kind: Pod
+                  name: pod-2
+            "
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn バックエンドのポートとエンドポイントの状態を返す() {
+        let service = service();
+        let slices = slices();
+
+        assert_eq!(
+            backend_status(&service, Some(&IntOrString::Int(80)), &slices),
+            BackendStatus::Endpoints(vec![
+                EndpointPod {
+                    name: "pod-1".into(),
+                    ready: true,
+                },
+                EndpointPod {
+                    name: "pod-2".into(),
+                    ready: false,
+                },
+            ])
+        );
+
+        assert_eq!(
+            backend_status(&service, Some(&IntOrString::String("grpc".into())), &slices),
+            BackendStatus::PortNotFound
+        );
+    }
+
+    fn api_error(code: u16, message: &str) -> anyhow::Error {
+        kube::Error::Api(ErrorResponse {
+            status: "Failure".into(),
+            message: message.into(),
+            reason: "reason".into(),
+            code,
+        })
+        .into()
+    }
+
+    #[tokio::test]
+    async fn 存在しないときと取得できないときを区別する() {
+        let mut client = MockTestKubeClient::new();
+
+        mock_expect!(
+            client,
+            request,
+            [
+                (
+                    Service,
+                    eq("/api/v1/namespaces/default/services/missing"),
+                    Err(api_error(404, "services \"missing\" not found"))
+                ),
+                (
+                    Service,
+                    eq("/api/v1/namespaces/default/services/service"),
+                    Ok(service())
+                ),
+                (
+                    List<EndpointSlice>,
+                    eq("/apis/discovery.k8s.io/v1/namespaces/default/endpointslices?labelSelector=kubernetes.io/service-name=service"),
+                    Err(api_error(403, "endpointslices.discovery.k8s.io is forbidden"))
+                )
+            ]
+        );
+
+        let backend = |name: &str| Backend {
+            namespace: "default".into(),
+            name: name.into(),
+            port: None,
+        };
+
+        assert_eq!(
+            fetch_backend_status(&client, &backend("missing")).await,
+            BackendStatus::ServiceNotFound
+        );
+
+        assert_eq!(
+            fetch_backend_status(&client, &backend("service")).await,
+            BackendStatus::Error(
+                "unable to list endpointslices: endpointslices.discovery.k8s.io is forbidden"
+                    .into()
+            )
+        );
+    }
+
+    #[test]
+    fn 証明書の有効期限とホストを検証する() {
+        let secret = Secret {
+            data: Some(BTreeMap::from([(
+                "tls.crt".into(),
+                ByteString(CERTIFICATE.as_bytes().to_vec()),
+            )])),
+            ..Default::default()
+        };
+
+        let now = "2126-09-15T00:00:00Z".parse().unwrap();
+
+        assert_eq!(
+            tls_status(
+                Ok(Some(&secret)),
+                &["example.com".into(), "foo.example.com".into()],
+                now
+            ),
+            format!(
+                "CN=example.com, expires 2126-09-25 {}{}",
+                Color::Yellow.wrap("(in 10d)"),
+                Color::Red.wrap(" host not in certificate: foo.example.com")
+            )
+        );
+
+        assert_eq!(
+            tls_status(Ok(None), &[], now),
+            Color::Red.wrap("secret not found")
+        );
+
+        assert_eq!(
+            tls_status(Err("secrets \"tls\" is forbidden"), &[], now),
+            Color::Red.wrap("unable to read secret: secrets \"tls\" is forbidden")
+        );
+    }
+
+    #[test]
+    fn ルーティングテーブルを描画する() {
+        let route = Route {
+            host: "example.com".into(),
+            path: "/ (Prefix)".into(),
+            backend: Backend {
+                namespace: "default".into(),
+                name: "service".into(),
+                port: Some(IntOrString::Int(80)),
+            },
+        };
+
+        let missing = Route {
+            host: "*".into(),
+            path: "/api (Exact)".into(),
+            backend: Backend {
+                namespace: "default".into(),
+                name: "missing".into(),
+                port: None,
+            },
+        };
+
+        let tls = Tls {
+            namespace: "default".into(),
+            secret: "tls".into(),
+            hosts: vec!["example.com".into()],
+        };
+
+        let ready = BackendStatus::Endpoints(vec![EndpointPod {
+            name: "pod-1".into(),
+            ready: true,
+        }]);
+
+        let actual = render(
+            vec![
+                (&route, &ready),
+                (&missing, &BackendStatus::ServiceNotFound),
+            ],
+            vec![(&tls, "ok".into())],
+        );
+
+        let expected = vec![
+            "routingTable:".to_string(),
+            "  HOST         PATH          BACKEND     ENDPOINTS".into(),
+            format!(
+                "  example.com  / (Prefix)    service:80  {}",
+                Color::Green.wrap("pod-1")
+            ),
+            format!(
+                "  *            /api (Exact)  missing     {}",
+                Color::Red.wrap("service not found")
+            ),
+            "".into(),
+            "tls:".into(),
+            "  SECRET  HOSTS        STATUS".into(),
+            "  tls     example.com  ok".into(),
+        ];
+
+        assert_eq!(actual, expected);
+    }
+}
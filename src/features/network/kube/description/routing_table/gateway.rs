@@ -0,0 +1,247 @@
+use k8s_openapi::{
+    api::core::v1::{Secret, Service},
+    apimachinery::pkg::util::intstr::IntOrString,
+    Resource as _,
+};
+use serde::Serialize;
+
+use crate::kube::apis::networking::gateway::v1::{
+    Gateway, HTTPRoute, HTTPRouteRulesBackendRefs, HTTPRouteRulesMatches,
+};
+
+use super::{Backend, Route, Tls};
+
+/// HTTPRouteのhostnames、matches、backendRefsの組み合わせを返す
+pub fn httproute_routes(namespace: &str, httproute: &HTTPRoute) -> Vec<Route> {
+    let hosts = match httproute.spec.hostnames.as_ref() {
+        Some(hostnames) if !hostnames.is_empty() => hostnames.clone(),
+        _ => vec!["*".into()],
+    };
+
+    let mut routes = Vec::new();
+
+    for rule in httproute.spec.rules.iter().flatten() {
+        let paths: Vec<String> = match rule.matches.as_ref() {
+            Some(matches) if !matches.is_empty() => matches.iter().map(match_path).collect(),
+            _ => vec!["/ (PathPrefix)".into()],
+        };
+
+        let backends: Vec<Backend> = rule
+            .backend_refs
+            .iter()
+            .flatten()
+            .filter_map(|r| backend(namespace, r))
+            .collect();
+
+        for host in &hosts {
+            for path in &paths {
+                for backend in &backends {
+                    routes.push(Route {
+                        host: host.clone(),
+                        path: path.clone(),
+                        backend: backend.clone(),
+                    });
+                }
+            }
+        }
+    }
+
+    routes
+}
+
+/// Gatewayのリスナーが参照するSecretを返す
+pub fn gateway_tls(namespace: &str, gateway: &Gateway) -> Vec<Tls> {
+    gateway
+        .spec
+        .listeners
+        .iter()
+        .flat_map(|listener| {
+            let hosts: Vec<String> = listener.hostname.iter().cloned().collect();
+
+            listener
+                .tls
+                .iter()
+                .flat_map(|tls| tls.certificate_refs.iter().flatten())
+                .filter(|r| {
+                    r.group.as_ref().is_none_or(|g| g.is_empty())
+                        && r.kind.as_ref().is_none_or(|k| k == Secret::KIND)
+                })
+                .map(move |r| Tls {
+                    namespace: r.namespace.clone().unwrap_or_else(|| namespace.to_string()),
+                    secret: r.name.clone(),
+                    hosts: hosts.clone(),
+                })
+        })
+        .collect()
+}
+
+/// 例: `/api (PathPrefix) GET header:x-env=dev`
+fn match_path(m: &HTTPRouteRulesMatches) -> String {
+    let mut conditions = Vec::new();
+
+    match m.path.as_ref() {
+        Some(path) => conditions.push(format!(
+            "{} ({})",
+            path.value.as_deref().unwrap_or("/"),
+            path.r#type
+                .as_ref()
+                .map_or("PathPrefix".into(), enum_to_string)
+        )),
+        None => conditions.push("/ (PathPrefix)".into()),
+    }
+
+    if let Some(method) = m.method.as_ref() {
+        conditions.push(enum_to_string(method));
+    }
+
+    for header in m.headers.iter().flatten() {
+        conditions.push(format!("header:{}={}", header.name, header.value));
+    }
+
+    for query in m.query_params.iter().flatten() {
+        conditions.push(format!("query:{}={}", query.name, query.value));
+    }
+
+    conditions.join(" ")
+}
+
+fn backend(namespace: &str, r: &HTTPRouteRulesBackendRefs) -> Option<Backend> {
+    if r.group.as_ref().is_some_and(|g| !g.is_empty())
+        || r.kind.as_ref().is_some_and(|k| k != Service::KIND)
+    {
+        return None;
+    }
+
+    Some(Backend {
+        namespace: r.namespace.clone().unwrap_or_else(|| namespace.to_string()),
+        name: r.name.clone(),
+        port: r.port.map(IntOrString::Int),
+    })
+}
+
+/// serdeでリネームされた列挙子の名前を返す
+fn enum_to_string<T: Serialize>(value: &T) -> String {
+    serde_json::to_value(value)
+        .ok()
+        .and_then(|v| v.as_str().map(ToString::to_string))
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn httprouteのルールをルーティングテーブルの行に変換する() {
+        let httproute: HTTPRoute = serde_yaml::from_str(indoc! {
+            "
+            apiVersion: gateway.networking.k8s.io/v1
+            kind: HTTPRoute
+            metadata:
+              name: httproute
+            spec:
+              hostnames:
+              - example.com
+              rules:
+              - matches:
+                - path:
+                    type: Exact
+                    value: /api
+                  method: GET
+                  headers:
+                  - name: x-env
+                    value: dev
+                backendRefs:
+                - name: service-1
+                  port: 8080
+                - name: service-2
+                  namespace: other
+                  port: 8080
+              - backendRefs:
+                - group: example.com
+                  kind: Bucket
+                  name: bucket
+                - name: service-3
+            "
+        })
+        .unwrap();
+
+        let actual = httproute_routes("default", &httproute);
+
+        let route = |path: &str, namespace: &str, name: &str, port: Option<i32>| Route {
+            host: "example.com".into(),
+            path: path.into(),
+            backend: Backend {
+                namespace: namespace.into(),
+                name: name.into(),
+                port: port.map(IntOrString::Int),
+            },
+        };
+
+        let expected = vec![
+            route(
+                "/api (Exact) GET header:x-env=dev",
+                "default",
+                "service-1",
+                Some(8080),
+            ),
+            route(
+                "/api (Exact) GET header:x-env=dev",
+                "other",
+                "service-2",
+                Some(8080),
+            ),
+            route("/ (PathPrefix)", "default", "service-3", None),
+        ];
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn gatewayのリスナーが参照するsecretを返す() {
+        let gateway: Gateway = serde_yaml::from_str(indoc! {
+            "
+            apiVersion: gateway.networking.k8s.io/v1
+            kind: Gateway
+            metadata:
+              name: gateway
+            spec:
+              gatewayClassName: example
+              listeners:
+              - name: http
+                port: 80
+                protocol: HTTP
+              - name: https
+                hostname: example.com
+                port: 443
+                protocol: HTTPS
+                tls:
+                  certificateRefs:
+                  - name: tls
+                  - name: other-tls
+                    namespace: other
+            "
+        })
+        .unwrap();
+
+        let actual = gateway_tls("default", &gateway);
+
+        let expected = vec![
+            Tls {
+                namespace: "default".into(),
+                secret: "tls".into(),
+                hosts: vec!["example.com".into()],
+            },
+            Tls {
+                namespace: "other".into(),
+                secret: "other-tls".into(),
+                hosts: vec!["example.com".into()],
+            },
+        ];
+
+        assert_eq!(actual, expected);
+    }
+}
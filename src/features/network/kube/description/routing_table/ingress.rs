@@ -0,0 +1,153 @@
+use k8s_openapi::{
+    api::networking::v1::{Ingress, IngressBackend},
+    apimachinery::pkg::util::intstr::IntOrString,
+};
+
+use super::{Backend, Route, Tls};
+
+/// Ingressのルールとデフォルトバックエンド、TLSの設定を返す
+pub fn ingress_routing(namespace: &str, ingress: &Ingress) -> (Vec<Route>, Vec<Tls>) {
+    let Some(spec) = ingress.spec.as_ref() else {
+        return Default::default();
+    };
+
+    let mut routes = Vec::new();
+
+    for rule in spec.rules.iter().flatten() {
+        let host = rule.host.clone().unwrap_or_else(|| "*".into());
+
+        for path in rule.http.iter().flat_map(|http| &http.paths) {
+            let Some(backend) = backend(namespace, &path.backend) else {
+                continue;
+            };
+
+            routes.push(Route {
+                host: host.clone(),
+                path: format!(
+                    "{} ({})",
+                    path.path.as_deref().unwrap_or("/"),
+                    path.path_type
+                ),
+                backend,
+            });
+        }
+    }
+
+    if let Some(backend) = spec
+        .default_backend
+        .as_ref()
+        .and_then(|b| backend(namespace, b))
+    {
+        routes.push(Route {
+            host: "*".into(),
+            path: "* (default)".into(),
+            backend,
+        });
+    }
+
+    let tls = spec
+        .tls
+        .iter()
+        .flatten()
+        .filter_map(|tls| {
+            Some(Tls {
+                namespace: namespace.to_string(),
+                secret: tls.secret_name.clone()?,
+                hosts: tls.hosts.clone().unwrap_or_default(),
+            })
+        })
+        .collect();
+
+    (routes, tls)
+}
+
+fn backend(namespace: &str, backend: &IngressBackend) -> Option<Backend> {
+    let service = backend.service.as_ref()?;
+
+    let port = service.port.as_ref().and_then(|port| {
+        port.number
+            .map(IntOrString::Int)
+            .or_else(|| port.name.clone().map(IntOrString::String))
+    });
+
+    Some(Backend {
+        namespace: namespace.to_string(),
+        name: service.name.clone(),
+        port,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn ingressのルールをルーティングテーブルの行に変換する() {
+        let ingress: Ingress = serde_yaml::from_str(indoc! {
+            "
+            metadata:
+              name: ingress
+            spec:
+              defaultBackend:
+                service:
+                  name: default-service
+                  port:
+                    name: http
+              rules:
+              - host: example.com
+                http:
+                  paths:
+                  - backend:
+                      service:
+                        name: service
+                        port:
+                          number: 80
+                    path: /api
+                    pathType: Prefix
+              tls:
+              - hosts:
+                - example.com
+                secretName: tls
+            "
+        })
+        .unwrap();
+
+        let (routes, tls) = ingress_routing("default", &ingress);
+
+        assert_eq!(
+            routes,
+            vec![
+                Route {
+                    host: "example.com".into(),
+                    path: "/api (Prefix)".into(),
+                    backend: Backend {
+                        namespace: "default".into(),
+                        name: "service".into(),
+                        port: Some(IntOrString::Int(80)),
+                    },
+                },
+                Route {
+                    host: "*".into(),
+                    path: "* (default)".into(),
+                    backend: Backend {
+                        namespace: "default".into(),
+                        name: "default-service".into(),
+                        port: Some(IntOrString::String("http".into())),
+                    },
+                },
+            ]
+        );
+
+        assert_eq!(
+            tls,
+            vec![Tls {
+                namespace: "default".into(),
+                secret: "tls".into(),
+                hosts: vec!["example.com".into()],
+            }]
+        );
+    }
+}
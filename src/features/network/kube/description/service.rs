@@ -1,7 +1,10 @@
+mod endpoint;
+
 use anyhow::Result;
 use k8s_openapi::{
     api::{
         core::v1::{Pod, Service, ServiceSpec},
+        discovery::v1::EndpointSlice,
         networking::v1::Ingress,
     },
     List,
@@ -14,12 +17,14 @@ use crate::{
         api_resources::kube::SharedApiResources, network::message::NetworkRequestTargetParams,
     },
     kube::KubeClientRequest,
+    workers::kube::color::fg::Color,
 };
 
-use self::to_value::ToValue;
+use self::{endpoint::EndpointHealth, to_value::ToValue};
 
 use super::{
     related_resources::{to_list_value::ToListValue, RelatedClient},
+    utils::error_message,
     Fetch, FetchedData,
 };
 
@@ -78,6 +83,30 @@ where
             None
         };
 
+        // EndpointSliceはkubernetes.io/service-nameラベルでServiceと紐づく
+        // 取得できなくてもServiceの情報は表示する
+        let endpoint_slices: Result<List<EndpointSlice>, String> = self
+            .client
+            .request(&format!(
+                "{}?labelSelector=kubernetes.io/service-name={}",
+                EndpointSlice::url_path(&(), Some(&self.namespace)),
+                self.name
+            ))
+            .await
+            .map_err(|err| error_message(&err));
+
+        let endpoint_health = EndpointHealth::new(
+            &service,
+            related_pods
+                .as_ref()
+                .map(|pods| pods.items.as_slice())
+                .unwrap_or_default(),
+            endpoint_slices
+                .as_ref()
+                .map(|slices| slices.items.as_slice())
+                .map_err(String::as_str),
+        );
+
         let mut related_resources = Mapping::new();
 
         if let Some(ingresses) = related_ingresses {
@@ -97,7 +126,41 @@ where
             .map(ToString::to_string)
             .collect();
 
-        let mut value = service;
+        let mut value = Vec::new();
+
+        // 警告は目立つように先頭に赤色で表示する
+        if !endpoint_health.warnings().is_empty() {
+            let mut root = Mapping::new();
+
+            root.insert(
+                "warnings".into(),
+                serde_yaml::to_value(endpoint_health.warnings())?,
+            );
+
+            value.extend(
+                serde_yaml::to_string(&root)?
+                    .lines()
+                    .map(|line| Color::Red.wrap(line)),
+            );
+
+            value.push(Default::default());
+        }
+
+        value.extend(service);
+
+        if endpoint_slices.is_ok() {
+            let mut endpoints = Mapping::new();
+
+            endpoints.insert("endpoints".into(), serde_yaml::to_value(&endpoint_health)?);
+
+            value.push(Default::default());
+
+            value.extend(
+                serde_yaml::to_string(&endpoints)?
+                    .lines()
+                    .map(ToString::to_string),
+            );
+        }
 
         if !related_resources.is_empty() {
             let mut root = Mapping::new();
@@ -123,7 +186,7 @@ mod tests {
     use anyhow::bail;
     use indoc::indoc;
     use k8s_openapi::{
-        api::{core::v1::Pod, networking::v1::Ingress},
+        api::{core::v1::Pod, discovery::v1::EndpointSlice, networking::v1::Ingress},
         List,
     };
     use mockall::predicate::eq;
@@ -188,6 +251,33 @@ mod tests {
         .unwrap()
     }
 
+    fn endpoint_slices() -> List<EndpointSlice> {
+        serde_yaml::from_str(indoc! {
+            "
+            items:
+            - metadata:
+                name: service-abc
+              addressType: IPv4
+              endpoints:
+              - addresses:
+                - 10.1.0.1
+                conditions:
+                  ready: true
+                targetRef:
+                  kind: Pod
+                  name: pod-1
+              - addresses:
+                - 10.1.0.2
+                conditions:
+                  ready: true
+                targetRef:
+                  kind: Pod
+                  name: pod-2
+            "
+        })
+        .unwrap()
+    }
+
     fn ingresses() -> List<Ingress> {
         serde_yaml::from_str(indoc! {
             "
@@ -239,6 +329,11 @@ mod tests {
                     List<Pod>,
                     eq("/api/v1/namespaces/default/pods"),
                     Ok(pods())
+                ),
+                (
+                    List<EndpointSlice>,
+                    eq("/apis/discovery.k8s.io/v1/namespaces/default/endpointslices?labelSelector=kubernetes.io/service-name=service"),
+                    Ok(endpoint_slices())
                 )
             ]
         );
@@ -274,6 +369,28 @@ mod tests {
                 sessionAffinity: None
                 type: ClusterIP
 
+            endpoints:
+              ready: 2
+              serving: 2
+              terminating: 0
+              addresses:
+              - address: 10.1.0.1
+                pod: pod-1
+                ready: true
+                serving: true
+                terminating: false
+              - address: 10.1.0.2
+                pod: pod-2
+                ready: true
+                serving: true
+                terminating: false
+              ports:
+              - port: 80/TCP
+                targetPort: '80'
+                containerPorts:
+                  pod-1: '80'
+                  pod-2: '80'
+
             relatedResources:
               ingresses:
               - ingress-1
@@ -289,6 +406,61 @@ mod tests {
         assert_eq!(result.unwrap(), expected);
     }
 
+    #[tokio::test]
+    async fn endpoint_sliceを取得できないときは警告を表示して残りを返す() {
+        let mut client = MockTestKubeClient::new();
+        mock_expect!(
+            client,
+            request,
+            [
+                (
+                    Service,
+                    eq("/api/v1/namespaces/default/services/service"),
+                    Ok(service())
+                ),
+                (
+                    List<Ingress>,
+                    eq("/apis/networking.k8s.io/v1/namespaces/default/ingresses"),
+                    Ok(ingresses())
+                ),
+                (
+                    List<Pod>,
+                    eq("/api/v1/namespaces/default/pods"),
+                    Ok(pods())
+                ),
+                (
+                    List<EndpointSlice>,
+                    eq("/apis/discovery.k8s.io/v1/namespaces/default/endpointslices?labelSelector=kubernetes.io/service-name=service"),
+                    bail!("forbidden")
+                )
+            ]
+        );
+
+        let target_params = NetworkRequestTargetParams {
+            namespace: "default".to_string(),
+            name: "service".to_string(),
+            version: "v1".to_string(),
+        };
+
+        let worker = ServiceDescriptionWorker::new(&client, target_params, ApiResources::shared());
+
+        let result = worker.fetch().await.unwrap();
+
+        let expected_warnings = [
+            Color::Red.wrap("warnings:"),
+            Color::Red.wrap("- 'unable to list EndpointSlices: forbidden'"),
+            String::new(),
+        ];
+
+        assert_eq!(result[..3], expected_warnings);
+
+        assert_eq!(result[3], "service:");
+
+        assert!(!result.iter().any(|line| line == "endpoints:"));
+
+        assert!(result.iter().any(|line| line == "relatedResources:"));
+    }
+
     #[tokio::test]
     async fn エラーのときerrorを返す() {
         let mut client = MockTestKubeClient::new();
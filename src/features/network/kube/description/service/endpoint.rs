@@ -0,0 +1,393 @@
+use std::collections::BTreeMap;
+
+use k8s_openapi::{
+    api::{
+        core::v1::{Pod, Service, ServicePort},
+        discovery::v1::{Endpoint, EndpointSlice},
+    },
+    apimachinery::pkg::util::intstr::IntOrString,
+};
+use kube::ResourceExt as _;
+use serde::Serialize;
+
+/// EndpointSliceのエンドポイントの状態とServiceのポートの対応
+#[derive(Debug, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EndpointHealth {
+    ready: usize,
+    serving: usize,
+    terminating: usize,
+
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    addresses: Vec<EndpointAddress>,
+
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    ports: Vec<PortMapping>,
+
+    #[serde(skip)]
+    warnings: Vec<String>,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct EndpointAddress {
+    address: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pod: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    node: Option<String>,
+
+    ready: bool,
+    serving: bool,
+    terminating: bool,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PortMapping {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+
+    /// port/protocol
+    port: String,
+
+    target_port: String,
+
+    /// Pod名とコンテナのポート
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    container_ports: BTreeMap<String, String>,
+}
+
+impl EndpointHealth {
+    /// Service、セレクターに一致したPod、ServiceのEndpointSliceから状態を集計する
+    ///
+    /// EndpointSliceを取得できなかったときはその理由を警告に含める
+    pub fn new(service: &Service, pods: &[Pod], slices: Result<&[EndpointSlice], &str>) -> Self {
+        let mut health = Self::default();
+
+        match slices {
+            Ok(slices) => {
+                for slice in slices {
+                    for endpoint in &slice.endpoints {
+                        health.push_endpoint(endpoint);
+                    }
+                }
+            }
+            Err(err) => {
+                health
+                    .warnings
+                    .push(format!("unable to list EndpointSlices: {}", err));
+            }
+        }
+
+        let spec = service.spec.as_ref();
+
+        if let Some(ports) = spec.and_then(|spec| spec.ports.as_ref()) {
+            health.ports = ports
+                .iter()
+                .map(|port| port_mapping(port, pods, &mut health.warnings))
+                .collect();
+        }
+
+        let selector = spec.and_then(|spec| spec.selector.as_ref());
+
+        if let Some(selector) = selector.filter(|selector| !selector.is_empty()) {
+            if pods.is_empty() {
+                health.warnings.push(format!(
+                    "Selector '{}' matches no pods",
+                    selector
+                        .iter()
+                        .map(|(k, v)| format!("{}={}", k, v))
+                        .collect::<Vec<_>>()
+                        .join(",")
+                ));
+            }
+        }
+
+        let is_external_name = spec.and_then(|spec| spec.type_.as_deref()) == Some("ExternalName");
+
+        // EndpointSliceを取得できなかったときはエンドポイントの有無を判断できない
+        if !is_external_name && slices.is_ok() && health.ready == 0 {
+            health
+                .warnings
+                .push("Service has no ready endpoints".to_string());
+        }
+
+        health
+    }
+
+    pub fn warnings(&self) -> &[String] {
+        &self.warnings
+    }
+
+    fn push_endpoint(&mut self, endpoint: &Endpoint) {
+        let conditions = endpoint.conditions.as_ref();
+
+        // readyとservingが未設定のときはreadyとして扱う
+        let ready = conditions.and_then(|c| c.ready).unwrap_or(true);
+        let serving = conditions.and_then(|c| c.serving).unwrap_or(ready);
+        let terminating = conditions.and_then(|c| c.terminating).unwrap_or(false);
+
+        let pod = endpoint
+            .target_ref
+            .as_ref()
+            .filter(|r| r.kind.as_deref() == Some("Pod"))
+            .and_then(|r| r.name.clone());
+
+        for address in &endpoint.addresses {
+            self.ready += usize::from(ready);
+            self.serving += usize::from(serving);
+            self.terminating += usize::from(terminating);
+
+            self.addresses.push(EndpointAddress {
+                address: address.to_string(),
+                pod: pod.clone(),
+                node: endpoint.node_name.clone(),
+                ready,
+                serving,
+                terminating,
+            });
+        }
+    }
+}
+
+/// Serviceのポートと各Podのコンテナのポートを対応付ける
+fn port_mapping(port: &ServicePort, pods: &[Pod], warnings: &mut Vec<String>) -> PortMapping {
+    let protocol = port.protocol.as_deref().unwrap_or("TCP");
+
+    let target_port = port
+        .target_port
+        .clone()
+        .unwrap_or(IntOrString::Int(port.port));
+
+    let container_ports = pods
+        .iter()
+        .map(|pod| {
+            let resolved = resolve_container_port(pod, &target_port);
+
+            let value = match (&target_port, resolved) {
+                (_, Some((container, port))) => format!("{} ({})", port, container),
+                (IntOrString::Int(port), None) => port.to_string(),
+                (IntOrString::String(name), None) => {
+                    warnings.push(format!(
+                        "targetPort '{}' is not found in pod '{}'",
+                        name,
+                        pod.name_any()
+                    ));
+
+                    "<unresolved>".to_string()
+                }
+            };
+
+            (pod.name_any(), value)
+        })
+        .collect();
+
+    PortMapping {
+        name: port.name.clone(),
+        port: format!("{}/{}", port.port, protocol),
+        target_port: match &target_port {
+            IntOrString::Int(port) => port.to_string(),
+            IntOrString::String(name) => name.to_string(),
+        },
+        container_ports,
+    }
+}
+
+/// targetPortに一致するコンテナ名とポート番号を返す
+fn resolve_container_port(pod: &Pod, target_port: &IntOrString) -> Option<(String, i32)> {
+    let containers = &pod.spec.as_ref()?.containers;
+
+    containers.iter().find_map(|container| {
+        container.ports.as_ref()?.iter().find_map(|p| {
+            let is_match = match target_port {
+                IntOrString::Int(port) => p.container_port == *port,
+                IntOrString::String(name) => p.name.as_ref() == Some(name),
+            };
+
+            is_match.then(|| (container.name.clone(), p.container_port))
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn service(yaml: &str) -> Service {
+        serde_yaml::from_str(yaml).unwrap()
+    }
+
+    fn pods() -> Vec<Pod> {
+        serde_yaml::from_str(indoc! {
+            "
+            - metadata:
+                name: pod-1
+              spec:
+                containers:
+                - name: app
+                  ports:
+                  - name: http
+                    containerPort: 8080
+            - metadata:
+                name: pod-2
+              spec:
+                containers:
+                - name: app
+            "
+        })
+        .unwrap()
+    }
+
+    fn slices() -> Vec<EndpointSlice> {
+        serde_yaml::from_str(indoc! {
+            "
+            - metadata:
+                name: service-abc
+              addressType: IPv4
+              endpoints:
+              - addresses:
+                - 10.0.0.1
+                conditions:
+                  ready: true
+                  serving: true
+                  terminating: false
+                nodeName: node-1
+                targetRef:
+                  kind: Pod
+                  name: pod-1
+              - addresses:
+                - 10.0.0.2
+                conditions:
+                  ready: false
+                  serving: true
+                  terminating: true
+                targetRef:
+                  kind: Pod
+                  name: pod-2
+            "
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn エンドポイントの状態とポートの対応を返す() {
+        let service = service(indoc! {
+            "
+            metadata:
+              name: service
+            spec:
+              ports:
+              - name: http
+                port: 80
+                targetPort: http
+              selector:
+                app: app
+            "
+        });
+
+        let actual = EndpointHealth::new(&service, &pods(), Ok(&slices()));
+
+        let expected = indoc! {
+            "
+            ready: 1
+            serving: 2
+            terminating: 1
+            addresses:
+            - address: 10.0.0.1
+              pod: pod-1
+              node: node-1
+              ready: true
+              serving: true
+              terminating: false
+            - address: 10.0.0.2
+              pod: pod-2
+              ready: false
+              serving: true
+              terminating: true
+            ports:
+            - name: http
+              port: 80/TCP
+              targetPort: http
+              containerPorts:
+                pod-1: 8080 (app)
+                pod-2: <unresolved>
+            "
+        };
+
+        assert_eq!(serde_yaml::to_string(&actual).unwrap(), expected);
+
+        assert_eq!(
+            actual.warnings(),
+            vec!["targetPort 'http' is not found in pod 'pod-2'"]
+        );
+    }
+
+    #[test]
+    fn セレクターに一致するpodとreadyなエンドポイントがないとき警告を返す() {
+        let service = service(indoc! {
+            "
+            metadata:
+              name: service
+            spec:
+              ports:
+              - port: 80
+              selector:
+                app: app
+                version: v1
+            "
+        });
+
+        let actual = EndpointHealth::new(&service, &[], Ok(&[]));
+
+        assert_eq!(
+            actual.warnings(),
+            vec![
+                "Selector 'app=app,version=v1' matches no pods",
+                "Service has no ready endpoints",
+            ]
+        );
+    }
+
+    #[test]
+    fn external_nameのときは警告を返さない() {
+        let service = service(indoc! {
+            "
+            metadata:
+              name: service
+            spec:
+              type: ExternalName
+              externalName: example.com
+            "
+        });
+
+        let actual = EndpointHealth::new(&service, &[], Ok(&[]));
+
+        assert!(actual.warnings().is_empty());
+    }
+
+    #[test]
+    fn endpoint_sliceを取得できなかったときはその理由を警告に含める() {
+        let service = service(indoc! {
+            "
+            metadata:
+              name: service
+            spec:
+              ports:
+              - port: 80
+            "
+        });
+
+        let actual = EndpointHealth::new(&service, &[], Err("forbidden"));
+
+        assert_eq!(
+            actual.warnings(),
+            vec!["unable to list EndpointSlices: forbidden"]
+        );
+    }
+}
@@ -16,6 +16,14 @@ where
     }
 }
 
+/// APIのエラーはメッセージのみ表示する
+pub fn error_message(err: &anyhow::Error) -> String {
+    match err.downcast_ref::<kube::Error>() {
+        Some(kube::Error::Api(res)) => res.message.clone(),
+        _ => err.to_string(),
+    }
+}
+
 pub fn label_selector_to_query(selector: Option<LabelSelector>) -> String {
     let Some(LabelSelector {
         match_labels,
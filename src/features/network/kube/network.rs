@@ -2,27 +2,28 @@ use std::{collections::BTreeMap, time};
 
 use anyhow::{Context, Result};
 use async_trait::async_trait;
-use futures::future::{join_all, try_join_all};
+use futures::future::join_all;
 use k8s_openapi::{
     api::{
         core::v1::{Pod, Service},
         networking::v1::{Ingress, NetworkPolicy},
     },
-    Resource,
+    NamespaceResourceScope, Resource,
 };
 
 use crate::{
     features::{
         api_resources::kube::{ApiResource, ApiResources, SharedApiResources},
-        network::message::{GatewayVersion, HTTPRouteVersion, NetworkResponse},
+        network::message::{GRPCRouteVersion, GatewayVersion, HTTPRouteVersion, NetworkResponse},
     },
     kube::{
+        access_review::explain_forbidden,
         apis::{
-            networking::gateway::{v1, v1beta1},
+            networking::gateway::{v1, v1alpha2, v1beta1},
             v1_table::Table,
         },
-        table::{insert_ns, KubeTable, KubeTableRow},
-        KubeClient, KubeClientRequest,
+        table::{insert_ns, partition_namespaces, KubeTable, KubeTableRow, SkippedNamespace},
+        KubeClientRequest,
     },
     logger,
     workers::kube::{PollerBase, Worker, WorkerResult},
@@ -102,6 +103,7 @@ impl NetworkTable {
         KubeTable {
             header: self.header(),
             rows: self.to_kube_table_rows(),
+            ..Default::default()
         }
     }
 }
@@ -114,6 +116,10 @@ enum TargetResource {
     NetworkPolicy,
     Gateway(GatewayVersion),
     HTTPRoute(HTTPRouteVersion),
+    GRPCRoute(GRPCRouteVersion),
+    TLSRoute,
+    TCPRoute,
+    UDPRoute,
 }
 
 impl TargetResource {
@@ -131,6 +137,13 @@ impl TargetResource {
                 HTTPRouteVersion::V1 => v1::HTTPRoute::KIND,
                 HTTPRouteVersion::V1Beta1 => v1beta1::HTTPRoute::KIND,
             },
+            Self::GRPCRoute(version) => match version {
+                GRPCRouteVersion::V1 => v1::GRPCRoute::KIND,
+                GRPCRouteVersion::V1Alpha2 => v1alpha2::GRPCRoute::KIND,
+            },
+            Self::TLSRoute => v1alpha2::TLSRoute::KIND,
+            Self::TCPRoute => v1alpha2::TCPRoute::KIND,
+            Self::UDPRoute => v1alpha2::UDPRoute::KIND,
         }
     }
 
@@ -144,25 +157,41 @@ impl TargetResource {
             Self::Gateway(GatewayVersion::V1Beta1) => v1beta1::Gateway::VERSION,
             Self::HTTPRoute(HTTPRouteVersion::V1) => v1::HTTPRoute::VERSION,
             Self::HTTPRoute(HTTPRouteVersion::V1Beta1) => v1beta1::HTTPRoute::VERSION,
+            Self::GRPCRoute(GRPCRouteVersion::V1) => v1::GRPCRoute::VERSION,
+            Self::GRPCRoute(GRPCRouteVersion::V1Alpha2) => v1alpha2::GRPCRoute::VERSION,
+            Self::TLSRoute => v1alpha2::TLSRoute::VERSION,
+            Self::TCPRoute => v1alpha2::TCPRoute::VERSION,
+            Self::UDPRoute => v1alpha2::UDPRoute::VERSION,
         }
     }
 
-    async fn fetch_table(&self, client: &KubeClient, ns: &str) -> Result<Table> {
+    async fn fetch_table<C: KubeClientRequest>(&self, client: &C, ns: &str) -> Result<Table> {
         match self {
-            Self::Ingress => client.table_namespaced::<Ingress>(ns).await,
-            Self::Service => client.table_namespaced::<Service>(ns).await,
-            Self::Pod => client.table_namespaced::<Pod>(ns).await,
-            Self::NetworkPolicy => client.table_namespaced::<NetworkPolicy>(ns).await,
-            Self::Gateway(GatewayVersion::V1) => client.table_namespaced::<v1::Gateway>(ns).await,
+            Self::Ingress => fetch_table_namespaced::<_, Ingress>(client, ns).await,
+            Self::Service => fetch_table_namespaced::<_, Service>(client, ns).await,
+            Self::Pod => fetch_table_namespaced::<_, Pod>(client, ns).await,
+            Self::NetworkPolicy => fetch_table_namespaced::<_, NetworkPolicy>(client, ns).await,
+            Self::Gateway(GatewayVersion::V1) => {
+                fetch_table_namespaced::<_, v1::Gateway>(client, ns).await
+            }
             Self::Gateway(GatewayVersion::V1Beta1) => {
-                client.table_namespaced::<v1beta1::Gateway>(ns).await
+                fetch_table_namespaced::<_, v1beta1::Gateway>(client, ns).await
             }
             Self::HTTPRoute(HTTPRouteVersion::V1) => {
-                client.table_namespaced::<v1::HTTPRoute>(ns).await
+                fetch_table_namespaced::<_, v1::HTTPRoute>(client, ns).await
             }
             Self::HTTPRoute(HTTPRouteVersion::V1Beta1) => {
-                client.table_namespaced::<v1beta1::HTTPRoute>(ns).await
+                fetch_table_namespaced::<_, v1beta1::HTTPRoute>(client, ns).await
+            }
+            Self::GRPCRoute(GRPCRouteVersion::V1) => {
+                fetch_table_namespaced::<_, v1::GRPCRoute>(client, ns).await
+            }
+            Self::GRPCRoute(GRPCRouteVersion::V1Alpha2) => {
+                fetch_table_namespaced::<_, v1alpha2::GRPCRoute>(client, ns).await
             }
+            Self::TLSRoute => fetch_table_namespaced::<_, v1alpha2::TLSRoute>(client, ns).await,
+            Self::TCPRoute => fetch_table_namespaced::<_, v1alpha2::TCPRoute>(client, ns).await,
+            Self::UDPRoute => fetch_table_namespaced::<_, v1alpha2::UDPRoute>(client, ns).await,
         }
         .with_context(|| {
             format!(
@@ -175,6 +204,18 @@ impl TargetResource {
     }
 }
 
+/// Tableを取得する。許可されていないときは、どの操作が許可されていないかをエラーに補足する
+async fn fetch_table_namespaced<C, K>(client: &C, ns: &str) -> Result<Table>
+where
+    C: KubeClientRequest,
+    K: kube::Resource<DynamicType = (), Scope = NamespaceResourceScope> + 'static,
+{
+    client
+        .table_namespaced::<K>(ns)
+        .await
+        .map_err(|err| explain_forbidden("list", &K::url_path(&(), Some(ns)), err))
+}
+
 impl std::fmt::Display for TargetResource {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.as_str())
@@ -246,6 +287,63 @@ fn target_resources(api_resources: &ApiResources) -> Vec<TargetResource> {
         }
     }
 
+    match find_api_resource(
+        api_resources,
+        v1::GRPCRoute::GROUP,
+        v1::GRPCRoute::URL_PATH_SEGMENT,
+    )
+    .map(|api| api.version())
+    {
+        Some("v1") => {
+            targets.push(TargetResource::GRPCRoute(GRPCRouteVersion::V1));
+        }
+        Some("v1alpha2") => {
+            targets.push(TargetResource::GRPCRoute(GRPCRouteVersion::V1Alpha2));
+        }
+        Some(v) => {
+            logger!(warn, "GRPCRoute is not support: {}", v);
+        }
+        None => {
+            logger!(warn, "GRPCRoute is not found.");
+        }
+    }
+
+    // TLSRoute, TCPRoute, UDPRouteはv1alpha2のみサポートする
+    // preferredVersionが新しいバージョンの場合でもv1alpha2が提供されていれば対象にする
+    for (target, kind, group, version, url_path_segment) in [
+        (
+            TargetResource::TLSRoute,
+            v1alpha2::TLSRoute::KIND,
+            v1alpha2::TLSRoute::GROUP,
+            v1alpha2::TLSRoute::VERSION,
+            v1alpha2::TLSRoute::URL_PATH_SEGMENT,
+        ),
+        (
+            TargetResource::TCPRoute,
+            v1alpha2::TCPRoute::KIND,
+            v1alpha2::TCPRoute::GROUP,
+            v1alpha2::TCPRoute::VERSION,
+            v1alpha2::TCPRoute::URL_PATH_SEGMENT,
+        ),
+        (
+            TargetResource::UDPRoute,
+            v1alpha2::UDPRoute::KIND,
+            v1alpha2::UDPRoute::GROUP,
+            v1alpha2::UDPRoute::VERSION,
+            v1alpha2::UDPRoute::URL_PATH_SEGMENT,
+        ),
+    ] {
+        let is_found = api_resources.iter().any(|api| {
+            api.group() == group && api.name() == url_path_segment && api.version() == version
+        });
+
+        if is_found {
+            targets.push(target);
+        } else {
+            logger!(warn, "{} ({}) is not found.", kind, version);
+        }
+    }
+
     targets
 }
 
@@ -283,49 +381,69 @@ impl NetworkPoller {
     async fn polling(&self, target_resources: &[TargetResource]) -> Result<KubeTable> {
         let target_namespaces = self.base.shared_target_namespaces.read().await;
 
-        let rows: Vec<_> = join_all(
-            target_resources
-                .iter()
-                .map(|kind| self.fetch_resource(kind, &target_namespaces)),
-        )
-        .await
-        .into_iter()
-        .inspect(|res| {
-            if let Err(e) = res {
+        Ok(fetch_network_table(&self.base.kube_client, target_resources, &target_namespaces).await)
+    }
+}
+
+async fn fetch_network_table<C: KubeClientRequest>(
+    client: &C,
+    target_resources: &[TargetResource],
+    namespaces: &[String],
+) -> KubeTable {
+    let results = join_all(
+        target_resources
+            .iter()
+            .map(|kind| fetch_resource(client, kind, namespaces)),
+    )
+    .await;
+
+    let mut rows = Vec::new();
+    let mut skipped: Vec<SkippedNamespace> = Vec::new();
+
+    for result in results {
+        match result {
+            Ok((kind_rows, kind_skipped)) => {
+                rows.extend(kind_rows);
+
+                for s in kind_skipped {
+                    if !skipped.contains(&s) {
+                        skipped.push(s);
+                    }
+                }
+            }
+            Err(e) => {
                 logger!(error, "Failed to fetch resource: {:?}", e);
             }
-        })
-        .filter_map(|res| res.ok())
-        .collect();
+        }
+    }
 
-        let table = NetworkTable::new(
-            insert_ns(&target_namespaces),
-            rows.into_iter().flatten().collect(),
-        );
+    let mut table = NetworkTable::new(insert_ns(namespaces), rows).to_kube_table();
 
-        Ok(table.to_kube_table())
-    }
+    table.skipped = skipped;
 
-    async fn fetch_resource(
-        &self,
-        kind: &TargetResource,
-        namespaces: &[String],
-    ) -> Result<Vec<NetworkTableRow>> {
-        let client = &self.base.kube_client;
+    table
+}
 
-        let jobs = try_join_all(
-            namespaces
-                .iter()
-                .map(|ns| fetch_resource_per_namespace(client, kind, ns, &TARGET_COLUMNS)),
-        )
-        .await?;
+/// kindのリソースをネームスペースごとに取得する。取得できなかったネームスペースは除外する
+async fn fetch_resource<C: KubeClientRequest>(
+    client: &C,
+    kind: &TargetResource,
+    namespaces: &[String],
+) -> Result<(Vec<NetworkTableRow>, Vec<SkippedNamespace>)> {
+    let jobs = join_all(
+        namespaces
+            .iter()
+            .map(|ns| fetch_resource_per_namespace(client, kind, ns, &TARGET_COLUMNS)),
+    )
+    .await;
 
-        Ok(jobs.into_iter().flatten().collect())
-    }
+    let (rows, skipped) = partition_namespaces(namespaces.iter().cloned().zip(jobs))?;
+
+    Ok((rows.into_iter().flatten().collect(), skipped))
 }
 
-async fn fetch_resource_per_namespace(
-    client: &KubeClient,
+async fn fetch_resource_per_namespace<C: KubeClientRequest>(
+    client: &C,
     kind: &TargetResource,
     ns: &str,
     target_columns: &[&str],
@@ -454,4 +572,82 @@ mod tests {
             assert_eq!(actual, None);
         }
     }
+
+    mod fetch_network_table {
+        use super::*;
+
+        use kube::core::ErrorResponse;
+        use mockall::predicate::eq;
+        use pretty_assertions::assert_eq;
+        use serde_json::json;
+
+        use crate::kube::mock::MockTestKubeClient;
+
+        fn table(names: &[&str]) -> Table {
+            let rows: Vec<_> = names
+                .iter()
+                .map(|name| json!({"cells": [name, "1d"]}))
+                .collect();
+
+            serde_json::from_value(json!({
+                "kind": "Table",
+                "apiVersion": "meta.k8s.io/v1",
+                "metadata": {},
+                "columnDefinitions": [
+                    {"name": "Name", "type": "string", "format": "name", "description": "", "priority": 0},
+                    {"name": "Age", "type": "string", "format": "", "description": "", "priority": 0}
+                ],
+                "rows": rows
+            }))
+            .unwrap()
+        }
+
+        #[tokio::test]
+        async fn 取得できないネームスペースを除外する() {
+            let mut client = MockTestKubeClient::new();
+
+            client
+                .expect_table_namespaced::<Ingress>()
+                .with(eq("default"))
+                .returning(|_| Ok(table(&["web"])));
+
+            client
+                .expect_table_namespaced::<Ingress>()
+                .with(eq("kube-system"))
+                .returning(|_| {
+                    Err(kube::Error::Api(ErrorResponse {
+                        status: "Failure".into(),
+                        message: "forbidden".into(),
+                        reason: "Forbidden".into(),
+                        code: 403,
+                    })
+                    .into())
+                });
+
+            let actual = fetch_network_table(
+                &client,
+                &[TargetResource::Ingress],
+                &["default".to_string(), "kube-system".to_string()],
+            )
+            .await;
+
+            assert_eq!(
+                actual
+                    .rows
+                    .iter()
+                    .map(|r| r.row.clone())
+                    .collect::<Vec<_>>(),
+                vec![vec!["default", "Ingress", "web", "1d"]]
+            );
+
+            assert_eq!(
+                actual
+                    .skipped
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>(),
+                vec!["kube-system (forbidden: cannot list ingresses.networking.k8s.io)"]
+            );
+        }
+    }
 }
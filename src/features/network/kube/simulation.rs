@@ -0,0 +1,302 @@
+//! NetworkPolicyによる通信の許可・拒否をシミュレーションする
+mod cidr;
+mod evaluate;
+mod query;
+
+use std::collections::BTreeMap;
+
+use anyhow::{Context as _, Result};
+use k8s_openapi::{
+    api::{
+        core::v1::{Namespace, Pod},
+        networking::v1::NetworkPolicy,
+    },
+    List,
+};
+use kube::ResourceExt as _;
+
+use crate::{kube::KubeClientRequest, workers::kube::color::fg::Color};
+
+use self::{
+    evaluate::{container_port, DirectionResult, Evaluator, Peer, PolicyType, TargetPort},
+    query::{PodRef, PortNumber, Query, Source},
+};
+
+/// クエリを評価して結果のレポートを返す
+pub async fn simulate(
+    client: &impl KubeClientRequest,
+    query: &str,
+    default_namespace: &str,
+) -> Result<Vec<String>> {
+    let query = Query::parse(query, default_namespace)?;
+
+    let destination = fetch_pod(client, &query.destination).await?;
+
+    let source = match &query.source {
+        Source::Pod(pod) => Some(fetch_pod(client, pod).await?),
+        Source::Cidr(_) => None,
+    };
+
+    let port = target_port(&query, &destination)?;
+
+    let mut namespaces = vec![query.destination.namespace.clone()];
+
+    if let Source::Pod(pod) = &query.source {
+        if !namespaces.contains(&pod.namespace) {
+            namespaces.push(pod.namespace.clone());
+        }
+    }
+
+    let mut policies = Vec::new();
+    let mut namespace_labels = BTreeMap::new();
+
+    for namespace in &namespaces {
+        let list: List<NetworkPolicy> = client
+            .request(&format!(
+                "/apis/networking.k8s.io/v1/namespaces/{}/networkpolicies",
+                namespace
+            ))
+            .await
+            .with_context(|| format!("Failed to fetch NetworkPolicies: namespace={}", namespace))?;
+
+        policies.extend(list.items);
+
+        namespace_labels.insert(
+            namespace.to_string(),
+            fetch_namespace_labels(client, namespace).await,
+        );
+    }
+
+    let evaluator = Evaluator {
+        namespace_labels: &namespace_labels,
+        policies: &policies,
+    };
+
+    let egress = source.as_ref().map(|source| {
+        evaluator.evaluate(
+            PolicyType::Egress,
+            source,
+            &Peer::Pod(&destination),
+            &port,
+            &destination,
+        )
+    });
+
+    let ingress_peer = match (&source, &query.source) {
+        (Some(source), _) => Peer::Pod(source),
+        (None, Source::Cidr(cidr)) => Peer::Cidr(*cidr),
+        (None, Source::Pod(_)) => unreachable!(),
+    };
+
+    let ingress = evaluator.evaluate(
+        PolicyType::Ingress,
+        &destination,
+        &ingress_peer,
+        &port,
+        &destination,
+    );
+
+    let source_label = match (&query.source, &source) {
+        (Source::Pod(pod_ref), Some(pod)) => pod_label(pod_ref, pod),
+        (Source::Pod(pod_ref), None) => pod_ref.to_string(),
+        (Source::Cidr(cidr), _) => cidr.to_string(),
+    };
+
+    Ok(report(
+        &source_label,
+        &pod_label(&query.destination, &destination),
+        &port,
+        egress.as_ref(),
+        &ingress,
+    ))
+}
+
+async fn fetch_pod(client: &impl KubeClientRequest, pod: &PodRef) -> Result<Pod> {
+    client
+        .request(&format!(
+            "/api/v1/namespaces/{}/pods/{}",
+            pod.namespace, pod.name
+        ))
+        .await
+        .with_context(|| format!("Failed to fetch Pod: {}", pod))
+}
+
+/// namespaceを取得できないときは、kubernetes.io/metadata.nameラベルのみを持つものとして扱う
+async fn fetch_namespace_labels(
+    client: &impl KubeClientRequest,
+    namespace: &str,
+) -> BTreeMap<String, String> {
+    match client
+        .request::<Namespace>(&format!("/api/v1/namespaces/{}", namespace))
+        .await
+    {
+        Ok(ns) => ns.labels().clone(),
+        Err(_) => BTreeMap::from([(
+            "kubernetes.io/metadata.name".to_string(),
+            namespace.to_string(),
+        )]),
+    }
+}
+
+fn target_port(query: &Query, destination: &Pod) -> Result<TargetPort> {
+    let protocol = query.port.protocol.clone();
+
+    let number = match &query.port.port {
+        PortNumber::Number(number) => *number,
+        PortNumber::Name(name) => {
+            container_port(destination, name, &protocol).with_context(|| {
+                format!(
+                    "Port '{}/{}' is not found in pod '{}'",
+                    name, protocol, query.destination
+                )
+            })?
+        }
+    };
+
+    Ok(TargetPort { number, protocol })
+}
+
+fn pod_label(pod_ref: &PodRef, pod: &Pod) -> String {
+    match pod.status.as_ref().and_then(|s| s.pod_ip.as_ref()) {
+        Some(ip) => format!("{} ({})", pod_ref, ip),
+        None => pod_ref.to_string(),
+    }
+}
+
+fn report(
+    source: &str,
+    destination: &str,
+    port: &TargetPort,
+    egress: Option<&DirectionResult>,
+    ingress: &DirectionResult,
+) -> Vec<String> {
+    let is_allowed = egress.is_none_or(DirectionResult::is_allowed) && ingress.is_allowed();
+
+    let result = if is_allowed {
+        Color::Green.wrap("ALLOWED")
+    } else {
+        Color::Red.wrap("DENIED")
+    };
+
+    let mut lines = vec![
+        format!("source:      {}", source),
+        format!("destination: {}", destination),
+        format!("port:        {}/{}", port.number, port.protocol),
+        format!("result:      {}", result),
+        "".into(),
+    ];
+
+    match egress {
+        Some(egress) => lines.extend(direction_report(egress)),
+        None => {
+            lines.push("egress: skipped (source is not a pod)".into());
+        }
+    }
+
+    lines.push("".into());
+
+    lines.extend(direction_report(ingress));
+
+    lines
+}
+
+fn direction_report(result: &DirectionResult) -> Vec<String> {
+    let name = match result.policy_type {
+        PolicyType::Ingress => "ingress",
+        PolicyType::Egress => "egress",
+    };
+
+    let status = if result.is_allowed() {
+        Color::Green.wrap("allowed")
+    } else {
+        Color::Red.wrap("denied")
+    };
+
+    let mut lines = vec![format!("{}: {}", name, status)];
+
+    if result.policies.is_empty() {
+        lines.push("  no policy selects the pod (not isolated)".into());
+    }
+
+    for policy in &result.policies {
+        let detail = if policy.allowed_rules.is_empty() {
+            "no rule matches".to_string()
+        } else {
+            format!(
+                "allowed by {}",
+                policy
+                    .allowed_rules
+                    .iter()
+                    .map(|i| format!("{}[{}]", name, i))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )
+        };
+
+        lines.push(format!("  {}: {}", policy.name, detail));
+    }
+
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use evaluate::PolicyResult;
+
+    #[test]
+    fn 評価結果をレポートに変換する() {
+        let egress = DirectionResult {
+            policy_type: PolicyType::Egress,
+            policies: vec![],
+        };
+
+        let ingress = DirectionResult {
+            policy_type: PolicyType::Ingress,
+            policies: vec![
+                PolicyResult {
+                    name: "backend/deny-all".into(),
+                    allowed_rules: vec![],
+                },
+                PolicyResult {
+                    name: "backend/allow-frontend".into(),
+                    allowed_rules: vec![0, 2],
+                },
+            ],
+        };
+
+        let actual = report(
+            "frontend/client (10.0.1.1)",
+            "backend/server (10.0.2.1)",
+            &TargetPort {
+                number: 8080,
+                protocol: "TCP".into(),
+            },
+            Some(&egress),
+            &ingress,
+        );
+
+        let expected = indoc! {
+            "
+            source:      frontend/client (10.0.1.1)
+            destination: backend/server (10.0.2.1)
+            port:        8080/TCP
+            result:      \x1b[32mALLOWED\x1b[39m
+
+            egress: \x1b[32mallowed\x1b[39m
+              no policy selects the pod (not isolated)
+
+            ingress: \x1b[32mallowed\x1b[39m
+              backend/deny-all: no rule matches
+              backend/allow-frontend: allowed by ingress[0], ingress[2]
+            "
+        }
+        .lines()
+        .collect::<Vec<_>>();
+
+        assert_eq!(actual, expected);
+    }
+}
@@ -0,0 +1,131 @@
+use std::{net::IpAddr, str::FromStr};
+
+use anyhow::{bail, Context as _, Result};
+
+/// IPアドレスの範囲
+///
+/// プレフィックス長を省略したときは単一のアドレスとして扱う
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cidr {
+    addr: IpAddr,
+    prefix: u8,
+}
+
+impl Cidr {
+    /// otherの範囲がすべて含まれるときにtrueを返す
+    pub fn contains(&self, other: &Cidr) -> bool {
+        if self.is_ipv4() != other.is_ipv4() || other.prefix < self.prefix {
+            return false;
+        }
+
+        self.network() == mask(other.bits(), self.prefix, self.max_prefix())
+    }
+
+    fn is_ipv4(&self) -> bool {
+        self.addr.is_ipv4()
+    }
+
+    fn max_prefix(&self) -> u8 {
+        if self.is_ipv4() {
+            32
+        } else {
+            128
+        }
+    }
+
+    fn bits(&self) -> u128 {
+        match self.addr {
+            IpAddr::V4(addr) => u32::from(addr) as u128,
+            IpAddr::V6(addr) => u128::from(addr),
+        }
+    }
+
+    fn network(&self) -> u128 {
+        mask(self.bits(), self.prefix, self.max_prefix())
+    }
+}
+
+fn mask(bits: u128, prefix: u8, max_prefix: u8) -> u128 {
+    let host_bits = u32::from(max_prefix - prefix);
+
+    bits.checked_shr(host_bits)
+        .and_then(|b| b.checked_shl(host_bits))
+        .unwrap_or_default()
+}
+
+impl From<IpAddr> for Cidr {
+    fn from(addr: IpAddr) -> Self {
+        let prefix = if addr.is_ipv4() { 32 } else { 128 };
+
+        Self { addr, prefix }
+    }
+}
+
+impl FromStr for Cidr {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let Some((addr, prefix)) = s.split_once('/') else {
+            let addr: IpAddr = s
+                .parse()
+                .with_context(|| format!("Invalid IP address: {}", s))?;
+
+            return Ok(addr.into());
+        };
+
+        let addr: IpAddr = addr
+            .parse()
+            .with_context(|| format!("Invalid CIDR: {}", s))?;
+
+        let prefix: u8 = prefix
+            .parse()
+            .with_context(|| format!("Invalid CIDR: {}", s))?;
+
+        let cidr = Self { addr, prefix };
+
+        if cidr.max_prefix() < prefix {
+            bail!("Invalid CIDR prefix length: {}", s);
+        }
+
+        Ok(cidr)
+    }
+}
+
+impl std::fmt::Display for Cidr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{}", self.addr, self.prefix)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    fn cidr(s: &str) -> Cidr {
+        s.parse().unwrap()
+    }
+
+    #[rstest]
+    #[case("10.0.0.0/8", "10.1.2.3", true)]
+    #[case("10.0.0.0/8", "10.1.0.0/16", true)]
+    #[case("10.1.0.0/16", "10.0.0.0/8", false)]
+    #[case("10.0.0.0/8", "192.168.0.1", false)]
+    #[case("0.0.0.0/0", "192.168.0.1", true)]
+    #[case("fd00::/8", "fd00::1", true)]
+    #[case("fd00::/8", "10.0.0.1", false)]
+    fn 範囲に含まれるか判定する(
+        #[case] parent: &str,
+        #[case] child: &str,
+        #[case] expected: bool,
+    ) {
+        assert_eq!(cidr(parent).contains(&cidr(child)), expected);
+    }
+
+    #[test]
+    fn 不正なプレフィックス長のときエラーを返す() {
+        assert!("10.0.0.0/33".parse::<Cidr>().is_err());
+    }
+}
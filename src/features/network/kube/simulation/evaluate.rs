@@ -0,0 +1,548 @@
+use std::collections::BTreeMap;
+
+use k8s_openapi::{
+    api::{
+        core::v1::Pod,
+        networking::v1::{NetworkPolicy, NetworkPolicyPeer, NetworkPolicyPort},
+    },
+    apimachinery::pkg::{apis::meta::v1::LabelSelector, util::intstr::IntOrString},
+};
+use kube::ResourceExt as _;
+
+use crate::features::network::kube::description::related_resources::label_selector::{
+    LabelSelectorExpression as _, LabelSelectorWrapper,
+};
+
+use super::cidr::Cidr;
+
+/// 通信の相手
+pub enum Peer<'a> {
+    Pod(&'a Pod),
+    Cidr(Cidr),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PolicyType {
+    Ingress,
+    Egress,
+}
+
+impl PolicyType {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Ingress => "Ingress",
+            Self::Egress => "Egress",
+        }
+    }
+}
+
+/// 通信先のポート番号とプロトコル
+pub struct TargetPort {
+    pub number: i32,
+    pub protocol: String,
+}
+
+/// 対象のPodを選択しているNetworkPolicyの評価結果
+#[derive(Debug, PartialEq, Eq)]
+pub struct PolicyResult {
+    /// namespace/name
+    pub name: String,
+
+    /// 通信を許可したルールのインデックス
+    pub allowed_rules: Vec<usize>,
+}
+
+/// IngressまたはEgressの評価結果
+#[derive(Debug, PartialEq, Eq)]
+pub struct DirectionResult {
+    pub policy_type: PolicyType,
+
+    /// 対象のPodを選択しているNetworkPolicy
+    ///
+    /// 空のときはPodが隔離されていないため、すべての通信が許可される
+    pub policies: Vec<PolicyResult>,
+}
+
+impl DirectionResult {
+    pub fn is_allowed(&self) -> bool {
+        self.policies.is_empty() || self.policies.iter().any(|p| !p.allowed_rules.is_empty())
+    }
+}
+
+/// ルールのfrom/toとports
+type Rule<'a> = (
+    Option<&'a Vec<NetworkPolicyPeer>>,
+    Option<&'a Vec<NetworkPolicyPort>>,
+);
+
+/// 取得済みのリソースからNetworkPolicyを評価する
+pub struct Evaluator<'a> {
+    /// namespace名とラベル
+    pub namespace_labels: &'a BTreeMap<String, BTreeMap<String, String>>,
+    pub policies: &'a [NetworkPolicy],
+}
+
+impl Evaluator<'_> {
+    /// targetに対するpolicy_typeの通信をpeerとportの条件で評価する
+    ///
+    /// - Ingressのときtargetは通信先、peerは通信元
+    /// - Egressのときtargetは通信元、peerは通信先
+    ///
+    /// destinationは名前付きポートの解決に使う
+    pub fn evaluate(
+        &self,
+        policy_type: PolicyType,
+        target: &Pod,
+        peer: &Peer,
+        port: &TargetPort,
+        destination: &Pod,
+    ) -> DirectionResult {
+        let target_namespace = target.namespace().unwrap_or_default();
+
+        let policies = self
+            .policies
+            .iter()
+            .filter(|policy| policy.namespace().unwrap_or_default() == target_namespace)
+            .filter(|policy| {
+                policy.spec.as_ref().is_some_and(|spec| {
+                    selector_matches(&spec.pod_selector, target.labels())
+                        && has_policy_type(policy, policy_type)
+                })
+            })
+            .map(|policy| PolicyResult {
+                name: format!("{}/{}", target_namespace, policy.name_any()),
+                allowed_rules: self.allowed_rules(policy, policy_type, peer, port, destination),
+            })
+            .collect();
+
+        DirectionResult {
+            policy_type,
+            policies,
+        }
+    }
+
+    fn allowed_rules(
+        &self,
+        policy: &NetworkPolicy,
+        policy_type: PolicyType,
+        peer: &Peer,
+        port: &TargetPort,
+        destination: &Pod,
+    ) -> Vec<usize> {
+        let Some(spec) = policy.spec.as_ref() else {
+            return Vec::default();
+        };
+
+        let policy_namespace = policy.namespace().unwrap_or_default();
+
+        let rules: Vec<Rule> = match policy_type {
+            PolicyType::Ingress => spec
+                .ingress
+                .iter()
+                .flatten()
+                .map(|rule| (rule.from.as_ref(), rule.ports.as_ref()))
+                .collect(),
+            PolicyType::Egress => spec
+                .egress
+                .iter()
+                .flatten()
+                .map(|rule| (rule.to.as_ref(), rule.ports.as_ref()))
+                .collect(),
+        };
+
+        rules
+            .into_iter()
+            .enumerate()
+            .filter(|(_, (peers, ports))| {
+                // peersまたはportsが空のときはすべてに一致する
+                let peer_matches = peers.is_none_or(|peers| {
+                    peers.is_empty()
+                        || peers
+                            .iter()
+                            .any(|p| self.peer_matches(p, &policy_namespace, peer))
+                });
+
+                let port_matches = ports.is_none_or(|ports| {
+                    ports.is_empty() || ports.iter().any(|p| port_matches(p, port, destination))
+                });
+
+                peer_matches && port_matches
+            })
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    fn peer_matches(
+        &self,
+        policy_peer: &NetworkPolicyPeer,
+        policy_namespace: &str,
+        peer: &Peer,
+    ) -> bool {
+        if let Some(ip_block) = &policy_peer.ip_block {
+            let Some(peer_cidr) = peer_cidr(peer) else {
+                return false;
+            };
+
+            let Ok(block) = ip_block.cidr.parse::<Cidr>() else {
+                return false;
+            };
+
+            let is_excepted = ip_block.except.iter().flatten().any(|except| {
+                except
+                    .parse::<Cidr>()
+                    .is_ok_and(|except| except.contains(&peer_cidr))
+            });
+
+            return block.contains(&peer_cidr) && !is_excepted;
+        }
+
+        // podSelectorとnamespaceSelectorはPod以外に一致しない
+        let Peer::Pod(pod) = peer else {
+            return false;
+        };
+
+        let pod_namespace = pod.namespace().unwrap_or_default();
+
+        let namespace_matches = match &policy_peer.namespace_selector {
+            Some(selector) => self
+                .namespace_labels
+                .get(&pod_namespace)
+                .is_some_and(|labels| selector_matches(selector, labels)),
+            None => pod_namespace == policy_namespace,
+        };
+
+        let pod_matches = policy_peer
+            .pod_selector
+            .as_ref()
+            .is_none_or(|selector| selector_matches(selector, pod.labels()));
+
+        namespace_matches && pod_matches
+    }
+}
+
+/// policyTypesが指定されていないときは、Ingressと（egressルールがあれば）Egressを対象とする
+fn has_policy_type(policy: &NetworkPolicy, policy_type: PolicyType) -> bool {
+    let Some(spec) = policy.spec.as_ref() else {
+        return false;
+    };
+
+    match &spec.policy_types {
+        Some(types) => types.iter().any(|t| t == policy_type.as_str()),
+        None => match policy_type {
+            PolicyType::Ingress => true,
+            PolicyType::Egress => spec
+                .egress
+                .as_ref()
+                .is_some_and(|egress| !egress.is_empty()),
+        },
+    }
+}
+
+fn selector_matches(selector: &LabelSelector, labels: &BTreeMap<String, String>) -> bool {
+    LabelSelectorWrapper::new(selector.clone()).expression(labels)
+}
+
+fn peer_cidr(peer: &Peer) -> Option<Cidr> {
+    match peer {
+        Peer::Pod(pod) => pod.status.as_ref()?.pod_ip.as_ref()?.parse::<Cidr>().ok(),
+        Peer::Cidr(cidr) => Some(*cidr),
+    }
+}
+
+fn port_matches(policy_port: &NetworkPolicyPort, port: &TargetPort, destination: &Pod) -> bool {
+    let protocol = policy_port.protocol.as_deref().unwrap_or("TCP");
+
+    if protocol != port.protocol {
+        return false;
+    }
+
+    match &policy_port.port {
+        None => true,
+        Some(IntOrString::Int(number)) => match policy_port.end_port {
+            Some(end_port) => (*number..=end_port).contains(&port.number),
+            None => *number == port.number,
+        },
+        // 名前付きポートは通信先のPodのコンテナのポートで解決する
+        Some(IntOrString::String(name)) => {
+            container_port(destination, name, &port.protocol) == Some(port.number)
+        }
+    }
+}
+
+/// 名前付きポートのポート番号を返す
+pub fn container_port(pod: &Pod, name: &str, protocol: &str) -> Option<i32> {
+    pod.spec.as_ref()?.containers.iter().find_map(|container| {
+        container.ports.as_ref()?.iter().find_map(|p| {
+            let p_protocol = p.protocol.as_deref().unwrap_or("TCP");
+
+            (p.name.as_deref() == Some(name) && p_protocol == protocol).then_some(p.container_port)
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn pod(yaml: &str) -> Pod {
+        serde_yaml::from_str(yaml).unwrap()
+    }
+
+    fn client() -> Pod {
+        pod(indoc! {
+            "
+            metadata:
+              name: client
+              namespace: frontend
+              labels:
+                app: client
+            status:
+              podIP: 10.0.1.1
+            "
+        })
+    }
+
+    fn server() -> Pod {
+        pod(indoc! {
+            "
+            metadata:
+              name: server
+              namespace: backend
+              labels:
+                app: server
+            spec:
+              containers:
+              - name: app
+                ports:
+                - name: http
+                  containerPort: 8080
+            status:
+              podIP: 10.0.2.1
+            "
+        })
+    }
+
+    fn policies() -> Vec<NetworkPolicy> {
+        serde_yaml::from_str(indoc! {
+            "
+            - metadata:
+                name: deny-all
+                namespace: backend
+              spec:
+                podSelector: {}
+                policyTypes:
+                - Ingress
+            - metadata:
+                name: allow-frontend
+                namespace: backend
+              spec:
+                podSelector:
+                  matchLabels:
+                    app: server
+                ingress:
+                - from:
+                  - ipBlock:
+                      cidr: 192.168.0.0/16
+                - from:
+                  - namespaceSelector:
+                      matchLabels:
+                        team: frontend
+                    podSelector:
+                      matchLabels:
+                        app: client
+                  ports:
+                  - port: http
+            - metadata:
+                name: restrict-egress
+                namespace: frontend
+              spec:
+                podSelector: {}
+                policyTypes:
+                - Egress
+                egress:
+                - to:
+                  - ipBlock:
+                      cidr: 10.0.0.0/16
+                      except:
+                      - 10.0.3.0/24
+                  ports:
+                  - port: 8000
+                    endPort: 9000
+            "
+        })
+        .unwrap()
+    }
+
+    fn namespace_labels() -> BTreeMap<String, BTreeMap<String, String>> {
+        BTreeMap::from([
+            (
+                "frontend".into(),
+                BTreeMap::from([("team".into(), "frontend".into())]),
+            ),
+            ("backend".into(), BTreeMap::default()),
+        ])
+    }
+
+    fn tcp(number: i32) -> TargetPort {
+        TargetPort {
+            number,
+            protocol: "TCP".into(),
+        }
+    }
+
+    #[test]
+    fn 名前付きポートとnamespace_selectorで許可する() {
+        let namespace_labels = namespace_labels();
+        let policies = policies();
+
+        let evaluator = Evaluator {
+            namespace_labels: &namespace_labels,
+            policies: &policies,
+        };
+
+        let (client, server) = (client(), server());
+
+        let actual = evaluator.evaluate(
+            PolicyType::Ingress,
+            &server,
+            &Peer::Pod(&client),
+            &tcp(8080),
+            &server,
+        );
+
+        assert_eq!(
+            actual,
+            DirectionResult {
+                policy_type: PolicyType::Ingress,
+                policies: vec![
+                    PolicyResult {
+                        name: "backend/deny-all".into(),
+                        allowed_rules: vec![],
+                    },
+                    PolicyResult {
+                        name: "backend/allow-frontend".into(),
+                        allowed_rules: vec![1],
+                    },
+                ],
+            }
+        );
+
+        assert!(actual.is_allowed());
+    }
+
+    #[test]
+    fn ポートが一致しないとき拒否する() {
+        let namespace_labels = namespace_labels();
+        let policies = policies();
+
+        let evaluator = Evaluator {
+            namespace_labels: &namespace_labels,
+            policies: &policies,
+        };
+
+        let (client, server) = (client(), server());
+
+        let actual = evaluator.evaluate(
+            PolicyType::Ingress,
+            &server,
+            &Peer::Pod(&client),
+            &tcp(9090),
+            &server,
+        );
+
+        assert!(!actual.is_allowed());
+    }
+
+    #[test]
+    fn ip_blockとend_portでegressを評価する() {
+        let namespace_labels = namespace_labels();
+        let policies = policies();
+
+        let evaluator = Evaluator {
+            namespace_labels: &namespace_labels,
+            policies: &policies,
+        };
+
+        let (client, server) = (client(), server());
+
+        let actual = evaluator.evaluate(
+            PolicyType::Egress,
+            &client,
+            &Peer::Pod(&server),
+            &tcp(8080),
+            &server,
+        );
+
+        assert!(actual.is_allowed());
+
+        let excepted = pod(indoc! {
+            "
+            metadata:
+              name: excepted
+              namespace: backend
+            status:
+              podIP: 10.0.3.1
+            "
+        });
+
+        let actual = evaluator.evaluate(
+            PolicyType::Egress,
+            &client,
+            &Peer::Pod(&excepted),
+            &tcp(8080),
+            &excepted,
+        );
+
+        assert!(!actual.is_allowed());
+    }
+
+    #[test]
+    fn cidrからのingressはip_blockのみで評価する() {
+        let namespace_labels = namespace_labels();
+        let policies = policies();
+
+        let evaluator = Evaluator {
+            namespace_labels: &namespace_labels,
+            policies: &policies,
+        };
+
+        let server = server();
+
+        let actual = evaluator.evaluate(
+            PolicyType::Ingress,
+            &server,
+            &Peer::Cidr("192.168.1.0/24".parse().unwrap()),
+            &tcp(8080),
+            &server,
+        );
+
+        assert_eq!(actual.policies[1].allowed_rules, vec![0]);
+    }
+
+    #[test]
+    fn ポリシーに選択されていないpodは隔離されない() {
+        let namespace_labels = namespace_labels();
+        let policies = policies();
+
+        let evaluator = Evaluator {
+            namespace_labels: &namespace_labels,
+            policies: &policies,
+        };
+
+        let (client, server) = (client(), server());
+
+        let actual = evaluator.evaluate(
+            PolicyType::Ingress,
+            &client,
+            &Peer::Pod(&server),
+            &tcp(80),
+            &client,
+        );
+
+        assert!(actual.policies.is_empty());
+        assert!(actual.is_allowed());
+    }
+}
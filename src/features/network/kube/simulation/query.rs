@@ -0,0 +1,176 @@
+use anyhow::{bail, Result};
+
+use super::cidr::Cidr;
+
+/// 通信元
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    Pod(PodRef),
+    Cidr(Cidr),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PodRef {
+    pub namespace: String,
+    pub name: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PortNumber {
+    Number(i32),
+    Name(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Port {
+    pub port: PortNumber,
+    pub protocol: String,
+}
+
+/// シミュレーションの条件
+///
+/// `from:<namespace>/<pod>|<cidr> to:<namespace>/<pod> port:<port>[/<protocol>]`
+///
+/// - namespaceを省略したときはdefault_namespaceを使う
+/// - protocolを省略したときはTCPを使う
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Query {
+    pub source: Source,
+    pub destination: PodRef,
+    pub port: Port,
+}
+
+impl Query {
+    pub fn parse(query: &str, default_namespace: &str) -> Result<Self> {
+        let mut source = None;
+        let mut destination = None;
+        let mut port = None;
+
+        for token in query.split_whitespace() {
+            let Some((key, value)) = token.split_once(':') else {
+                bail!("Invalid query: '{}'. Expected <key>:<value>", token);
+            };
+
+            if value.is_empty() {
+                bail!("Empty value: '{}'", token);
+            }
+
+            match key {
+                "from" | "src" => source = Some(parse_source(value, default_namespace)?),
+                "to" | "dst" => destination = Some(parse_pod(value, default_namespace)),
+                "port" => port = Some(parse_port(value)?),
+                _ => bail!(
+                    "Unknown key: '{}'. Supported keys are from, to and port",
+                    key
+                ),
+            }
+        }
+
+        let (Some(source), Some(destination), Some(port)) = (source, destination, port) else {
+            bail!("Query requires from, to and port. e.g. from:default/client to:default/server port:80");
+        };
+
+        Ok(Self {
+            source,
+            destination,
+            port,
+        })
+    }
+}
+
+fn parse_source(value: &str, default_namespace: &str) -> Result<Source> {
+    // IPv6は':'を含むため、IPアドレスとして解釈できるかを先に確認する
+    if value.starts_with(|c: char| c.is_ascii_digit()) || value.contains("::") {
+        if let Ok(cidr) = value.parse::<Cidr>() {
+            return Ok(Source::Cidr(cidr));
+        }
+    }
+
+    Ok(Source::Pod(parse_pod(value, default_namespace)))
+}
+
+fn parse_pod(value: &str, default_namespace: &str) -> PodRef {
+    match value.split_once('/') {
+        Some((namespace, name)) => PodRef {
+            namespace: namespace.to_string(),
+            name: name.to_string(),
+        },
+        None => PodRef {
+            namespace: default_namespace.to_string(),
+            name: value.to_string(),
+        },
+    }
+}
+
+fn parse_port(value: &str) -> Result<Port> {
+    let (port, protocol) = match value.split_once('/') {
+        Some((port, protocol)) => (port, protocol.to_uppercase()),
+        None => (value, "TCP".to_string()),
+    };
+
+    if !matches!(protocol.as_str(), "TCP" | "UDP" | "SCTP") {
+        bail!("Unsupported protocol: '{}'", protocol);
+    }
+
+    let port = match port.parse() {
+        Ok(number) => PortNumber::Number(number),
+        Err(_) => PortNumber::Name(port.to_string()),
+    };
+
+    Ok(Port { port, protocol })
+}
+
+impl std::fmt::Display for PodRef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{}", self.namespace, self.name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn podからpodへのクエリを解析する() {
+        let actual = Query::parse("from:app/client to:server port:http/udp", "default").unwrap();
+
+        let expected = Query {
+            source: Source::Pod(PodRef {
+                namespace: "app".into(),
+                name: "client".into(),
+            }),
+            destination: PodRef {
+                namespace: "default".into(),
+                name: "server".into(),
+            },
+            port: Port {
+                port: PortNumber::Name("http".into()),
+                protocol: "UDP".into(),
+            },
+        };
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn cidrからのクエリを解析する() {
+        let actual = Query::parse("src:10.0.0.0/8 dst:default/server port:80", "default").unwrap();
+
+        assert_eq!(actual.source, Source::Cidr("10.0.0.0/8".parse().unwrap()));
+        assert_eq!(
+            actual.port,
+            Port {
+                port: PortNumber::Number(80),
+                protocol: "TCP".into(),
+            }
+        );
+    }
+
+    #[test]
+    fn 必須の項目がないときエラーを返す() {
+        assert!(Query::parse("from:client to:server", "default").is_err());
+        assert!(Query::parse("from:client to:server port:80 foo:bar", "default").is_err());
+    }
+}
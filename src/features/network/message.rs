@@ -19,6 +19,14 @@ pub enum HTTPRouteVersion {
     V1Beta1,
 }
 
+#[derive(Copy, Clone, Default, Debug, EnumString)]
+#[strum(serialize_all = "lowercase")]
+pub enum GRPCRouteVersion {
+    #[default]
+    V1,
+    V1Alpha2,
+}
+
 #[derive(Debug, Clone)]
 pub struct NetworkRequestTargetParams {
     pub name: String,
@@ -34,18 +42,25 @@ pub enum NetworkRequest {
     NetworkPolicy(NetworkRequestTargetParams),
     Gateway(NetworkRequestTargetParams),
     HTTPRoute(NetworkRequestTargetParams),
+    GRPCRoute(NetworkRequestTargetParams),
+    TLSRoute(NetworkRequestTargetParams),
+    TCPRoute(NetworkRequestTargetParams),
+    UDPRoute(NetworkRequestTargetParams),
 }
 
 #[derive(Debug)]
 pub enum NetworkResponse {
     List(Result<KubeTable>),
     Yaml(Result<Vec<String>>),
+    Simulation(Result<Vec<String>>),
 }
 
 #[derive(Debug)]
 pub enum NetworkMessage {
     Request(NetworkRequest),
     Response(NetworkResponse),
+    /// NetworkPolicyのシミュレーションのクエリ
+    SimulationRequest(String),
 }
 
 impl NetworkRequest {
@@ -57,6 +72,10 @@ impl NetworkRequest {
             Self::NetworkPolicy(data) => data,
             Self::Gateway(data) => data,
             Self::HTTPRoute(data) => data,
+            Self::GRPCRoute(data) => data,
+            Self::TLSRoute(data) => data,
+            Self::TCPRoute(data) => data,
+            Self::UDPRoute(data) => data,
         }
     }
 }
@@ -7,17 +7,22 @@ use crate::{
     clipboard::Clipboard,
     features::{
         component_id::NETWORK_TAB_ID,
-        network::view::widgets::{description_widget, network_widget},
+        network::view::widgets::{
+            description_widget, network_widget, simulation_query_widget, simulation_widget,
+        },
     },
     message::Message,
     ui::{
         tab::{LayoutElement, NestedLayoutElement, NestedWidgetLayout, TabLayout},
+        widget::Widget,
         Tab,
     },
 };
 
 pub struct NetworkTab {
     pub tab: Tab<'static>,
+    pub simulation_query_dialog: Widget<'static>,
+    pub simulation_dialog: Widget<'static>,
 }
 
 impl NetworkTab {
@@ -39,6 +44,8 @@ impl NetworkTab {
                 [network_widget, description_widget],
                 layout,
             ),
+            simulation_query_dialog: simulation_query_widget(tx),
+            simulation_dialog: simulation_widget(clipboard),
         }
     }
 }
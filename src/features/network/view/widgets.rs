@@ -1,5 +1,7 @@
 mod description;
 mod network;
+mod simulation;
 
 pub(super) use description::*;
 pub(super) use network::*;
+pub(super) use simulation::*;
@@ -9,14 +9,19 @@ use k8s_openapi::{
 
 use crate::{
     features::{
-        component_id::{NETWORK_DESCRIPTION_WIDGET_ID, NETWORK_WIDGET_ID},
+        component_id::{
+            NETWORK_DESCRIPTION_WIDGET_ID, NETWORK_SIMULATION_QUERY_DIALOG_ID, NETWORK_WIDGET_ID,
+        },
         network::message::{NetworkRequest, NetworkRequestTargetParams},
     },
-    kube::apis::networking::gateway::v1::{Gateway, HTTPRoute},
+    kube::apis::networking::gateway::{
+        v1::{GRPCRoute, Gateway, HTTPRoute},
+        v1alpha2::{TCPRoute, TLSRoute, UDPRoute},
+    },
     message::Message,
     ui::{
         event::EventResult,
-        widget::{Table, TableItem, Widget, WidgetBase, WidgetTrait as _},
+        widget::{Item, SelectedItem, Table, TableItem, Widget, WidgetBase, WidgetTrait as _},
         Window, WindowAction,
     },
 };
@@ -30,6 +35,7 @@ pub fn network_widget(tx: &Sender<Message>) -> Widget<'static> {
         .filtered_key("NAME")
         .block_injection(block_injection())
         .on_select(on_select(tx))
+        .action('p', open_simulation_dialog)
         .build()
         .into()
 }
@@ -50,6 +56,31 @@ fn block_injection() -> impl Fn(&Table) -> WidgetBase {
     }
 }
 
+/// NetworkPolicyのシミュレーションのダイアログを開く
+///
+/// Podを選択しているときは通信元に設定する
+fn open_simulation_dialog(w: &mut Window) -> EventResult {
+    let query = match w.find_widget(NETWORK_WIDGET_ID).widget_item() {
+        Some(SelectedItem::TableRow {
+            metadata: Some(metadata),
+            ..
+        }) if metadata.get("kind").is_some_and(|kind| kind == Pod::KIND) => {
+            match (metadata.get("namespace"), metadata.get("name")) {
+                (Some(namespace), Some(name)) => format!("from:{}/{} ", namespace, name),
+                _ => String::default(),
+            }
+        }
+        _ => String::default(),
+    };
+
+    w.find_widget_mut(NETWORK_SIMULATION_QUERY_DIALOG_ID)
+        .update_widget_item(Item::Single(query.into()));
+
+    w.open_dialog(NETWORK_SIMULATION_QUERY_DIALOG_ID);
+
+    EventResult::Nop
+}
+
 fn on_select(tx: Sender<Message>) -> impl Fn(&mut Window, &TableItem) -> EventResult {
     move |w: &mut Window, v: &TableItem| {
         w.widget_clear(NETWORK_DESCRIPTION_WIDGET_ID);
@@ -109,6 +140,22 @@ fn on_select(tx: Sender<Message>) -> impl Fn(&mut Window, &TableItem) -> EventRe
                 tx.send(NetworkRequest::HTTPRoute(request_data).into())
                     .expect("Failed to send NetworkRequest::HTTPRoute");
             }
+            GRPCRoute::KIND => {
+                tx.send(NetworkRequest::GRPCRoute(request_data).into())
+                    .expect("Failed to send NetworkRequest::GRPCRoute");
+            }
+            TLSRoute::KIND => {
+                tx.send(NetworkRequest::TLSRoute(request_data).into())
+                    .expect("Failed to send NetworkRequest::TLSRoute");
+            }
+            TCPRoute::KIND => {
+                tx.send(NetworkRequest::TCPRoute(request_data).into())
+                    .expect("Failed to send NetworkRequest::TCPRoute");
+            }
+            UDPRoute::KIND => {
+                tx.send(NetworkRequest::UDPRoute(request_data).into())
+                    .expect("Failed to send NetworkRequest::UDPRoute");
+            }
             _ => {
                 unreachable!()
             }
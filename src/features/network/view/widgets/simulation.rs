@@ -0,0 +1,73 @@
+use std::{cell::RefCell, rc::Rc};
+
+use crossbeam::channel::Sender;
+use ratatui::crossterm::event::KeyCode;
+
+use crate::{
+    clipboard::Clipboard,
+    features::{
+        component_id::{NETWORK_SIMULATION_DIALOG_ID, NETWORK_SIMULATION_QUERY_DIALOG_ID},
+        network::message::NetworkMessage,
+    },
+    message::{Message, UserEvent},
+    ui::{
+        event::EventResult,
+        widget::{InputFormBuilder, SelectedItem, Text, Widget, WidgetBase, WidgetTrait as _},
+        Window,
+    },
+};
+
+pub fn simulation_query_widget(tx: &Sender<Message>) -> Widget<'static> {
+    let tx = tx.clone();
+
+    InputFormBuilder::default()
+        .id(NETWORK_SIMULATION_QUERY_DIALOG_ID)
+        .widget_base(
+            WidgetBase::builder()
+                .title("NetworkPolicy Simulation (from:<ns>/<pod>|<cidr> to:<ns>/<pod> port:<port>[/<protocol>])")
+                .build(),
+        )
+        .actions(UserEvent::from(KeyCode::Enter), exec_simulation(tx))
+        .build()
+        .into()
+}
+
+pub fn simulation_widget(clipboard: &Option<Rc<RefCell<Clipboard>>>) -> Widget<'static> {
+    let builder = Text::builder()
+        .id(NETWORK_SIMULATION_DIALOG_ID)
+        .widget_base(
+            WidgetBase::builder()
+                .title("NetworkPolicy Simulation")
+                .build(),
+        );
+
+    if let Some(cb) = clipboard {
+        builder.clipboard(cb.clone())
+    } else {
+        builder
+    }
+    .build()
+    .into()
+}
+
+fn exec_simulation(tx: Sender<Message>) -> impl Fn(&mut Window) -> EventResult {
+    move |w: &mut Window| {
+        let widget = w.find_widget_mut(NETWORK_SIMULATION_QUERY_DIALOG_ID);
+
+        let Some(SelectedItem::Literal { metadata: _, item }) = widget.widget_item() else {
+            return EventResult::Ignore;
+        };
+
+        if item.trim().is_empty() {
+            return EventResult::Ignore;
+        }
+
+        tx.send(NetworkMessage::SimulationRequest(item).into())
+            .expect("Failed to send NetworkMessage::SimulationRequest");
+
+        w.widget_clear(NETWORK_SIMULATION_DIALOG_ID);
+        w.open_dialog(NETWORK_SIMULATION_DIALOG_ID);
+
+        EventResult::Nop
+    }
+}
@@ -2,13 +2,15 @@ use std::collections::BTreeMap;
 
 use anyhow::Result;
 use async_trait::async_trait;
-use futures::future::try_join_all;
+use futures::future::join_all;
 use k8s_openapi::{api::core::v1::Pod, Resource as _};
 
 use crate::{
     kube::{
         apis::v1_table::TableRow,
-        table::{get_resource_per_namespace, insert_ns, KubeTable, KubeTableRow},
+        table::{
+            get_resource_per_namespace, insert_ns, partition_namespaces, KubeTable, KubeTableRow,
+        },
     },
     message::Message,
     workers::kube::{
@@ -60,7 +62,9 @@ impl PodPoller {
 
         let jobs = self.get_pods_per_namespace(&namespaces).await;
 
-        let ok_only: Vec<KubeTableRow> = jobs?.into_iter().flatten().collect();
+        let (rows, skipped) = partition_namespaces(namespaces.iter().cloned().zip(jobs))?;
+
+        let ok_only: Vec<KubeTableRow> = rows.into_iter().flatten().collect();
 
         let mut table = KubeTable {
             header: if namespaces.len() == 1 {
@@ -74,6 +78,7 @@ impl PodPoller {
                     .map(ToString::to_string)
                     .collect()
             },
+            skipped,
             ..Default::default()
         };
 
@@ -85,9 +90,9 @@ impl PodPoller {
     async fn get_pods_per_namespace(
         &self,
         namespaces: &[String],
-    ) -> Result<Vec<Vec<KubeTableRow>>> {
+    ) -> Vec<Result<Vec<KubeTableRow>>> {
         let insert_ns = insert_ns(namespaces);
-        try_join_all(namespaces.iter().map(|ns| {
+        join_all(namespaces.iter().map(|ns| {
             get_resource_per_namespace(
                 &self.base.kube_client,
                 format!("api/v1/namespaces/{}/{}", ns, "pods"),
@@ -0,0 +1,6 @@
+mod can_i;
+mod permission;
+mod subject;
+
+pub use permission::*;
+pub use subject::*;
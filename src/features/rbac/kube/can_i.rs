@@ -0,0 +1,138 @@
+use anyhow::Result;
+use futures::future::try_join_all;
+use k8s_openapi::api::{
+    authorization::v1::{
+        SelfSubjectRulesReview, SelfSubjectRulesReviewSpec, SubjectRulesReviewStatus,
+    },
+    rbac::v1::PolicyRule,
+};
+use kube::{api::PostParams, Api};
+
+use crate::{features::rbac::message::RulesReview, kube::KubeClientRequest};
+
+use super::permission::{permission_table, GrantedRule};
+
+/// SelfSubjectRulesReviewのルールをネームスペースごとのルールに変換する
+///
+/// nonResourceURLsのルールはネームスペースに依存しないため、クラスタ全体として扱う
+fn granted_rules_from_review(
+    namespace: &str,
+    status: &SubjectRulesReviewStatus,
+) -> Vec<GrantedRule> {
+    let resource_rules = status.resource_rules.iter().map(|rule| GrantedRule {
+        namespace: Some(namespace.to_string()),
+        rule: PolicyRule {
+            api_groups: rule.api_groups.clone(),
+            resources: rule.resources.clone(),
+            resource_names: rule.resource_names.clone(),
+            verbs: rule.verbs.clone(),
+            ..Default::default()
+        },
+        source: None,
+    });
+
+    let non_resource_rules = status.non_resource_rules.iter().map(|rule| GrantedRule {
+        namespace: None,
+        rule: PolicyRule {
+            non_resource_urls: rule.non_resource_urls.clone(),
+            verbs: rule.verbs.clone(),
+            ..Default::default()
+        },
+        source: None,
+    });
+
+    resource_rules.chain(non_resource_rules).collect()
+}
+
+/// 対象のネームスペースで自分に許可されている操作を返す（kubectl auth can-i --list 相当）
+pub(super) async fn fetch_rules_review<C: KubeClientRequest>(
+    client: &C,
+    namespaces: &[String],
+) -> Result<RulesReview> {
+    let api: Api<SelfSubjectRulesReview> = Api::all(client.client().clone());
+
+    let reviews = try_join_all(namespaces.iter().map(|ns| {
+        let api = api.clone();
+
+        async move {
+            let review = SelfSubjectRulesReview {
+                spec: SelfSubjectRulesReviewSpec {
+                    namespace: Some(ns.to_string()),
+                },
+                ..Default::default()
+            };
+
+            api.create(&PostParams::default(), &review)
+                .await
+                .map(|review| (ns, review.status))
+        }
+    }))
+    .await?;
+
+    let mut incomplete = false;
+
+    let mut rules = Vec::new();
+
+    for (ns, status) in reviews.iter() {
+        if let Some(status) = status {
+            incomplete |= status.incomplete;
+
+            rules.extend(granted_rules_from_review(ns, status));
+        }
+    }
+
+    Ok(RulesReview {
+        table: permission_table(&rules, false),
+        incomplete,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn rulesreviewの結果から権限の行列を作る() {
+        let status: SubjectRulesReviewStatus = serde_json::from_value(json!({
+            "incomplete": false,
+            "resourceRules": [
+                { "apiGroups": [""], "resources": ["pods"], "verbs": ["get", "list"] },
+                { "apiGroups": ["authorization.k8s.io"], "resources": ["selfsubjectrulesreviews"], "verbs": ["create"] },
+            ],
+            "nonResourceRules": [
+                { "nonResourceURLs": ["/version"], "verbs": ["get"] },
+            ],
+        }))
+        .unwrap();
+
+        let actual: Vec<Vec<String>> =
+            permission_table(&granted_rules_from_review("dev", &status), false)
+                .rows
+                .into_iter()
+                .map(|row| row.row)
+                .collect();
+
+        assert_eq!(
+            actual,
+            vec![
+                vec!["*", "/version", "✓", "-", "-", "-", "-", "-", "-", "-"],
+                vec!["dev", "pods", "✓", "✓", "-", "-", "-", "-", "-", "-"],
+                vec![
+                    "dev",
+                    "selfsubjectrulesreviews.authorization.k8s.io",
+                    "-",
+                    "-",
+                    "-",
+                    "✓",
+                    "-",
+                    "-",
+                    "-",
+                    "-"
+                ],
+            ]
+        );
+    }
+}
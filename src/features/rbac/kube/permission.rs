@@ -0,0 +1,587 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use anyhow::Result;
+use futures::future::try_join_all;
+use k8s_openapi::api::rbac::v1::{ClusterRole, PolicyRule, Role, RoleRef, Subject};
+use kube::{Resource, ResourceExt as _};
+
+use crate::{
+    features::rbac::message::{RbacSubject, SubjectKind},
+    kube::{
+        access_review::explain_forbidden,
+        table::{KubeTable, KubeTableRow},
+        KubeClientRequest,
+    },
+};
+
+use super::subject::{fetch_bindings, Bindings};
+
+/// 権限の行列に列として表示する動詞（それ以外はOTHERにまとめる）
+const VERBS: [&str; 7] = [
+    "get", "list", "watch", "create", "update", "patch", "delete",
+];
+
+/// 付与されたルール
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct GrantedRule {
+    /// ルールが有効なネームスペース（Noneのときはクラスタ全体）
+    pub namespace: Option<String>,
+    pub rule: PolicyRule,
+    /// ルールを付与したバインディングとロール
+    pub source: Option<String>,
+}
+
+/// subjectを含むバインディングが参照するロール
+#[derive(Debug, Clone)]
+struct BoundRole {
+    namespace: Option<String>,
+    role_ref: RoleRef,
+    binding: String,
+}
+
+impl BoundRole {
+    fn source(&self) -> String {
+        format!(
+            "{} -> {}/{}",
+            self.binding, self.role_ref.kind, self.role_ref.name
+        )
+    }
+}
+
+/// subjectが暗黙に所属するグループ
+fn implicit_groups(subject: &RbacSubject) -> Vec<String> {
+    match subject.kind {
+        SubjectKind::ServiceAccount => vec![
+            "system:serviceaccounts".to_string(),
+            format!(
+                "system:serviceaccounts:{}",
+                subject.namespace.as_deref().unwrap_or_default()
+            ),
+            "system:authenticated".to_string(),
+        ],
+        SubjectKind::User => vec!["system:authenticated".to_string()],
+        SubjectKind::Group => Vec::new(),
+    }
+}
+
+fn is_bound(subject: &Subject, binding_namespace: Option<&str>, target: &RbacSubject) -> bool {
+    if subject.kind == "Group" && implicit_groups(target).contains(&subject.name) {
+        return true;
+    }
+
+    super::subject::to_rbac_subject(subject, binding_namespace).as_ref() == Some(target)
+}
+
+fn bound_roles(bindings: &Bindings, target: &RbacSubject) -> Vec<BoundRole> {
+    let cluster_role_bindings = bindings
+        .cluster_role_bindings
+        .iter()
+        .filter(|b| {
+            b.subjects
+                .iter()
+                .flatten()
+                .any(|s| is_bound(s, None, target))
+        })
+        .map(|b| BoundRole {
+            namespace: None,
+            role_ref: b.role_ref.clone(),
+            binding: format!("ClusterRoleBinding/{}", b.name_any()),
+        });
+
+    let role_bindings = bindings
+        .role_bindings
+        .iter()
+        .filter(|b| {
+            let namespace = b.metadata.namespace.as_deref();
+
+            b.subjects
+                .iter()
+                .flatten()
+                .any(|s| is_bound(s, namespace, target))
+        })
+        .map(|b| BoundRole {
+            namespace: b.metadata.namespace.clone(),
+            role_ref: b.role_ref.clone(),
+            binding: format!("RoleBinding/{}", b.name_any()),
+        });
+
+    cluster_role_bindings.chain(role_bindings).collect()
+}
+
+async fn fetch_role_rules<C: KubeClientRequest>(
+    client: &C,
+    bound: &BoundRole,
+) -> Result<Vec<PolicyRule>> {
+    // ClusterRoleBindingはRoleを参照できない
+    let role_namespace = bound
+        .namespace
+        .as_deref()
+        .filter(|_| bound.role_ref.kind == "Role");
+
+    let (path, rules) = match role_namespace {
+        Some(namespace) => {
+            let path = format!(
+                "{}/{}",
+                Role::url_path(&(), Some(namespace)),
+                bound.role_ref.name
+            );
+
+            let rules = client.request::<Role>(&path).await.map(|role| role.rules);
+
+            (path, rules)
+        }
+        None => {
+            let path = format!(
+                "{}/{}",
+                ClusterRole::url_path(&(), None),
+                bound.role_ref.name
+            );
+
+            let rules = client
+                .request::<ClusterRole>(&path)
+                .await
+                .map(|role| role.rules);
+
+            (path, rules)
+        }
+    };
+
+    match rules {
+        Ok(rules) => Ok(rules.unwrap_or_default()),
+        // 存在しないロールを参照しているバインディングは権限を付与しない
+        Err(err) if is_not_found(&err) => Ok(Vec::new()),
+        Err(err) => Err(explain_forbidden("get", &path, err)),
+    }
+}
+
+fn is_not_found(err: &anyhow::Error) -> bool {
+    matches!(
+        err.downcast_ref::<kube::Error>(),
+        Some(kube::Error::Api(res)) if res.code == 404
+    )
+}
+
+/// バインディングとロールを解決して、subjectに付与されている権限の行列を返す
+///
+/// RoleBindingは対象のネームスペースのものだけを対象にする
+pub async fn fetch_permissions<C: KubeClientRequest>(
+    client: &C,
+    subject: &RbacSubject,
+    namespaces: &[String],
+) -> Result<KubeTable> {
+    let bindings = fetch_bindings(client, namespaces).await?;
+
+    let bound_roles = bound_roles(&bindings, subject);
+
+    let rules = try_join_all(bound_roles.iter().map(|b| fetch_role_rules(client, b))).await?;
+
+    let granted: Vec<GrantedRule> = bound_roles
+        .iter()
+        .zip(rules)
+        .flat_map(|(bound, rules)| {
+            rules.into_iter().map(|rule| GrantedRule {
+                namespace: bound.namespace.clone(),
+                rule,
+                source: Some(bound.source()),
+            })
+        })
+        .collect();
+
+    Ok(permission_table(&granted, true))
+}
+
+/// ルールを（ネームスペース, リソース）ごとにまとめる
+///
+/// リソースは kubectl と同じく `<resource>.<group>` で表し、resourceNamesがあれば後ろに付ける
+fn rule_keys(granted: &GrantedRule) -> Vec<(String, String)> {
+    let rule = &granted.rule;
+
+    let namespace = granted.namespace.clone().unwrap_or_else(|| "*".to_string());
+
+    let resource_names = rule
+        .resource_names
+        .as_ref()
+        .filter(|names| !names.is_empty())
+        .map(|names| format!(" [{}]", names.join(",")))
+        .unwrap_or_default();
+
+    let groups = rule
+        .api_groups
+        .clone()
+        .filter(|groups| !groups.is_empty())
+        .unwrap_or_else(|| vec![String::new()]);
+
+    let resource_names = &resource_names;
+
+    let resources = rule.resources.iter().flatten().flat_map(|resource| {
+        groups.iter().map(move |group| {
+            if group.is_empty() {
+                format!("{}{}", resource, resource_names)
+            } else {
+                format!("{}.{}{}", resource, group, resource_names)
+            }
+        })
+    });
+
+    let resources = resources.map(|resource| (namespace.clone(), resource));
+
+    let non_resource_urls = rule
+        .non_resource_urls
+        .iter()
+        .flatten()
+        .map(|url| ("*".to_string(), url.to_string()));
+
+    resources.chain(non_resource_urls).collect()
+}
+
+/// 権限の行列（✓は許可されている動詞）
+///
+/// `with_source` がtrueのとき、ルールを付与したバインディングをVIAに表示する
+pub fn permission_table(rules: &[GrantedRule], with_source: bool) -> KubeTable {
+    #[derive(Default)]
+    struct Cell {
+        verbs: BTreeSet<String>,
+        sources: BTreeSet<String>,
+    }
+
+    let mut matrix: BTreeMap<(String, String), Cell> = BTreeMap::new();
+
+    for granted in rules {
+        for key in rule_keys(granted) {
+            let cell = matrix.entry(key).or_default();
+
+            cell.verbs.extend(granted.rule.verbs.iter().cloned());
+            cell.sources.extend(granted.source.iter().cloned());
+        }
+    }
+
+    let mut header = vec!["NAMESPACE".to_string(), "RESOURCE".to_string()];
+
+    header.extend(VERBS.iter().map(|verb| verb.to_uppercase()));
+
+    header.push("OTHER".to_string());
+
+    if with_source {
+        header.push("VIA".to_string());
+    }
+
+    let mut table = KubeTable {
+        header,
+        ..Default::default()
+    };
+
+    table.update_rows(
+        matrix
+            .into_iter()
+            .map(|((namespace, resource), cell)| {
+                let all = cell.verbs.contains("*");
+
+                let mut row = vec![namespace.clone(), resource.clone()];
+
+                row.extend(VERBS.iter().map(|verb| {
+                    if all || cell.verbs.contains(*verb) {
+                        "✓".to_string()
+                    } else {
+                        "-".to_string()
+                    }
+                }));
+
+                let other: Vec<&str> = cell
+                    .verbs
+                    .iter()
+                    .map(String::as_str)
+                    .filter(|verb| !VERBS.contains(verb))
+                    .collect();
+
+                row.push(if other.is_empty() {
+                    "-".to_string()
+                } else {
+                    other.join(",")
+                });
+
+                if with_source {
+                    row.push(cell.sources.into_iter().collect::<Vec<_>>().join(","));
+                }
+
+                KubeTableRow {
+                    namespace,
+                    name: resource,
+                    metadata: None,
+                    row,
+                }
+            })
+            .collect(),
+    );
+
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use mockall::predicate::eq;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use crate::{kube::mock::MockTestKubeClient, mock_expect};
+    use k8s_openapi::{api::rbac::v1::ClusterRoleBinding, api::rbac::v1::RoleBinding, List};
+
+    use super::*;
+
+    fn service_account() -> RbacSubject {
+        RbacSubject {
+            kind: SubjectKind::ServiceAccount,
+            namespace: Some("default".into()),
+            name: "ci".into(),
+        }
+    }
+
+    fn rule(value: serde_json::Value) -> PolicyRule {
+        serde_json::from_value(value).unwrap()
+    }
+
+    fn rows(table: KubeTable) -> Vec<Vec<String>> {
+        table.rows.into_iter().map(|row| row.row).collect()
+    }
+
+    #[tokio::test]
+    async fn subjectに付与されている権限を返す() {
+        let mut client = MockTestKubeClient::new();
+
+        mock_expect!(
+            client,
+            request,
+            [
+                (
+                    List<ClusterRoleBinding>,
+                    eq("/apis/rbac.authorization.k8s.io/v1/clusterrolebindings"),
+                    Ok(serde_json::from_value(json!({
+                        "metadata": {},
+                        "items": [
+                            {
+                                "metadata": { "name": "view-all" },
+                                "roleRef": { "apiGroup": "rbac.authorization.k8s.io", "kind": "ClusterRole", "name": "view" },
+                                "subjects": [{ "kind": "Group", "name": "system:serviceaccounts" }],
+                            },
+                            {
+                                "metadata": { "name": "admin" },
+                                "roleRef": { "apiGroup": "rbac.authorization.k8s.io", "kind": "ClusterRole", "name": "admin" },
+                                "subjects": [{ "kind": "User", "name": "alice" }],
+                            },
+                        ],
+                    }))
+                    .unwrap())
+                ),
+                (
+                    List<RoleBinding>,
+                    eq("/apis/rbac.authorization.k8s.io/v1/namespaces/default/rolebindings"),
+                    Ok(serde_json::from_value(json!({
+                        "metadata": {},
+                        "items": [{
+                            "metadata": { "name": "deployer", "namespace": "default" },
+                            "roleRef": { "apiGroup": "rbac.authorization.k8s.io", "kind": "Role", "name": "deployer" },
+                            "subjects": [{ "kind": "ServiceAccount", "name": "ci" }],
+                        }],
+                    }))
+                    .unwrap())
+                )
+            ]
+        );
+
+        mock_expect!(
+            client,
+            request,
+            [(
+                ClusterRole,
+                eq("/apis/rbac.authorization.k8s.io/v1/clusterroles/view"),
+                Ok(serde_json::from_value(json!({
+                    "metadata": { "name": "view" },
+                    "rules": [{ "apiGroups": [""], "resources": ["pods"], "verbs": ["get", "list", "watch"] }],
+                }))
+                .unwrap())
+            )]
+        );
+
+        mock_expect!(
+            client,
+            request,
+            [(
+                Role,
+                eq("/apis/rbac.authorization.k8s.io/v1/namespaces/default/roles/deployer"),
+                Ok(serde_json::from_value(json!({
+                    "metadata": { "name": "deployer", "namespace": "default" },
+                    "rules": [{ "apiGroups": ["apps"], "resources": ["deployments"], "verbs": ["get", "patch"] }],
+                }))
+                .unwrap())
+            )]
+        );
+
+        let actual = fetch_permissions(&client, &service_account(), &["default".to_string()])
+            .await
+            .unwrap();
+
+        assert_eq!(
+            actual.header,
+            vec![
+                "NAMESPACE",
+                "RESOURCE",
+                "GET",
+                "LIST",
+                "WATCH",
+                "CREATE",
+                "UPDATE",
+                "PATCH",
+                "DELETE",
+                "OTHER",
+                "VIA"
+            ]
+        );
+
+        assert_eq!(
+            rows(actual),
+            vec![
+                vec![
+                    "*",
+                    "pods",
+                    "✓",
+                    "✓",
+                    "✓",
+                    "-",
+                    "-",
+                    "-",
+                    "-",
+                    "-",
+                    "ClusterRoleBinding/view-all -> ClusterRole/view"
+                ],
+                vec![
+                    "default",
+                    "deployments.apps",
+                    "✓",
+                    "-",
+                    "-",
+                    "-",
+                    "-",
+                    "✓",
+                    "-",
+                    "-",
+                    "RoleBinding/deployer -> Role/deployer"
+                ],
+            ]
+        );
+    }
+
+    #[test]
+    fn 同じリソースのルールをまとめる() {
+        let rules = vec![
+            GrantedRule {
+                namespace: Some("default".into()),
+                rule: rule(json!({ "apiGroups": [""], "resources": ["pods"], "verbs": ["get"] })),
+                source: None,
+            },
+            GrantedRule {
+                namespace: Some("default".into()),
+                rule: rule(
+                    json!({ "apiGroups": [""], "resources": ["pods", "pods/log"], "verbs": ["list", "deletecollection"] }),
+                ),
+                source: None,
+            },
+        ];
+
+        assert_eq!(
+            rows(permission_table(&rules, false)),
+            vec![
+                vec![
+                    "default",
+                    "pods",
+                    "✓",
+                    "✓",
+                    "-",
+                    "-",
+                    "-",
+                    "-",
+                    "-",
+                    "deletecollection"
+                ],
+                vec![
+                    "default",
+                    "pods/log",
+                    "-",
+                    "✓",
+                    "-",
+                    "-",
+                    "-",
+                    "-",
+                    "-",
+                    "deletecollection"
+                ],
+            ]
+        );
+    }
+
+    #[test]
+    fn ワイルドカードとresourcenamesとnonresourceurlsを表示する() {
+        let rules = vec![
+            GrantedRule {
+                namespace: None,
+                rule: rule(json!({ "apiGroups": ["*"], "resources": ["*"], "verbs": ["*"] })),
+                source: None,
+            },
+            GrantedRule {
+                namespace: Some("default".into()),
+                rule: rule(
+                    json!({ "apiGroups": [""], "resources": ["secrets"], "resourceNames": ["tls"], "verbs": ["get"] }),
+                ),
+                source: None,
+            },
+            GrantedRule {
+                namespace: None,
+                rule: rule(json!({ "nonResourceURLs": ["/healthz"], "verbs": ["get"] })),
+                source: None,
+            },
+        ];
+
+        assert_eq!(
+            rows(permission_table(&rules, false)),
+            vec![
+                vec!["*", "*.*", "✓", "✓", "✓", "✓", "✓", "✓", "✓", "*"],
+                vec!["*", "/healthz", "✓", "-", "-", "-", "-", "-", "-", "-"],
+                vec![
+                    "default",
+                    "secrets [tls]",
+                    "✓",
+                    "-",
+                    "-",
+                    "-",
+                    "-",
+                    "-",
+                    "-",
+                    "-"
+                ],
+            ]
+        );
+    }
+
+    #[test]
+    fn serviceaccountは暗黙のグループのバインディングも対象にする() {
+        let group = |name: &str| Subject {
+            kind: "Group".into(),
+            name: name.into(),
+            ..Default::default()
+        };
+
+        assert!(is_bound(
+            &group("system:serviceaccounts:default"),
+            None,
+            &service_account()
+        ));
+        assert!(is_bound(
+            &group("system:authenticated"),
+            None,
+            &service_account()
+        ));
+        assert!(!is_bound(
+            &group("system:serviceaccounts:kube-system"),
+            None,
+            &service_account()
+        ));
+    }
+}
@@ -0,0 +1,436 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    str::FromStr as _,
+    time,
+};
+
+use anyhow::Result;
+use async_trait::async_trait;
+use futures::future::join_all;
+use k8s_openapi::{
+    api::rbac::v1::{ClusterRoleBinding, RoleBinding, Subject},
+    List, ListableResource,
+};
+use kube::{Resource, ResourceExt as _};
+use serde::de::DeserializeOwned;
+
+use crate::{
+    features::{
+        component_id::RBAC_TAB_ID,
+        rbac::message::{RbacResponse, RbacSubject, SubjectKind},
+    },
+    kube::{
+        access_review::{explain_forbidden, Forbidden},
+        table::{partition_namespaces, KubeTable, KubeTableRow, SkippedNamespace},
+        KubeClientRequest,
+    },
+    logger,
+    workers::kube::{PollerBase, SharedActiveTab, Worker, WorkerResult},
+};
+
+use super::can_i::fetch_rules_review;
+
+const INTERVAL: u64 = 5;
+
+#[derive(Clone)]
+pub struct RbacPoller {
+    base: PollerBase,
+    shared_active_tab: SharedActiveTab,
+}
+
+impl RbacPoller {
+    pub fn new(base: PollerBase, shared_active_tab: SharedActiveTab) -> Self {
+        Self {
+            base,
+            shared_active_tab,
+        }
+    }
+}
+
+#[async_trait]
+impl Worker for RbacPoller {
+    type Output = WorkerResult;
+
+    async fn run(&self) -> Self::Output {
+        let mut interval = tokio::time::interval(time::Duration::from_secs(INTERVAL));
+
+        let Self {
+            base:
+                PollerBase {
+                    is_terminated,
+                    tx,
+                    shared_target_namespaces,
+                    kube_client,
+                },
+            shared_active_tab,
+        } = self;
+
+        // SelfSubjectRulesReviewを送ったときのネームスペース
+        let mut reviewed_namespaces: Option<Vec<String>> = None;
+
+        while !is_terminated.load(std::sync::atomic::Ordering::Relaxed) {
+            interval.tick().await;
+
+            // RBACタブを表示していないときは取得しない
+            if shared_active_tab.wait_until_active(RBAC_TAB_ID).await {
+                interval.reset();
+                reviewed_namespaces = None;
+            }
+
+            let target_namespaces = shared_target_namespaces.read().await.clone();
+
+            let subjects = fetch_bindings(kube_client, &target_namespaces)
+                .await
+                .map(|bindings| subject_table(&bindings));
+
+            tx.send(RbacResponse::Subjects(subjects).into())
+                .expect("Failed to send RbacResponse::Subjects");
+
+            // 自分の権限はタブを表示したときとネームスペースを変えたときだけ確認する
+            if reviewed_namespaces.as_ref() == Some(&target_namespaces) {
+                continue;
+            }
+
+            let can_i = fetch_rules_review(kube_client, &target_namespaces).await;
+
+            tx.send(RbacResponse::CanI(can_i).into())
+                .expect("Failed to send RbacResponse::CanI");
+
+            reviewed_namespaces = Some(target_namespaces);
+        }
+
+        WorkerResult::Terminated
+    }
+}
+
+/// 対象のネームスペースのRoleBindingとClusterRoleBinding
+#[derive(Debug, Default)]
+pub struct Bindings {
+    pub cluster_role_bindings: Vec<ClusterRoleBinding>,
+    pub role_bindings: Vec<RoleBinding>,
+    /// RoleBindingを取得できなかったネームスペース
+    pub skipped: Vec<SkippedNamespace>,
+}
+
+async fn fetch_list<C, K>(client: &C, namespace: Option<&str>) -> Result<Vec<K>>
+where
+    C: KubeClientRequest,
+    K: Resource<DynamicType = ()> + ListableResource + DeserializeOwned + Clone + 'static,
+{
+    let path = K::url_path(&(), namespace);
+
+    match client.request::<List<K>>(&path).await {
+        Ok(list) => Ok(list.items),
+        Err(err) => Err(explain_forbidden("list", &path, err)),
+    }
+}
+
+/// バインディングを取得する
+///
+/// ネームスペースに権限を持つユーザーはClusterRoleBindingを一覧できないため、許可されていないときは空として扱う
+pub async fn fetch_bindings<C: KubeClientRequest>(
+    client: &C,
+    namespaces: &[String],
+) -> Result<Bindings> {
+    let cluster_role_bindings = match fetch_list::<C, ClusterRoleBinding>(client, None).await {
+        Ok(bindings) => bindings,
+        Err(err) if err.downcast_ref::<Forbidden>().is_some() => {
+            logger!(info, "Skip ClusterRoleBindings: {}", err);
+            Vec::new()
+        }
+        Err(err) => return Err(err),
+    };
+
+    let jobs = join_all(
+        namespaces
+            .iter()
+            .map(|ns| fetch_list::<C, RoleBinding>(client, Some(ns))),
+    )
+    .await;
+
+    let (role_bindings, skipped) = partition_namespaces(namespaces.iter().cloned().zip(jobs))?;
+
+    Ok(Bindings {
+        cluster_role_bindings,
+        role_bindings: role_bindings.into_iter().flatten().collect(),
+        skipped,
+    })
+}
+
+/// バインディングのsubjectをRbacSubjectに変換する（ServiceAccountのnamespaceの省略時はバインディングのnamespace）
+pub(super) fn to_rbac_subject(
+    subject: &Subject,
+    binding_namespace: Option<&str>,
+) -> Option<RbacSubject> {
+    let kind = SubjectKind::from_str(&subject.kind).ok()?;
+
+    let namespace = match kind {
+        SubjectKind::ServiceAccount => Some(
+            subject
+                .namespace
+                .as_deref()
+                .or(binding_namespace)?
+                .to_string(),
+        ),
+        SubjectKind::User | SubjectKind::Group => None,
+    };
+
+    Some(RbacSubject {
+        kind,
+        namespace,
+        name: subject.name.clone(),
+    })
+}
+
+/// バインディングから参照されているsubjectの一覧
+pub fn subject_table(bindings: &Bindings) -> KubeTable {
+    let mut subjects: BTreeMap<RbacSubject, BTreeSet<String>> = BTreeMap::new();
+
+    let cluster_role_bindings = bindings.cluster_role_bindings.iter().map(|b| {
+        (
+            b.subjects.as_ref(),
+            None,
+            format!("ClusterRoleBinding/{}", b.name_any()),
+        )
+    });
+
+    let role_bindings = bindings.role_bindings.iter().map(|b| {
+        (
+            b.subjects.as_ref(),
+            b.metadata.namespace.as_deref(),
+            format!(
+                "RoleBinding/{}/{}",
+                b.metadata.namespace.as_deref().unwrap_or_default(),
+                b.name_any()
+            ),
+        )
+    });
+
+    for (binding_subjects, binding_namespace, binding) in cluster_role_bindings.chain(role_bindings)
+    {
+        for subject in binding_subjects.into_iter().flatten() {
+            if let Some(subject) = to_rbac_subject(subject, binding_namespace) {
+                subjects.entry(subject).or_default().insert(binding.clone());
+            }
+        }
+    }
+
+    let mut table = KubeTable {
+        header: ["KIND", "NAMESPACE", "NAME", "BINDINGS"]
+            .iter()
+            .map(ToString::to_string)
+            .collect(),
+        skipped: bindings.skipped.clone(),
+        ..Default::default()
+    };
+
+    table.update_rows(
+        subjects
+            .into_iter()
+            .map(|(subject, bindings)| {
+                let namespace = subject.namespace.clone().unwrap_or_default();
+
+                let mut metadata = BTreeMap::from([
+                    ("kind".to_string(), subject.kind.to_string()),
+                    ("name".to_string(), subject.name.clone()),
+                ]);
+
+                if let Some(namespace) = &subject.namespace {
+                    metadata.insert("namespace".to_string(), namespace.clone());
+                }
+
+                KubeTableRow {
+                    namespace: namespace.clone(),
+                    name: subject.name.clone(),
+                    metadata: Some(metadata),
+                    row: vec![
+                        subject.kind.to_string(),
+                        namespace,
+                        subject.name,
+                        bindings.len().to_string(),
+                    ],
+                }
+            })
+            .collect(),
+    );
+
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use kube::core::ErrorResponse;
+    use mockall::predicate::eq;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use crate::{kube::mock::MockTestKubeClient, mock_expect};
+
+    use super::*;
+
+    fn bindings() -> Bindings {
+        Bindings {
+            cluster_role_bindings: serde_json::from_value(json!([
+                {
+                    "metadata": { "name": "view-all" },
+                    "roleRef": { "apiGroup": "rbac.authorization.k8s.io", "kind": "ClusterRole", "name": "view" },
+                    "subjects": [
+                        { "kind": "Group", "name": "developers", "apiGroup": "rbac.authorization.k8s.io" },
+                        { "kind": "ServiceAccount", "name": "ci", "namespace": "default" },
+                    ],
+                },
+            ]))
+            .unwrap(),
+            role_bindings: serde_json::from_value(json!([
+                {
+                    "metadata": { "name": "deployer", "namespace": "default" },
+                    "roleRef": { "apiGroup": "rbac.authorization.k8s.io", "kind": "Role", "name": "deployer" },
+                    "subjects": [
+                        { "kind": "ServiceAccount", "name": "ci" },
+                        { "kind": "User", "name": "alice", "apiGroup": "rbac.authorization.k8s.io" },
+                    ],
+                },
+                {
+                    "metadata": { "name": "edit", "namespace": "default" },
+                    "roleRef": { "apiGroup": "rbac.authorization.k8s.io", "kind": "ClusterRole", "name": "edit" },
+                    "subjects": [
+                        { "kind": "Group", "name": "system:serviceaccounts:default", "apiGroup": "rbac.authorization.k8s.io" },
+                    ],
+                },
+            ]))
+            .unwrap(),
+            skipped: Vec::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn バインディングを取得する() {
+        let mut client = MockTestKubeClient::new();
+
+        mock_expect!(
+            client,
+            request,
+            [
+                (
+                    List<ClusterRoleBinding>,
+                    eq("/apis/rbac.authorization.k8s.io/v1/clusterrolebindings"),
+                    Ok(serde_json::from_value(json!({
+                        "metadata": {},
+                        "items": [{ "metadata": { "name": "admin" }, "roleRef": { "apiGroup": "rbac.authorization.k8s.io", "kind": "ClusterRole", "name": "admin" } }],
+                    }))
+                    .unwrap())
+                ),
+                (
+                    List<RoleBinding>,
+                    eq("/apis/rbac.authorization.k8s.io/v1/namespaces/default/rolebindings"),
+                    Ok(serde_json::from_value(json!({
+                        "metadata": {},
+                        "items": [{ "metadata": { "name": "deployer", "namespace": "default" }, "roleRef": { "apiGroup": "rbac.authorization.k8s.io", "kind": "Role", "name": "deployer" } }],
+                    }))
+                    .unwrap())
+                )
+            ]
+        );
+
+        let actual = fetch_bindings(&client, &["default".to_string()])
+            .await
+            .unwrap();
+
+        assert_eq!(actual.cluster_role_bindings.len(), 1);
+        assert_eq!(actual.role_bindings.len(), 1);
+    }
+
+    fn forbidden() -> anyhow::Error {
+        kube::Error::Api(ErrorResponse {
+            status: "Failure".into(),
+            message: "forbidden".into(),
+            reason: "Forbidden".into(),
+            code: 403,
+        })
+        .into()
+    }
+
+    #[tokio::test]
+    async fn 許可されていないバインディングを除外する() {
+        let mut client = MockTestKubeClient::new();
+
+        mock_expect!(
+            client,
+            request,
+            [
+                (
+                    List<ClusterRoleBinding>,
+                    eq("/apis/rbac.authorization.k8s.io/v1/clusterrolebindings"),
+                    Err(forbidden())
+                ),
+                (
+                    List<RoleBinding>,
+                    eq("/apis/rbac.authorization.k8s.io/v1/namespaces/default/rolebindings"),
+                    Ok(serde_json::from_value(json!({
+                        "metadata": {},
+                        "items": [{ "metadata": { "name": "deployer", "namespace": "default" }, "roleRef": { "apiGroup": "rbac.authorization.k8s.io", "kind": "Role", "name": "deployer" } }],
+                    }))
+                    .unwrap())
+                ),
+                (
+                    List<RoleBinding>,
+                    eq("/apis/rbac.authorization.k8s.io/v1/namespaces/kube-system/rolebindings"),
+                    Err(forbidden())
+                )
+            ]
+        );
+
+        let actual = fetch_bindings(&client, &["default".to_string(), "kube-system".to_string()])
+            .await
+            .unwrap();
+
+        assert!(actual.cluster_role_bindings.is_empty());
+        assert_eq!(actual.role_bindings.len(), 1);
+        assert_eq!(
+            actual.skipped,
+            vec![SkippedNamespace {
+                namespace: "kube-system".into(),
+                reason: "forbidden: cannot list rolebindings.rbac.authorization.k8s.io".into(),
+            }]
+        );
+
+        assert_eq!(subject_table(&actual).skipped, actual.skipped);
+    }
+
+    #[test]
+    fn バインディングからsubjectの一覧を作る() {
+        let actual = subject_table(&bindings());
+
+        let rows: Vec<Vec<String>> = actual.rows.into_iter().map(|row| row.row).collect();
+
+        assert_eq!(
+            rows,
+            vec![
+                vec!["User", "", "alice", "1"],
+                vec!["Group", "", "developers", "1"],
+                vec!["Group", "", "system:serviceaccounts:default", "1"],
+                vec!["ServiceAccount", "default", "ci", "2"],
+            ]
+        );
+    }
+
+    #[test]
+    fn serviceaccountのnamespaceを省略したときはバインディングのnamespaceを使う() {
+        let subject = Subject {
+            kind: "ServiceAccount".into(),
+            name: "ci".into(),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            to_rbac_subject(&subject, Some("default")),
+            Some(RbacSubject {
+                kind: SubjectKind::ServiceAccount,
+                namespace: Some("default".into()),
+                name: "ci".into(),
+            })
+        );
+
+        assert_eq!(to_rbac_subject(&subject, None), None);
+    }
+}
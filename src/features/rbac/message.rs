@@ -0,0 +1,73 @@
+use anyhow::Result;
+use strum::{Display, EnumString};
+
+use crate::{kube::table::KubeTable, message::Message, workers::kube::message::Kube};
+
+/// RoleBinding / ClusterRoleBindingのsubjectsの種類
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Display, EnumString)]
+pub enum SubjectKind {
+    User,
+    Group,
+    ServiceAccount,
+}
+
+/// 権限を調べる対象（ServiceAccountのときだけnamespaceを持つ）
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct RbacSubject {
+    pub kind: SubjectKind,
+    pub namespace: Option<String>,
+    pub name: String,
+}
+
+impl std::fmt::Display for RbacSubject {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.namespace {
+            Some(namespace) => write!(f, "{}/{}/{}", self.kind, namespace, self.name),
+            None => write!(f, "{}/{}", self.kind, self.name),
+        }
+    }
+}
+
+/// SelfSubjectRulesReviewの結果
+#[derive(Debug, Default)]
+pub struct RulesReview {
+    pub table: KubeTable,
+    /// 認可モジュールがルールを列挙できず、結果が不完全なとき
+    pub incomplete: bool,
+}
+
+#[derive(Debug, Clone)]
+pub enum RbacRequest {
+    Permissions(RbacSubject),
+}
+
+#[derive(Debug)]
+pub enum RbacResponse {
+    Subjects(Result<KubeTable>),
+    Permissions(Result<KubeTable>),
+    CanI(Result<RulesReview>),
+}
+
+#[derive(Debug)]
+pub enum RbacMessage {
+    Request(RbacRequest),
+    Response(RbacResponse),
+}
+
+impl From<RbacMessage> for Message {
+    fn from(m: RbacMessage) -> Self {
+        Self::Kube(Kube::Rbac(m))
+    }
+}
+
+impl From<RbacRequest> for Message {
+    fn from(req: RbacRequest) -> Self {
+        RbacMessage::Request(req).into()
+    }
+}
+
+impl From<RbacResponse> for Message {
+    fn from(res: RbacResponse) -> Self {
+        RbacMessage::Response(res).into()
+    }
+}
@@ -0,0 +1,59 @@
+use crossbeam::channel::Sender;
+use ratatui::layout::{Constraint, Direction};
+
+use crate::{
+    features::component_id::{RBAC_SUBJECT_WIDGET_ID, RBAC_TAB_ID},
+    message::Message,
+    ui::{
+        tab::{LayoutElement, NestedLayoutElement, NestedWidgetLayout, TabLayout},
+        Tab,
+    },
+};
+
+use super::widgets::{can_i_widget, permission_widget, subject_widget};
+
+pub struct RbacTab {
+    pub tab: Tab<'static>,
+}
+
+impl RbacTab {
+    pub fn new(title: &'static str, tx: &Sender<Message>, split_direction: Direction) -> Self {
+        let subject_widget = subject_widget(tx);
+        let permission_widget = permission_widget();
+        let can_i_widget = can_i_widget();
+
+        let layout = TabLayout::new(layout, split_direction);
+
+        let mut tab = Tab::new(
+            RBAC_TAB_ID,
+            title,
+            [subject_widget, permission_widget, can_i_widget],
+            layout,
+        );
+
+        tab.activate_widget_by_id(RBAC_SUBJECT_WIDGET_ID);
+
+        Self { tab }
+    }
+}
+
+fn layout(split_direction: Direction) -> NestedWidgetLayout {
+    let detail_layout = NestedLayoutElement(
+        Constraint::Percentage(60),
+        LayoutElement::NestedElement(
+            NestedWidgetLayout::default()
+                .direction(Direction::Vertical)
+                .nested_widget_layout([
+                    NestedLayoutElement(Constraint::Percentage(50), LayoutElement::WidgetIndex(1)),
+                    NestedLayoutElement(Constraint::Percentage(50), LayoutElement::WidgetIndex(2)),
+                ]),
+        ),
+    );
+
+    NestedWidgetLayout::default()
+        .direction(split_direction)
+        .nested_widget_layout([
+            NestedLayoutElement(Constraint::Percentage(40), LayoutElement::WidgetIndex(0)),
+            detail_layout,
+        ])
+}
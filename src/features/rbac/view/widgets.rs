@@ -0,0 +1,7 @@
+mod can_i;
+mod permission;
+mod subject;
+
+pub(super) use can_i::*;
+pub(super) use permission::*;
+pub(super) use subject::*;
@@ -0,0 +1,14 @@
+use crate::{
+    features::component_id::RBAC_CAN_I_WIDGET_ID,
+    ui::widget::{Table, Widget, WidgetBase},
+};
+
+/// 対象のネームスペースで自分に許可されている操作（SelfSubjectRulesReview）
+pub fn can_i_widget() -> Widget<'static> {
+    Table::builder()
+        .id(RBAC_CAN_I_WIDGET_ID)
+        .widget_base(WidgetBase::builder().title("Can I").build())
+        .filtered_key("RESOURCE")
+        .build()
+        .into()
+}
@@ -0,0 +1,14 @@
+use crate::{
+    features::component_id::RBAC_PERMISSION_WIDGET_ID,
+    ui::widget::{Table, Widget, WidgetBase},
+};
+
+/// 選択したsubjectに付与されている権限の行列
+pub fn permission_widget() -> Widget<'static> {
+    Table::builder()
+        .id(RBAC_PERMISSION_WIDGET_ID)
+        .widget_base(WidgetBase::builder().title("Permission").build())
+        .filtered_key("RESOURCE")
+        .build()
+        .into()
+}
@@ -0,0 +1,83 @@
+use std::str::FromStr as _;
+
+use crossbeam::channel::Sender;
+
+use crate::{
+    features::{
+        component_id::{RBAC_PERMISSION_WIDGET_ID, RBAC_SUBJECT_WIDGET_ID},
+        rbac::message::{RbacRequest, RbacSubject, SubjectKind},
+    },
+    message::Message,
+    ui::{
+        event::EventResult,
+        widget::{Table, TableItem, Widget, WidgetBase, WidgetTrait as _},
+        Window, WindowAction,
+    },
+};
+
+/// RoleBinding / ClusterRoleBindingのsubjectの一覧
+///
+/// Enterで選択したsubjectの権限を表示する
+pub fn subject_widget(tx: &Sender<Message>) -> Widget<'static> {
+    Table::builder()
+        .id(RBAC_SUBJECT_WIDGET_ID)
+        .widget_base(WidgetBase::builder().title("Subject").build())
+        .filtered_key("NAME")
+        .block_injection(block_injection())
+        .on_select(on_select(tx.clone()))
+        .build()
+        .into()
+}
+
+fn block_injection() -> impl Fn(&Table) -> WidgetBase {
+    |table: &Table| {
+        let index = if let Some(index) = table.state().selected() {
+            index + 1
+        } else {
+            0
+        };
+
+        let mut base = table.widget_base().clone();
+
+        *base.append_title_mut() = Some(format!(" [{}/{}]", index, table.items().len()).into());
+
+        base
+    }
+}
+
+fn rbac_subject(item: &TableItem) -> Option<RbacSubject> {
+    let metadata = item.metadata.as_ref()?;
+
+    let kind = SubjectKind::from_str(metadata.get("kind")?).ok()?;
+
+    // テーブルの行は常にnamespaceを持つため、ServiceAccountのときだけ使う
+    let namespace = metadata
+        .get("namespace")
+        .filter(|_| kind == SubjectKind::ServiceAccount)
+        .cloned();
+
+    Some(RbacSubject {
+        kind,
+        namespace,
+        name: metadata.get("name")?.to_string(),
+    })
+}
+
+fn on_select(tx: Sender<Message>) -> impl Fn(&mut Window, &TableItem) -> EventResult {
+    move |w: &mut Window, v: &TableItem| {
+        w.widget_clear(RBAC_PERMISSION_WIDGET_ID);
+
+        let Some(subject) = rbac_subject(v) else {
+            return EventResult::Ignore;
+        };
+
+        *(w.find_widget_mut(RBAC_PERMISSION_WIDGET_ID)
+            .widget_base_mut()
+            .append_title_mut()) = Some((format!(" : {}", subject)).into());
+
+        tx.send(RbacRequest::Permissions(subject).into())
+            .expect("Failed to send RbacRequest::Permissions");
+
+        EventResult::WindowAction(WindowAction::Continue)
+    }
+}
@@ -0,0 +1,10 @@
+mod cronjob;
+mod description;
+mod revision;
+mod rollout;
+mod workload;
+
+pub use cronjob::*;
+pub use description::*;
+pub use revision::*;
+pub use workload::*;
@@ -0,0 +1,368 @@
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Utc};
+use chrono_tz::Tz;
+use croner::Cron;
+use k8s_openapi::{
+    api::batch::v1::{CronJob, Job},
+    apimachinery::pkg::apis::meta::v1::{ObjectMeta, OwnerReference, Time},
+};
+use kube::{api::PostParams, Api, ResourceExt as _};
+
+use crate::{
+    features::{api_resources::kube::format_age, workload::message::WorkloadTarget},
+    kube::KubeClientRequest,
+};
+
+use super::description::fetch_object;
+
+/// 表示する次回以降の実行時刻の数
+const NEXT_RUNS: usize = 5;
+
+/// 手動で作成したJobに付与するアノテーション（kubectl create job --from と同じ）
+const INSTANTIATE_ANNOTATION: &str = "cronjob.kubernetes.io/instantiate";
+
+/// Jobの名前の最大長（job-nameラベルの値の上限）
+const MAX_JOB_NAME_LENGTH: usize = 63;
+
+/// scheduleを解釈して `now` より後の実行時刻を返す
+///
+/// timeZoneを省略したときはUTCとして扱う。scheduleの先頭の `CRON_TZ=` / `TZ=` も解釈する
+pub fn next_runs(
+    schedule: &str,
+    time_zone: Option<&str>,
+    now: DateTime<Utc>,
+    count: usize,
+) -> Result<Vec<DateTime<Tz>>> {
+    let (prefix_time_zone, schedule) = split_time_zone_prefix(schedule);
+
+    let tz = parse_time_zone(time_zone.or(prefix_time_zone))?;
+
+    let cron = Cron::new(schedule)
+        .parse()
+        .map_err(|err| anyhow!("invalid schedule {:?}: {}", schedule, err))?;
+
+    Ok(cron
+        .iter_after(now.with_timezone(&tz))
+        .take(count)
+        .collect())
+}
+
+fn split_time_zone_prefix(schedule: &str) -> (Option<&str>, &str) {
+    let schedule = schedule.trim();
+
+    for prefix in ["CRON_TZ=", "TZ="] {
+        if let Some((tz, rest)) = schedule
+            .strip_prefix(prefix)
+            .and_then(|s| s.split_once(char::is_whitespace))
+        {
+            return (Some(tz), rest.trim());
+        }
+    }
+
+    (None, schedule)
+}
+
+fn parse_time_zone(time_zone: Option<&str>) -> Result<Tz> {
+    match time_zone {
+        Some(name) => name
+            .parse()
+            .map_err(|_| anyhow!("unknown time zone: {}", name)),
+        None => Ok(Tz::UTC),
+    }
+}
+
+fn format_time(time: &DateTime<Tz>, now: DateTime<Utc>) -> String {
+    let relative = if time.with_timezone(&Utc) > now {
+        format!("in {}", format_age(time.with_timezone(&Utc) - now))
+    } else {
+        format!("{} ago", format_age(now - time.with_timezone(&Utc)))
+    };
+
+    format!("{} ({})", time.format("%Y-%m-%d %H:%M:%S %Z"), relative)
+}
+
+/// スケジュール、次回以降の実行時刻、最終実行時刻、実行中のJob
+pub fn schedule_lines(cronjob: &CronJob, now: DateTime<Utc>) -> Vec<String> {
+    let spec = cronjob.spec.clone().unwrap_or_default();
+    let status = cronjob.status.clone().unwrap_or_default();
+
+    let time_zone = spec.time_zone.as_deref();
+
+    let mut lines = vec![
+        format!(
+            "schedule:        {} ({})",
+            spec.schedule,
+            time_zone.unwrap_or("UTC")
+        ),
+        format!("suspend:         {}", spec.suspend.unwrap_or(false)),
+    ];
+
+    let tz = parse_time_zone(time_zone).unwrap_or(Tz::UTC);
+
+    let format_last = |time: Option<&Time>| {
+        time.map(|t| format_time(&t.0.with_timezone(&tz), now))
+            .unwrap_or_else(|| "<none>".into())
+    };
+
+    lines.push(format!(
+        "last schedule:   {}",
+        format_last(status.last_schedule_time.as_ref())
+    ));
+    lines.push(format!(
+        "last successful: {}",
+        format_last(status.last_successful_time.as_ref())
+    ));
+
+    let active: Vec<String> = status
+        .active
+        .iter()
+        .flatten()
+        .filter_map(|r| r.name.clone())
+        .collect();
+
+    lines.push(format!(
+        "active:          {}",
+        if active.is_empty() {
+            "<none>".to_string()
+        } else {
+            active.join(",")
+        }
+    ));
+
+    if spec.suspend.unwrap_or(false) {
+        lines.push("next runs:       <suspended>".into());
+        return lines;
+    }
+
+    match next_runs(&spec.schedule, time_zone, now, NEXT_RUNS) {
+        Ok(runs) => {
+            lines.push("next runs:".into());
+            lines.extend(runs.iter().map(|t| format!("  {}", format_time(t, now))));
+        }
+        Err(err) => lines.push(format!("next runs:       \x1b[31m{}\x1b[39m", err)),
+    }
+
+    lines
+}
+
+/// jobTemplateから手動実行用のJobを作る（kubectl create job --from=cronjob/<name> 相当）
+pub fn job_from_cronjob(cronjob: &CronJob, now: DateTime<Utc>) -> Job {
+    let name = cronjob.name_any();
+
+    let suffix = format!("-manual-{}", now.timestamp());
+
+    let base: String = name
+        .chars()
+        .take(MAX_JOB_NAME_LENGTH - suffix.len())
+        .collect();
+
+    let template = cronjob
+        .spec
+        .as_ref()
+        .map(|s| s.job_template.clone())
+        .unwrap_or_default();
+
+    let template_metadata = template.metadata.unwrap_or_default();
+
+    let mut annotations = template_metadata.annotations.unwrap_or_default();
+    annotations.insert(INSTANTIATE_ANNOTATION.into(), "manual".into());
+
+    Job {
+        metadata: ObjectMeta {
+            name: Some(format!("{}{}", base.trim_end_matches('-'), suffix)),
+            namespace: cronjob.metadata.namespace.clone(),
+            labels: template_metadata.labels,
+            annotations: Some(annotations),
+            owner_references: Some(vec![OwnerReference {
+                api_version: "batch/v1".into(),
+                kind: "CronJob".into(),
+                name,
+                uid: cronjob.metadata.uid.clone().unwrap_or_default(),
+                controller: Some(true),
+                block_owner_deletion: Some(true),
+            }]),
+            ..Default::default()
+        },
+        spec: template.spec,
+        ..Default::default()
+    }
+}
+
+/// CronJobからJobを作成して、作成したJobの名前を返す
+pub async fn trigger_cronjob<C: KubeClientRequest>(
+    client: &C,
+    target: &WorkloadTarget,
+    now: DateTime<Utc>,
+) -> Result<String> {
+    let cronjob: CronJob = fetch_object(client, target).await?;
+
+    let job = job_from_cronjob(&cronjob, now);
+
+    let api: Api<Job> = Api::namespaced(client.client().clone(), &target.namespace);
+
+    let job = api.create(&PostParams::default(), &job).await?;
+
+    Ok(format!("job.batch/{} created", job.name_any()))
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use super::*;
+
+    fn now() -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339("2024-01-02T00:30:00Z")
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    fn cronjob(spec: serde_json::Value, status: serde_json::Value) -> CronJob {
+        let mut value = json!({
+            "metadata": { "name": "backup", "namespace": "default", "uid": "uid-backup" },
+            "spec": {
+                "schedule": "0 */6 * * *",
+                "jobTemplate": {
+                    "metadata": { "labels": { "app": "backup" } },
+                    "spec": { "template": { "spec": { "containers": [{ "name": "backup", "image": "backup:1" }] } } },
+                },
+            },
+            "status": status,
+        });
+
+        if let (Some(target), serde_json::Value::Object(spec)) =
+            (value["spec"].as_object_mut(), spec)
+        {
+            target.extend(spec);
+        }
+
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn 次回以降の実行時刻を返す() {
+        let actual: Vec<String> = next_runs("0 */6 * * *", None, now(), 3)
+            .unwrap()
+            .iter()
+            .map(|t| t.to_rfc3339())
+            .collect();
+
+        assert_eq!(
+            actual,
+            vec![
+                "2024-01-02T06:00:00+00:00",
+                "2024-01-02T12:00:00+00:00",
+                "2024-01-02T18:00:00+00:00",
+            ]
+        );
+    }
+
+    #[test]
+    fn タイムゾーンを考慮して実行時刻を返す() {
+        let actual: Vec<String> = next_runs("0 9 * * 1-5", Some("Asia/Tokyo"), now(), 2)
+            .unwrap()
+            .iter()
+            .map(|t| t.to_rfc3339())
+            .collect();
+
+        // 2024-01-02T00:30:00Z は 2024-01-02(火) 09:30 JST
+        assert_eq!(
+            actual,
+            vec!["2024-01-03T09:00:00+09:00", "2024-01-04T09:00:00+09:00"]
+        );
+    }
+
+    #[test]
+    fn scheduleの先頭のタイムゾーン指定を解釈する() {
+        let actual = next_runs("CRON_TZ=Asia/Tokyo 0 9 * * *", None, now(), 1).unwrap();
+
+        assert_eq!(actual[0].to_rfc3339(), "2024-01-03T09:00:00+09:00");
+    }
+
+    #[test]
+    fn 不正なscheduleやタイムゾーンのときエラーを返す() {
+        assert!(next_runs("0 25 * * *", None, now(), 1).is_err());
+        assert!(next_runs("@hourly", Some("Mars/Olympus"), now(), 1).is_err());
+    }
+
+    #[test]
+    fn スケジュールの情報を返す() {
+        let cronjob = cronjob(
+            json!({ "timeZone": "Asia/Tokyo" }),
+            json!({
+                "active": [{ "name": "backup-28400000" }],
+                "lastScheduleTime": "2024-01-02T00:00:00Z",
+            }),
+        );
+
+        let actual = schedule_lines(&cronjob, now());
+
+        assert_eq!(
+            actual,
+            vec![
+                "schedule:        0 */6 * * * (Asia/Tokyo)",
+                "suspend:         false",
+                "last schedule:   2024-01-02 09:00:00 JST (30m ago)",
+                "last successful: <none>",
+                "active:          backup-28400000",
+                "next runs:",
+                "  2024-01-02 12:00:00 JST (in 150m)",
+                "  2024-01-02 18:00:00 JST (in 8h)",
+                "  2024-01-03 00:00:00 JST (in 14h)",
+                "  2024-01-03 06:00:00 JST (in 20h)",
+                "  2024-01-03 12:00:00 JST (in 26h)",
+            ]
+        );
+    }
+
+    #[test]
+    fn 停止中のときは次回の実行時刻を表示しない() {
+        let cronjob = cronjob(json!({ "suspend": true }), json!({}));
+
+        let actual = schedule_lines(&cronjob, now());
+
+        assert_eq!(actual.last().unwrap(), "next runs:       <suspended>");
+    }
+
+    #[test]
+    fn jobtemplateから手動実行用のjobを作る() {
+        let cronjob = cronjob(json!({}), json!({}));
+
+        let actual = serde_json::to_value(job_from_cronjob(&cronjob, now())).unwrap();
+
+        assert_eq!(
+            actual,
+            json!({
+                "apiVersion": "batch/v1",
+                "kind": "Job",
+                "metadata": {
+                    "name": "backup-manual-1704155400",
+                    "namespace": "default",
+                    "labels": { "app": "backup" },
+                    "annotations": { INSTANTIATE_ANNOTATION: "manual" },
+                    "ownerReferences": [{
+                        "apiVersion": "batch/v1",
+                        "kind": "CronJob",
+                        "name": "backup",
+                        "uid": "uid-backup",
+                        "controller": true,
+                        "blockOwnerDeletion": true,
+                    }],
+                },
+                "spec": { "template": { "spec": { "containers": [{ "name": "backup", "image": "backup:1" }] } } },
+            })
+        );
+    }
+
+    #[test]
+    fn 長い名前のときjob名を63文字に切り詰める() {
+        let mut cronjob = cronjob(json!({}), json!({}));
+        cronjob.metadata.name = Some("a".repeat(70));
+
+        let actual = job_from_cronjob(&cronjob, now()).metadata.name.unwrap();
+
+        assert_eq!(actual.len(), MAX_JOB_NAME_LENGTH);
+        assert!(actual.ends_with("-manual-1704155400"));
+    }
+}
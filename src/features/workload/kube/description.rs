@@ -0,0 +1,630 @@
+use std::sync::{atomic::AtomicBool, Arc};
+
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use crossbeam::channel::Sender;
+use k8s_openapi::{
+    api::{
+        apps::v1::{ControllerRevision, DaemonSet, Deployment, StatefulSet},
+        batch::v1::{CronJob, Job},
+        core::v1::PodTemplateSpec,
+    },
+    apimachinery::pkg::apis::meta::v1::{LabelSelector, ObjectMeta},
+    List, ListableResource,
+};
+use kube::Resource;
+use serde::de::DeserializeOwned;
+use serde_json::Value as JsonValue;
+
+use crate::{
+    features::{
+        api_resources::kube::{format_age, render_conditions},
+        workload::message::{WorkloadKind, WorkloadResponse, WorkloadTarget},
+    },
+    kube::KubeClientRequest,
+    message::Message,
+    workers::kube::AbortWorker,
+};
+
+use super::{
+    cronjob::schedule_lines,
+    revision::fetch_rollout,
+    rollout::{
+        cronjob_status, daemonset_rollout_status, deployment_rollout_status, job_status,
+        statefulset_rollout_status,
+    },
+    workload::template_images,
+};
+
+const INTERVAL: u64 = 3;
+
+#[derive(Clone)]
+pub struct WorkloadDescriptionWorker<C>
+where
+    C: KubeClientRequest,
+{
+    is_terminated: Arc<AtomicBool>,
+    tx: Sender<Message>,
+    client: C,
+    target: WorkloadTarget,
+}
+
+impl<C> WorkloadDescriptionWorker<C>
+where
+    C: KubeClientRequest,
+{
+    pub fn new(
+        is_terminated: Arc<AtomicBool>,
+        tx: Sender<Message>,
+        client: C,
+        target: WorkloadTarget,
+    ) -> Self {
+        Self {
+            is_terminated,
+            tx,
+            client,
+            target,
+        }
+    }
+}
+
+#[async_trait]
+impl<C> AbortWorker for WorkloadDescriptionWorker<C>
+where
+    C: KubeClientRequest,
+{
+    async fn run(&self) {
+        let mut interval = tokio::time::interval(std::time::Duration::from_secs(INTERVAL));
+
+        while !self
+            .is_terminated
+            .load(std::sync::atomic::Ordering::Relaxed)
+        {
+            interval.tick().await;
+
+            let description = fetch_description(&self.client, &self.target, Utc::now()).await;
+
+            self.tx
+                .send(WorkloadResponse::Description(description).into())
+                .expect("Failed to send WorkloadResponse::Description");
+
+            if self.target.kind != WorkloadKind::Deployment {
+                continue;
+            }
+
+            match fetch_rollout(&self.client, &self.target, Utc::now()).await {
+                Ok((progress, history)) => {
+                    self.tx
+                        .send(WorkloadResponse::Progress(progress).into())
+                        .expect("Failed to send WorkloadResponse::Progress");
+
+                    self.tx
+                        .send(WorkloadResponse::History(Ok(history)).into())
+                        .expect("Failed to send WorkloadResponse::History");
+                }
+                Err(err) => {
+                    self.tx
+                        .send(WorkloadResponse::History(Err(err)).into())
+                        .expect("Failed to send WorkloadResponse::History");
+                }
+            }
+        }
+    }
+}
+
+/// 説明ペインに表示する値
+struct Summary<'a> {
+    kind: WorkloadKind,
+    metadata: &'a ObjectMeta,
+    selector: Option<&'a LabelSelector>,
+    template: Option<&'a PodTemplateSpec>,
+    rollout: String,
+    /// 種類ごとの追加情報（CronJobのスケジュールなど）
+    details: Vec<String>,
+    object: JsonValue,
+}
+
+/// リビジョン履歴の表
+struct History {
+    header: &'static [&'static str],
+    rows: Vec<Vec<String>>,
+}
+
+pub(super) async fn fetch_object<C, K>(client: &C, target: &WorkloadTarget) -> Result<K>
+where
+    C: KubeClientRequest,
+    K: Resource<DynamicType = ()> + ListableResource + DeserializeOwned + Clone + 'static,
+{
+    let url = format!(
+        "{}/{}",
+        K::url_path(&(), Some(&target.namespace)),
+        target.name
+    );
+
+    client.request(&url).await
+}
+
+pub(super) async fn fetch_owned<C, K>(
+    client: &C,
+    namespace: &str,
+    owner: &ObjectMeta,
+) -> Result<Vec<K>>
+where
+    C: KubeClientRequest,
+    K: Resource<DynamicType = ()> + ListableResource + DeserializeOwned + Clone + 'static,
+{
+    let list: List<K> = client.request(&K::url_path(&(), Some(namespace))).await?;
+
+    Ok(list
+        .items
+        .into_iter()
+        .filter(|item| is_owned_by(item.meta(), owner))
+        .collect())
+}
+
+fn is_owned_by(metadata: &ObjectMeta, owner: &ObjectMeta) -> bool {
+    metadata
+        .owner_references
+        .iter()
+        .flatten()
+        .any(|r| Some(&r.uid) == owner.uid.as_ref())
+}
+
+pub async fn fetch_description<C: KubeClientRequest>(
+    client: &C,
+    target: &WorkloadTarget,
+    now: DateTime<Utc>,
+) -> Result<Vec<String>> {
+    let namespace = target.namespace.as_str();
+
+    let lines = match target.kind {
+        WorkloadKind::Deployment => {
+            let deployment: Deployment = fetch_object(client, target).await?;
+
+            let spec = deployment.spec.as_ref();
+
+            render(
+                Summary {
+                    kind: target.kind,
+                    metadata: &deployment.metadata,
+                    selector: spec.map(|s| &s.selector),
+                    template: spec.map(|s| &s.template),
+                    rollout: deployment_rollout_status(&deployment),
+                    details: Vec::new(),
+                    object: serde_json::to_value(&deployment)?,
+                },
+                None,
+                now,
+            )
+        }
+        WorkloadKind::StatefulSet => {
+            let statefulset: StatefulSet = fetch_object(client, target).await?;
+
+            let revisions: Vec<ControllerRevision> =
+                fetch_owned(client, namespace, &statefulset.metadata).await?;
+
+            let spec = statefulset.spec.as_ref();
+
+            render(
+                Summary {
+                    kind: target.kind,
+                    metadata: &statefulset.metadata,
+                    selector: spec.map(|s| &s.selector),
+                    template: spec.map(|s| &s.template),
+                    rollout: statefulset_rollout_status(&statefulset),
+                    details: Vec::new(),
+                    object: serde_json::to_value(&statefulset)?,
+                },
+                Some(controller_revision_history(&revisions, now)),
+                now,
+            )
+        }
+        WorkloadKind::DaemonSet => {
+            let daemonset: DaemonSet = fetch_object(client, target).await?;
+
+            let revisions: Vec<ControllerRevision> =
+                fetch_owned(client, namespace, &daemonset.metadata).await?;
+
+            let spec = daemonset.spec.as_ref();
+
+            render(
+                Summary {
+                    kind: target.kind,
+                    metadata: &daemonset.metadata,
+                    selector: spec.map(|s| &s.selector),
+                    template: spec.map(|s| &s.template),
+                    rollout: daemonset_rollout_status(&daemonset),
+                    details: Vec::new(),
+                    object: serde_json::to_value(&daemonset)?,
+                },
+                Some(controller_revision_history(&revisions, now)),
+                now,
+            )
+        }
+        WorkloadKind::Job => {
+            let job: Job = fetch_object(client, target).await?;
+
+            let spec = job.spec.as_ref();
+
+            render(
+                Summary {
+                    kind: target.kind,
+                    metadata: &job.metadata,
+                    selector: spec.and_then(|s| s.selector.as_ref()),
+                    template: spec.map(|s| &s.template),
+                    rollout: job_status(&job),
+                    details: Vec::new(),
+                    object: serde_json::to_value(&job)?,
+                },
+                None,
+                now,
+            )
+        }
+        WorkloadKind::CronJob => {
+            let cronjob: CronJob = fetch_object(client, target).await?;
+
+            let jobs: Vec<Job> = fetch_owned(client, namespace, &cronjob.metadata).await?;
+
+            let job_spec = cronjob
+                .spec
+                .as_ref()
+                .and_then(|s| s.job_template.spec.as_ref());
+
+            render(
+                Summary {
+                    kind: target.kind,
+                    metadata: &cronjob.metadata,
+                    selector: job_spec.and_then(|s| s.selector.as_ref()),
+                    template: job_spec.map(|s| &s.template),
+                    rollout: cronjob_status(&cronjob),
+                    details: schedule_lines(&cronjob, now),
+                    object: serde_json::to_value(&cronjob)?,
+                },
+                Some(job_history(&jobs, now)),
+                now,
+            )
+        }
+    };
+
+    Ok(lines)
+}
+
+fn render(summary: Summary, history: Option<History>, now: DateTime<Utc>) -> Vec<String> {
+    let Summary {
+        kind,
+        metadata,
+        selector,
+        template,
+        rollout,
+        details,
+        object,
+    } = summary;
+
+    let images = template_images(template);
+
+    let mut lines = vec![
+        format!("kind:      {}", kind),
+        format!(
+            "name:      {}",
+            metadata.name.as_deref().unwrap_or_default()
+        ),
+        format!(
+            "namespace: {}",
+            metadata.namespace.as_deref().unwrap_or_default()
+        ),
+        format!(
+            "selector:  {}",
+            selector.map(format_selector).unwrap_or_default()
+        ),
+        format!("images:    {}", images.join(",")),
+        format!("rollout:   {}", rollout),
+    ];
+
+    lines.extend(details);
+    lines.push(String::default());
+
+    lines.extend(render_conditions(&object, now));
+
+    if let Some(History { header, rows }) = history {
+        lines.push(String::default());
+
+        if rows.is_empty() {
+            lines.push("revision history: <none>".to_string());
+        } else {
+            lines.push("revision history:".to_string());
+            lines.extend(render_table(header, &rows));
+        }
+    }
+
+    lines
+}
+
+/// kubectlと同じ形式のラベルセレクタ（例: app=web,tier in (front,back)）
+fn format_selector(selector: &LabelSelector) -> String {
+    let labels = selector
+        .match_labels
+        .iter()
+        .flatten()
+        .map(|(k, v)| format!("{}={}", k, v));
+
+    let expressions = selector.match_expressions.iter().flatten().map(|e| {
+        let values = e.values.clone().unwrap_or_default().join(",");
+
+        match e.operator.as_str() {
+            "In" => format!("{} in ({})", e.key, values),
+            "NotIn" => format!("{} notin ({})", e.key, values),
+            "Exists" => e.key.clone(),
+            "DoesNotExist" => format!("!{}", e.key),
+            op => format!("{} {} ({})", e.key, op, values),
+        }
+    });
+
+    labels.chain(expressions).collect::<Vec<_>>().join(",")
+}
+
+fn render_table(header: &[&str], rows: &[Vec<String>]) -> Vec<String> {
+    let widths: Vec<usize> = header
+        .iter()
+        .enumerate()
+        .map(|(i, h)| {
+            rows.iter()
+                .map(|row| row[i].len())
+                .chain([h.len()])
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+
+    let format_row = |row: Vec<&str>| {
+        let last = row.len() - 1;
+
+        row.iter()
+            .enumerate()
+            .map(|(i, value)| {
+                if i == last {
+                    value.to_string()
+                } else {
+                    format!("{:width$}", value, width = widths[i])
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("  ")
+    };
+
+    std::iter::once(header.to_vec())
+        .chain(
+            rows.iter()
+                .map(|row| row.iter().map(String::as_str).collect()),
+        )
+        .map(|row| format!("  {}", format_row(row)))
+        .collect()
+}
+
+pub(super) fn age(metadata: &ObjectMeta, now: DateTime<Utc>) -> String {
+    metadata
+        .creation_timestamp
+        .as_ref()
+        .map(|t| format_age(now - t.0))
+        .unwrap_or_default()
+}
+
+/// StatefulSet・DaemonSetが管理するControllerRevisionの履歴（新しい順）
+fn controller_revision_history(revisions: &[ControllerRevision], now: DateTime<Utc>) -> History {
+    let mut revisions: Vec<&ControllerRevision> = revisions.iter().collect();
+    revisions.sort_by_key(|r| std::cmp::Reverse(r.revision));
+
+    let rows = revisions
+        .into_iter()
+        .map(|r| {
+            let images = r
+                .data
+                .as_ref()
+                .and_then(|data| data.0.pointer("/spec/template/spec/containers"))
+                .and_then(JsonValue::as_array)
+                .map(|containers| {
+                    containers
+                        .iter()
+                        .filter_map(|c| c.get("image").and_then(JsonValue::as_str))
+                        .collect::<Vec<_>>()
+                        .join(",")
+                })
+                .unwrap_or_default();
+
+            vec![
+                r.revision.to_string(),
+                r.metadata.name.clone().unwrap_or_default(),
+                images,
+                age(&r.metadata, now),
+            ]
+        })
+        .collect();
+
+    History {
+        header: &["REVISION", "CONTROLLERREVISION", "IMAGES", "AGE"],
+        rows,
+    }
+}
+
+/// CronJobが作成したJobの履歴（新しい順）
+fn job_history(jobs: &[Job], now: DateTime<Utc>) -> History {
+    let mut jobs: Vec<&Job> = jobs.iter().collect();
+    jobs.sort_by_key(|job| std::cmp::Reverse(job.metadata.creation_timestamp.clone()));
+
+    let rows = jobs
+        .into_iter()
+        .map(|job| {
+            let status = job.status.clone().unwrap_or_default();
+
+            let outcome = status
+                .conditions
+                .iter()
+                .flatten()
+                .find(|c| (c.type_ == "Complete" || c.type_ == "Failed") && c.status == "True")
+                .map_or("Running", |c| c.type_.as_str());
+
+            let duration = status.start_time.as_ref().map_or_else(
+                || "-".to_string(),
+                |start| {
+                    let end = status.completion_time.as_ref().map_or(now, |t| t.0);
+                    format_age(end - start.0)
+                },
+            );
+
+            vec![
+                job.metadata.name.clone().unwrap_or_default(),
+                outcome.to_string(),
+                format!(
+                    "{}/{}",
+                    status.succeeded.unwrap_or(0),
+                    job.spec.as_ref().and_then(|s| s.completions).unwrap_or(1)
+                ),
+                duration,
+                age(&job.metadata, now),
+            ]
+        })
+        .collect();
+
+    History {
+        header: &["JOB", "STATUS", "COMPLETIONS", "DURATION", "AGE"],
+        rows,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+    use mockall::predicate::eq;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use crate::{kube::mock::MockTestKubeClient, mock_expect};
+
+    use super::*;
+
+    fn now() -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339("2024-01-02T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    #[tokio::test]
+    async fn deploymentのロールアウト状況とconditionsを返す() {
+        let mut client = MockTestKubeClient::new();
+
+        mock_expect!(
+            client,
+            request,
+            [
+                (
+                    Deployment,
+                    eq("/apis/apps/v1/namespaces/default/deployments/web"),
+                    Ok(serde_json::from_value(json!({
+                        "metadata": { "name": "web", "namespace": "default", "uid": "uid-web", "generation": 2 },
+                        "spec": {
+                            "replicas": 2,
+                            "selector": {
+                                "matchLabels": { "app": "web" },
+                                "matchExpressions": [{ "key": "tier", "operator": "In", "values": ["front", "back"] }],
+                            },
+                            "template": { "spec": { "containers": [{ "name": "app", "image": "nginx:1.2" }] } },
+                        },
+                        "status": {
+                            "observedGeneration": 2,
+                            "replicas": 2,
+                            "updatedReplicas": 2,
+                            "availableReplicas": 2,
+                            "conditions": [{
+                                "type": "Available",
+                                "status": "True",
+                                "reason": "MinimumReplicasAvailable",
+                                "message": "ok",
+                                "lastTransitionTime": "2024-01-01T00:00:00Z",
+                            }],
+                        },
+                    }))
+                    .unwrap())
+                )
+            ]
+        );
+
+        let target = WorkloadTarget {
+            kind: WorkloadKind::Deployment,
+            namespace: "default".into(),
+            name: "web".into(),
+        };
+
+        let actual = fetch_description(&client, &target, now()).await.unwrap();
+
+        let expected = indoc! {r#"
+            kind:      Deployment
+            name:      web
+            namespace: default
+            selector:  app=web,tier in (front,back)
+            images:    nginx:1.2
+            rollout:   deployment "web" successfully rolled out
+
+            conditions:
+              TYPE       STATUS  REASON                    AGE  MESSAGE
+              Available  True    MinimumReplicasAvailable  24h  ok"#};
+
+        assert_eq!(actual.join("\n"), expected);
+    }
+
+    #[test]
+    fn controllerrevisionの履歴を新しい順に返す() {
+        let revisions: Vec<ControllerRevision> = serde_json::from_value(json!([
+            {
+                "metadata": { "name": "db-a", "creationTimestamp": "2024-01-01T00:00:00Z" },
+                "revision": 1,
+                "data": { "spec": { "template": { "spec": { "containers": [{ "image": "postgres:15" }] } } } },
+            },
+            {
+                "metadata": { "name": "db-b", "creationTimestamp": "2024-01-01T12:00:00Z" },
+                "revision": 2,
+                "data": { "spec": { "template": { "spec": { "containers": [{ "image": "postgres:16" }] } } } },
+            },
+        ]))
+        .unwrap();
+
+        let actual = controller_revision_history(&revisions, now());
+
+        assert_eq!(
+            actual.rows,
+            vec![
+                vec!["2", "db-b", "postgres:16", "12h"],
+                vec!["1", "db-a", "postgres:15", "24h"],
+            ]
+        );
+    }
+
+    #[test]
+    fn cronjobが作成したjobの履歴を新しい順に返す() {
+        let jobs: Vec<Job> = serde_json::from_value(json!([
+            {
+                "metadata": { "name": "backup-1", "creationTimestamp": "2024-01-01T00:00:00Z" },
+                "status": {
+                    "succeeded": 1,
+                    "startTime": "2024-01-01T00:00:00Z",
+                    "completionTime": "2024-01-01T00:00:45Z",
+                    "conditions": [{ "type": "Complete", "status": "True" }],
+                },
+            },
+            {
+                "metadata": { "name": "backup-2", "creationTimestamp": "2024-01-01T23:00:00Z" },
+                "status": { "active": 1, "startTime": "2024-01-01T23:00:00Z" },
+            },
+        ]))
+        .unwrap();
+
+        let actual = job_history(&jobs, now());
+
+        assert_eq!(
+            actual.rows,
+            vec![
+                vec!["backup-2", "Running", "0/1", "60m", "60m"],
+                vec!["backup-1", "Complete", "1/1", "45s", "24h"],
+            ]
+        );
+    }
+}
@@ -0,0 +1,447 @@
+use std::collections::BTreeMap;
+
+use anyhow::{Context as _, Result};
+use chrono::{DateTime, Utc};
+use k8s_openapi::api::{
+    apps::v1::{Deployment, ReplicaSet},
+    core::v1::PodTemplateSpec,
+};
+
+use crate::{
+    features::{
+        workload::message::{DiffBase, WorkloadTarget},
+        yaml::kube::unified_diff,
+    },
+    kube::{
+        table::{KubeTable, KubeTableRow},
+        KubeClientRequest,
+    },
+};
+
+use super::{
+    description::{age, fetch_object, fetch_owned},
+    workload::template_images,
+};
+
+const REVISION_ANNOTATION: &str = "deployment.kubernetes.io/revision";
+
+const CHANGE_CAUSE_ANNOTATION: &str = "kubernetes.io/change-cause";
+
+/// ReplicaSetごとに異なるラベル（差分から除外する）
+const POD_TEMPLATE_HASH_LABEL: &str = "pod-template-hash";
+
+const PROGRESS_BAR_WIDTH: i32 = 20;
+
+/// Deploymentのロールアウトの進捗とReplicaSetの履歴
+pub async fn fetch_rollout<C: KubeClientRequest>(
+    client: &C,
+    target: &WorkloadTarget,
+    now: DateTime<Utc>,
+) -> Result<(String, KubeTable)> {
+    let deployment: Deployment = fetch_object(client, target).await?;
+
+    let replicasets: Vec<ReplicaSet> =
+        fetch_owned(client, &target.namespace, &deployment.metadata).await?;
+
+    Ok((
+        progress(&deployment),
+        history_table(&deployment, &replicasets, now),
+    ))
+}
+
+fn revision(annotations: Option<&BTreeMap<String, String>>) -> Option<i64> {
+    annotations
+        .and_then(|a| a.get(REVISION_ANNOTATION))
+        .and_then(|r| r.parse().ok())
+}
+
+fn replicaset_revision(rs: &ReplicaSet) -> i64 {
+    revision(rs.metadata.annotations.as_ref()).unwrap_or(0)
+}
+
+/// statusとProgressingのconditionから作る進捗バー
+///
+/// 例: [██████████░░░░░░░░░░]  50% updated 2/4, available 2/4, old 1 | Progressing: True (ReplicaSetUpdated)
+fn progress(deployment: &Deployment) -> String {
+    let spec = deployment.spec.as_ref();
+    let status = deployment.status.clone().unwrap_or_default();
+
+    let desired = spec.and_then(|s| s.replicas).unwrap_or(1);
+    let replicas = status.replicas.unwrap_or(0);
+    let updated = status.updated_replicas.unwrap_or(0);
+    let available = status.available_replicas.unwrap_or(0);
+
+    let filled = if desired == 0 {
+        PROGRESS_BAR_WIDTH
+    } else {
+        updated.min(available).clamp(0, desired) * PROGRESS_BAR_WIDTH / desired
+    };
+
+    let mut progress = format!(
+        "[{}{}] {:>3}% updated {}/{}, available {}/{}",
+        "█".repeat(filled as usize),
+        "░".repeat((PROGRESS_BAR_WIDTH - filled) as usize),
+        filled * 100 / PROGRESS_BAR_WIDTH,
+        updated,
+        desired,
+        available,
+        desired,
+    );
+
+    if replicas > updated {
+        progress += &format!(", old {}", replicas - updated);
+    }
+
+    if let Some(condition) = status
+        .conditions
+        .iter()
+        .flatten()
+        .find(|c| c.type_ == "Progressing")
+    {
+        progress += &format!(" | Progressing: {}", condition.status);
+
+        if let Some(reason) = &condition.reason {
+            progress += &format!(" ({})", reason);
+        }
+    }
+
+    if spec.and_then(|s| s.paused).unwrap_or(false) {
+        progress += " | paused";
+    }
+
+    progress
+}
+
+/// ReplicaSetをリビジョンとして新しい順に並べた表
+fn history_table(
+    deployment: &Deployment,
+    replicasets: &[ReplicaSet],
+    now: DateTime<Utc>,
+) -> KubeTable {
+    let current = revision(deployment.metadata.annotations.as_ref());
+
+    let mut table = KubeTable {
+        header: [
+            "REVISION",
+            "REPLICASET",
+            "IMAGES",
+            "CHANGE-CAUSE",
+            "DESIRED",
+            "CURRENT",
+            "READY",
+            "AGE",
+        ]
+        .iter()
+        .map(ToString::to_string)
+        .collect(),
+        ..Default::default()
+    };
+
+    let mut replicasets: Vec<&ReplicaSet> = replicasets.iter().collect();
+    replicasets.sort_by_key(|rs| std::cmp::Reverse(replicaset_revision(rs)));
+
+    table.update_rows(
+        replicasets
+            .into_iter()
+            .map(|rs| {
+                let revision = replicaset_revision(rs);
+
+                let status = rs.status.as_ref();
+
+                KubeTableRow {
+                    namespace: deployment.metadata.namespace.clone().unwrap_or_default(),
+                    name: deployment.metadata.name.clone().unwrap_or_default(),
+                    metadata: Some(BTreeMap::from([(
+                        "revision".to_string(),
+                        revision.to_string(),
+                    )])),
+                    row: vec![
+                        if Some(revision) == current {
+                            format!("{} (current)", revision)
+                        } else {
+                            revision.to_string()
+                        },
+                        rs.metadata.name.clone().unwrap_or_default(),
+                        template_images(rs.spec.as_ref().and_then(|s| s.template.as_ref()))
+                            .join(","),
+                        rs.metadata
+                            .annotations
+                            .as_ref()
+                            .and_then(|a| a.get(CHANGE_CAUSE_ANNOTATION))
+                            .cloned()
+                            .unwrap_or_else(|| "<none>".into()),
+                        rs.spec
+                            .as_ref()
+                            .and_then(|s| s.replicas)
+                            .unwrap_or(0)
+                            .to_string(),
+                        status.map_or(0, |s| s.replicas).to_string(),
+                        status
+                            .and_then(|s| s.ready_replicas)
+                            .unwrap_or(0)
+                            .to_string(),
+                        age(&rs.metadata, now),
+                    ],
+                }
+            })
+            .collect(),
+    );
+
+    table
+}
+
+/// 指定したリビジョンのPodテンプレートと、比較対象のPodテンプレートの差分
+pub async fn fetch_template_diff<C: KubeClientRequest>(
+    client: &C,
+    target: &WorkloadTarget,
+    revision: i64,
+    base: DiffBase,
+) -> Result<Vec<String>> {
+    let deployment: Deployment = fetch_object(client, target).await?;
+
+    let replicasets: Vec<ReplicaSet> =
+        fetch_owned(client, &target.namespace, &deployment.metadata).await?;
+
+    template_diff(&deployment, &replicasets, revision, base)
+}
+
+fn template_yaml(template: Option<&PodTemplateSpec>) -> Result<String> {
+    let mut template = template.cloned().unwrap_or_default();
+
+    if let Some(labels) = template.metadata.as_mut().and_then(|m| m.labels.as_mut()) {
+        labels.remove(POD_TEMPLATE_HASH_LABEL);
+    }
+
+    Ok(serde_yaml::to_string(&template)?)
+}
+
+fn template_diff(
+    deployment: &Deployment,
+    replicasets: &[ReplicaSet],
+    revision: i64,
+    base: DiffBase,
+) -> Result<Vec<String>> {
+    let header = |rs: &ReplicaSet| {
+        format!(
+            "{} (revision {})",
+            rs.metadata.name.as_deref().unwrap_or_default(),
+            replicaset_revision(rs)
+        )
+    };
+
+    let selected = replicasets
+        .iter()
+        .find(|rs| replicaset_revision(rs) == revision)
+        .with_context(|| format!("revision {} not found", revision))?;
+
+    let selected_yaml = template_yaml(selected.spec.as_ref().and_then(|s| s.template.as_ref()))?;
+
+    let diff = match base {
+        DiffBase::Previous => match replicasets
+            .iter()
+            .filter(|rs| replicaset_revision(rs) < revision)
+            .max_by_key(|rs| replicaset_revision(rs))
+        {
+            Some(previous) => unified_diff(
+                &template_yaml(previous.spec.as_ref().and_then(|s| s.template.as_ref()))?,
+                &selected_yaml,
+                &header(previous),
+                &header(selected),
+            ),
+            None => unified_diff(
+                "",
+                &selected_yaml,
+                "(no previous revision)",
+                &header(selected),
+            ),
+        },
+        DiffBase::Current => unified_diff(
+            &selected_yaml,
+            &template_yaml(deployment.spec.as_ref().map(|s| &s.template))?,
+            &header(selected),
+            &format!(
+                "{} (current template)",
+                deployment.metadata.name.as_deref().unwrap_or_default()
+            ),
+        ),
+    };
+
+    Ok(diff)
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use crate::workers::kube::color::fg::Color;
+
+    use super::*;
+
+    fn now() -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339("2024-01-02T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    fn deployment(status: serde_json::Value) -> Deployment {
+        serde_json::from_value(json!({
+            "metadata": {
+                "name": "web",
+                "namespace": "default",
+                "annotations": { REVISION_ANNOTATION: "2" },
+            },
+            "spec": {
+                "replicas": 4,
+                "selector": {},
+                "template": {
+                    "metadata": { "labels": { "app": "web" } },
+                    "spec": { "containers": [{ "name": "app", "image": "nginx:1.3" }] },
+                },
+            },
+            "status": status,
+        }))
+        .unwrap()
+    }
+
+    fn replicaset(revision: i64, image: &str, change_cause: Option<&str>) -> ReplicaSet {
+        let mut annotations = json!({ REVISION_ANNOTATION: revision.to_string() });
+
+        if let Some(change_cause) = change_cause {
+            annotations[CHANGE_CAUSE_ANNOTATION] = change_cause.into();
+        }
+
+        serde_json::from_value(json!({
+            "metadata": {
+                "name": format!("web-{}", revision),
+                "creationTimestamp": "2024-01-01T00:00:00Z",
+                "annotations": annotations,
+            },
+            "spec": {
+                "replicas": if revision == 2 { 4 } else { 0 },
+                "selector": {},
+                "template": {
+                    "metadata": { "labels": { "app": "web", POD_TEMPLATE_HASH_LABEL: format!("hash{}", revision) } },
+                    "spec": { "containers": [{ "name": "app", "image": image }] },
+                },
+            },
+            "status": { "replicas": if revision == 2 { 4 } else { 0 }, "readyReplicas": if revision == 2 { 3 } else { 0 } },
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn ロールアウトの進捗バーを返す() {
+        let actual = progress(&deployment(json!({
+            "replicas": 5,
+            "updatedReplicas": 2,
+            "availableReplicas": 3,
+            "conditions": [{
+                "type": "Progressing",
+                "status": "True",
+                "reason": "ReplicaSetUpdated",
+            }],
+        })));
+
+        assert_eq!(
+            actual,
+            "[██████████░░░░░░░░░░]  50% updated 2/4, available 3/4, old 3 | Progressing: True (ReplicaSetUpdated)"
+        );
+    }
+
+    #[test]
+    fn ロールアウトが完了したとき進捗は100パーセント() {
+        let actual = progress(&deployment(json!({
+            "replicas": 4,
+            "updatedReplicas": 4,
+            "availableReplicas": 4,
+        })));
+
+        assert_eq!(
+            actual,
+            "[████████████████████] 100% updated 4/4, available 4/4"
+        );
+    }
+
+    #[test]
+    fn replicasetを新しいリビジョンから順に返す() {
+        let replicasets = vec![
+            replicaset(1, "nginx:1.1", None),
+            replicaset(
+                2,
+                "nginx:1.2",
+                Some("kubectl set image deployment/web app=nginx:1.2"),
+            ),
+        ];
+
+        let actual = history_table(&deployment(json!({})), &replicasets, now());
+
+        assert_eq!(
+            actual
+                .rows
+                .iter()
+                .map(|r| r.row.clone())
+                .collect::<Vec<_>>(),
+            vec![
+                vec![
+                    "2 (current)",
+                    "web-2",
+                    "nginx:1.2",
+                    "kubectl set image deployment/web app=nginx:1.2",
+                    "4",
+                    "4",
+                    "3",
+                    "24h",
+                ],
+                vec!["1", "web-1", "nginx:1.1", "<none>", "0", "0", "0", "24h"],
+            ]
+        );
+        assert_eq!(
+            actual.rows[0].metadata.as_ref().unwrap()["revision"],
+            "2".to_string()
+        );
+    }
+
+    #[test]
+    fn 前のリビジョンとのpodテンプレートの差分を返す() {
+        let replicasets = vec![
+            replicaset(1, "nginx:1.1", None),
+            replicaset(2, "nginx:1.2", None),
+        ];
+
+        let actual =
+            template_diff(&deployment(json!({})), &replicasets, 2, DiffBase::Previous).unwrap();
+
+        assert_eq!(
+            actual,
+            vec![
+                Color::Red.wrap("--- web-1 (revision 1)"),
+                Color::Green.wrap("+++ web-2 (revision 2)"),
+                Color::Cyan.wrap("@@ -3,5 +3,5 @@"),
+                "     app: web".to_string(),
+                " spec:".to_string(),
+                "   containers:".to_string(),
+                Color::Red.wrap("-  - image: nginx:1.1"),
+                Color::Green.wrap("+  - image: nginx:1.2"),
+                "     name: app".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn 現在のテンプレートとの差分を返す() {
+        let replicasets = vec![replicaset(1, "nginx:1.1", None)];
+
+        let actual =
+            template_diff(&deployment(json!({})), &replicasets, 1, DiffBase::Current).unwrap();
+
+        assert_eq!(actual[0], Color::Red.wrap("--- web-1 (revision 1)"));
+        assert_eq!(actual[1], Color::Green.wrap("+++ web (current template)"));
+        assert!(actual.contains(&Color::Green.wrap("+  - image: nginx:1.3")));
+    }
+
+    #[test]
+    fn 存在しないリビジョンのときエラーを返す() {
+        assert!(template_diff(&deployment(json!({})), &[], 1, DiffBase::Previous).is_err());
+    }
+}
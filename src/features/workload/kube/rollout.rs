@@ -0,0 +1,519 @@
+use k8s_openapi::{
+    api::{
+        apps::v1::{DaemonSet, Deployment, StatefulSet},
+        batch::v1::{CronJob, Job},
+    },
+    apimachinery::pkg::apis::meta::v1::ObjectMeta,
+};
+
+/// metadata.generation が status.observedGeneration に反映されているか
+fn is_observed(metadata: &ObjectMeta, observed_generation: Option<i64>) -> bool {
+    metadata.generation.unwrap_or(0) <= observed_generation.unwrap_or(0)
+}
+
+/// `kubectl rollout status` と同じ形式のDeploymentのロールアウト状況
+pub fn deployment_rollout_status(deployment: &Deployment) -> String {
+    let name = deployment.metadata.name.as_deref().unwrap_or_default();
+
+    let status = deployment.status.clone().unwrap_or_default();
+
+    if !is_observed(&deployment.metadata, status.observed_generation) {
+        return "Waiting for deployment spec update to be observed...".into();
+    }
+
+    let progress_deadline_exceeded = status.conditions.iter().flatten().any(|c| {
+        c.type_ == "Progressing" && c.reason.as_deref() == Some("ProgressDeadlineExceeded")
+    });
+
+    if progress_deadline_exceeded {
+        return format!("deployment {:?} exceeded its progress deadline", name);
+    }
+
+    let desired = deployment
+        .spec
+        .as_ref()
+        .and_then(|s| s.replicas)
+        .unwrap_or(1);
+    let replicas = status.replicas.unwrap_or(0);
+    let updated = status.updated_replicas.unwrap_or(0);
+    let available = status.available_replicas.unwrap_or(0);
+
+    if updated < desired {
+        format!(
+            "Waiting for deployment {:?} rollout to finish: {} out of {} new replicas have been updated...",
+            name, updated, desired
+        )
+    } else if replicas > updated {
+        format!(
+            "Waiting for deployment {:?} rollout to finish: {} old replicas are pending termination...",
+            name,
+            replicas - updated
+        )
+    } else if available < updated {
+        format!(
+            "Waiting for deployment {:?} rollout to finish: {} of {} updated replicas are available...",
+            name, available, updated
+        )
+    } else {
+        format!("deployment {:?} successfully rolled out", name)
+    }
+}
+
+/// `kubectl rollout status` と同じ形式のStatefulSetのロールアウト状況
+pub fn statefulset_rollout_status(statefulset: &StatefulSet) -> String {
+    let spec = statefulset.spec.clone().unwrap_or_default();
+    let status = statefulset.status.clone().unwrap_or_default();
+
+    let strategy = spec.update_strategy.unwrap_or_default();
+
+    if strategy.type_.as_deref().unwrap_or("RollingUpdate") != "RollingUpdate" {
+        return "rollout status is only available for RollingUpdate strategy type".into();
+    }
+
+    if status.observed_generation.unwrap_or(0) == 0
+        || !is_observed(&statefulset.metadata, status.observed_generation)
+    {
+        return "Waiting for statefulset spec update to be observed...".into();
+    }
+
+    let desired = spec.replicas.unwrap_or(1);
+    let ready = status.ready_replicas.unwrap_or(0);
+    let updated = status.updated_replicas.unwrap_or(0);
+
+    if ready < desired {
+        return format!("Waiting for {} pods to be ready...", desired - ready);
+    }
+
+    if let Some(partition) = strategy.rolling_update.and_then(|r| r.partition) {
+        let expected = (desired - partition).max(0);
+
+        return if updated < expected {
+            format!(
+                "Waiting for partitioned roll out to finish: {} out of {} new pods have been updated...",
+                updated, expected
+            )
+        } else {
+            format!(
+                "partitioned roll out complete: {} new pods have been updated...",
+                updated
+            )
+        };
+    }
+
+    let update_revision = status.update_revision.unwrap_or_default();
+
+    if status.current_revision.as_deref() != Some(update_revision.as_str()) {
+        format!(
+            "waiting for statefulset rolling update to complete {} pods at revision {}...",
+            updated, update_revision
+        )
+    } else {
+        format!(
+            "statefulset rolling update complete {} pods at revision {}...",
+            status.current_replicas.unwrap_or(0),
+            update_revision
+        )
+    }
+}
+
+/// `kubectl rollout status` と同じ形式のDaemonSetのロールアウト状況
+pub fn daemonset_rollout_status(daemonset: &DaemonSet) -> String {
+    let name = daemonset.metadata.name.as_deref().unwrap_or_default();
+
+    let strategy = daemonset
+        .spec
+        .as_ref()
+        .and_then(|s| s.update_strategy.as_ref())
+        .and_then(|s| s.type_.as_deref())
+        .unwrap_or("RollingUpdate");
+
+    if strategy != "RollingUpdate" {
+        return "rollout status is only available for RollingUpdate strategy type".into();
+    }
+
+    let status = daemonset.status.clone().unwrap_or_default();
+
+    if !is_observed(&daemonset.metadata, status.observed_generation) {
+        return "Waiting for daemon set spec update to be observed...".into();
+    }
+
+    let desired = status.desired_number_scheduled;
+    let updated = status.updated_number_scheduled.unwrap_or(0);
+    let available = status.number_available.unwrap_or(0);
+
+    if updated < desired {
+        format!(
+            "Waiting for daemon set {:?} rollout to finish: {} out of {} new pods have been updated...",
+            name, updated, desired
+        )
+    } else if available < desired {
+        format!(
+            "Waiting for daemon set {:?} rollout to finish: {} of {} updated pods are available...",
+            name, available, desired
+        )
+    } else {
+        format!("daemon set {:?} successfully rolled out", name)
+    }
+}
+
+/// Jobの完了状況
+pub fn job_status(job: &Job) -> String {
+    let name = job.metadata.name.as_deref().unwrap_or_default();
+
+    let status = job.status.clone().unwrap_or_default();
+
+    let finished = status
+        .conditions
+        .iter()
+        .flatten()
+        .find(|c| (c.type_ == "Complete" || c.type_ == "Failed") && c.status == "True");
+
+    match finished {
+        Some(c) if c.type_ == "Complete" => format!("job {:?} completed", name),
+        Some(c) => format!(
+            "job {:?} failed: {}",
+            name,
+            c.reason.as_deref().unwrap_or("Unknown")
+        ),
+        None => format!(
+            "job {:?} running: {} active, {} succeeded, {} failed",
+            name,
+            status.active.unwrap_or(0),
+            status.succeeded.unwrap_or(0),
+            status.failed.unwrap_or(0)
+        ),
+    }
+}
+
+/// CronJobの実行状況（スケジュールの詳細は説明ペインに表示する）
+pub fn cronjob_status(cronjob: &CronJob) -> String {
+    let name = cronjob.metadata.name.as_deref().unwrap_or_default();
+
+    if cronjob
+        .spec
+        .as_ref()
+        .and_then(|s| s.suspend)
+        .unwrap_or(false)
+    {
+        return format!("cronjob {:?} is suspended", name);
+    }
+
+    let active = cronjob
+        .status
+        .as_ref()
+        .and_then(|s| s.active.as_ref())
+        .map_or(0, |a| a.len());
+
+    format!("cronjob {:?} has {} active jobs", name, active)
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use super::*;
+
+    mod deployment {
+        use pretty_assertions::assert_eq;
+
+        use super::*;
+
+        fn deployment(status: serde_json::Value) -> Deployment {
+            serde_json::from_value(json!({
+                "metadata": { "name": "web", "generation": 2 },
+                "spec": { "replicas": 3, "selector": {}, "template": {} },
+                "status": status,
+            }))
+            .unwrap()
+        }
+
+        #[test]
+        fn 新しいレプリカの更新を待つ() {
+            let actual = deployment_rollout_status(&deployment(json!({
+                "observedGeneration": 2,
+                "replicas": 4,
+                "updatedReplicas": 1,
+            })));
+
+            assert_eq!(
+                actual,
+                r#"Waiting for deployment "web" rollout to finish: 1 out of 3 new replicas have been updated..."#
+            );
+        }
+
+        #[test]
+        fn 古いレプリカの終了を待つ() {
+            let actual = deployment_rollout_status(&deployment(json!({
+                "observedGeneration": 2,
+                "replicas": 4,
+                "updatedReplicas": 3,
+            })));
+
+            assert_eq!(
+                actual,
+                r#"Waiting for deployment "web" rollout to finish: 1 old replicas are pending termination..."#
+            );
+        }
+
+        #[test]
+        fn 更新したレプリカが利用可能になるのを待つ() {
+            let actual = deployment_rollout_status(&deployment(json!({
+                "observedGeneration": 2,
+                "replicas": 3,
+                "updatedReplicas": 3,
+                "availableReplicas": 2,
+            })));
+
+            assert_eq!(
+                actual,
+                r#"Waiting for deployment "web" rollout to finish: 2 of 3 updated replicas are available..."#
+            );
+        }
+
+        #[test]
+        fn ロールアウトが完了している() {
+            let actual = deployment_rollout_status(&deployment(json!({
+                "observedGeneration": 2,
+                "replicas": 3,
+                "updatedReplicas": 3,
+                "availableReplicas": 3,
+            })));
+
+            assert_eq!(actual, r#"deployment "web" successfully rolled out"#);
+        }
+
+        #[test]
+        fn 進行期限を超えている() {
+            let actual = deployment_rollout_status(&deployment(json!({
+                "observedGeneration": 2,
+                "conditions": [{
+                    "type": "Progressing",
+                    "status": "False",
+                    "reason": "ProgressDeadlineExceeded",
+                }],
+            })));
+
+            assert_eq!(actual, r#"deployment "web" exceeded its progress deadline"#);
+        }
+
+        #[test]
+        fn specの更新が反映されていない() {
+            let actual = deployment_rollout_status(&deployment(json!({
+                "observedGeneration": 1,
+            })));
+
+            assert_eq!(
+                actual,
+                "Waiting for deployment spec update to be observed..."
+            );
+        }
+    }
+
+    mod statefulset {
+        use pretty_assertions::assert_eq;
+
+        use super::*;
+
+        fn statefulset(
+            update_strategy: serde_json::Value,
+            status: serde_json::Value,
+        ) -> StatefulSet {
+            serde_json::from_value(json!({
+                "metadata": { "name": "db", "generation": 1 },
+                "spec": {
+                    "replicas": 3,
+                    "selector": {},
+                    "template": {},
+                    "serviceName": "db",
+                    "updateStrategy": update_strategy,
+                },
+                "status": status,
+            }))
+            .unwrap()
+        }
+
+        #[test]
+        fn podの準備を待つ() {
+            let actual = statefulset_rollout_status(&statefulset(
+                json!(null),
+                json!({ "observedGeneration": 1, "replicas": 3, "readyReplicas": 1 }),
+            ));
+
+            assert_eq!(actual, "Waiting for 2 pods to be ready...");
+        }
+
+        #[test]
+        fn ローリングアップデートの完了を待つ() {
+            let actual = statefulset_rollout_status(&statefulset(
+                json!(null),
+                json!({
+                    "observedGeneration": 1,
+                    "replicas": 3,
+                    "readyReplicas": 3,
+                    "updatedReplicas": 1,
+                    "currentRevision": "db-1",
+                    "updateRevision": "db-2",
+                }),
+            ));
+
+            assert_eq!(
+                actual,
+                "waiting for statefulset rolling update to complete 1 pods at revision db-2..."
+            );
+        }
+
+        #[test]
+        fn ローリングアップデートが完了している() {
+            let actual = statefulset_rollout_status(&statefulset(
+                json!(null),
+                json!({
+                    "observedGeneration": 1,
+                    "replicas": 3,
+                    "readyReplicas": 3,
+                    "currentReplicas": 3,
+                    "currentRevision": "db-2",
+                    "updateRevision": "db-2",
+                }),
+            ));
+
+            assert_eq!(
+                actual,
+                "statefulset rolling update complete 3 pods at revision db-2..."
+            );
+        }
+
+        #[test]
+        fn パーティション分の更新を待つ() {
+            let actual = statefulset_rollout_status(&statefulset(
+                json!({ "type": "RollingUpdate", "rollingUpdate": { "partition": 1 } }),
+                json!({
+                    "observedGeneration": 1,
+                    "replicas": 3,
+                    "readyReplicas": 3,
+                    "updatedReplicas": 1,
+                }),
+            ));
+
+            assert_eq!(
+                actual,
+                "Waiting for partitioned roll out to finish: 1 out of 2 new pods have been updated..."
+            );
+        }
+
+        #[test]
+        fn ondeleteのときはロールアウト状況を表示しない() {
+            let actual = statefulset_rollout_status(&statefulset(
+                json!({ "type": "OnDelete" }),
+                json!({ "observedGeneration": 1 }),
+            ));
+
+            assert_eq!(
+                actual,
+                "rollout status is only available for RollingUpdate strategy type"
+            );
+        }
+    }
+
+    mod daemonset {
+        use pretty_assertions::assert_eq;
+
+        use super::*;
+
+        fn daemonset(status: serde_json::Value) -> DaemonSet {
+            serde_json::from_value(json!({
+                "metadata": { "name": "agent", "generation": 1 },
+                "spec": { "selector": {}, "template": {} },
+                "status": status,
+            }))
+            .unwrap()
+        }
+
+        fn status(updated: i32, available: i32) -> serde_json::Value {
+            json!({
+                "observedGeneration": 1,
+                "currentNumberScheduled": 3,
+                "desiredNumberScheduled": 3,
+                "numberMisscheduled": 0,
+                "numberReady": available,
+                "updatedNumberScheduled": updated,
+                "numberAvailable": available,
+            })
+        }
+
+        #[test]
+        fn 新しいpodの更新を待つ() {
+            assert_eq!(
+                daemonset_rollout_status(&daemonset(status(1, 3))),
+                r#"Waiting for daemon set "agent" rollout to finish: 1 out of 3 new pods have been updated..."#
+            );
+        }
+
+        #[test]
+        fn 更新したpodが利用可能になるのを待つ() {
+            assert_eq!(
+                daemonset_rollout_status(&daemonset(status(3, 2))),
+                r#"Waiting for daemon set "agent" rollout to finish: 2 of 3 updated pods are available..."#
+            );
+        }
+
+        #[test]
+        fn ロールアウトが完了している() {
+            assert_eq!(
+                daemonset_rollout_status(&daemonset(status(3, 3))),
+                r#"daemon set "agent" successfully rolled out"#
+            );
+        }
+    }
+
+    #[test]
+    fn jobの完了状況を返す() {
+        let job = |status: serde_json::Value| -> Job {
+            serde_json::from_value(json!({
+                "metadata": { "name": "migrate" },
+                "spec": { "template": {} },
+                "status": status,
+            }))
+            .unwrap()
+        };
+
+        assert_eq!(
+            job_status(&job(json!({ "active": 1, "failed": 2 }))),
+            r#"job "migrate" running: 1 active, 0 succeeded, 2 failed"#
+        );
+        assert_eq!(
+            job_status(&job(json!({
+                "succeeded": 1,
+                "conditions": [{ "type": "Complete", "status": "True" }],
+            }))),
+            r#"job "migrate" completed"#
+        );
+        assert_eq!(
+            job_status(&job(json!({
+                "conditions": [{ "type": "Failed", "status": "True", "reason": "BackoffLimitExceeded" }],
+            }))),
+            r#"job "migrate" failed: BackoffLimitExceeded"#
+        );
+    }
+
+    #[test]
+    fn cronjobの実行状況を返す() {
+        let cronjob = |suspend: bool| -> CronJob {
+            serde_json::from_value(json!({
+                "metadata": { "name": "backup" },
+                "spec": { "schedule": "0 0 * * *", "suspend": suspend, "jobTemplate": {} },
+                "status": { "lastScheduleTime": "2024-01-01T00:00:00Z" },
+            }))
+            .unwrap()
+        };
+
+        assert_eq!(
+            cronjob_status(&cronjob(false)),
+            r#"cronjob "backup" has 0 active jobs"#
+        );
+        assert_eq!(
+            cronjob_status(&cronjob(true)),
+            r#"cronjob "backup" is suspended"#
+        );
+    }
+}
@@ -0,0 +1,523 @@
+use std::{collections::BTreeMap, time};
+
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use futures::future::join_all;
+use k8s_openapi::{
+    api::{
+        apps::v1::{DaemonSet, Deployment, StatefulSet},
+        batch::v1::{CronJob, Job},
+        core::v1::PodTemplateSpec,
+    },
+    apimachinery::pkg::apis::meta::v1::ObjectMeta,
+    List, ListableResource,
+};
+use kube::Resource;
+use serde::de::DeserializeOwned;
+
+use crate::{
+    features::{
+        api_resources::kube::{format_age, API_RESOURCE_KEY},
+        workload::message::{WorkloadKind, WorkloadResponse},
+    },
+    kube::{
+        access_review::explain_forbidden,
+        table::{insert_ns, partition_namespaces, KubeTable, KubeTableRow},
+        KubeClientRequest,
+    },
+    workers::kube::{PollerBase, Worker, WorkerResult},
+};
+
+const INTERVAL: u64 = 3;
+
+const HEADER: [&str; 8] = [
+    "KIND",
+    "NAME",
+    "DESIRED",
+    "READY",
+    "UP-TO-DATE",
+    "AVAILABLE",
+    "IMAGES",
+    "AGE",
+];
+
+#[derive(Clone)]
+pub struct WorkloadPoller {
+    base: PollerBase,
+}
+
+impl WorkloadPoller {
+    pub fn new(base: PollerBase) -> Self {
+        Self { base }
+    }
+}
+
+#[async_trait]
+impl Worker for WorkloadPoller {
+    type Output = WorkerResult;
+
+    async fn run(&self) -> Self::Output {
+        let mut interval = tokio::time::interval(time::Duration::from_secs(INTERVAL));
+
+        let Self {
+            base:
+                PollerBase {
+                    is_terminated,
+                    tx,
+                    shared_target_namespaces,
+                    kube_client,
+                },
+        } = self;
+
+        while !is_terminated.load(std::sync::atomic::Ordering::Relaxed) {
+            interval.tick().await;
+
+            let target_namespaces = shared_target_namespaces.read().await;
+
+            let table = fetch_workloads(kube_client, &target_namespaces, Utc::now()).await;
+
+            tx.send(WorkloadResponse::Table(table).into())
+                .expect("Failed to send WorkloadResponse::Table");
+        }
+
+        WorkerResult::Terminated
+    }
+}
+
+/// テーブルの1行分の値
+struct WorkloadRow<'a> {
+    kind: WorkloadKind,
+    metadata: &'a ObjectMeta,
+    desired: Option<i32>,
+    ready: Option<i32>,
+    up_to_date: Option<i32>,
+    available: Option<i32>,
+    template: Option<&'a PodTemplateSpec>,
+}
+
+impl WorkloadRow<'_> {
+    fn to_kube_table_row(
+        &self,
+        namespace: &str,
+        insert_ns: bool,
+        now: DateTime<Utc>,
+    ) -> KubeTableRow {
+        let count = |value: Option<i32>| value.map_or_else(|| "-".to_string(), |v| v.to_string());
+
+        let name = self.metadata.name.clone().unwrap_or_default();
+
+        let mut row = vec![
+            self.kind.to_string(),
+            name.to_string(),
+            count(self.desired),
+            count(self.ready),
+            count(self.up_to_date),
+            count(self.available),
+            template_images(self.template).join(","),
+            self.metadata
+                .creation_timestamp
+                .as_ref()
+                .map(|t| format_age(now - t.0))
+                .unwrap_or_default(),
+        ];
+
+        if insert_ns {
+            row.insert(0, namespace.to_string());
+        }
+
+        let metadata = BTreeMap::from([
+            ("kind".to_string(), self.kind.to_string()),
+            (
+                API_RESOURCE_KEY.to_string(),
+                serde_json::to_string(&self.kind.api_resource()).unwrap_or_default(),
+            ),
+        ]);
+
+        KubeTableRow {
+            namespace: namespace.to_string(),
+            name,
+            metadata: Some(metadata),
+            row,
+        }
+    }
+}
+
+/// Podテンプレートのコンテナイメージ
+pub fn template_images(template: Option<&PodTemplateSpec>) -> Vec<String> {
+    template
+        .and_then(|t| t.spec.as_ref())
+        .map(|spec| {
+            spec.containers
+                .iter()
+                .filter_map(|c| c.image.clone())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn deployment_row(deployment: &Deployment) -> WorkloadRow<'_> {
+    let status = deployment.status.as_ref();
+
+    WorkloadRow {
+        kind: WorkloadKind::Deployment,
+        metadata: &deployment.metadata,
+        desired: Some(
+            deployment
+                .spec
+                .as_ref()
+                .and_then(|s| s.replicas)
+                .unwrap_or(1),
+        ),
+        ready: Some(status.and_then(|s| s.ready_replicas).unwrap_or(0)),
+        up_to_date: Some(status.and_then(|s| s.updated_replicas).unwrap_or(0)),
+        available: Some(status.and_then(|s| s.available_replicas).unwrap_or(0)),
+        template: deployment.spec.as_ref().map(|s| &s.template),
+    }
+}
+
+fn statefulset_row(statefulset: &StatefulSet) -> WorkloadRow<'_> {
+    let status = statefulset.status.as_ref();
+
+    WorkloadRow {
+        kind: WorkloadKind::StatefulSet,
+        metadata: &statefulset.metadata,
+        desired: Some(
+            statefulset
+                .spec
+                .as_ref()
+                .and_then(|s| s.replicas)
+                .unwrap_or(1),
+        ),
+        ready: Some(status.and_then(|s| s.ready_replicas).unwrap_or(0)),
+        up_to_date: Some(status.and_then(|s| s.updated_replicas).unwrap_or(0)),
+        available: Some(status.and_then(|s| s.available_replicas).unwrap_or(0)),
+        template: statefulset.spec.as_ref().map(|s| &s.template),
+    }
+}
+
+fn daemonset_row(daemonset: &DaemonSet) -> WorkloadRow<'_> {
+    let status = daemonset.status.as_ref();
+
+    WorkloadRow {
+        kind: WorkloadKind::DaemonSet,
+        metadata: &daemonset.metadata,
+        desired: status.map(|s| s.desired_number_scheduled),
+        ready: status.map(|s| s.number_ready),
+        up_to_date: Some(status.and_then(|s| s.updated_number_scheduled).unwrap_or(0)),
+        available: Some(status.and_then(|s| s.number_available).unwrap_or(0)),
+        template: daemonset.spec.as_ref().map(|s| &s.template),
+    }
+}
+
+/// Jobは完了数を DESIRED / READY に表示する
+fn job_row(job: &Job) -> WorkloadRow<'_> {
+    WorkloadRow {
+        kind: WorkloadKind::Job,
+        metadata: &job.metadata,
+        desired: Some(job.spec.as_ref().and_then(|s| s.completions).unwrap_or(1)),
+        ready: Some(job.status.as_ref().and_then(|s| s.succeeded).unwrap_or(0)),
+        up_to_date: None,
+        available: None,
+        template: job.spec.as_ref().map(|s| &s.template),
+    }
+}
+
+/// CronJobは実行中のJobの数を READY に表示する
+fn cronjob_row(cronjob: &CronJob) -> WorkloadRow<'_> {
+    WorkloadRow {
+        kind: WorkloadKind::CronJob,
+        metadata: &cronjob.metadata,
+        desired: None,
+        ready: Some(
+            cronjob
+                .status
+                .as_ref()
+                .and_then(|s| s.active.as_ref())
+                .map_or(0, |active| active.len() as i32),
+        ),
+        up_to_date: None,
+        available: None,
+        template: cronjob
+            .spec
+            .as_ref()
+            .and_then(|s| s.job_template.spec.as_ref())
+            .map(|s| &s.template),
+    }
+}
+
+async fn fetch_list<C, K>(client: &C, namespace: &str) -> Result<List<K>>
+where
+    C: KubeClientRequest,
+    K: Resource<DynamicType = ()> + ListableResource + DeserializeOwned + Clone + 'static,
+{
+    let path = K::url_path(&(), Some(namespace));
+
+    client
+        .request(&path)
+        .await
+        .map_err(|err| explain_forbidden("list", &path, err))
+}
+
+async fn fetch_workloads_per_namespace<C: KubeClientRequest>(
+    client: &C,
+    namespace: &str,
+    insert_ns: bool,
+    now: DateTime<Utc>,
+) -> Result<Vec<KubeTableRow>> {
+    let (deployments, statefulsets, daemonsets, jobs, cronjobs) = futures::try_join!(
+        fetch_list::<_, Deployment>(client, namespace),
+        fetch_list::<_, StatefulSet>(client, namespace),
+        fetch_list::<_, DaemonSet>(client, namespace),
+        fetch_list::<_, Job>(client, namespace),
+        fetch_list::<_, CronJob>(client, namespace),
+    )?;
+
+    let rows = deployments
+        .items
+        .iter()
+        .map(deployment_row)
+        .chain(statefulsets.items.iter().map(statefulset_row))
+        .chain(daemonsets.items.iter().map(daemonset_row))
+        .chain(jobs.items.iter().map(job_row))
+        .chain(cronjobs.items.iter().map(cronjob_row))
+        .map(|row| row.to_kube_table_row(namespace, insert_ns, now))
+        .collect();
+
+    Ok(rows)
+}
+
+async fn fetch_workloads<C: KubeClientRequest>(
+    client: &C,
+    namespaces: &[String],
+    now: DateTime<Utc>,
+) -> Result<KubeTable> {
+    let insert_ns = insert_ns(namespaces);
+
+    let mut header: Vec<String> = HEADER.iter().map(ToString::to_string).collect();
+
+    if insert_ns {
+        header.insert(0, "NAMESPACE".to_string());
+    }
+
+    let mut table = KubeTable {
+        header,
+        ..Default::default()
+    };
+
+    let jobs = join_all(
+        namespaces
+            .iter()
+            .map(|ns| fetch_workloads_per_namespace(client, ns, insert_ns, now)),
+    )
+    .await;
+
+    let (rows, skipped) = partition_namespaces(namespaces.iter().cloned().zip(jobs))?;
+
+    table.skipped = skipped;
+
+    table.update_rows(rows.into_iter().flatten().collect());
+
+    Ok(table)
+}
+
+#[cfg(test)]
+mod tests {
+    use kube::core::ErrorResponse;
+    use mockall::predicate::{always, eq};
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use crate::{kube::mock::MockTestKubeClient, mock_expect};
+
+    use super::*;
+
+    fn now() -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339("2024-01-02T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    fn list<K: ListableResource + DeserializeOwned + Clone>(items: serde_json::Value) -> List<K> {
+        serde_json::from_value(json!({ "metadata": {}, "items": items })).unwrap()
+    }
+
+    #[tokio::test]
+    async fn コントローラーごとに行を作成する() {
+        let mut client = MockTestKubeClient::new();
+
+        mock_expect!(
+            client,
+            request,
+            [
+                (
+                    List<Deployment>,
+                    eq("/apis/apps/v1/namespaces/default/deployments"),
+                    Ok(list(json!([{
+                        "metadata": { "name": "web", "creationTimestamp": "2024-01-01T00:00:00Z" },
+                        "spec": {
+                            "replicas": 3,
+                            "selector": {},
+                            "template": { "spec": { "containers": [
+                                { "name": "app", "image": "nginx:1.25" },
+                                { "name": "proxy", "image": "envoy:1.29" }
+                            ] } }
+                        },
+                        "status": { "readyReplicas": 2, "updatedReplicas": 3, "availableReplicas": 2 }
+                    }])))
+                ),
+                (
+                    List<StatefulSet>,
+                    eq("/apis/apps/v1/namespaces/default/statefulsets"),
+                    Ok(list(json!([])))
+                ),
+                (
+                    List<DaemonSet>,
+                    eq("/apis/apps/v1/namespaces/default/daemonsets"),
+                    Ok(list(json!([{
+                        "metadata": { "name": "agent", "creationTimestamp": "2024-01-01T23:00:00Z" },
+                        "spec": {
+                            "selector": {},
+                            "template": { "spec": { "containers": [{ "name": "agent", "image": "agent:1" }] } }
+                        },
+                        "status": {
+                            "currentNumberScheduled": 2,
+                            "desiredNumberScheduled": 2,
+                            "numberMisscheduled": 0,
+                            "numberReady": 2,
+                            "updatedNumberScheduled": 2,
+                            "numberAvailable": 2
+                        }
+                    }])))
+                ),
+                (
+                    List<Job>,
+                    eq("/apis/batch/v1/namespaces/default/jobs"),
+                    Ok(list(json!([{
+                        "metadata": { "name": "migrate", "creationTimestamp": "2024-01-01T23:59:00Z" },
+                        "spec": {
+                            "template": { "spec": { "containers": [{ "name": "migrate", "image": "migrate:1" }] } }
+                        },
+                        "status": { "succeeded": 1 }
+                    }])))
+                ),
+                (
+                    List<CronJob>,
+                    eq("/apis/batch/v1/namespaces/default/cronjobs"),
+                    Ok(list(json!([{
+                        "metadata": { "name": "backup", "creationTimestamp": "2023-12-01T00:00:00Z" },
+                        "spec": {
+                            "schedule": "0 0 * * *",
+                            "jobTemplate": { "spec": {
+                                "template": { "spec": { "containers": [{ "name": "backup", "image": "backup:1" }] } }
+                            } }
+                        },
+                        "status": { "active": [{ "name": "backup-1" }] }
+                    }])))
+                )
+            ]
+        );
+
+        let actual = fetch_workloads(&client, &["default".to_string()], now())
+            .await
+            .unwrap();
+
+        let expected = vec![
+            vec![
+                "Deployment",
+                "web",
+                "3",
+                "2",
+                "3",
+                "2",
+                "nginx:1.25,envoy:1.29",
+                "24h",
+            ],
+            vec!["DaemonSet", "agent", "2", "2", "2", "2", "agent:1", "60m"],
+            vec!["Job", "migrate", "1", "1", "-", "-", "migrate:1", "60s"],
+            vec!["CronJob", "backup", "-", "1", "-", "-", "backup:1", "32d"],
+        ];
+
+        assert_eq!(
+            actual
+                .rows
+                .iter()
+                .map(|r| r.row.clone())
+                .collect::<Vec<_>>(),
+            expected
+        );
+
+        assert_eq!(
+            actual.rows[0]
+                .metadata
+                .as_ref()
+                .and_then(|m| m.get("kind"))
+                .map(String::as_str),
+            Some("Deployment")
+        );
+    }
+
+    #[tokio::test]
+    async fn 取得できないネームスペースを除外する() {
+        let mut client = MockTestKubeClient::new();
+
+        mock_expect!(
+            client,
+            request,
+            [
+                (
+                    List<Deployment>,
+                    eq("/apis/apps/v1/namespaces/default/deployments"),
+                    Ok(list(json!([{
+                        "metadata": { "name": "web", "creationTimestamp": "2024-01-01T00:00:00Z" },
+                        "spec": {
+                            "selector": {},
+                            "template": { "spec": { "containers": [{ "name": "app", "image": "nginx:1.25" }] } }
+                        }
+                    }])))
+                ),
+                (
+                    List<Deployment>,
+                    eq("/apis/apps/v1/namespaces/kube-system/deployments"),
+                    Err(kube::Error::Api(ErrorResponse {
+                        status: "Failure".into(),
+                        message: "forbidden".into(),
+                        reason: "Forbidden".into(),
+                        code: 403,
+                    })
+                    .into())
+                ),
+                (List<StatefulSet>, always(), Ok(list(json!([])))),
+                (List<DaemonSet>, always(), Ok(list(json!([])))),
+                (List<Job>, always(), Ok(list(json!([])))),
+                (List<CronJob>, always(), Ok(list(json!([]))))
+            ]
+        );
+
+        let actual = fetch_workloads(
+            &client,
+            &["default".to_string(), "kube-system".to_string()],
+            now(),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            actual
+                .rows
+                .iter()
+                .map(|r| (r.namespace.as_str(), r.name.as_str()))
+                .collect::<Vec<_>>(),
+            vec![("default", "web")]
+        );
+
+        assert_eq!(
+            actual
+                .skipped
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>(),
+            vec!["kube-system (forbidden: cannot list deployments.apps)"]
+        );
+    }
+}
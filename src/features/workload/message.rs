@@ -0,0 +1,111 @@
+use anyhow::Result;
+use kube::discovery::Scope;
+use strum::{Display, EnumString};
+
+use crate::{
+    features::api_resources::kube::ApiResource, kube::table::KubeTable, message::Message,
+    workers::kube::message::Kube,
+};
+
+/// Workloadsタブで表示するコントローラーの種類
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Display, EnumString)]
+pub enum WorkloadKind {
+    Deployment,
+    StatefulSet,
+    DaemonSet,
+    Job,
+    CronJob,
+}
+
+impl WorkloadKind {
+    pub fn api_resource(&self) -> ApiResource {
+        let (name, group) = match self {
+            Self::Deployment => ("deployments", "apps"),
+            Self::StatefulSet => ("statefulsets", "apps"),
+            Self::DaemonSet => ("daemonsets", "apps"),
+            Self::Job => ("jobs", "batch"),
+            Self::CronJob => ("cronjobs", "batch"),
+        };
+
+        ApiResource::Apis {
+            name: name.to_string(),
+            group: group.to_string(),
+            version: "v1".to_string(),
+            preferred_version: true,
+            scope: Scope::Namespaced,
+        }
+    }
+
+    /// ログクエリの `<resource>/<name>` で指定するリソース名（CronJobは指定できない）
+    pub fn log_resource(&self) -> Option<&'static str> {
+        match self {
+            Self::Deployment => Some("deployment"),
+            Self::StatefulSet => Some("statefulset"),
+            Self::DaemonSet => Some("daemonset"),
+            Self::Job => Some("job"),
+            Self::CronJob => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorkloadTarget {
+    pub kind: WorkloadKind,
+    pub namespace: String,
+    pub name: String,
+}
+
+/// Podテンプレートの差分の比較対象
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiffBase {
+    /// ひとつ前のリビジョン
+    Previous,
+    /// Deploymentの現在のテンプレート
+    Current,
+}
+
+#[derive(Debug, Clone)]
+pub enum WorkloadRequest {
+    Description(WorkloadTarget),
+    Diff {
+        target: WorkloadTarget,
+        revision: i64,
+        base: DiffBase,
+    },
+    /// CronJobからJobを作成する
+    Trigger(WorkloadTarget),
+}
+
+#[derive(Debug)]
+pub enum WorkloadResponse {
+    Table(Result<KubeTable>),
+    Description(Result<Vec<String>>),
+    Progress(String),
+    History(Result<KubeTable>),
+    Diff(Result<Vec<String>>),
+    Trigger(Result<String>),
+}
+
+#[derive(Debug)]
+pub enum WorkloadMessage {
+    Request(WorkloadRequest),
+    Response(WorkloadResponse),
+}
+
+impl From<WorkloadMessage> for Message {
+    fn from(m: WorkloadMessage) -> Self {
+        Self::Kube(Kube::Workload(m))
+    }
+}
+
+impl From<WorkloadRequest> for Message {
+    fn from(req: WorkloadRequest) -> Self {
+        WorkloadMessage::Request(req).into()
+    }
+}
+
+impl From<WorkloadResponse> for Message {
+    fn from(res: WorkloadResponse) -> Self {
+        WorkloadMessage::Response(res).into()
+    }
+}
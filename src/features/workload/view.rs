@@ -0,0 +1,5 @@
+mod state;
+mod tab;
+mod widgets;
+
+pub use tab::*;
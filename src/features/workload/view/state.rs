@@ -0,0 +1,6 @@
+use std::{cell::RefCell, rc::Rc};
+
+use crate::features::workload::message::WorkloadTarget;
+
+/// Jobの作成を確認しているCronJob（Workloadテーブルと確認ダイアログで共有する）
+pub(super) type PendingTrigger = Rc<RefCell<Option<WorkloadTarget>>>;
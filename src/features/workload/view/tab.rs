@@ -0,0 +1,86 @@
+use std::{cell::RefCell, rc::Rc};
+
+use crossbeam::channel::Sender;
+use ratatui::layout::{Constraint, Direction};
+
+use crate::{
+    clipboard::Clipboard,
+    features::component_id::{WORKLOAD_TAB_ID, WORKLOAD_WIDGET_ID},
+    message::Message,
+    ui::{
+        dialog::OnCloseCallback,
+        tab::{LayoutElement, NestedLayoutElement, NestedWidgetLayout, TabLayout},
+        widget::Widget,
+        Tab,
+    },
+};
+
+use super::{
+    state::PendingTrigger,
+    widgets::{
+        description_widget, diff_widget, on_close_trigger_dialog, rollout_widget, trigger_widget,
+        workload_widget,
+    },
+};
+
+pub struct WorkloadTab {
+    pub tab: Tab<'static>,
+    pub diff_dialog: Widget<'static>,
+    pub trigger_dialog: Widget<'static>,
+    pub on_close_trigger_dialog: OnCloseCallback,
+}
+
+impl WorkloadTab {
+    pub fn new(
+        title: &'static str,
+        tx: &Sender<Message>,
+        clipboard: &Option<Rc<RefCell<Clipboard>>>,
+        split_direction: Direction,
+        read_only: bool,
+    ) -> Self {
+        let pending_trigger = PendingTrigger::default();
+
+        let workload_widget = workload_widget(tx, read_only, &pending_trigger);
+        let description_widget = description_widget(clipboard);
+        let rollout_widget = rollout_widget(tx);
+
+        let layout = TabLayout::new(layout, split_direction);
+
+        let mut tab = Tab::new(
+            WORKLOAD_TAB_ID,
+            title,
+            [workload_widget, description_widget, rollout_widget],
+            layout,
+        );
+
+        tab.activate_widget_by_id(WORKLOAD_WIDGET_ID);
+
+        Self {
+            tab,
+            diff_dialog: diff_widget(clipboard),
+            trigger_dialog: trigger_widget(tx, &pending_trigger),
+            on_close_trigger_dialog: on_close_trigger_dialog(&pending_trigger),
+        }
+    }
+}
+
+fn layout(split_direction: Direction) -> NestedWidgetLayout {
+    let detail_layout = NestedLayoutElement(
+        Constraint::Percentage(50),
+        LayoutElement::NestedElement(
+            NestedWidgetLayout::default()
+                .direction(Direction::Vertical)
+                .nested_widget_layout([
+                    NestedLayoutElement(Constraint::Percentage(60), LayoutElement::WidgetIndex(1)),
+                    NestedLayoutElement(Constraint::Percentage(40), LayoutElement::WidgetIndex(2)),
+                ]),
+        ),
+    );
+
+    NestedWidgetLayout::default()
+        .direction(split_direction)
+        .nested_widget_layout([
+            NestedLayoutElement(Constraint::Percentage(50), LayoutElement::WidgetIndex(0)),
+            detail_layout,
+        ])
+}
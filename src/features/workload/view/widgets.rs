@@ -0,0 +1,11 @@
+mod description;
+mod diff;
+mod rollout;
+mod trigger;
+mod workload;
+
+pub(super) use description::*;
+pub(super) use diff::*;
+pub(super) use rollout::*;
+pub(super) use trigger::*;
+pub(super) use workload::*;
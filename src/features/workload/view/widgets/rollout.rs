@@ -0,0 +1,89 @@
+use crossbeam::channel::Sender;
+
+use crate::{
+    features::{
+        component_id::{WORKLOAD_DIFF_DIALOG_ID, WORKLOAD_ROLLOUT_WIDGET_ID},
+        workload::message::{DiffBase, WorkloadKind, WorkloadRequest, WorkloadTarget},
+    },
+    message::Message,
+    ui::{
+        event::EventResult,
+        widget::{SelectedItem, Table, TableItem, Widget, WidgetBase, WidgetTrait as _},
+        Window,
+    },
+};
+
+/// DeploymentのReplicaSetをリビジョンとして表示する
+///
+/// タイトルにロールアウトの進捗を表示する
+pub fn rollout_widget(tx: &Sender<Message>) -> Widget<'static> {
+    Table::builder()
+        .id(WORKLOAD_ROLLOUT_WIDGET_ID)
+        .widget_base(WidgetBase::builder().title("Rollout").build())
+        .on_select(on_select(tx.clone()))
+        .action('d', diff_with_current(tx.clone()))
+        .build()
+        .into()
+}
+
+fn diff_request(v: &TableItem, base: DiffBase) -> Option<WorkloadRequest> {
+    let metadata = v.metadata.as_ref()?;
+
+    let (Some(namespace), Some(name), Some(revision)) = (
+        metadata.get("namespace"),
+        metadata.get("name"),
+        metadata.get("revision").and_then(|r| r.parse().ok()),
+    ) else {
+        return None;
+    };
+
+    Some(WorkloadRequest::Diff {
+        target: WorkloadTarget {
+            kind: WorkloadKind::Deployment,
+            namespace: namespace.to_string(),
+            name: name.to_string(),
+        },
+        revision,
+        base,
+    })
+}
+
+fn open_diff(tx: &Sender<Message>, w: &mut Window, req: WorkloadRequest) {
+    tx.send(req.into())
+        .expect("Failed to send WorkloadRequest::Diff");
+
+    w.widget_clear(WORKLOAD_DIFF_DIALOG_ID);
+    w.open_dialog(WORKLOAD_DIFF_DIALOG_ID);
+}
+
+/// 選択したリビジョンとその前のリビジョンのPodテンプレートの差分を表示する
+fn on_select(tx: Sender<Message>) -> impl Fn(&mut Window, &TableItem) -> EventResult {
+    move |w: &mut Window, v: &TableItem| {
+        let Some(req) = diff_request(v, DiffBase::Previous) else {
+            return EventResult::Ignore;
+        };
+
+        open_diff(&tx, w, req);
+
+        EventResult::Nop
+    }
+}
+
+/// 選択したリビジョンと現在のPodテンプレートの差分を表示する
+fn diff_with_current(tx: Sender<Message>) -> impl Fn(&mut Window) -> EventResult {
+    move |w: &mut Window| {
+        let widget = w.find_widget(WORKLOAD_ROLLOUT_WIDGET_ID);
+
+        let Some(SelectedItem::TableRow { metadata, item }) = widget.widget_item() else {
+            return EventResult::Ignore;
+        };
+
+        let Some(req) = diff_request(&TableItem { metadata, item }, DiffBase::Current) else {
+            return EventResult::Ignore;
+        };
+
+        open_diff(&tx, w, req);
+
+        EventResult::Nop
+    }
+}
@@ -0,0 +1,74 @@
+use crossbeam::channel::Sender;
+
+use crate::{
+    features::{
+        component_id::WORKLOAD_TRIGGER_DIALOG_ID,
+        workload::{message::WorkloadRequest, view::state::PendingTrigger},
+    },
+    message::Message,
+    ui::{
+        dialog::OnCloseCallback,
+        event::EventResult,
+        widget::{Item, Text, Widget, WidgetBase, WidgetTrait as _},
+        Window,
+    },
+};
+
+/// CronJobからJobを作成するか確認し、作成した結果を表示するダイアログ
+///
+/// yで作成し、nで取り消す。ダイアログを開いている間は、y/nを他のアクションに渡さない
+pub fn trigger_widget(tx: &Sender<Message>, pending_trigger: &PendingTrigger) -> Widget<'static> {
+    Text::builder()
+        .id(WORKLOAD_TRIGGER_DIALOG_ID)
+        .widget_base(WidgetBase::builder().title("Trigger").build())
+        .action('y', trigger_cronjob(tx.clone(), pending_trigger.clone()))
+        .action('n', cancel_trigger(pending_trigger.clone()))
+        .build()
+        .into()
+}
+
+fn trigger_cronjob(
+    tx: Sender<Message>,
+    pending_trigger: PendingTrigger,
+) -> impl Fn(&mut Window) -> EventResult {
+    move |w: &mut Window| {
+        // 作成の結果を表示しているときは何もしない
+        let Some(target) = pending_trigger.borrow_mut().take() else {
+            return EventResult::Nop;
+        };
+
+        w.find_widget_mut(WORKLOAD_TRIGGER_DIALOG_ID)
+            .update_widget_item(Item::Array(vec![format!(
+                "Creating job from cronjob/{}/{} ...",
+                target.namespace, target.name
+            )
+            .into()]));
+
+        tx.send(WorkloadRequest::Trigger(target).into())
+            .expect("Failed to send WorkloadRequest::Trigger");
+
+        EventResult::Nop
+    }
+}
+
+fn cancel_trigger(pending_trigger: PendingTrigger) -> impl Fn(&mut Window) -> EventResult {
+    move |w: &mut Window| {
+        if pending_trigger.borrow().is_none() {
+            return EventResult::Nop;
+        }
+
+        // 確認中のCronJobはダイアログを閉じたときに取り消す
+        w.close_dialog();
+
+        EventResult::Nop
+    }
+}
+
+/// Escなどでダイアログを閉じたときも、確認中のCronJobを取り消す
+pub fn on_close_trigger_dialog(pending_trigger: &PendingTrigger) -> OnCloseCallback {
+    let pending_trigger = pending_trigger.clone();
+
+    OnCloseCallback::new(move || {
+        pending_trigger.borrow_mut().take();
+    })
+}
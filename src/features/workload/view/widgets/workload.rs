@@ -0,0 +1,192 @@
+use std::{collections::BTreeMap, str::FromStr as _};
+
+use crossbeam::channel::Sender;
+
+use crate::{
+    features::{
+        component_id::{
+            POD_LOG_QUERY_WIDGET_ID, POD_LOG_WIDGET_ID, POD_TAB_ID, WORKLOAD_DESCRIPTION_WIDGET_ID,
+            WORKLOAD_ROLLOUT_WIDGET_ID, WORKLOAD_TRIGGER_DIALOG_ID, WORKLOAD_WIDGET_ID,
+        },
+        pod::{
+            kube::{LogConfig, LogPrefixType},
+            message::LogMessage,
+        },
+        workload::{
+            message::{WorkloadKind, WorkloadRequest, WorkloadTarget},
+            view::state::PendingTrigger,
+        },
+    },
+    kube::context::Namespace,
+    message::Message,
+    ui::{
+        event::EventResult,
+        widget::{Item, SelectedItem, Table, TableItem, Widget, WidgetBase, WidgetTrait as _},
+        Window, WindowAction,
+    },
+};
+
+/// コントローラーの一覧
+///
+/// Enterで説明（Deploymentはロールアウトも）を表示し、lでPodタブにログを表示する
+///
+/// 読み取り専用モードでないときは、TでCronJobからJobを作成する確認ダイアログを開く
+pub fn workload_widget(
+    tx: &Sender<Message>,
+    read_only: bool,
+    pending_trigger: &PendingTrigger,
+) -> Widget<'static> {
+    let builder = Table::builder()
+        .id(WORKLOAD_WIDGET_ID)
+        .widget_base(WidgetBase::builder().title("Workload").build())
+        .filtered_key("NAME")
+        .block_injection(block_injection())
+        .on_select(on_select(tx.clone()))
+        .action('l', open_logs(tx.clone()));
+
+    if read_only {
+        builder
+    } else {
+        builder.action('T', confirm_trigger(pending_trigger.clone()))
+    }
+    .build()
+    .into()
+}
+
+fn block_injection() -> impl Fn(&Table) -> WidgetBase {
+    |table: &Table| {
+        let index = if let Some(index) = table.state().selected() {
+            index + 1
+        } else {
+            0
+        };
+
+        let mut base = table.widget_base().clone();
+
+        *base.append_title_mut() = Some(format!(" [{}/{}]", index, table.items().len()).into());
+
+        base
+    }
+}
+
+fn workload_target(item: &TableItem) -> Option<WorkloadTarget> {
+    workload_target_from_metadata(item.metadata.as_ref()?)
+}
+
+fn workload_target_from_metadata(metadata: &BTreeMap<String, String>) -> Option<WorkloadTarget> {
+    let kind = WorkloadKind::from_str(metadata.get("kind")?).ok()?;
+
+    Some(WorkloadTarget {
+        kind,
+        namespace: metadata.get("namespace")?.to_string(),
+        name: metadata.get("name")?.to_string(),
+    })
+}
+
+fn on_select(tx: Sender<Message>) -> impl Fn(&mut Window, &TableItem) -> EventResult {
+    move |w: &mut Window, v: &TableItem| {
+        w.widget_clear(WORKLOAD_DESCRIPTION_WIDGET_ID);
+        w.widget_clear(WORKLOAD_ROLLOUT_WIDGET_ID);
+
+        *(w.find_widget_mut(WORKLOAD_ROLLOUT_WIDGET_ID)
+            .widget_base_mut()
+            .append_title_mut()) = None;
+
+        let Some(target) = workload_target(v) else {
+            return EventResult::Ignore;
+        };
+
+        *(w.find_widget_mut(WORKLOAD_DESCRIPTION_WIDGET_ID)
+            .widget_base_mut()
+            .append_title_mut()) = Some((format!(" : {}/{}", target.kind, target.name)).into());
+
+        tx.send(WorkloadRequest::Description(target).into())
+            .expect("Failed to send WorkloadRequest::Description");
+
+        EventResult::WindowAction(WindowAction::Continue)
+    }
+}
+
+/// 選択したコントローラーのログクエリ（例: deployment/web）をPodタブに設定する
+fn open_logs(tx: Sender<Message>) -> impl Fn(&mut Window) -> EventResult {
+    move |w: &mut Window| {
+        let widget = w.find_widget(WORKLOAD_WIDGET_ID);
+
+        let Some(SelectedItem::TableRow {
+            metadata: Some(metadata),
+            ..
+        }) = widget.widget_item()
+        else {
+            return EventResult::Ignore;
+        };
+
+        let (Some(kind), Some(namespace), Some(name)) = (
+            metadata.get("kind"),
+            metadata.get("namespace"),
+            metadata.get("name"),
+        ) else {
+            return EventResult::Ignore;
+        };
+
+        let Some(resource) = WorkloadKind::from_str(kind)
+            .ok()
+            .and_then(|kind| kind.log_resource())
+        else {
+            return EventResult::Ignore;
+        };
+
+        let query = format!("{}/{}", resource, name);
+
+        w.find_widget_mut(POD_LOG_QUERY_WIDGET_ID)
+            .update_widget_item(Item::Single(query.clone().into()));
+
+        w.widget_clear(POD_LOG_WIDGET_ID);
+
+        let config = LogConfig::new(
+            query,
+            Namespace(vec![namespace.to_string()]),
+            LogPrefixType::PodAndContainer,
+        );
+
+        tx.send(LogMessage::Request(config).into())
+            .expect("Failed to send LogMessage::Request");
+
+        w.activate_tab_by_id(POD_TAB_ID);
+
+        EventResult::Nop
+    }
+}
+
+/// 選択したCronJobからJobを作成するか確認するダイアログを開く
+fn confirm_trigger(pending_trigger: PendingTrigger) -> impl Fn(&mut Window) -> EventResult {
+    move |w: &mut Window| {
+        let widget = w.find_widget(WORKLOAD_WIDGET_ID);
+
+        let Some(SelectedItem::TableRow {
+            metadata: Some(metadata),
+            ..
+        }) = widget.widget_item()
+        else {
+            return EventResult::Ignore;
+        };
+
+        let Some(target) = workload_target_from_metadata(&metadata)
+            .filter(|target| target.kind == WorkloadKind::CronJob)
+        else {
+            return EventResult::Ignore;
+        };
+
+        w.find_widget_mut(WORKLOAD_TRIGGER_DIALOG_ID)
+            .update_widget_item(Item::Array(vec![format!(
+                "Create job from cronjob/{}/{}? y/N",
+                target.namespace, target.name
+            )
+            .into()]));
+
+        *pending_trigger.borrow_mut() = Some(target);
+
+        w.open_dialog(WORKLOAD_TRIGGER_DIALOG_ID);
+
+        EventResult::Nop
+    }
+}
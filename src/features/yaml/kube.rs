@@ -1,5 +1,11 @@
+mod compare;
 mod fetch;
+mod filter;
+mod highlight;
 mod worker;
 
+pub use compare::*;
 pub use fetch::*;
+pub use filter::*;
+pub use highlight::*;
 pub use worker::*;
@@ -0,0 +1,370 @@
+use std::sync::{atomic::AtomicBool, Arc};
+
+use anyhow::{anyhow, Result};
+use crossbeam::channel::Sender;
+use serde_yaml::Value;
+use similar::{ChangeTag, TextDiff};
+
+use crate::{
+    features::{
+        api_resources::kube::{ApiResource, SharedApiResources},
+        yaml::message::YamlResponse,
+    },
+    kube::KubeClientRequest,
+    logger,
+    message::Message,
+    workers::kube::{color::fg::Color, AbortWorker},
+};
+
+use super::{filter::LAST_APPLIED_CONFIGURATION, worker::fetch_resource_value, YamlTarget};
+
+/// 比較時に無視するmetadataのフィールド
+const NOISY_METADATA_FIELDS: [&str; 7] = [
+    "uid",
+    "resourceVersion",
+    "managedFields",
+    "creationTimestamp",
+    "generation",
+    "selfLink",
+    "namespace",
+];
+
+const DIFF_CONTEXT_RADIUS: usize = 3;
+
+/// 比較対象
+///
+/// sourceと同じkind/nameのリソースを、指定したcontext/namespaceから取得して比較する
+#[derive(Debug, Clone)]
+pub struct YamlCompareTarget {
+    pub source: YamlTarget,
+    pub context: String,
+    pub namespace: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct YamlCompareWorker<C>
+where
+    C: KubeClientRequest,
+{
+    is_terminated: Arc<AtomicBool>,
+    tx: Sender<Message>,
+    source_context: String,
+    source_client: C,
+    target_client: C,
+    shared_api_resources: SharedApiResources,
+    req: YamlCompareTarget,
+}
+
+impl<C: KubeClientRequest> YamlCompareWorker<C> {
+    pub fn new(
+        is_terminated: Arc<AtomicBool>,
+        tx: Sender<Message>,
+        source_context: String,
+        source_client: C,
+        target_client: C,
+        shared_api_resources: SharedApiResources,
+        req: YamlCompareTarget,
+    ) -> Self {
+        Self {
+            is_terminated,
+            tx,
+            source_context,
+            source_client,
+            target_client,
+            shared_api_resources,
+            req,
+        }
+    }
+
+    async fn compare(&self) -> Result<Vec<String>> {
+        let YamlCompareTarget {
+            source:
+                YamlTarget {
+                    kind,
+                    name,
+                    namespace: source_namespace,
+                },
+            context: target_context,
+            namespace: target_namespace,
+        } = &self.req;
+
+        logger!(
+            info,
+            "Comparing resource [kind={} name={}] {}/{} <=> {}/{}",
+            kind,
+            name,
+            self.source_context,
+            source_namespace,
+            target_context,
+            target_namespace
+        );
+
+        let api = {
+            let api_resources = self.shared_api_resources.read().await;
+
+            api_resources
+                .iter()
+                .find(|api| *api == kind)
+                .cloned()
+                .ok_or_else(|| anyhow!("Can't get {} from API resource", kind))?
+        };
+
+        let mut source = fetch_resource_value(&self.source_client, &api, name, source_namespace)
+            .await
+            .map_err(|e| {
+                anyhow!(
+                    "Failed to fetch {}/{} from {}: {}",
+                    kind,
+                    name,
+                    self.source_context,
+                    e
+                )
+            })?;
+
+        let mut target = fetch_resource_value(&self.target_client, &api, name, target_namespace)
+            .await
+            .map_err(|e| {
+                anyhow!(
+                    "Failed to fetch {}/{} from {}: {}",
+                    kind,
+                    name,
+                    target_context,
+                    e
+                )
+            })?;
+
+        normalize(&mut source);
+        normalize(&mut target);
+
+        let source = serde_yaml::to_string(&source)?;
+        let target = serde_yaml::to_string(&target)?;
+
+        Ok(unified_diff(
+            &source,
+            &target,
+            &location(&api, &self.source_context, source_namespace),
+            &location(&api, target_context, target_namespace),
+        ))
+    }
+}
+
+#[async_trait::async_trait]
+impl<C: KubeClientRequest> AbortWorker for YamlCompareWorker<C> {
+    async fn run(&self) {
+        let mut interval = tokio::time::interval(std::time::Duration::from_secs(3));
+
+        while !self
+            .is_terminated
+            .load(std::sync::atomic::Ordering::Relaxed)
+        {
+            interval.tick().await;
+
+            let diff = self.compare().await;
+
+            self.tx
+                .send(YamlResponse::Compare(diff).into())
+                .expect("Failed to send YamlResponse::Compare");
+        }
+    }
+}
+
+fn location(api: &ApiResource, context: &str, namespace: &str) -> String {
+    if api.is_namespaced() {
+        format!("{}/{}", context, namespace)
+    } else {
+        context.to_string()
+    }
+}
+
+/// 環境ごとに必ず差分がでるフィールドを削除する
+///
+/// - status
+/// - metadata.{uid, resourceVersion, managedFields, creationTimestamp, generation, selfLink, namespace}
+/// - metadata.annotations."kubectl.kubernetes.io/last-applied-configuration"
+pub fn normalize(value: &mut Value) {
+    let Value::Mapping(root) = value else {
+        return;
+    };
+
+    root.remove("status");
+
+    let Some(Value::Mapping(metadata)) = root.get_mut("metadata") else {
+        return;
+    };
+
+    for field in NOISY_METADATA_FIELDS {
+        metadata.remove(field);
+    }
+
+    if let Some(Value::Mapping(annotations)) = metadata.get_mut("annotations") {
+        annotations.remove(LAST_APPLIED_CONFIGURATION);
+
+        if annotations.is_empty() {
+            metadata.remove("annotations");
+        }
+    }
+}
+
+/// 2つのテキストの差分をunified形式で返す
+///
+/// 差分は色付けされたansiエスケープシーケンスを含む
+pub fn unified_diff(old: &str, new: &str, old_header: &str, new_header: &str) -> Vec<String> {
+    let diff = TextDiff::from_lines(old, new);
+
+    let mut lines = vec![
+        Color::Red.wrap(format!("--- {}", old_header)),
+        Color::Green.wrap(format!("+++ {}", new_header)),
+    ];
+
+    let hunks: Vec<_> = diff
+        .unified_diff()
+        .context_radius(DIFF_CONTEXT_RADIUS)
+        .iter_hunks()
+        .collect();
+
+    if hunks.is_empty() {
+        lines.push(String::default());
+        lines.push("No differences found.".to_string());
+
+        return lines;
+    }
+
+    for hunk in hunks {
+        lines.push(Color::Cyan.wrap(hunk.header().to_string()));
+
+        for change in hunk.iter_changes() {
+            let value = change.as_str().unwrap_or_default().trim_end_matches('\n');
+
+            let line = match change.tag() {
+                ChangeTag::Delete => Color::Red.wrap(format!("-{}", value)),
+                ChangeTag::Insert => Color::Green.wrap(format!("+{}", value)),
+                ChangeTag::Equal => format!(" {}", value),
+            };
+
+            lines.push(line);
+        }
+    }
+
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use indoc::indoc;
+
+    mod normalize {
+        use super::*;
+        use pretty_assertions::assert_eq;
+
+        #[test]
+        fn 環境依存のフィールドを削除する() {
+            let mut value: Value = serde_yaml::from_str(indoc! {
+                r#"
+                apiVersion: v1
+                kind: ConfigMap
+                metadata:
+                  name: app
+                  namespace: staging
+                  uid: 2c4a1c2b-3f7f-4f55-9a9d-0f6d3c1c7c0e
+                  resourceVersion: "1234"
+                  generation: 2
+                  creationTimestamp: "2024-01-01T00:00:00Z"
+                  managedFields:
+                  - manager: kubectl
+                  labels:
+                    app: app
+                  annotations:
+                    kubectl.kubernetes.io/last-applied-configuration: "{}"
+                data:
+                  key: value
+                status:
+                  phase: Active
+                "#
+            })
+            .unwrap();
+
+            normalize(&mut value);
+
+            let expected: Value = serde_yaml::from_str(indoc! {
+                r#"
+                apiVersion: v1
+                kind: ConfigMap
+                metadata:
+                  name: app
+                  labels:
+                    app: app
+                data:
+                  key: value
+                "#
+            })
+            .unwrap();
+
+            assert_eq!(value, expected);
+        }
+
+        #[test]
+        fn last_applied_configuration以外のannotationは残す() {
+            let mut value: Value = serde_yaml::from_str(indoc! {
+                r#"
+                metadata:
+                  name: app
+                  annotations:
+                    kubectl.kubernetes.io/last-applied-configuration: "{}"
+                    owner: team-a
+                "#
+            })
+            .unwrap();
+
+            normalize(&mut value);
+
+            let expected: Value = serde_yaml::from_str(indoc! {
+                r#"
+                metadata:
+                  name: app
+                  annotations:
+                    owner: team-a
+                "#
+            })
+            .unwrap();
+
+            assert_eq!(value, expected);
+        }
+    }
+
+    mod unified_diff {
+        use super::*;
+        use pretty_assertions::assert_eq;
+
+        #[test]
+        fn 差分がないとき差分なしのメッセージを返す() {
+            let actual = unified_diff("a: 1\n", "a: 1\n", "stg/default", "prd/default");
+
+            let expected = vec![
+                "\x1b[31m--- stg/default\x1b[39m".to_string(),
+                "\x1b[32m+++ prd/default\x1b[39m".to_string(),
+                "".to_string(),
+                "No differences found.".to_string(),
+            ];
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn 差分があるとき色付きのunified形式で返す() {
+            let actual = unified_diff("a: 1\nb: 2\n", "a: 1\nb: 3\n", "stg/default", "prd/default");
+
+            let expected = vec![
+                "\x1b[31m--- stg/default\x1b[39m".to_string(),
+                "\x1b[32m+++ prd/default\x1b[39m".to_string(),
+                "\x1b[36m@@ -1,2 +1,2 @@\x1b[39m".to_string(),
+                " a: 1".to_string(),
+                "\x1b[31m-b: 2\x1b[39m".to_string(),
+                "\x1b[32m+b: 3\x1b[39m".to_string(),
+            ];
+
+            assert_eq!(actual, expected);
+        }
+    }
+}
@@ -0,0 +1,155 @@
+use std::sync::Arc;
+
+use serde::Deserialize;
+use serde_yaml::Value;
+use tokio::sync::RwLock;
+
+pub const LAST_APPLIED_CONFIGURATION: &str = "kubectl.kubernetes.io/last-applied-configuration";
+
+pub type SharedYamlFilter = Arc<RwLock<YamlFilter>>;
+
+/// yaml表示時に取り除くフィールドの設定
+///
+/// - hide_noisy_fields: metadata.managedFieldsとlast-applied-configurationのannotationを取り除く
+/// - hide_status: hide_noisy_fieldsが有効なとき、statusも取り除く
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(default)]
+pub struct YamlFilter {
+    pub hide_noisy_fields: bool,
+    pub hide_status: bool,
+}
+
+impl Default for YamlFilter {
+    fn default() -> Self {
+        Self {
+            hide_noisy_fields: true,
+            hide_status: false,
+        }
+    }
+}
+
+impl YamlFilter {
+    pub fn shared(self) -> SharedYamlFilter {
+        Arc::new(RwLock::new(self))
+    }
+
+    pub fn toggle(&mut self) {
+        self.hide_noisy_fields = !self.hide_noisy_fields;
+    }
+
+    pub fn apply(&self, value: &mut Value) {
+        if !self.hide_noisy_fields {
+            return;
+        }
+
+        let Value::Mapping(root) = value else {
+            return;
+        };
+
+        if self.hide_status {
+            root.remove("status");
+        }
+
+        let Some(Value::Mapping(metadata)) = root.get_mut("metadata") else {
+            return;
+        };
+
+        metadata.remove("managedFields");
+
+        if let Some(Value::Mapping(annotations)) = metadata.get_mut("annotations") {
+            annotations.remove(LAST_APPLIED_CONFIGURATION);
+
+            if annotations.is_empty() {
+                metadata.remove("annotations");
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    const YAML: &str = indoc! {
+        r#"
+        apiVersion: v1
+        kind: Pod
+        metadata:
+          name: app
+          managedFields:
+          - manager: kubectl
+          annotations:
+            kubectl.kubernetes.io/last-applied-configuration: "{}"
+        spec:
+          containers: []
+        status:
+          phase: Running
+        "#
+    };
+
+    #[test]
+    fn hide_noisy_fieldsが有効なときmanaged_fieldsとlast_applied_configurationを取り除く() {
+        let mut value: Value = serde_yaml::from_str(YAML).unwrap();
+
+        YamlFilter::default().apply(&mut value);
+
+        let expected: Value = serde_yaml::from_str(indoc! {
+            r#"
+            apiVersion: v1
+            kind: Pod
+            metadata:
+              name: app
+            spec:
+              containers: []
+            status:
+              phase: Running
+            "#
+        })
+        .unwrap();
+
+        assert_eq!(value, expected);
+    }
+
+    #[test]
+    fn hide_statusが有効なときstatusも取り除く() {
+        let mut value: Value = serde_yaml::from_str(YAML).unwrap();
+
+        YamlFilter {
+            hide_noisy_fields: true,
+            hide_status: true,
+        }
+        .apply(&mut value);
+
+        let expected: Value = serde_yaml::from_str(indoc! {
+            r#"
+            apiVersion: v1
+            kind: Pod
+            metadata:
+              name: app
+            spec:
+              containers: []
+            "#
+        })
+        .unwrap();
+
+        assert_eq!(value, expected);
+    }
+
+    #[test]
+    fn hide_noisy_fieldsが無効なとき何も取り除かない() {
+        let mut value: Value = serde_yaml::from_str(YAML).unwrap();
+
+        YamlFilter {
+            hide_noisy_fields: false,
+            hide_status: true,
+        }
+        .apply(&mut value);
+
+        let expected: Value = serde_yaml::from_str(YAML).unwrap();
+
+        assert_eq!(value, expected);
+    }
+}
@@ -0,0 +1,277 @@
+use crate::workers::kube::color::fg::Color;
+
+const KEY_COLOR: Color = Color::Cyan;
+const STRING_COLOR: Color = Color::Green;
+const NUMBER_COLOR: Color = Color::Yellow;
+const BOOL_COLOR: Color = Color::Magenta;
+const NULL_COLOR: Color = Color::DarkGray;
+const COMMENT_COLOR: Color = Color::DarkGray;
+
+/// yaml文字列をシンタックスハイライトしたansiエスケープシーケンス付きの行に変換する
+///
+/// - キー、文字列、数値、真偽値、null、コメントを色分けする
+/// - ブロックスカラー（`|`, `>`）の内容は文字列として扱う
+pub fn highlight_yaml(yaml: &str) -> Vec<String> {
+    let mut lines = Vec::new();
+
+    // ブロックスカラーを持つキーのインデント
+    // このインデントより深い行はブロックスカラーの内容になる
+    let mut block_scalar_indent: Option<usize> = None;
+
+    for line in yaml.lines() {
+        let indent = indent_width(line);
+
+        if let Some(parent_indent) = block_scalar_indent {
+            if line.trim().is_empty() {
+                lines.push(line.to_string());
+                continue;
+            }
+
+            if parent_indent < indent {
+                let (spaces, content) = line.split_at(indent);
+                lines.push(format!("{}{}", spaces, STRING_COLOR.wrap(content)));
+                continue;
+            }
+        }
+
+        let (highlighted, block_scalar) = highlight_line(line);
+
+        block_scalar_indent = block_scalar;
+
+        lines.push(highlighted);
+    }
+
+    lines
+}
+
+fn indent_width(line: &str) -> usize {
+    line.len() - line.trim_start_matches(' ').len()
+}
+
+/// 1行分をハイライトする
+///
+/// ブロックスカラーの開始行のときは、キーのインデントを返す
+fn highlight_line(line: &str) -> (String, Option<usize>) {
+    let indent = indent_width(line);
+
+    let (spaces, mut rest) = line.split_at(indent);
+
+    if rest.is_empty() {
+        return (line.to_string(), None);
+    }
+
+    if rest.starts_with('#') || rest == "---" || rest == "..." {
+        return (format!("{}{}", spaces, COMMENT_COLOR.wrap(rest)), None);
+    }
+
+    let mut highlighted = spaces.to_string();
+    let mut key_indent = indent;
+
+    while let Some(r) = rest.strip_prefix("- ") {
+        highlighted.push_str("- ");
+        rest = r;
+        key_indent += 2;
+    }
+
+    if rest == "-" {
+        highlighted.push('-');
+        return (highlighted, None);
+    }
+
+    let value = match split_key(rest) {
+        Some((key, value)) => {
+            highlighted.push_str(&KEY_COLOR.wrap(key));
+            highlighted.push(':');
+
+            if !value.is_empty() {
+                highlighted.push(' ');
+                highlighted.push_str(&highlight_value(value));
+            }
+
+            value
+        }
+        None => {
+            highlighted.push_str(&highlight_value(rest));
+
+            rest
+        }
+    };
+
+    let block_scalar = if value.starts_with('|') || value.starts_with('>') {
+        Some(key_indent)
+    } else {
+        None
+    };
+
+    (highlighted, block_scalar)
+}
+
+/// `key: value` 形式のとき、キーと値に分割する
+fn split_key(s: &str) -> Option<(&str, &str)> {
+    let key_end = match s.chars().next() {
+        Some(quote @ ('"' | '\'')) => quoted_end(s, quote)?,
+        _ => {
+            if let Some(i) = s.find(": ") {
+                i
+            } else if s.ends_with(':') {
+                s.len() - 1
+            } else {
+                return None;
+            }
+        }
+    };
+
+    let after = &s[key_end..];
+
+    if after == ":" {
+        Some((&s[..key_end], ""))
+    } else {
+        after.strip_prefix(": ").map(|value| (&s[..key_end], value))
+    }
+}
+
+/// クォートで始まる文字列の閉じクォートの次の位置を返す
+fn quoted_end(s: &str, quote: char) -> Option<usize> {
+    let mut chars = s.char_indices().skip(1).peekable();
+
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '\\' if quote == '"' => {
+                chars.next();
+            }
+            c if c == quote => {
+                // シングルクォートは '' でエスケープする
+                if quote == '\'' && chars.peek().is_some_and(|(_, c)| *c == '\'') {
+                    chars.next();
+                    continue;
+                }
+
+                return Some(i + c.len_utf8());
+            }
+            _ => {}
+        }
+    }
+
+    None
+}
+
+fn highlight_value(value: &str) -> String {
+    match value {
+        "" => String::default(),
+        "{}" | "[]" => value.to_string(),
+        "null" | "~" => NULL_COLOR.wrap(value),
+        "true" | "false" => BOOL_COLOR.wrap(value),
+        v if v.starts_with('|') || v.starts_with('>') => v.to_string(),
+        v if v.starts_with('"') || v.starts_with('\'') => STRING_COLOR.wrap(v),
+        v if v.parse::<i64>().is_ok() || v.parse::<f64>().is_ok() => NUMBER_COLOR.wrap(v),
+        v => STRING_COLOR.wrap(v),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn キーと値の型ごとに色をつける() {
+        let actual = highlight_yaml(indoc! {
+            r#"
+            metadata:
+              name: app
+              generation: 2
+              deletionTimestamp: null
+              annotations:
+                port: '8080'
+            spec:
+              paused: false
+            "#
+        });
+
+        let expected = vec![
+            "\x1b[36mmetadata\x1b[39m:",
+            "  \x1b[36mname\x1b[39m: \x1b[32mapp\x1b[39m",
+            "  \x1b[36mgeneration\x1b[39m: \x1b[33m2\x1b[39m",
+            "  \x1b[36mdeletionTimestamp\x1b[39m: \x1b[90mnull\x1b[39m",
+            "  \x1b[36mannotations\x1b[39m:",
+            "    \x1b[36mport\x1b[39m: \x1b[32m'8080'\x1b[39m",
+            "\x1b[36mspec\x1b[39m:",
+            "  \x1b[36mpaused\x1b[39m: \x1b[35mfalse\x1b[39m",
+        ];
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn シーケンスのキーと値に色をつける() {
+        let actual = highlight_yaml(indoc! {
+            r#"
+            args:
+            - --port=8080
+            - - 1
+            ports:
+            - name: http
+              containerPort: 80
+            "#
+        });
+
+        let expected = vec![
+            "\x1b[36margs\x1b[39m:",
+            "- \x1b[32m--port=8080\x1b[39m",
+            "- - \x1b[33m1\x1b[39m",
+            "\x1b[36mports\x1b[39m:",
+            "- \x1b[36mname\x1b[39m: \x1b[32mhttp\x1b[39m",
+            "  \x1b[36mcontainerPort\x1b[39m: \x1b[33m80\x1b[39m",
+        ];
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn ブロックスカラーの内容は文字列として扱う() {
+        let actual = highlight_yaml(indoc! {
+            r#"
+            data:
+              config.yaml: |
+                key: value
+
+                # comment
+              other: 1
+            "#
+        });
+
+        let expected = vec![
+            "\x1b[36mdata\x1b[39m:",
+            "  \x1b[36mconfig.yaml\x1b[39m: |",
+            "    \x1b[32mkey: value\x1b[39m",
+            "",
+            "    \x1b[32m# comment\x1b[39m",
+            "  \x1b[36mother\x1b[39m: \x1b[33m1\x1b[39m",
+        ];
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn クォートされたキーとコロンを含む値を扱う() {
+        let actual = highlight_yaml(indoc! {
+            r#"
+            'a: b': c
+            url: http://example.com
+            - 'x: y'
+            # comment
+            "#
+        });
+
+        let expected = vec![
+            "\x1b[36m'a: b'\x1b[39m: \x1b[32mc\x1b[39m",
+            "\x1b[36murl\x1b[39m: \x1b[32mhttp://example.com\x1b[39m",
+            "- \x1b[32m'x: y'\x1b[39m",
+            "\x1b[90m# comment\x1b[39m",
+        ];
+
+        assert_eq!(actual, expected);
+    }
+}
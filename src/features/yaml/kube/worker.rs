@@ -15,6 +15,8 @@ use crate::{
     workers::kube::AbortWorker,
 };
 
+use super::{highlight_yaml, SharedYamlFilter, YamlFilter};
+
 #[derive(Debug, Clone)]
 pub struct YamlTarget {
     pub kind: ApiResource,
@@ -32,6 +34,7 @@ where
     client: C,
     req: YamlTarget,
     shared_api_resources: SharedApiResources,
+    shared_yaml_filter: SharedYamlFilter,
 }
 
 impl<C: KubeClientRequest> YamlWorker<C> {
@@ -40,6 +43,7 @@ impl<C: KubeClientRequest> YamlWorker<C> {
         tx: Sender<Message>,
         client: C,
         shared_api_resources: SharedApiResources,
+        shared_yaml_filter: SharedYamlFilter,
         req: YamlTarget,
     ) -> Self {
         Self {
@@ -48,6 +52,7 @@ impl<C: KubeClientRequest> YamlWorker<C> {
             client,
             req,
             shared_api_resources,
+            shared_yaml_filter,
         }
     }
 }
@@ -70,10 +75,12 @@ impl<C: KubeClientRequest> AbortWorker for YamlWorker<C> {
             interval.tick().await;
 
             let api_resources = self.shared_api_resources.read().await;
+            let yaml_filter = *self.shared_yaml_filter.read().await;
 
             let fetched_data = fetch_resource_yaml(
                 &self.client,
                 &api_resources,
+                &yaml_filter,
                 kind,
                 name.to_string(),
                 namespace.to_string(),
@@ -91,6 +98,7 @@ impl<C: KubeClientRequest> AbortWorker for YamlWorker<C> {
 async fn fetch_resource_yaml<C: KubeClientRequest>(
     client: &C,
     api_resources: &ApiResources,
+    yaml_filter: &YamlFilter,
     kind: &ApiResource,
     name: String,
     ns: String,
@@ -107,6 +115,23 @@ async fn fetch_resource_yaml<C: KubeClientRequest>(
         .iter()
         .find(|api| *api == kind)
         .ok_or_else(|| anyhow!("Can't get {} from API resource", kind))?;
+
+    let mut yaml_data = fetch_resource_value(client, api, &name, &ns).await?;
+
+    yaml_filter.apply(&mut yaml_data);
+
+    let yaml_string = highlight_yaml(&serde_yaml::to_string(&yaml_data)?);
+
+    Ok(yaml_string)
+}
+
+/// リソースを取得してyamlのValueに変換する
+pub async fn fetch_resource_value<C: KubeClientRequest>(
+    client: &C,
+    api: &ApiResource,
+    name: &str,
+    ns: &str,
+) -> Result<Value> {
     // json string data
     let kind = api.name();
     let path = if api.is_namespaced() {
@@ -128,16 +153,7 @@ async fn fetch_resource_yaml<C: KubeClientRequest>(
     logger!(info, "Fetched resource - {}", res);
 
     // yaml dataに変換
-    let mut yaml_data: serde_yaml::Value = serde_json::from_str(&res)?;
-
-    if let Some(Value::Mapping(md)) = yaml_data.get_mut("metadata") {
-        md.remove("managedFields");
-    }
-
-    let yaml_string = serde_yaml::to_string(&yaml_data)?
-        .lines()
-        .map(ToString::to_string)
-        .collect();
+    let yaml_data: Value = serde_json::from_str(&res)?;
 
-    Ok(yaml_string)
+    Ok(yaml_data)
 }
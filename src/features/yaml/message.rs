@@ -4,7 +4,7 @@ use crate::{
     features::api_resources::kube::ApiResource, message::Message, workers::kube::message::Kube,
 };
 
-pub use super::kube::YamlTarget;
+pub use super::kube::{YamlCompareTarget, YamlTarget};
 
 #[derive(Debug, Clone)]
 pub struct YamlResourceListItem {
@@ -30,6 +30,11 @@ pub enum YamlRequest {
     APIs,
     Resource(ApiResource),
     Yaml(YamlTarget),
+    CompareContexts,
+    CompareNamespaces(String),
+    Compare(YamlCompareTarget),
+    CompareClose,
+    ToggleNoisyFields,
 }
 
 impl From<YamlRequest> for Message {
@@ -43,6 +48,9 @@ pub enum YamlResponse {
     APIs(Result<Vec<ApiResource>>),
     Resource(Result<YamlResourceList>),
     Yaml(Result<Vec<String>>),
+    CompareContexts(Vec<String>),
+    CompareNamespaces(Result<Vec<String>>),
+    Compare(Result<Vec<String>>),
 }
 
 impl From<YamlResponse> for Message {
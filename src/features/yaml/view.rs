@@ -1,4 +1,5 @@
 mod dialogs;
+mod state;
 mod tab;
 mod widget;
 
@@ -1,3 +1,6 @@
+pub(super) mod compare;
+pub(super) mod compare_context;
+pub(super) mod compare_namespace;
 pub(super) mod kind;
 pub(super) mod name;
 pub(super) mod not_found;
@@ -0,0 +1,30 @@
+use std::{cell::RefCell, rc::Rc};
+
+use crate::{
+    clipboard::Clipboard,
+    features::component_id::YAML_COMPARE_DIALOG_ID,
+    ui::widget::{Text, Widget, WidgetBase, WidgetTrait as _},
+};
+
+pub fn compare_dialog(clipboard: &Option<Rc<RefCell<Clipboard>>>) -> Widget<'static> {
+    let builder = Text::builder()
+        .id(YAML_COMPARE_DIALOG_ID)
+        .widget_base(WidgetBase::builder().title("Compare").build())
+        .block_injection(|text: &Text, is_active: bool, is_mouse_over: bool| {
+            let (index, size) = text.state();
+
+            let mut base = text.widget_base().clone();
+
+            *base.title_mut() = format!("Compare [{}/{}]", index, size).into();
+
+            base.render_block(text.can_activate() && is_active, is_mouse_over)
+        });
+
+    if let Some(cb) = clipboard {
+        builder.clipboard(cb.clone())
+    } else {
+        builder
+    }
+    .build()
+    .into()
+}
@@ -0,0 +1,87 @@
+use crossbeam::channel::Sender;
+
+use crate::{
+    features::{
+        component_id::{
+            YAML_COMPARE_CONTEXT_DIALOG_ID, YAML_COMPARE_DIALOG_ID,
+            YAML_COMPARE_NAMESPACE_DIALOG_ID,
+        },
+        yaml::{
+            message::{YamlCompareTarget, YamlRequest},
+            view::state::SharedCompareState,
+        },
+    },
+    logger,
+    message::Message,
+    ui::{
+        event::EventResult,
+        widget::{LiteralItem, SingleSelect, Widget, WidgetBase, WidgetTrait as _},
+        Window,
+    },
+};
+
+pub fn compare_context_dialog(
+    tx: &Sender<Message>,
+    compare_state: &SharedCompareState,
+) -> Widget<'static> {
+    let tx = tx.clone();
+
+    SingleSelect::builder()
+        .id(YAML_COMPARE_CONTEXT_DIALOG_ID)
+        .widget_base(WidgetBase::builder().title("Compare Context").build())
+        .on_select(on_select(tx, compare_state.clone()))
+        .build()
+        .into()
+}
+
+fn on_select(
+    tx: Sender<Message>,
+    compare_state: SharedCompareState,
+) -> impl Fn(&mut Window, &LiteralItem) -> EventResult {
+    move |w, v| {
+        logger!(info, "Select Item: {:?}", v);
+
+        w.close_dialog();
+
+        let context = v.item.to_string();
+
+        let Some(source) = compare_state.borrow().source.clone() else {
+            return EventResult::Nop;
+        };
+
+        compare_state.borrow_mut().context = Some(context.to_string());
+
+        // namespaceを持たないリソースはcontextのみで比較する
+        if source.kind.is_namespaced() {
+            tx.send(YamlRequest::CompareNamespaces(context).into())
+                .expect("Failed to send YamlRequest::CompareNamespaces");
+
+            w.widget_clear(YAML_COMPARE_NAMESPACE_DIALOG_ID);
+            w.open_dialog(YAML_COMPARE_NAMESPACE_DIALOG_ID);
+        } else {
+            let title = format!(" : {}/{}", source.kind, source.name);
+
+            let namespace = source.namespace.to_string();
+
+            tx.send(
+                YamlRequest::Compare(YamlCompareTarget {
+                    source,
+                    context,
+                    namespace,
+                })
+                .into(),
+            )
+            .expect("Failed to send YamlRequest::Compare");
+
+            w.widget_clear(YAML_COMPARE_DIALOG_ID);
+
+            *(w.find_widget_mut(YAML_COMPARE_DIALOG_ID)
+                .widget_base_mut()
+                .append_title_mut()) = Some(title.into());
+
+            w.open_dialog(YAML_COMPARE_DIALOG_ID);
+        }
+
+        EventResult::Nop
+    }
+}
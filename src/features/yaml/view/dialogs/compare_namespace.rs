@@ -0,0 +1,89 @@
+use crossbeam::channel::Sender;
+use ratatui::crossterm::event::KeyCode;
+
+use crate::{
+    features::{
+        component_id::{
+            YAML_COMPARE_CONTEXT_DIALOG_ID, YAML_COMPARE_DIALOG_ID,
+            YAML_COMPARE_NAMESPACE_DIALOG_ID,
+        },
+        yaml::{
+            message::{YamlCompareTarget, YamlRequest},
+            view::state::SharedCompareState,
+        },
+    },
+    logger,
+    message::Message,
+    ui::{
+        event::EventResult,
+        widget::{LiteralItem, SingleSelect, Widget, WidgetBase, WidgetTrait as _},
+        Window,
+    },
+};
+
+pub fn compare_namespace_dialog(
+    tx: &Sender<Message>,
+    compare_state: &SharedCompareState,
+) -> Widget<'static> {
+    let tx = tx.clone();
+
+    SingleSelect::builder()
+        .id(YAML_COMPARE_NAMESPACE_DIALOG_ID)
+        .widget_base(WidgetBase::builder().title("Compare Namespace").build())
+        .on_select(on_select(tx, compare_state.clone()))
+        .action(KeyCode::Esc, open_compare_context_dialog())
+        .build()
+        .into()
+}
+
+fn on_select(
+    tx: Sender<Message>,
+    compare_state: SharedCompareState,
+) -> impl Fn(&mut Window, &LiteralItem) -> EventResult {
+    move |w, v| {
+        logger!(info, "Select Item: {:?}", v);
+
+        w.close_dialog();
+
+        let (Some(source), Some(context)) = ({
+            let state = compare_state.borrow();
+            (state.source.clone(), state.context.clone())
+        }) else {
+            return EventResult::Nop;
+        };
+
+        let title = format!(" : {}/{}", source.kind, source.name);
+
+        tx.send(
+            YamlRequest::Compare(YamlCompareTarget {
+                source,
+                context,
+                namespace: v.item.to_string(),
+            })
+            .into(),
+        )
+        .expect("Failed to send YamlRequest::Compare");
+
+        w.widget_clear(YAML_COMPARE_DIALOG_ID);
+
+        *(w.find_widget_mut(YAML_COMPARE_DIALOG_ID)
+            .widget_base_mut()
+            .append_title_mut()) = Some(title.into());
+
+        w.open_dialog(YAML_COMPARE_DIALOG_ID);
+
+        EventResult::Nop
+    }
+}
+
+fn open_compare_context_dialog() -> impl Fn(&mut Window) -> EventResult {
+    move |w: &mut Window| {
+        w.open_dialog(YAML_COMPARE_CONTEXT_DIALOG_ID);
+
+        if let Widget::SingleSelect(w) = w.find_widget_mut(YAML_COMPARE_CONTEXT_DIALOG_ID) {
+            w.clear_filter();
+        }
+
+        EventResult::Nop
+    }
+}
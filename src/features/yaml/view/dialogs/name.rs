@@ -4,7 +4,10 @@ use ratatui::crossterm::event::KeyCode;
 use crate::{
     features::{
         component_id::{YAML_KIND_DIALOG_ID, YAML_NAME_DIALOG_ID},
-        yaml::message::{YamlRequest, YamlTarget},
+        yaml::{
+            message::{YamlRequest, YamlTarget},
+            view::state::SharedCompareState,
+        },
     },
     logger,
     message::Message,
@@ -15,19 +18,22 @@ use crate::{
     },
 };
 
-pub fn name_dialog(tx: &Sender<Message>) -> Widget<'static> {
+pub fn name_dialog(tx: &Sender<Message>, compare_state: &SharedCompareState) -> Widget<'static> {
     let tx = tx.clone();
 
     SingleSelect::builder()
         .id(YAML_NAME_DIALOG_ID)
         .widget_base(WidgetBase::builder().title("Name").build())
-        .on_select(on_select(tx))
+        .on_select(on_select(tx, compare_state.clone()))
         .action(KeyCode::Esc, open_kind_dialog())
         .build()
         .into()
 }
 
-fn on_select(tx: Sender<Message>) -> impl Fn(&mut Window, &LiteralItem) -> EventResult {
+fn on_select(
+    tx: Sender<Message>,
+    compare_state: SharedCompareState,
+) -> impl Fn(&mut Window, &LiteralItem) -> EventResult {
     move |w, v| {
         logger!(info, "Select Item: {:?}", v);
 
@@ -53,15 +59,16 @@ fn on_select(tx: Sender<Message>) -> impl Fn(&mut Window, &LiteralItem) -> Event
             unreachable!()
         };
 
-        tx.send(
-            YamlRequest::Yaml(YamlTarget {
-                kind,
-                name: name.to_string(),
-                namespace: namespace.to_string(),
-            })
-            .into(),
-        )
-        .expect("Failed to send YamlRequest::Yaml");
+        let target = YamlTarget {
+            kind,
+            name: name.to_string(),
+            namespace: namespace.to_string(),
+        };
+
+        compare_state.borrow_mut().source = Some(target.clone());
+
+        tx.send(YamlRequest::Yaml(target).into())
+            .expect("Failed to send YamlRequest::Yaml");
 
         EventResult::Nop
     }
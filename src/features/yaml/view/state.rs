@@ -0,0 +1,14 @@
+use std::{cell::RefCell, rc::Rc};
+
+use crate::features::yaml::message::YamlTarget;
+
+pub(super) type SharedCompareState = Rc<RefCell<CompareState>>;
+
+/// Yamlタブと比較ダイアログで共有する状態
+#[derive(Debug, Default)]
+pub(super) struct CompareState {
+    /// Yamlタブに表示しているリソース
+    pub source: Option<YamlTarget>,
+    /// 比較先のcontext
+    pub context: Option<String>,
+}
@@ -15,7 +15,12 @@ use crate::{
 };
 
 use super::{
-    dialogs::{kind::kind_dialog, name::name_dialog, not_found::not_found_dialog},
+    dialogs::{
+        compare::compare_dialog, compare_context::compare_context_dialog,
+        compare_namespace::compare_namespace_dialog, kind::kind_dialog, name::name_dialog,
+        not_found::not_found_dialog,
+    },
+    state::SharedCompareState,
     widget::yaml_widget,
 };
 
@@ -24,6 +29,9 @@ pub struct YamlTab {
     pub kind_dialog: Widget<'static>,
     pub name_dialog: Widget<'static>,
     pub not_found_dialog: Widget<'static>,
+    pub compare_context_dialog: Widget<'static>,
+    pub compare_namespace_dialog: Widget<'static>,
+    pub compare_dialog: Widget<'static>,
 }
 
 impl YamlTab {
@@ -32,7 +40,9 @@ impl YamlTab {
         tx: &Sender<Message>,
         clipboard: &Option<Rc<RefCell<Clipboard>>>,
     ) -> Self {
-        let yaml_widget = yaml_widget(tx, clipboard);
+        let compare_state = SharedCompareState::default();
+
+        let yaml_widget = yaml_widget(tx, clipboard, &compare_state);
 
         let layout = TabLayout::new(
             |_| {
@@ -47,8 +57,11 @@ impl YamlTab {
         YamlTab {
             tab: Tab::new(YAML_TAB_ID, title, [yaml_widget], layout),
             kind_dialog: kind_dialog(tx),
-            name_dialog: name_dialog(tx),
+            name_dialog: name_dialog(tx, &compare_state),
             not_found_dialog: not_found_dialog(),
+            compare_context_dialog: compare_context_dialog(tx, &compare_state),
+            compare_namespace_dialog: compare_namespace_dialog(tx, &compare_state),
+            compare_dialog: compare_dialog(clipboard),
         }
     }
 }
@@ -6,7 +6,7 @@ use ratatui::widgets::Block;
 use crate::{
     clipboard::Clipboard,
     features::{
-        component_id::{YAML_KIND_DIALOG_ID, YAML_WIDGET_ID},
+        component_id::{YAML_COMPARE_CONTEXT_DIALOG_ID, YAML_KIND_DIALOG_ID, YAML_WIDGET_ID},
         yaml::message::YamlRequest,
     },
     message::Message,
@@ -17,18 +17,25 @@ use crate::{
     },
 };
 
+use super::state::SharedCompareState;
+
 pub fn yaml_widget(
     tx: &Sender<Message>,
     clipboard: &Option<Rc<RefCell<Clipboard>>>,
+    compare_state: &SharedCompareState,
 ) -> Widget<'static> {
-    let tx = tx.clone();
-
     let builder = Text::builder()
         .id(YAML_WIDGET_ID)
         .widget_base(WidgetBase::builder().title("Yaml").build())
         .block_injection(block_injection())
-        .action('f', open_kind_dialog(tx))
-        .wrap();
+        .action('f', open_kind_dialog(tx.clone()))
+        .action(
+            'd',
+            open_compare_context_dialog(tx.clone(), compare_state.clone()),
+        )
+        .action('m', toggle_noisy_fields(tx.clone()))
+        .wrap()
+        .foldable();
 
     if let Some(cb) = clipboard {
         builder.clipboard(cb.clone())
@@ -48,6 +55,33 @@ fn open_kind_dialog(tx: Sender<Message>) -> impl Fn(&mut Window) -> EventResult
     }
 }
 
+fn open_compare_context_dialog(
+    tx: Sender<Message>,
+    compare_state: SharedCompareState,
+) -> impl Fn(&mut Window) -> EventResult {
+    move |w: &mut Window| {
+        if compare_state.borrow().source.is_none() {
+            return EventResult::Ignore;
+        }
+
+        tx.send(YamlRequest::CompareContexts.into())
+            .expect("Failed to send YamlRequest::CompareContexts");
+
+        w.open_dialog(YAML_COMPARE_CONTEXT_DIALOG_ID);
+
+        EventResult::Nop
+    }
+}
+
+fn toggle_noisy_fields(tx: Sender<Message>) -> impl Fn(&mut Window) -> EventResult {
+    move |_: &mut Window| {
+        tx.send(YamlRequest::ToggleNoisyFields.into())
+            .expect("Failed to send YamlRequest::ToggleNoisyFields");
+
+        EventResult::Nop
+    }
+}
+
 fn block_injection() -> impl Fn(&Text, bool, bool) -> Block<'static> {
     |text: &Text, is_active: bool, is_mouse_over: bool| {
         let (index, size) = text.state();
@@ -1,3 +1,4 @@
+pub mod access_review;
 pub mod apis;
 mod client;
 pub mod context;
@@ -0,0 +1,215 @@
+use anyhow::Error;
+use k8s_openapi::api::authorization::v1::ResourceAttributes;
+
+/// 403 Forbiddenのエラーかどうか
+pub fn is_forbidden(err: &Error) -> bool {
+    matches!(
+        err.downcast_ref::<kube::Error>(),
+        Some(kube::Error::Api(res)) if res.code == 403
+    )
+}
+
+/// リクエストのパス（例: api/v1/namespaces/default/pods）からリソースの属性を作る
+pub fn resource_attributes(verb: &str, path: &str) -> Option<ResourceAttributes> {
+    let path = path.trim_start_matches('/');
+    let path = path.split_once('?').map_or(path, |(path, _)| path);
+
+    let segments: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+
+    let (group, version, rest) = match segments.as_slice() {
+        ["api", version, rest @ ..] => ("", *version, rest),
+        ["apis", group, version, rest @ ..] => (*group, *version, rest),
+        _ => return None,
+    };
+
+    let (namespace, resource, name) = match rest {
+        ["namespaces", namespace, resource] => (Some(*namespace), *resource, None),
+        ["namespaces", namespace, resource, name, ..] => (Some(*namespace), *resource, Some(*name)),
+        [resource] => (None, *resource, None),
+        [resource, name, ..] => (None, *resource, Some(*name)),
+        _ => return None,
+    };
+
+    Some(ResourceAttributes {
+        verb: Some(verb.to_string()),
+        group: Some(group.to_string()),
+        version: Some(version.to_string()),
+        resource: Some(resource.to_string()),
+        namespace: namespace.map(ToString::to_string),
+        name: name.map(ToString::to_string),
+        ..Default::default()
+    })
+}
+
+/// 403 Forbiddenのとき、どの操作が許可されていないかをエラーに補足する
+///
+/// ポーリングのたびにAPIを呼ばないよう、リクエストのパスだけから説明を作る。
+/// 自分に許可されている操作はRBACタブのCan Iで確認する
+pub fn explain_forbidden(verb: &str, path: &str, err: Error) -> Error {
+    if !is_forbidden(&err) {
+        return err;
+    }
+
+    let Some(attributes) = resource_attributes(verb, path) else {
+        return err;
+    };
+
+    err.context(Forbidden::from(&attributes))
+}
+
+/// 許可されていない操作（explain_forbiddenでエラーに付けるコンテキスト）
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Forbidden {
+    pub verb: String,
+    /// `<resource>.<group>` の形式（コアグループは `<resource>`）
+    pub resource: String,
+    pub namespace: Option<String>,
+}
+
+impl From<&ResourceAttributes> for Forbidden {
+    fn from(attributes: &ResourceAttributes) -> Self {
+        let resource = attributes.resource.clone().unwrap_or_default();
+
+        let resource = match attributes.group.as_deref() {
+            Some(group) if !group.is_empty() => format!("{}.{}", resource, group),
+            _ => resource,
+        };
+
+        Self {
+            verb: attributes.verb.clone().unwrap_or_default(),
+            resource,
+            namespace: attributes.namespace.clone(),
+        }
+    }
+}
+
+impl std::fmt::Display for Forbidden {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let scope = match self.namespace.as_deref() {
+            Some(namespace) => format!("in namespace {:?}", namespace),
+            None => "at the cluster scope".to_string(),
+        };
+
+        write!(
+            f,
+            "Forbidden: you cannot {} {} {}. See the RBAC tab for what you can do",
+            self.verb, self.resource, scope
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::anyhow;
+    use kube::core::ErrorResponse;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn api_error(code: u16) -> Error {
+        kube::Error::Api(ErrorResponse {
+            status: "Failure".into(),
+            message: "message".into(),
+            reason: "reason".into(),
+            code,
+        })
+        .into()
+    }
+
+    #[test]
+    fn forbiddenのエラーのときtrueを返す() {
+        assert!(is_forbidden(&api_error(403)));
+        assert!(!is_forbidden(&api_error(404)));
+        assert!(!is_forbidden(&anyhow!("error")));
+    }
+
+    #[test]
+    fn コアapiのパスからリソースの属性を作る() {
+        let actual = resource_attributes("list", "api/v1/namespaces/default/pods").unwrap();
+
+        assert_eq!(
+            actual,
+            ResourceAttributes {
+                verb: Some("list".into()),
+                group: Some("".into()),
+                version: Some("v1".into()),
+                resource: Some("pods".into()),
+                namespace: Some("default".into()),
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn グループ付きのパスからリソースの属性を作る() {
+        let actual = resource_attributes(
+            "get",
+            "/apis/apps/v1/namespaces/default/deployments/web?timeout=10s",
+        )
+        .unwrap();
+
+        assert_eq!(
+            actual,
+            ResourceAttributes {
+                verb: Some("get".into()),
+                group: Some("apps".into()),
+                version: Some("v1".into()),
+                resource: Some("deployments".into()),
+                namespace: Some("default".into()),
+                name: Some("web".into()),
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn クラスタスコープのパスからリソースの属性を作る() {
+        let actual = resource_attributes("list", "api/v1/nodes").unwrap();
+
+        assert_eq!(actual.resource.as_deref(), Some("nodes"));
+        assert_eq!(actual.namespace, None);
+    }
+
+    #[test]
+    fn apiのパスでないときnoneを返す() {
+        assert_eq!(resource_attributes("get", "healthz"), None);
+    }
+
+    #[test]
+    fn 許可されていない操作を説明する() {
+        let err = explain_forbidden(
+            "list",
+            "apis/apps/v1/namespaces/prod/deployments",
+            api_error(403),
+        );
+
+        assert_eq!(
+            err.to_string(),
+            "Forbidden: you cannot list deployments.apps in namespace \"prod\". See the RBAC tab for what you can do"
+        );
+
+        assert_eq!(
+            err.downcast_ref::<Forbidden>(),
+            Some(&Forbidden {
+                verb: "list".into(),
+                resource: "deployments.apps".into(),
+                namespace: Some("prod".into()),
+            })
+        );
+
+        let err = explain_forbidden("list", "api/v1/nodes", api_error(403));
+
+        assert_eq!(
+            err.to_string(),
+            "Forbidden: you cannot list nodes at the cluster scope. See the RBAC tab for what you can do"
+        );
+    }
+
+    #[test]
+    fn forbiddenでないエラーはそのまま返す() {
+        let err = explain_forbidden("list", "api/v1/nodes", api_error(404));
+
+        assert!(err.downcast_ref::<Forbidden>().is_none());
+        assert!(err.downcast_ref::<kube::Error>().is_some());
+    }
+}
@@ -5,6 +5,9 @@ pub use gatewayclasses::*;
 mod gateways;
 pub use gateways::*;
 
+mod grpcroutes;
+pub use grpcroutes::*;
+
 mod httproutes;
 pub use httproutes::*;
 
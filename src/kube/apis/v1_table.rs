@@ -169,70 +169,6 @@ impl Table {
     }
 }
 
-impl Table {
-    pub fn to_print(&self) -> String {
-        let header: Vec<(usize, &str)> = self
-            .column_definitions
-            .iter()
-            .enumerate()
-            .filter_map(|(i, coldef)| {
-                if coldef.priority == 0 {
-                    Some((i, coldef.name.as_str()))
-                } else {
-                    None
-                }
-            })
-            .collect();
-
-        let rows: Vec<Vec<String>> = self
-            .rows
-            .iter()
-            .map(|row| {
-                header
-                    .iter()
-                    .map(|(i, _)| row.cells[*i].to_string())
-                    .collect()
-            })
-            .collect();
-
-        let mut digits: Vec<usize> = header.iter().map(|h| h.1.len()).collect();
-
-        rows.iter().for_each(|cells| {
-            cells.iter().enumerate().for_each(|(i, cell)| {
-                if digits[i] < cell.len() {
-                    digits[i] = cell.len()
-                }
-            });
-        });
-
-        let mut buf = header
-            .iter()
-            .enumerate()
-            .map(|(i, h)| {
-                let header_text = h.1.to_uppercase();
-
-                let formatted_header = format!("\x1b[90m{}\x1b[39m", header_text);
-                let padding = " ".repeat(digits[i] - header_text.len());
-
-                format!("{}{}", formatted_header, padding)
-            })
-            .collect::<Vec<String>>()
-            .join("   ");
-
-        rows.iter().for_each(|row| {
-            buf += &("\n".to_owned()
-                + &row
-                    .iter()
-                    .enumerate()
-                    .map(|(i, cell)| format!("{:<digit$}", cell, digit = digits[i]))
-                    .collect::<Vec<String>>()
-                    .join("   "));
-        });
-
-        buf
-    }
-}
-
 impl From<NodeMetricsList> for Table {
     fn from(list: NodeMetricsList) -> Self {
         Table {
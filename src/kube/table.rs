@@ -1,10 +1,14 @@
 use std::collections::BTreeMap;
 
-use anyhow::Result;
+use anyhow::{Error, Result};
 
-use crate::kube::{
-    apis::v1_table::{Table, TableRow},
-    KubeClient, KubeClientRequest as _,
+use crate::{
+    kube::{
+        access_review::{explain_forbidden, is_forbidden, Forbidden},
+        apis::v1_table::{Table, TableRow},
+        KubeClient, KubeClientRequest as _,
+    },
+    logger,
 };
 
 #[derive(Debug, Default)]
@@ -19,6 +23,94 @@ pub struct KubeTableRow {
 pub struct KubeTable {
     pub header: Vec<String>,
     pub rows: Vec<KubeTableRow>,
+    /// 取得できずに除外したネームスペース
+    pub skipped: Vec<SkippedNamespace>,
+}
+
+/// 取得できなかったネームスペースとその理由
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SkippedNamespace {
+    pub namespace: String,
+    pub reason: String,
+}
+
+impl SkippedNamespace {
+    pub fn new(namespace: impl Into<String>, err: &Error) -> Self {
+        // explain_forbiddenで補足した、許可されていない操作を表示する
+        let reason = if let Some(forbidden) = err.downcast_ref::<Forbidden>() {
+            format!(
+                "forbidden: cannot {} {}",
+                forbidden.verb, forbidden.resource
+            )
+        } else if is_forbidden(err) {
+            "forbidden".to_string()
+        } else if let Some(kube::Error::Api(res)) = err.downcast_ref::<kube::Error>() {
+            res.reason.clone()
+        } else {
+            "error".to_string()
+        };
+
+        Self {
+            namespace: namespace.into(),
+            reason,
+        }
+    }
+}
+
+impl std::fmt::Display for SkippedNamespace {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ({})", self.namespace, self.reason)
+    }
+}
+
+/// ウィジェットの下部に表示する、除外したネームスペースの一覧
+pub fn skipped_namespaces_status(skipped: &[SkippedNamespace]) -> Option<String> {
+    if skipped.is_empty() {
+        return None;
+    }
+
+    Some(format!(
+        "skipped: {}",
+        skipped
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(", ")
+    ))
+}
+
+/// ネームスペースごとの取得結果を、取得できた値と取得できなかったネームスペースに分ける
+///
+/// すべてのネームスペースで失敗したときは、最初のエラーを返す
+pub fn partition_namespaces<T>(
+    results: impl IntoIterator<Item = (String, Result<T>)>,
+) -> Result<(Vec<T>, Vec<SkippedNamespace>)> {
+    let mut values = Vec::new();
+    let mut skipped = Vec::new();
+    let mut first_error = None;
+
+    for (namespace, result) in results {
+        match result {
+            Ok(value) => values.push(value),
+            Err(err) => {
+                logger!(
+                    error,
+                    "Failed to fetch in namespace {}: {:?}",
+                    namespace,
+                    err
+                );
+
+                skipped.push(SkippedNamespace::new(&namespace, &err));
+
+                first_error.get_or_insert(err);
+            }
+        }
+    }
+
+    match first_error {
+        Some(err) if values.is_empty() => Err(err),
+        _ => Ok((values, skipped)),
+    }
 }
 
 #[allow(dead_code)]
@@ -82,7 +174,10 @@ pub async fn get_resource_per_namespace<F>(
 where
     F: Fn(&TableRow, &[usize]) -> KubeTableRow,
 {
-    let table: Table = client.table_request(&path).await?;
+    let table: Table = match client.table_request(&path).await {
+        Ok(table) => table,
+        Err(err) => return Err(explain_forbidden("list", &path, err)),
+    };
 
     let indexes = table.find_indexes(target_values);
 
@@ -92,3 +187,81 @@ where
         .map(|row| (create_cells)(row, &indexes))
         .collect())
 }
+
+#[cfg(test)]
+mod tests {
+    use anyhow::anyhow;
+    use kube::core::ErrorResponse;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn api_error(code: u16, reason: &str) -> Error {
+        kube::Error::Api(ErrorResponse {
+            status: "Failure".into(),
+            message: "message".into(),
+            reason: reason.into(),
+            code,
+        })
+        .into()
+    }
+
+    #[test]
+    fn 失敗したネームスペースを除いて結果を返す() {
+        let (values, skipped) = partition_namespaces([
+            ("default".to_string(), Ok(1)),
+            ("kube-system".to_string(), Err(api_error(403, "Forbidden"))),
+            ("dev".to_string(), Ok(2)),
+            ("gone".to_string(), Err(api_error(404, "NotFound"))),
+            ("prod".to_string(), Err(anyhow!("connection refused"))),
+        ])
+        .unwrap();
+
+        assert_eq!(values, vec![1, 2]);
+
+        assert_eq!(
+            skipped_namespaces_status(&skipped),
+            Some("skipped: kube-system (forbidden), gone (NotFound), prod (error)".to_string())
+        );
+    }
+
+    #[test]
+    fn 許可されていない操作を除外した理由に表示する() {
+        let (_, skipped) = partition_namespaces([
+            ("default".to_string(), Ok(1)),
+            (
+                "kube-system".to_string(),
+                Err(explain_forbidden(
+                    "list",
+                    "apis/apps/v1/namespaces/kube-system/deployments",
+                    api_error(403, "Forbidden"),
+                )),
+            ),
+        ])
+        .unwrap();
+
+        assert_eq!(
+            skipped_namespaces_status(&skipped),
+            Some("skipped: kube-system (forbidden: cannot list deployments.apps)".to_string())
+        );
+    }
+
+    #[test]
+    fn すべてのネームスペースで失敗したときは最初のエラーを返す() {
+        let actual = partition_namespaces::<()>([
+            ("default".to_string(), Err(anyhow!("first"))),
+            ("dev".to_string(), Err(anyhow!("second"))),
+        ]);
+
+        assert_eq!(actual.unwrap_err().to_string(), "first");
+    }
+
+    #[test]
+    fn ネームスペースがないときは空の結果を返す() {
+        let (values, skipped) = partition_namespaces::<()>([]).unwrap();
+
+        assert!(values.is_empty());
+        assert!(skipped.is_empty());
+        assert_eq!(skipped_namespaces_status(&skipped), None);
+    }
+}
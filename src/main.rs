@@ -1,7 +1,9 @@
 mod ansi;
 mod app;
+mod certificate;
 mod clipboard;
 mod cmd;
+mod config;
 mod features;
 mod kube;
 mod logging;
@@ -19,7 +21,7 @@ use ratatui::crossterm::{
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
 
-use crate::{app::App, cmd::Command, logging::Logger};
+use crate::{app::App, cmd::Command, config::Config, logging::Logger};
 
 macro_rules! enable_raw_mode {
     () => {
@@ -72,13 +74,15 @@ fn main() -> Result<()> {
 
     let command = Command::init();
 
+    let config = Config::load(command.config_file.clone())?;
+
     if command.logging {
         Logger::init()?;
     }
 
     enable_raw_mode!();
 
-    let result = App::run(command);
+    let result = App::run(command, config);
 
     disable_raw_mode!();
 
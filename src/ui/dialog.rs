@@ -5,11 +5,15 @@ use ratatui::{
     Frame,
 };
 
+use crate::define_callback;
+
 use super::{
     event::EventResult,
     widget::{RenderTrait, Widget, WidgetTrait},
 };
 
+define_callback!(pub OnCloseCallback, Fn());
+
 /// Dialogの大きさを決めるための構造体
 ///
 /// ┌─────────────────────────────────────────────────┐
@@ -35,6 +39,8 @@ struct DialogSize {
     width: f32,
     /// content height percentage (0.0 ~ 100.0)
     height: f32,
+    /// content height (lines). heightより優先する
+    fixed_height: Option<u16>,
 }
 
 impl Default for DialogSize {
@@ -42,6 +48,7 @@ impl Default for DialogSize {
         Self {
             width: 85.0,
             height: 85.0,
+            fixed_height: None,
         }
     }
 }
@@ -50,8 +57,12 @@ impl DialogSize {
     fn chunk(&self, parent_chunk: Rect) -> Rect {
         let horizontal_margin =
             (parent_chunk.width as f32 * ((100.0 - self.width) / 2.0 / 100.0)).round() as u16;
-        let vertical_margin =
-            (parent_chunk.height as f32 * ((100.0 - self.height) / 2.0 / 100.0)).round() as u16;
+        let vertical_margin = match self.fixed_height {
+            Some(height) => parent_chunk.height.saturating_sub(height) / 2,
+            None => {
+                (parent_chunk.height as f32 * ((100.0 - self.height) / 2.0 / 100.0)).round() as u16
+            }
+        };
 
         parent_chunk.inner(Margin {
             vertical: vertical_margin,
@@ -64,6 +75,7 @@ pub struct Dialog<'a> {
     widget: Widget<'a>,
     chunk: Rect,
     chunk_size: DialogSize,
+    on_close: Option<OnCloseCallback>,
 }
 
 impl<'a> Dialog<'a> {
@@ -72,6 +84,28 @@ impl<'a> Dialog<'a> {
             widget,
             chunk: Default::default(),
             chunk_size: Default::default(),
+            on_close: None,
+        }
+    }
+
+    /// 高さを行数で固定する
+    pub fn fixed_height(mut self, height: u16) -> Self {
+        self.chunk_size.fixed_height = Some(height);
+        self
+    }
+
+    /// ダイアログを閉じたときに呼ばれるコールバックを設定する
+    pub fn on_close<F>(mut self, cb: F) -> Self
+    where
+        F: Into<OnCloseCallback>,
+    {
+        self.on_close = Some(cb.into());
+        self
+    }
+
+    pub fn close(&self) {
+        if let Some(cb) = &self.on_close {
+            (cb)();
         }
     }
 
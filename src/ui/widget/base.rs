@@ -14,6 +14,8 @@ pub struct WidgetBaseBuilder(WidgetBase);
 pub struct WidgetBase {
     title: Title,
     append_title: Option<Title>,
+    /// 枠の下部に表示する状態（例: 取得できなかったネームスペース）
+    status: Option<Title>,
     block: Block<'static>,
     can_activate: bool,
 }
@@ -23,6 +25,7 @@ impl Default for WidgetBase {
         Self {
             title: Default::default(),
             append_title: Default::default(),
+            status: Default::default(),
             block: Block::default()
                 .border_type(BorderType::Plain)
                 .borders(Borders::ALL),
@@ -90,6 +93,14 @@ impl WidgetBase {
         &mut self.append_title
     }
 
+    pub fn status(&self) -> &Option<Title> {
+        &self.status
+    }
+
+    pub fn status_mut(&mut self) -> &mut Option<Title> {
+        &mut self.status
+    }
+
     pub fn render_title(&self, is_active: bool) -> Vec<Span<'static>> {
         if self.title.to_string() == "" {
             return Vec::new();
@@ -145,6 +156,16 @@ impl WidgetBase {
             self.block.clone()
         };
 
+        let block = if let Some(status) = &self.status {
+            let mut status = status.spans();
+            status.spans.insert(0, " ".into());
+            status.spans.push(" ".into());
+
+            block.title_bottom(status)
+        } else {
+            block
+        };
+
         let title = self.render_title(is_active);
         if title.is_empty() {
             block
@@ -269,4 +290,29 @@ mod tests {
             title
         )
     }
+
+    #[test]
+    fn render_block_with_status() {
+        let mut wc = WidgetBase::builder()
+            .title("Title")
+            .disable_activation()
+            .build();
+
+        *wc.status_mut() = Some("skipped: dev (forbidden)".into());
+
+        let block = wc.render_block(false, false);
+
+        assert_eq!(
+            Block::default()
+                .border_type(BorderType::Plain)
+                .borders(Borders::ALL)
+                .title_bottom(Line::from(vec![
+                    Span::raw(" "),
+                    Span::raw("skipped: dev (forbidden)"),
+                    Span::raw(" "),
+                ]))
+                .title(vec![Span::raw(" "), Span::raw("Title"), Span::raw(" ")]),
+            block
+        )
+    }
 }
@@ -1,3 +1,4 @@
+mod fold;
 mod item;
 mod render;
 mod search;
@@ -32,6 +33,7 @@ use super::{
 };
 
 use self::{
+    fold::Fold,
     highlight_content::{HighlightArea, HighlightContent, Point},
     item::TextItem,
     render::{Render, Scroll},
@@ -165,6 +167,7 @@ pub struct TextBuilder {
     item: Vec<LiteralItem>,
     wrap: bool,
     follow: bool,
+    foldable: bool,
     #[derivative(Debug = "ignore")]
     block_injection: Option<RenderBlockInjection>,
     #[derivative(Debug = "ignore")]
@@ -209,6 +212,14 @@ impl TextBuilder {
         self
     }
 
+    /// インデントによる折りたたみを有効にする
+    ///
+    /// 有効なときはカーソルを表示し、カーソル行を折りたたみの対象とする
+    pub fn foldable(mut self) -> Self {
+        self.foldable = true;
+        self
+    }
+
     pub fn action<F, E>(mut self, ev: E, cb: F) -> Self
     where
         E: Into<UserEvent>,
@@ -232,13 +243,23 @@ impl TextBuilder {
     }
 
     pub fn build(self) -> Text {
+        let (item, fold) = if self.foldable {
+            let mut fold = Fold::default();
+            fold.update(self.item);
+
+            (fold.visible_items(), Some(fold))
+        } else {
+            (self.item, None)
+        };
+
         Text {
             id: self.id,
             widget_base: self.widget_base,
             search_form: self.search_form,
-            item: TextItem::new(self.item, None),
+            item: TextItem::new(item, None),
             wrap: self.wrap,
             follow: self.follow,
+            fold,
             actions: self.actions,
             block_injection: self.block_injection,
             clipboard: self.clipboard,
@@ -261,6 +282,11 @@ pub struct Text {
     /// 検索中、検索ワード入力中、オフの3つのモード
     mode: Mode,
     highlight_content: Option<HighlightContent>,
+    /// 折りたたみが有効なときのみSome
+    fold: Option<Fold>,
+    /// カーソル位置（折り返しを考慮した行のインデックス）
+    /// 折りたたみが有効なときのみ使用する
+    cursor: usize,
     #[derivative(Debug = "ignore")]
     block_injection: Option<RenderBlockInjection>,
     #[derivative(Debug = "ignore")]
@@ -289,7 +315,7 @@ impl Text {
         self.mode.search_input();
 
         if is_bottom {
-            self.select_last()
+            self.scroll_to_last()
         }
 
         let word = self.search_form.content();
@@ -307,6 +333,7 @@ impl Text {
             .select_nearest_highlight(self.search_nearest_highlight_target_index())
         {
             self.scroll.y = self.search_scroll(index);
+            self.cursor = index;
         }
     }
 
@@ -324,6 +351,7 @@ impl Text {
 
             if let Some(index) = index {
                 self.scroll.y = self.search_scroll(index);
+                self.cursor = index;
             }
         }
     }
@@ -342,6 +370,7 @@ impl Text {
 
             if let Some(index) = index {
                 self.scroll.y = self.search_scroll(index);
+                self.cursor = index;
             }
         }
     }
@@ -351,7 +380,7 @@ impl Text {
         self.item.clear_highlight();
 
         if self.scroll_y_last_index() < self.scroll.y {
-            self.select_last()
+            self.scroll_to_last()
         }
     }
 
@@ -448,6 +477,107 @@ impl Text {
     fn is_bottom(&self) -> bool {
         self.scroll_y_last_index() <= self.scroll.y
     }
+
+    fn scroll_to_last(&mut self) {
+        self.scroll.y = self.scroll_y_last_index();
+    }
+}
+
+/// インデントによる折りたたみ機能
+///
+/// # Features
+///
+/// - カーソル行の折りたたみを切り替える
+/// - 全ての折りたたみを展開する
+/// - アイテム更新時に同じ内容の行の折りたたみを維持する
+impl Text {
+    /// カーソル行の折りたたみを切り替える
+    pub fn toggle_fold(&mut self) {
+        let Some(line_index) = self.cursor_line_index() else {
+            return;
+        };
+
+        let Some(fold) = &mut self.fold else {
+            return;
+        };
+
+        if !fold.toggle(line_index) {
+            return;
+        }
+
+        let items = fold.visible_items();
+        self.item.update(items);
+
+        self.select_line(line_index);
+    }
+
+    pub fn unfold_all(&mut self) {
+        let Some(line_index) = self.cursor_line_index() else {
+            return;
+        };
+
+        let Some(fold) = &mut self.fold else {
+            return;
+        };
+
+        let source_index = fold.source_index(line_index);
+
+        fold.unfold_all();
+
+        let items = fold.visible_items();
+
+        let line_index = source_index
+            .and_then(|i| fold.visible_index(i))
+            .unwrap_or_default();
+
+        self.item.update(items);
+
+        self.select_line(line_index);
+    }
+
+    fn cursor_line_index(&self) -> Option<usize> {
+        self.item
+            .wrapped_lines()
+            .get(self.cursor)
+            .map(|line| line.index())
+    }
+
+    /// 行の先頭にカーソルを移動する
+    fn select_line(&mut self, line_index: usize) {
+        self.cursor = self
+            .item
+            .wrapped_lines()
+            .iter()
+            .position(|line| line.index() == line_index)
+            .unwrap_or_default();
+
+        self.scroll_to_cursor();
+    }
+
+    fn move_cursor(&mut self, cursor: usize) {
+        self.cursor = cursor;
+        self.clamp_cursor();
+        self.scroll_to_cursor();
+    }
+
+    fn clamp_cursor(&mut self) {
+        self.cursor = self
+            .cursor
+            .min(self.item.wrapped_lines().len().saturating_sub(1));
+    }
+
+    /// カーソルが表示範囲に収まるようにスクロールする
+    fn scroll_to_cursor(&mut self) {
+        let height = self.inner_chunk().height as usize;
+
+        if self.cursor < self.scroll.y {
+            self.scroll.y = self.cursor;
+        } else if 0 < height && self.scroll.y + height <= self.cursor {
+            self.scroll.y = self.cursor + 1 - height;
+        }
+
+        self.scroll.y = self.scroll.y.min(self.scroll_y_last_index());
+    }
 }
 
 impl Text {
@@ -502,6 +632,11 @@ impl WidgetTrait for Text {
     }
 
     fn select_next(&mut self, i: usize) {
+        if self.fold.is_some() {
+            self.move_cursor(self.cursor.saturating_add(i));
+            return;
+        }
+
         self.scroll.y = self
             .scroll
             .y
@@ -510,30 +645,53 @@ impl WidgetTrait for Text {
     }
 
     fn select_prev(&mut self, i: usize) {
+        if self.fold.is_some() {
+            self.move_cursor(self.cursor.saturating_sub(i));
+            return;
+        }
+
         self.scroll.y = self.scroll.y.saturating_sub(i)
     }
 
     fn select_first(&mut self) {
         self.scroll.y = 0;
+        self.cursor = 0;
     }
 
     fn select_last(&mut self) {
-        self.scroll.y = self.scroll_y_last_index();
+        if self.fold.is_some() {
+            self.move_cursor(usize::MAX);
+            return;
+        }
+
+        self.scroll_to_last();
     }
 
     fn append_widget_item(&mut self, item: Item) {
         let is_bottom = self.is_bottom();
 
-        match item {
-            Item::Single(i) => self.item.push(i),
-            Item::Array(i) => self.item.extend(i),
-            _ => {
-                unreachable!()
+        if let Some(fold) = &mut self.fold {
+            match item {
+                Item::Single(i) => fold.extend(vec![i]),
+                Item::Array(i) => fold.extend(i),
+                _ => {
+                    unreachable!()
+                }
+            }
+
+            self.item.update(fold.visible_items());
+        } else {
+            match item {
+                Item::Single(i) => self.item.push(i),
+                Item::Array(i) => self.item.extend(i),
+                _ => {
+                    unreachable!()
+                }
             }
         }
 
         if self.follow && is_bottom {
-            self.select_last()
+            self.scroll_to_last()
         }
     }
 
@@ -541,14 +699,21 @@ impl WidgetTrait for Text {
         let is_bottom = self.is_bottom();
 
         let item = item.array();
-        self.item.update(item);
+
+        if let Some(fold) = &mut self.fold {
+            fold.update(item);
+            self.item.update(fold.visible_items());
+            self.clamp_cursor();
+        } else {
+            self.item.update(item);
+        }
 
         if self.follow && is_bottom {
-            self.select_last()
+            self.scroll_to_last()
         }
 
         if self.is_bottom() {
-            self.select_last()
+            self.scroll_to_last()
         }
     }
 
@@ -568,6 +733,11 @@ impl WidgetTrait for Text {
 
                 let (x, y) = (pos.x + self.scroll.x, pos.y + self.scroll.y);
 
+                if self.fold.is_some() {
+                    self.cursor = y;
+                    self.clamp_cursor();
+                }
+
                 let area = HighlightArea::new().start(x, y).end(x, y);
 
                 self.highlight_content = Some(HighlightContent {
@@ -735,6 +905,14 @@ impl WidgetTrait for Text {
                     self.search_prev();
                 }
 
+                Char('z') if self.fold.is_some() => {
+                    self.toggle_fold();
+                }
+
+                Char('Z') if self.fold.is_some() => {
+                    self.unfold_all();
+                }
+
                 _ => {
                     if let Some(cb) = self.match_action(UserEvent::Key(ev)) {
                         return EventResult::Callback(cb.clone());
@@ -784,11 +962,15 @@ impl WidgetTrait for Text {
         ));
 
         if self.follow && is_bottom {
-            self.select_last()
+            self.scroll_to_last()
         }
 
         if self.scroll_y_last_index() < self.scroll.y || is_bottom {
-            self.select_last()
+            self.scroll_to_last()
+        }
+
+        if self.fold.is_some() {
+            self.clamp_cursor();
         }
     }
 
@@ -802,6 +984,12 @@ impl WidgetTrait for Text {
         };
 
         self.item = TextItem::new(vec![], wrap_width);
+        self.cursor = 0;
+
+        if let Some(fold) = &mut self.fold {
+            fold.clear();
+        }
+
         self.search_cancel();
 
         *(self.widget_base.append_title_mut()) = None;
@@ -824,6 +1012,10 @@ impl RenderTrait for Text {
             .lines(wrapped_lines)
             .scroll(self.scroll);
 
+        if self.fold.is_some() {
+            builder = builder.cursor(Some(self.cursor));
+        }
+
         if let Some(highlight_content) = &self.highlight_content {
             builder = builder.highlight_area(Some(highlight_content.area));
         }
@@ -965,4 +1157,67 @@ mod tests {
             assert_eq!(text.scroll.x, 7);
         }
     }
+
+    mod 折りたたみ {
+        use super::*;
+        use pretty_assertions::assert_eq;
+
+        fn lines(text: &Text) -> Vec<String> {
+            text.item
+                .wrapped_lines()
+                .iter()
+                .map(|l| l.line().iter().map(StyledGrapheme::symbol).collect())
+                .collect()
+        }
+
+        #[test]
+        fn カーソル行を折りたたんで展開する() {
+            let mut text = Text::builder()
+                .items([
+                    "metadata:".to_string(),
+                    "  name: app".to_string(),
+                    "spec:".to_string(),
+                    "  replicas: 1".to_string(),
+                ])
+                .foldable()
+                .build();
+
+            text.update_chunk(Rect::new(0, 0, 40, 10));
+
+            text.select_next(2);
+            text.toggle_fold();
+
+            assert_eq!(
+                lines(&text),
+                vec!["metadata:", "  name: app", "spec: ... (1 lines)"]
+            );
+            assert_eq!(text.cursor, 2);
+
+            text.unfold_all();
+
+            assert_eq!(
+                lines(&text),
+                vec!["metadata:", "  name: app", "spec:", "  replicas: 1"]
+            );
+            assert_eq!(text.cursor, 2);
+        }
+
+        #[test]
+        fn カーソルが表示範囲に収まるようにスクロールする() {
+            let mut text = Text::builder()
+                .items((0..10).map(|i| i.to_string()).collect::<Vec<_>>())
+                .foldable()
+                .build();
+
+            text.update_chunk(Rect::new(0, 0, 10, 7));
+
+            text.select_next(6);
+
+            assert_eq!((text.cursor, text.scroll.y), (6, 2));
+
+            text.select_prev(4);
+
+            assert_eq!((text.cursor, text.scroll.y), (2, 2));
+        }
+    }
 }
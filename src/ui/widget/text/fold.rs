@@ -0,0 +1,289 @@
+//! インデントによる行の折りたたみ
+//!
+//! yamlのようなインデントで階層を表すテキストを対象とする
+//! - 行より深いインデントの行を子要素とする
+//! - `key:` の直後に続く同じインデントの `- ` で始まる行も子要素とする（kubectl形式のシーケンス）
+use std::collections::BTreeSet;
+
+use crate::{
+    ansi::{AnsiEscapeSequence, TextParser},
+    ui::widget::LiteralItem,
+};
+
+#[derive(Debug, Default)]
+pub struct Fold {
+    /// 折りたたむ前の全ての行
+    items: Vec<LiteralItem>,
+
+    /// エスケープシーケンスを取り除いた行
+    plain_lines: Vec<String>,
+
+    /// 折りたたんでいる行のインデックス（itemsのインデックス）
+    folded: BTreeSet<usize>,
+
+    /// 表示している行に対応するitemsのインデックス
+    visible: Vec<usize>,
+}
+
+impl Fold {
+    /// 行を更新する
+    ///
+    /// 同じ位置に同じ内容の行がある場合は折りたたみ状態を維持する
+    pub fn update(&mut self, items: Vec<LiteralItem>) {
+        let plain_lines: Vec<String> = items.iter().map(|i| plain(&i.item)).collect();
+
+        self.folded
+            .retain(|i| plain_lines.get(*i) == self.plain_lines.get(*i));
+
+        self.items = items;
+        self.plain_lines = plain_lines;
+    }
+
+    pub fn extend(&mut self, items: Vec<LiteralItem>) {
+        self.plain_lines
+            .extend(items.iter().map(|i| plain(&i.item)));
+        self.items.extend(items);
+    }
+
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    /// 表示する行を返す
+    ///
+    /// 折りたたんでいる行には隠れている行数を付与する
+    pub fn visible_items(&mut self) -> Vec<LiteralItem> {
+        self.visible.clear();
+
+        let mut items = Vec::new();
+
+        let mut i = 0;
+
+        while i < self.items.len() {
+            self.visible.push(i);
+
+            let end = self.children_end(i);
+
+            if self.folded.contains(&i) && i + 1 < end {
+                let item = &self.items[i];
+
+                items.push(LiteralItem::new(
+                    format!("{} \x1b[90m... ({} lines)\x1b[39m", item.item, end - i - 1),
+                    item.metadata.clone(),
+                ));
+
+                i = end;
+            } else {
+                items.push(self.items[i].clone());
+
+                i += 1;
+            }
+        }
+
+        items
+    }
+
+    /// 表示している行の折りたたみを切り替える
+    ///
+    /// 子要素がなく切り替えられないときはfalseを返す
+    pub fn toggle(&mut self, visible_index: usize) -> bool {
+        let Some(&index) = self.visible.get(visible_index) else {
+            return false;
+        };
+
+        if self.folded.remove(&index) {
+            return true;
+        }
+
+        if self.children_end(index) <= index + 1 {
+            return false;
+        }
+
+        self.folded.insert(index)
+    }
+
+    pub fn unfold_all(&mut self) {
+        self.folded.clear();
+    }
+
+    /// 表示している行に対応する折りたたむ前の行のインデックスを返す
+    pub fn source_index(&self, visible_index: usize) -> Option<usize> {
+        self.visible.get(visible_index).copied()
+    }
+
+    /// 折りたたむ前の行が表示されているインデックスを返す
+    pub fn visible_index(&self, source_index: usize) -> Option<usize> {
+        self.visible.iter().position(|i| *i == source_index)
+    }
+
+    /// 子要素の終わり（排他的）を返す
+    ///
+    /// 子要素がないときは index + 1 を返す
+    fn children_end(&self, index: usize) -> usize {
+        let Some(parent) = self.plain_lines.get(index) else {
+            return index + 1;
+        };
+
+        if parent.trim().is_empty() {
+            return index + 1;
+        }
+
+        let parent_indent = indent_width(parent);
+        let parent_content = parent.trim();
+        let accept_sequence = !is_sequence_item(parent_content) && parent_content.ends_with(':');
+
+        let mut end = index + 1;
+
+        for (i, line) in self.plain_lines.iter().enumerate().skip(index + 1) {
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let indent = indent_width(line);
+
+            let is_child = parent_indent < indent
+                || (accept_sequence
+                    && parent_indent == indent
+                    && is_sequence_item(line.trim_start()));
+
+            if !is_child {
+                break;
+            }
+
+            end = i + 1;
+        }
+
+        end
+    }
+}
+
+fn plain(s: &str) -> String {
+    s.ansi_parse()
+        .filter(|p| p.ty == AnsiEscapeSequence::Chars)
+        .map(|p| p.chars)
+        .collect()
+}
+
+fn indent_width(line: &str) -> usize {
+    line.len() - line.trim_start_matches(' ').len()
+}
+
+fn is_sequence_item(s: &str) -> bool {
+    s == "-" || s.starts_with("- ")
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn fold(lines: &[&str]) -> Fold {
+        let mut fold = Fold::default();
+        fold.update(lines.iter().map(|l| LiteralItem::new(*l, None)).collect());
+        fold
+    }
+
+    fn visible(fold: &mut Fold) -> Vec<String> {
+        fold.visible_items()
+            .into_iter()
+            .map(|i| plain(&i.item))
+            .collect()
+    }
+
+    const YAML: [&str; 7] = [
+        "metadata:",
+        "  name: app",
+        "  labels:",
+        "    app: app",
+        "args:",
+        "- a",
+        "- b",
+    ];
+
+    #[test]
+    fn インデントが深い行を折りたたむ() {
+        let mut fold = fold(&YAML);
+
+        visible(&mut fold);
+
+        assert!(fold.toggle(0));
+
+        assert_eq!(
+            visible(&mut fold),
+            vec!["metadata: ... (3 lines)", "args:", "- a", "- b"]
+        );
+    }
+
+    #[test]
+    fn 同じインデントのシーケンスを折りたたむ() {
+        let mut fold = fold(&YAML);
+
+        visible(&mut fold);
+
+        assert!(fold.toggle(4));
+
+        assert_eq!(
+            visible(&mut fold),
+            vec![
+                "metadata:",
+                "  name: app",
+                "  labels:",
+                "    app: app",
+                "args: ... (2 lines)"
+            ]
+        );
+    }
+
+    #[test]
+    fn 子要素がない行は折りたたまない() {
+        let mut fold = fold(&YAML);
+
+        visible(&mut fold);
+
+        assert!(!fold.toggle(1));
+        assert_eq!(visible(&mut fold).len(), YAML.len());
+    }
+
+    #[test]
+    fn 折りたたんでいる行を展開する() {
+        let mut fold = fold(&YAML);
+
+        visible(&mut fold);
+        fold.toggle(0);
+        visible(&mut fold);
+        fold.toggle(0);
+
+        assert_eq!(visible(&mut fold), YAML.to_vec());
+    }
+
+    #[test]
+    fn 更新時に同じ内容の行の折りたたみを維持する() {
+        let mut fold = fold(&YAML);
+
+        visible(&mut fold);
+        fold.toggle(0);
+
+        fold.update(
+            ["metadata:", "  name: app", "spec:", "  replicas: 1"]
+                .iter()
+                .map(|l| LiteralItem::new(*l, None))
+                .collect(),
+        );
+
+        assert_eq!(
+            visible(&mut fold),
+            vec!["metadata: ... (1 lines)", "spec:", "  replicas: 1"]
+        );
+    }
+
+    #[test]
+    fn エスケープシーケンスを無視してインデントを判定する() {
+        let mut fold = fold(&["\x1b[36mspec\x1b[39m:", "  \x1b[36mreplicas\x1b[39m: 1"]);
+
+        visible(&mut fold);
+
+        assert!(fold.toggle(0));
+        assert_eq!(visible(&mut fold), vec!["spec: ... (1 lines)"]);
+    }
+}
@@ -35,6 +35,7 @@ pub struct Render<'a> {
     lines: &'a [WrappedLine],
     scroll: Scroll,
     highlight_area: Option<HighlightArea>,
+    cursor: Option<usize>,
 }
 
 pub struct RenderBuilder<'a>(Render<'a>);
@@ -60,6 +61,11 @@ impl<'a> RenderBuilder<'a> {
         self
     }
 
+    pub fn cursor(mut self, cursor: Option<usize>) -> Self {
+        self.0.cursor = cursor;
+        self
+    }
+
     pub fn build(self) -> Render<'a> {
         self.0
     }
@@ -84,6 +90,8 @@ impl Widget for Render<'_> {
         for (y, line) in self.lines.iter().skip(start).take(end).enumerate() {
             let mut x = 0;
 
+            let is_cursor_line = self.cursor == Some(start + y);
+
             let iter = LineIterator::new(line.line(), self.scroll.x, text_area.width as usize)
                 .collect::<Vec<_>>();
 
@@ -100,6 +108,10 @@ impl Widget for Render<'_> {
                     }
                 }
 
+                if is_cursor_line {
+                    style = style.add_modifier(CURSOR_MODIFIER);
+                }
+
                 let pos = Position::new(text_area.left() + x as u16, text_area.top() + y as u16);
 
                 if let Some(cell) = buf.cell_mut(pos) {
@@ -117,6 +129,10 @@ impl Widget for Render<'_> {
                     if let Some(cell) = buf.cell_mut(pos) {
                         cell.set_symbol(RENDER_RIGHT_PADDING.symbol())
                             .set_style(RENDER_RIGHT_PADDING.style);
+
+                        if is_cursor_line {
+                            cell.modifier.insert(CURSOR_MODIFIER);
+                        }
                     }
 
                     x += 1
@@ -128,6 +144,10 @@ impl Widget for Render<'_> {
                     buf.cell_mut((text_area.left() + x as u16, text_area.top() + y as u16))
                 {
                     cell.set_symbol(" ");
+
+                    if is_cursor_line {
+                        cell.modifier.insert(CURSOR_MODIFIER);
+                    }
                 }
 
                 x += " ".width()
@@ -158,6 +178,9 @@ struct LineIterator<'a> {
     sum_width_offset: usize,
 }
 
+/// カーソル行のスタイル
+const CURSOR_MODIFIER: Modifier = Modifier::UNDERLINED;
+
 const RENDER_LEFT_PADDING_SYMBOL: &str = "<";
 const RENDER_RIGHT_PADDING_SYMBOL: &str = ">";
 
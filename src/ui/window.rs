@@ -1,7 +1,7 @@
 use std::rc::Rc;
 
 use ratatui::{
-    crossterm::event::{KeyCode, KeyEvent, MouseButton, MouseEvent, MouseEventKind},
+    crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind},
     layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
@@ -221,6 +221,18 @@ impl<'a> Window<'a> {
             .highlight_style(Style::default().add_modifier(Modifier::REVERSED))
     }
 
+    /// キーの割り当てを追加する。同じキーの割り当てがあるときは置き換える
+    pub fn add_action<F, E>(&mut self, ev: E, cb: F)
+    where
+        E: Into<UserEvent>,
+        F: Into<Callback>,
+    {
+        let ev = ev.into();
+
+        self.callbacks.retain(|(cb_ev, _)| *cb_ev != ev);
+        self.callbacks.push((ev, cb.into()));
+    }
+
     pub fn match_callback(&self, ev: UserEvent) -> Option<Callback> {
         self.callbacks.iter().find_map(|(cb_ev, cb)| {
             logger!(debug, "match_callback {:?} <=> {:?}", ev, cb_ev);
@@ -249,13 +261,31 @@ impl<'a> Window<'a> {
 // Dialog
 impl<'a> Window<'a> {
     pub fn open_dialog(&mut self, id: impl Into<String>) {
-        self.opening_dialog_id = Some(id.into());
+        let id = id.into();
+
+        if self.opening_dialog_id.as_ref() != Some(&id) {
+            self.notify_dialog_close();
+        }
+
+        self.opening_dialog_id = Some(id);
     }
 
     pub fn close_dialog(&mut self) {
+        self.notify_dialog_close();
+
         self.opening_dialog_id = None;
     }
 
+    fn notify_dialog_close(&self) {
+        let Some(id) = &self.opening_dialog_id else {
+            return;
+        };
+
+        if let Some(dialog) = self.dialogs.iter().find(|d| d.id() == id) {
+            dialog.close();
+        }
+    }
+
     pub fn opening_dialog(&self) -> bool {
         self.opening_dialog_id.is_some()
     }
@@ -470,7 +500,8 @@ impl Window<'_> {
                     self.activate_prev_widget();
                 }
 
-                KeyCode::Char(n @ '1'..='9') => {
+                // Alt+数字キーは保存したビューの切り替えに使うため、修飾キーなしのときのみタブを切り替える
+                KeyCode::Char(n @ '1'..='9') if ev.modifiers == KeyModifiers::NONE => {
                     let index = n as usize - b'0' as usize;
                     self.activate_tab_by_index(index - 1);
                 }
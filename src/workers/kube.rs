@@ -1,3 +1,4 @@
+mod active_tab;
 pub mod color;
 mod config;
 mod controller;
@@ -5,6 +6,7 @@ pub mod message;
 mod store;
 mod worker;
 
+pub use active_tab::*;
 pub use config::KubeWorkerConfig;
 pub use controller::*;
 pub use worker::*;
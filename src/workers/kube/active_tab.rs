@@ -0,0 +1,78 @@
+use std::sync::Arc;
+
+use tokio::sync::watch;
+
+/// 表示しているタブのID
+///
+/// タブを表示しているときだけ取得するPollerが、タブが表示されるまで待つために使う
+#[derive(Clone)]
+pub struct SharedActiveTab(Arc<watch::Sender<String>>);
+
+impl Default for SharedActiveTab {
+    fn default() -> Self {
+        Self(Arc::new(watch::Sender::new(String::new())))
+    }
+}
+
+impl SharedActiveTab {
+    pub fn set(&self, id: String) {
+        self.0.send_if_modified(|active| {
+            if *active == id {
+                return false;
+            }
+
+            *active = id;
+
+            true
+        });
+    }
+
+    pub fn is_active(&self, id: &str) -> bool {
+        *self.0.borrow() == id
+    }
+
+    /// タブが表示されるまで待つ
+    ///
+    /// 待ったときはtrueを返す
+    pub async fn wait_until_active(&self, id: &str) -> bool {
+        if self.is_active(id) {
+            return false;
+        }
+
+        let mut rx = self.0.subscribe();
+
+        // Senderを保持しているため、Errにはならない
+        let _ = rx.wait_for(|active| active == id).await;
+
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn タブが表示されるまで待つ() {
+        let active_tab = SharedActiveTab::default();
+
+        active_tab.set("pod_tab".into());
+
+        let waiting = tokio::spawn({
+            let active_tab = active_tab.clone();
+            async move { active_tab.wait_until_active("rbac_tab").await }
+        });
+
+        tokio::time::sleep(Duration::from_millis(10)).await;
+
+        assert!(!waiting.is_finished());
+
+        active_tab.set("rbac_tab".into());
+
+        assert!(waiting.await.unwrap());
+
+        assert!(!active_tab.wait_until_active("rbac_tab").await);
+    }
+}
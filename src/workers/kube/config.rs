@@ -3,6 +3,8 @@ use std::{ops::Deref, path::PathBuf};
 use anyhow::{anyhow, Result};
 use kube::config::{Kubeconfig, KubeconfigError};
 
+use crate::features::{config::kube::SecretMask, yaml::kube::YamlFilter};
+
 use super::TargetNamespaces;
 
 #[derive(Debug, Default, Clone)]
@@ -11,6 +13,12 @@ pub struct KubeWorkerConfig {
     pub target_namespaces: Option<TargetNamespaces>,
     pub context: Option<String>,
     pub all_namespaces: bool,
+    pub yaml_filter: YamlFilter,
+    pub secret_mask: SecretMask,
+    /// リソースを作成・変更するリクエストを拒否する
+    pub read_only: bool,
+    /// Listタブのビューを保存する設定ファイル
+    pub config_file: Option<PathBuf>,
 }
 
 pub struct Context(String);
@@ -1,4 +1,5 @@
 use std::{
+    path::PathBuf,
     sync::{
         atomic::{AtomicBool, Ordering},
         Arc,
@@ -8,6 +9,7 @@ use std::{
 
 use anyhow::{anyhow, Result};
 use async_trait::async_trait;
+use chrono::Utc;
 use crossbeam::channel::{Receiver, Sender};
 use futures::future::select_all;
 use k8s_openapi::api::core::v1::Namespace;
@@ -20,27 +22,49 @@ use tokio::{
 use crate::{
     features::{
         api_resources::{
-            kube::{ApiPoller, ApiResource, ApiResources, SharedApiResources},
+            kube::{
+                resolve_resources, save_view, ApiPoller, ApiResource, ApiResources,
+                ConditionsWorker, OwnerTreeWorker, SavedView, SharedApiResources, SharedListQuery,
+            },
             message::{ApiMessage, ApiRequest, ApiResponse},
         },
         config::{
-            kube::{ConfigPoller, ConfigsDataWorker},
-            message::ConfigMessage,
+            kube::{ConfigPoller, ConfigsDataWorker, RawDataOptions, SharedSecretMask},
+            message::{ConfigMessage, ConfigRequest, RawDataOption, RequestData, SecretReveal},
         },
         context::message::{ContextMessage, ContextRequest, ContextResponse},
         event::kube::EventPoller,
-        get::{kube::yaml::GetYamlWorker, message::GetMessage},
+        get::{
+            kube::yaml::{fetch_yaml_for_copy, GetYamlWorker},
+            message::{GetMessage, GetRequest},
+        },
+        helm::{
+            kube::{fetch_revision_diff, HelmDescriptionWorker, HelmPoller},
+            message::{HelmMessage, HelmRequest, HelmResponse},
+        },
         namespace::message::{NamespaceMessage, NamespaceRequest, NamespaceResponse},
         network::{
-            kube::{NetworkDescriptionWorker, NetworkPoller},
-            message::NetworkMessage,
+            kube::{simulate, NetworkDescriptionWorker, NetworkPoller},
+            message::{NetworkMessage, NetworkResponse},
         },
         pod::{
             kube::{LogWorker, PodPoller},
             message::LogMessage,
         },
+        rbac::{
+            kube::{fetch_permissions, RbacPoller},
+            message::{RbacMessage, RbacRequest, RbacResponse},
+        },
+        workload::{
+            kube::{
+                fetch_template_diff, trigger_cronjob, WorkloadDescriptionWorker, WorkloadPoller,
+            },
+            message::{WorkloadMessage, WorkloadRequest, WorkloadResponse},
+        },
         yaml::{
-            kube::{FetchResourceList, YamlWorker},
+            kube::{
+                FetchResourceList, SharedYamlFilter, YamlCompareWorker, YamlTarget, YamlWorker,
+            },
             message::{YamlMessage, YamlRequest, YamlResponse},
         },
     },
@@ -51,8 +75,9 @@ use crate::{
 };
 
 use super::{
+    active_tab::SharedActiveTab,
     config::{read_kubeconfig, Context, KubeWorkerConfig},
-    store::{KubeState, KubeStore},
+    store::{KubeClients, KubeState, KubeStore},
     worker::Worker,
     AbortWorker as _,
 };
@@ -92,6 +117,10 @@ pub struct KubeController {
     kubeconfig: Kubeconfig,
     context: String,
     store: KubeStore,
+    shared_yaml_filter: SharedYamlFilter,
+    shared_secret_mask: SharedSecretMask,
+    read_only: bool,
+    config_file: Option<PathBuf>,
 }
 
 impl KubeController {
@@ -106,6 +135,10 @@ impl KubeController {
             target_namespaces,
             context,
             all_namespaces,
+            yaml_filter,
+            secret_mask,
+            read_only,
+            config_file,
         } = config;
 
         let kubeconfig = read_kubeconfig(kubeconfig)?;
@@ -137,6 +170,10 @@ impl KubeController {
             kubeconfig,
             context: context.to_string(),
             store,
+            shared_yaml_filter: yaml_filter.shared(),
+            shared_secret_mask: secret_mask.shared(),
+            read_only,
+            config_file,
         })
     }
 
@@ -148,8 +185,18 @@ impl KubeController {
             kubeconfig,
             mut context,
             mut store,
+            shared_yaml_filter,
+            shared_secret_mask,
+            read_only,
+            config_file,
         } = self;
 
+        // セレクタの入力欄はコンテキストを切り替えても残るため、共有する
+        let shared_list_query = SharedListQuery::default();
+
+        // 表示しているタブもコンテキストを切り替えても変わらない
+        let shared_active_tab = SharedActiveTab::default();
+
         while !is_terminated.load(Ordering::Relaxed) {
             let KubeState {
                 client,
@@ -180,13 +227,21 @@ impl KubeController {
             let event_controller_handle = EventController::new(
                 poller_base.clone(),
                 rx.clone(),
+                context.to_string(),
                 kubeconfig
                     .contexts
                     .iter()
                     .map(|ctx| ctx.name.to_string())
                     .collect(),
+                store.clients(),
                 shared_target_api_resources.clone(),
                 shared_api_resources.clone(),
+                shared_yaml_filter.clone(),
+                shared_secret_mask.clone(),
+                shared_list_query.clone(),
+                read_only,
+                config_file.clone(),
+                shared_active_tab.clone(),
             )
             .spawn();
 
@@ -195,10 +250,16 @@ impl KubeController {
             let network_handle =
                 NetworkPoller::new(poller_base.clone(), shared_api_resources.clone()).spawn();
             let event_handle = EventPoller::new(poller_base.clone()).spawn();
+            let helm_handle =
+                HelmPoller::new(poller_base.clone(), shared_active_tab.clone()).spawn();
+            let workload_handle = WorkloadPoller::new(poller_base.clone()).spawn();
+            let rbac_handle =
+                RbacPoller::new(poller_base.clone(), shared_active_tab.clone()).spawn();
             let api_handle = ApiPoller::new(
                 poller_base.clone(),
                 shared_target_api_resources.clone(),
                 shared_api_resources,
+                shared_list_query.clone(),
             )
             .spawn();
 
@@ -209,6 +270,9 @@ impl KubeController {
                 network_handle,
                 event_handle,
                 api_handle,
+                helm_handle,
+                workload_handle,
+                rbac_handle,
             ];
 
             while !handles.is_empty() {
@@ -259,25 +323,50 @@ impl KubeController {
 struct EventController {
     base: PollerBase,
     rx: Receiver<Message>,
+    context: String,
     contexts: Vec<String>,
+    kube_clients: KubeClients,
     shared_target_api_resources: SharedTargetApiResources,
     shared_api_resources: SharedApiResources,
+    shared_yaml_filter: SharedYamlFilter,
+    shared_secret_mask: SharedSecretMask,
+    shared_list_query: SharedListQuery,
+    read_only: bool,
+    config_file: Option<PathBuf>,
+    shared_active_tab: SharedActiveTab,
 }
 
 impl EventController {
+    #[allow(clippy::too_many_arguments)]
     fn new(
         base: PollerBase,
         rx: Receiver<Message>,
+        context: String,
         contexts: Vec<String>,
+        kube_clients: KubeClients,
         shared_target_api_resources: SharedTargetApiResources,
         shared_api_resources: SharedApiResources,
+        shared_yaml_filter: SharedYamlFilter,
+        shared_secret_mask: SharedSecretMask,
+        shared_list_query: SharedListQuery,
+        read_only: bool,
+        config_file: Option<PathBuf>,
+        shared_active_tab: SharedActiveTab,
     ) -> Self {
         Self {
             base,
             rx,
+            context,
             contexts,
+            kube_clients,
             shared_target_api_resources,
             shared_api_resources,
+            shared_yaml_filter,
+            shared_secret_mask,
+            shared_list_query,
+            read_only,
+            config_file,
+            shared_active_tab,
         }
     }
 }
@@ -291,14 +380,33 @@ impl Worker for EventController {
         let mut config_handler: Option<AbortHandle> = None;
         let mut network_handler: Option<AbortHandle> = None;
         let mut yaml_handler: Option<AbortHandle> = None;
+        let mut yaml_compare_handler: Option<AbortHandle> = None;
         let mut get_handler: Option<AbortHandle> = None;
+        let mut helm_handler: Option<AbortHandle> = None;
+        let mut workload_handler: Option<AbortHandle> = None;
+        let mut conditions_handler: Option<AbortHandle> = None;
+        let mut owner_tree_handler: Option<AbortHandle> = None;
+
+        let mut yaml_target: Option<YamlTarget> = None;
+        let mut get_request: Option<GetRequest> = None;
+        let mut config_request: Option<ConfigRequest> = None;
+
+        let shared_raw_data_options = RawDataOptions::default().shared();
 
         let EventController {
             base: poll_worker,
             rx,
+            context,
             contexts,
+            kube_clients,
             shared_target_api_resources,
             shared_api_resources,
+            shared_yaml_filter,
+            shared_secret_mask,
+            shared_list_query,
+            read_only,
+            config_file,
+            shared_active_tab,
         } = self;
 
         let PollerBase {
@@ -350,11 +458,36 @@ impl Worker for EventController {
                                 yaml_handler = None;
                             }
 
+                            if let Some(handler) = yaml_compare_handler {
+                                handler.abort();
+                                yaml_compare_handler = None;
+                            }
+
                             if let Some(handler) = get_handler {
                                 handler.abort();
                                 get_handler = None;
                             }
 
+                            if let Some(handler) = helm_handler {
+                                handler.abort();
+                                helm_handler = None;
+                            }
+
+                            if let Some(handler) = workload_handler {
+                                handler.abort();
+                                workload_handler = None;
+                            }
+
+                            if let Some(handler) = conditions_handler {
+                                handler.abort();
+                                conditions_handler = None;
+                            }
+
+                            if let Some(handler) = owner_tree_handler {
+                                handler.abort();
+                                owner_tree_handler = None;
+                            }
+
                             tx.send(NamespaceResponse::Set(req).into())
                                 .expect("Failed to send NamespaceResponse:Set");
                         }
@@ -375,11 +508,29 @@ impl Worker for EventController {
                             handler.abort();
                         }
 
+                        // 別のConfigMapやSecretを選択したときは伏せ字と表示方法を元に戻す
+                        if config_request.as_ref() != Some(&req) {
+                            shared_raw_data_options.write().await.reset();
+                        }
+
+                        let secret = match &req {
+                            ConfigRequest::Secret(RequestData { name, namespace }) => {
+                                Some((namespace.to_string(), name.to_string()))
+                            }
+                            ConfigRequest::ConfigMap(_) => None,
+                        };
+
+                        shared_secret_mask.write().await.set_secret(secret);
+
+                        config_request = Some(req.clone());
+
                         config_handler = Some(
                             ConfigsDataWorker::new(
                                 is_terminated.clone(),
                                 tx,
                                 kube_client.clone(),
+                                shared_secret_mask.clone(),
+                                shared_raw_data_options.clone(),
                                 req,
                             )
                             .spawn(),
@@ -388,6 +539,66 @@ impl Worker for EventController {
                         task::yield_now().await;
                     }
 
+                    Kube::Config(ConfigMessage::ToggleSecretReveal(reveal)) => {
+                        {
+                            let mut secret_mask = shared_secret_mask.write().await;
+
+                            match reveal {
+                                SecretReveal::All => secret_mask.toggle_all(),
+                                SecretReveal::Key(key) => secret_mask.toggle_key(&key),
+                            }
+                        }
+
+                        // 表示中のデータにすぐ反映させるためにworkerを再起動する
+                        if let (Some(handler), Some(req)) = (&config_handler, &config_request) {
+                            handler.abort();
+
+                            config_handler = Some(
+                                ConfigsDataWorker::new(
+                                    is_terminated.clone(),
+                                    tx,
+                                    kube_client.clone(),
+                                    shared_secret_mask.clone(),
+                                    shared_raw_data_options.clone(),
+                                    req.clone(),
+                                )
+                                .spawn(),
+                            );
+                        }
+
+                        task::yield_now().await;
+                    }
+
+                    Kube::Config(ConfigMessage::ToggleRawDataOption(option)) => {
+                        {
+                            let mut options = shared_raw_data_options.write().await;
+
+                            match option {
+                                RawDataOption::Expand => options.toggle_expand(),
+                                RawDataOption::Decode => options.toggle_decode(),
+                            }
+                        }
+
+                        // 表示中のデータにすぐ反映させるためにworkerを再起動する
+                        if let (Some(handler), Some(req)) = (&config_handler, &config_request) {
+                            handler.abort();
+
+                            config_handler = Some(
+                                ConfigsDataWorker::new(
+                                    is_terminated.clone(),
+                                    tx,
+                                    kube_client.clone(),
+                                    shared_secret_mask.clone(),
+                                    shared_raw_data_options.clone(),
+                                    req.clone(),
+                                )
+                                .spawn(),
+                            );
+                        }
+
+                        task::yield_now().await;
+                    }
+
                     Kube::Api(ApiMessage::Request(req)) => {
                         use ApiRequest::*;
                         match req {
@@ -401,6 +612,95 @@ impl Worker for EventController {
                                     shared_target_api_resources.write().await;
                                 *target_api_resources = req.clone();
                             }
+                            Query(query) => {
+                                *shared_list_query.write().await = query;
+                            }
+                            SetView(names) => {
+                                let api_resources = shared_api_resources.read().await;
+
+                                let resolved = resolve_resources(&names, &api_resources);
+
+                                if let Ok(resolved) = &resolved {
+                                    *shared_target_api_resources.write().await = resolved.clone();
+                                }
+
+                                // ダイアログの項目を更新してから選択状態を反映する
+                                tx.send(ApiResponse::Get(Ok(api_resources.to_vec())).into())
+                                    .expect("Failed to send ApiResponse::Get");
+
+                                tx.send(ApiResponse::SetView(resolved).into())
+                                    .expect("Failed to send ApiResponse::SetView");
+                            }
+                            SaveView(name) => {
+                                let resources: Vec<String> = shared_target_api_resources
+                                    .read()
+                                    .await
+                                    .iter()
+                                    .map(ApiResource::kubectl_name)
+                                    .collect();
+
+                                let views = if resources.is_empty() {
+                                    Err(anyhow!("No resources are selected"))
+                                } else if let Some(path) = config_file {
+                                    let view = SavedView {
+                                        name,
+                                        key: None,
+                                        resources,
+                                    };
+
+                                    save_view(path, view)
+                                } else {
+                                    Err(anyhow!("Cannot find the config file path"))
+                                };
+
+                                tx.send(ApiResponse::SaveView(views).into())
+                                    .expect("Failed to send ApiResponse::SaveView");
+                            }
+                            Conditions {
+                                resource,
+                                name,
+                                namespace,
+                            } => {
+                                if let Some(handler) = conditions_handler {
+                                    handler.abort();
+                                }
+
+                                conditions_handler = Some(
+                                    ConditionsWorker::new(
+                                        tx,
+                                        kube_client.clone(),
+                                        resource,
+                                        name,
+                                        namespace,
+                                    )
+                                    .spawn(),
+                                );
+
+                                task::yield_now().await;
+                            }
+                            OwnerTree {
+                                resource,
+                                name,
+                                namespace,
+                            } => {
+                                if let Some(handler) = owner_tree_handler {
+                                    handler.abort();
+                                }
+
+                                owner_tree_handler = Some(
+                                    OwnerTreeWorker::new(
+                                        tx,
+                                        kube_client.clone(),
+                                        shared_api_resources.clone(),
+                                        resource,
+                                        name,
+                                        namespace,
+                                    )
+                                    .spawn(),
+                                );
+
+                                task::yield_now().await;
+                            }
                         }
                     }
 
@@ -425,10 +725,30 @@ impl Worker for EventController {
                                 h.abort();
                             }
 
+                            if let Some(h) = yaml_compare_handler {
+                                h.abort();
+                            }
+
                             if let Some(h) = get_handler {
                                 h.abort();
                             }
 
+                            if let Some(h) = helm_handler {
+                                h.abort();
+                            }
+
+                            if let Some(h) = workload_handler {
+                                h.abort();
+                            }
+
+                            if let Some(h) = conditions_handler {
+                                h.abort();
+                            }
+
+                            if let Some(h) = owner_tree_handler {
+                                h.abort();
+                            }
+
                             return WorkerResult::ChangedContext(req);
                         }
                     },
@@ -467,33 +787,162 @@ impl Worker for EventController {
                                     handler.abort();
                                 }
 
+                                yaml_target = Some(req.clone());
+
                                 yaml_handler = Some(
                                     YamlWorker::new(
                                         is_terminated.clone(),
                                         tx,
                                         kube_client.clone(),
                                         shared_api_resources.clone(),
+                                        shared_yaml_filter.clone(),
                                         req,
                                     )
                                     .spawn(),
                                 );
                                 task::yield_now().await;
                             }
+                            ToggleNoisyFields => {
+                                shared_yaml_filter.write().await.toggle();
+
+                                // 表示中のyamlにすぐ反映させるためにworkerを再起動する
+                                if let (Some(handler), Some(req)) = (&yaml_handler, &yaml_target) {
+                                    handler.abort();
+
+                                    yaml_handler = Some(
+                                        YamlWorker::new(
+                                            is_terminated.clone(),
+                                            tx.clone(),
+                                            kube_client.clone(),
+                                            shared_api_resources.clone(),
+                                            shared_yaml_filter.clone(),
+                                            req.clone(),
+                                        )
+                                        .spawn(),
+                                    );
+                                }
+
+                                if let (Some(handler), Some(req)) = (&get_handler, &get_request) {
+                                    handler.abort();
+
+                                    get_handler = Some(
+                                        GetYamlWorker::new(
+                                            is_terminated.clone(),
+                                            tx,
+                                            kube_client.clone(),
+                                            shared_yaml_filter.clone(),
+                                            req.clone(),
+                                        )
+                                        .spawn(),
+                                    );
+                                }
+
+                                task::yield_now().await;
+                            }
+                            CompareContexts => {
+                                tx.send(YamlResponse::CompareContexts(contexts.to_vec()).into())
+                                    .expect("Failed to send YamlResponse::CompareContexts");
+                            }
+                            CompareNamespaces(req) => {
+                                let namespaces = match kube_clients.get(&req) {
+                                    Some(client) => fetch_all_namespaces(client.clone()).await,
+                                    None => Err(anyhow!("Cannot get context {}", req)),
+                                };
+
+                                tx.send(YamlResponse::CompareNamespaces(namespaces).into())
+                                    .expect("Failed to send YamlResponse::CompareNamespaces");
+                            }
+                            Compare(req) => {
+                                if let Some(handler) = yaml_compare_handler {
+                                    handler.abort();
+                                }
+
+                                let Some(target_client) = kube_clients.get(&req.context) else {
+                                    yaml_compare_handler = None;
+
+                                    tx.send(
+                                        YamlResponse::Compare(Err(anyhow!(
+                                            "Cannot get context {}",
+                                            req.context
+                                        )))
+                                        .into(),
+                                    )
+                                    .expect("Failed to send YamlResponse::Compare");
+
+                                    continue;
+                                };
+
+                                yaml_compare_handler = Some(
+                                    YamlCompareWorker::new(
+                                        is_terminated.clone(),
+                                        tx,
+                                        context.to_string(),
+                                        kube_client.clone(),
+                                        target_client.clone(),
+                                        shared_api_resources.clone(),
+                                        req,
+                                    )
+                                    .spawn(),
+                                );
+                                task::yield_now().await;
+                            }
+                            CompareClose => {
+                                if let Some(handler) = yaml_compare_handler {
+                                    handler.abort();
+                                }
+
+                                yaml_compare_handler = None;
+                            }
                         }
                     }
 
+                    Kube::Get(GetMessage::CopyYamlRequest(req)) => {
+                        let yaml_filter = *shared_yaml_filter.read().await;
+
+                        let secret_mask = shared_secret_mask.read().await.clone();
+
+                        let yaml =
+                            fetch_yaml_for_copy(kube_client, &req, &yaml_filter, &secret_mask)
+                                .await;
+
+                        tx.send(GetMessage::CopyYamlResponse(yaml).into())
+                            .expect("Failed to send GetMessage::CopyYamlResponse");
+                    }
+
                     Kube::Get(GetMessage::Request(req)) => {
                         if let Some(handler) = get_handler {
                             handler.abort();
                         }
 
+                        get_request = Some(req.clone());
+
                         get_handler = Some(
-                            GetYamlWorker::new(is_terminated.clone(), tx, kube_client.clone(), req)
-                                .spawn(),
+                            GetYamlWorker::new(
+                                is_terminated.clone(),
+                                tx,
+                                kube_client.clone(),
+                                shared_yaml_filter.clone(),
+                                req,
+                            )
+                            .spawn(),
                         );
                         task::yield_now().await;
                     }
 
+                    Kube::Network(NetworkMessage::SimulationRequest(query)) => {
+                        let default_namespace = shared_target_namespaces
+                            .read()
+                            .await
+                            .first()
+                            .cloned()
+                            .unwrap_or_else(|| "default".to_string());
+
+                        let report = simulate(kube_client, &query, &default_namespace).await;
+
+                        tx.send(NetworkResponse::Simulation(report).into())
+                            .expect("Failed to send NetworkResponse::Simulation");
+                    }
+
                     Kube::Network(NetworkMessage::Request(req)) => {
                         if let Some(handler) = network_handler {
                             handler.abort();
@@ -512,6 +961,93 @@ impl Worker for EventController {
 
                         task::yield_now().await;
                     }
+
+                    Kube::Helm(HelmMessage::Request(req)) => match req {
+                        HelmRequest::Release(target) => {
+                            if let Some(handler) = helm_handler {
+                                handler.abort();
+                            }
+
+                            helm_handler = Some(
+                                HelmDescriptionWorker::new(
+                                    is_terminated.clone(),
+                                    tx,
+                                    kube_client.clone(),
+                                    target,
+                                )
+                                .spawn(),
+                            );
+
+                            task::yield_now().await;
+                        }
+                        HelmRequest::Diff { target, revision } => {
+                            let diff = fetch_revision_diff(kube_client, &target, revision).await;
+
+                            tx.send(HelmResponse::Diff(diff).into())
+                                .expect("Failed to send HelmResponse::Diff");
+                        }
+                    },
+
+                    Kube::Workload(WorkloadMessage::Request(req)) => match req {
+                        WorkloadRequest::Description(target) => {
+                            if let Some(handler) = workload_handler {
+                                handler.abort();
+                            }
+
+                            workload_handler = Some(
+                                WorkloadDescriptionWorker::new(
+                                    is_terminated.clone(),
+                                    tx,
+                                    kube_client.clone(),
+                                    target,
+                                )
+                                .spawn(),
+                            );
+
+                            task::yield_now().await;
+                        }
+                        WorkloadRequest::Diff {
+                            target,
+                            revision,
+                            base,
+                        } => {
+                            let diff =
+                                fetch_template_diff(kube_client, &target, revision, base).await;
+
+                            tx.send(WorkloadResponse::Diff(diff).into())
+                                .expect("Failed to send WorkloadResponse::Diff");
+                        }
+                        WorkloadRequest::Trigger(target) => {
+                            let result = if *read_only {
+                                Err(anyhow!(
+                                    "Cannot create job from cronjob/{}/{} in read-only mode",
+                                    target.namespace,
+                                    target.name
+                                ))
+                            } else {
+                                trigger_cronjob(kube_client, &target, Utc::now()).await
+                            };
+
+                            tx.send(WorkloadResponse::Trigger(result).into())
+                                .expect("Failed to send WorkloadResponse::Trigger");
+                        }
+                    },
+
+                    Kube::Rbac(RbacMessage::Request(req)) => match req {
+                        RbacRequest::Permissions(subject) => {
+                            let target_namespaces = shared_target_namespaces.read().await;
+
+                            let permissions =
+                                fetch_permissions(kube_client, &subject, &target_namespaces).await;
+
+                            tx.send(RbacResponse::Permissions(permissions).into())
+                                .expect("Failed to send RbacResponse::Permissions");
+                        }
+                    },
+
+                    Kube::ActiveTab(id) => {
+                        shared_active_tab.set(id);
+                    }
                     _ => unreachable!(),
                 },
                 Ok(_) => unreachable!(),
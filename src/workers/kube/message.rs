@@ -3,9 +3,10 @@ use anyhow::Result;
 use crate::{
     features::{
         api_resources::message::ApiMessage, config::message::ConfigMessage,
-        context::message::ContextMessage, get::message::GetMessage,
-        namespace::message::NamespaceMessage, network::message::NetworkMessage,
-        pod::message::LogMessage, yaml::message::YamlMessage,
+        context::message::ContextMessage, event::kube::EventList, get::message::GetMessage,
+        helm::message::HelmMessage, namespace::message::NamespaceMessage,
+        network::message::NetworkMessage, pod::message::LogMessage, rbac::message::RbacMessage,
+        workload::message::WorkloadMessage, yaml::message::YamlMessage,
     },
     kube::table::KubeTable,
     message::Message,
@@ -22,7 +23,7 @@ pub enum Kube {
         context: String,
         namespaces: TargetNamespaces,
     },
-    Event(Result<Vec<String>>),
+    Event(Result<EventList>),
     Namespace(NamespaceMessage),
     Pod(Result<KubeTable>),
     Log(LogMessage),
@@ -30,6 +31,11 @@ pub enum Kube {
     Network(NetworkMessage),
     Yaml(YamlMessage),
     Get(GetMessage),
+    Helm(HelmMessage),
+    Workload(WorkloadMessage),
+    Rbac(RbacMessage),
+    /// 表示しているタブのID
+    ActiveTab(String),
 }
 
 impl From<Kube> for Message {
@@ -13,6 +13,8 @@ use super::controller::{TargetApiResources, TargetNamespaces};
 
 pub type Context = String;
 
+pub type KubeClients = BTreeMap<Context, KubeClient>;
+
 #[derive(Clone)]
 pub struct KubeState {
     pub client: KubeClient,
@@ -132,6 +134,14 @@ impl KubeStore {
     pub fn insert(&mut self, context: Context, state: KubeState) {
         self.inner.insert(context, state);
     }
+
+    /// context毎のクライアントを返す
+    pub fn clients(&self) -> KubeClients {
+        self.inner
+            .iter()
+            .map(|(context, state)| (context.to_string(), state.client.clone()))
+            .collect()
+    }
 }
 
 #[cfg(test)]
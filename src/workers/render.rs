@@ -16,11 +16,14 @@ use crossbeam::channel::{Receiver, Sender};
 use ratatui::{backend::CrosstermBackend, layout::Direction, Terminal, TerminalOptions, Viewport};
 
 use crate::{
+    clipboard::{Clipboard, ClipboardBackend},
+    features::api_resources::kube::SavedView,
     kube::context::{Context, Namespace},
     logger,
     message::Message,
     panic_set_hook,
     ui::WindowAction,
+    workers::kube::message::Kube,
 };
 
 use self::{
@@ -33,6 +36,9 @@ pub struct Render {
     rx: Receiver<Message>,
     is_terminated: Arc<AtomicBool>,
     direction: Direction,
+    clipboard_backend: ClipboardBackend,
+    saved_views: Vec<SavedView>,
+    read_only: bool,
 }
 
 impl Render {
@@ -41,9 +47,15 @@ impl Render {
         rx: Receiver<Message>,
         is_terminated: Arc<AtomicBool>,
         direction: Direction,
+        clipboard_backend: ClipboardBackend,
+        saved_views: Vec<SavedView>,
+        read_only: bool,
     ) -> Self {
         Self {
             direction,
+            clipboard_backend,
+            saved_views,
+            read_only,
             tx,
             rx,
             is_terminated,
@@ -73,12 +85,18 @@ impl Render {
     fn render(&self) -> Result<()> {
         let namespace = Rc::new(RefCell::new(Namespace::new()));
         let context = Rc::new(RefCell::new(Context::new()));
+        let clipboard = Some(Rc::new(RefCell::new(Clipboard::new(
+            self.clipboard_backend,
+        ))));
 
         let mut window = WindowInit::new(
             self.direction,
             self.tx.clone(),
             context.clone(),
             namespace.clone(),
+            clipboard.clone(),
+            self.saved_views.clone(),
+            self.read_only,
         )
         .build();
 
@@ -91,7 +109,18 @@ impl Render {
 
         terminal.clear()?;
 
+        let mut active_tab = String::new();
+
         while !self.is_terminated.load(Ordering::Relaxed) {
+            // タブを表示しているときだけ取得する処理のために、表示しているタブを伝える
+            if window.active_tab_id() != active_tab {
+                active_tab = window.active_tab_id().to_string();
+
+                self.tx
+                    .send(Kube::ActiveTab(active_tab.clone()).into())
+                    .expect("Failed to send Kube::ActiveTab");
+            }
+
             terminal.draw(|f| {
                 window.render(f);
             })?;
@@ -109,6 +138,8 @@ impl Render {
                         ev,
                         &mut context.borrow_mut(),
                         &mut namespace.borrow_mut(),
+                        &clipboard,
+                        &self.tx,
                     );
                 }
             }
@@ -1,36 +1,56 @@
-use std::collections::BTreeMap;
+use std::{cell::RefCell, collections::BTreeMap, rc::Rc};
 
 use anyhow::Result;
-use crossbeam::channel::Receiver;
+use crossbeam::channel::{Receiver, Sender};
+use ratatui::{
+    crossterm::event::{KeyCode, KeyEvent, KeyModifiers},
+    style::{Color, Style},
+    text::Span,
+};
 
 use crate::{
+    clipboard::Clipboard,
     features::{
-        api_resources::message::{ApiMessage, ApiResponse},
+        api_resources::{
+            kube::{ApiResource, ApiTable},
+            message::{ApiMessage, ApiResponse},
+            view::{switch_view, view_items},
+        },
         component_id::{
-            CONFIG_RAW_DATA_WIDGET_ID, CONFIG_WIDGET_ID, CONTEXT_DIALOG_ID, EVENT_WIDGET_ID,
-            LIST_DIALOG_ID, LIST_WIDGET_ID, MULTIPLE_NAMESPACES_DIALOG_ID,
-            NETWORK_DESCRIPTION_WIDGET_ID, NETWORK_WIDGET_ID, POD_LOG_WIDGET_ID, POD_WIDGET_ID,
-            SINGLE_NAMESPACE_DIALOG_ID, YAML_DIALOG_ID, YAML_KIND_DIALOG_ID, YAML_NAME_DIALOG_ID,
-            YAML_NOT_FOUND_DIALOG_ID, YAML_WIDGET_ID,
+            CONFIG_RAW_DATA_WIDGET_ID, CONFIG_SECRET_REVEAL_DIALOG_ID, CONFIG_WIDGET_ID,
+            CONTEXT_DIALOG_ID, EVENT_WIDGET_ID, HELM_DESCRIPTION_WIDGET_ID, HELM_DIFF_DIALOG_ID,
+            HELM_HISTORY_WIDGET_ID, HELM_WIDGET_ID, LIST_CONDITIONS_DIALOG_ID, LIST_DIALOG_ID,
+            LIST_KIND_WIDGET_ID, LIST_OWNER_TREE_DIALOG_ID, LIST_SAVE_VIEW_RESULT_DIALOG_ID,
+            LIST_VIEW_DIALOG_ID, LIST_WIDGET_ID, MULTIPLE_NAMESPACES_DIALOG_ID,
+            NETWORK_DESCRIPTION_WIDGET_ID, NETWORK_SIMULATION_DIALOG_ID, NETWORK_WIDGET_ID,
+            POD_LOG_WIDGET_ID, POD_WIDGET_ID, RBAC_CAN_I_WIDGET_ID, RBAC_PERMISSION_WIDGET_ID,
+            RBAC_SUBJECT_WIDGET_ID, SINGLE_NAMESPACE_DIALOG_ID, WORKLOAD_DESCRIPTION_WIDGET_ID,
+            WORKLOAD_DIFF_DIALOG_ID, WORKLOAD_ROLLOUT_WIDGET_ID, WORKLOAD_TRIGGER_DIALOG_ID,
+            WORKLOAD_WIDGET_ID, YAML_COMPARE_CONTEXT_DIALOG_ID, YAML_COMPARE_DIALOG_ID,
+            YAML_COMPARE_NAMESPACE_DIALOG_ID, YAML_DIALOG_ID, YAML_KIND_DIALOG_ID,
+            YAML_NAME_DIALOG_ID, YAML_NOT_FOUND_DIALOG_ID, YAML_WIDGET_ID,
         },
         config::message::ConfigMessage,
         context::message::{ContextMessage, ContextResponse},
         get::message::{GetMessage, GetResponse},
+        helm::message::{HelmMessage, HelmResponse},
         namespace::message::{NamespaceMessage, NamespaceResponse},
         network::message::{NetworkMessage, NetworkResponse},
         pod::message::LogMessage,
+        rbac::message::{RbacMessage, RbacResponse, RulesReview},
+        workload::message::{WorkloadMessage, WorkloadResponse},
         yaml::message::{YamlMessage, YamlResourceListItem, YamlResponse},
     },
     kube::{
         context::{Context, Namespace},
-        table::{KubeTable, KubeTableRow},
+        table::{skipped_namespaces_status, KubeTable, KubeTableRow, SkippedNamespace},
     },
     logger,
     message::Message,
     ui::{
         event::{Callback, EventResult},
         util::chars::convert_tabs_to_spaces,
-        widget::{Item, LiteralItem, TableItem, WidgetTrait},
+        widget::{Item, LiteralItem, SelectedItem, TableItem, WidgetTrait},
         Window, WindowAction,
     },
     workers::kube::message::Kube,
@@ -95,7 +115,21 @@ pub fn window_action(window: &mut Window, rx: &Receiver<Message>) -> WindowActio
     WindowAction::Continue
 }
 
+/// 取得できなかったネームスペースをウィジェットの下部に表示する
+fn update_skipped_namespaces(window: &mut Window, id: &str, skipped: &[SkippedNamespace]) {
+    *(window.find_widget_mut(id).widget_base_mut().status_mut()) =
+        skipped_namespaces_status(skipped)
+            .map(|status| Span::styled(status, Style::default().fg(Color::Yellow)).into());
+}
+
 fn update_widget_item_for_table(window: &mut Window, id: &str, table: Result<KubeTable>) {
+    let skipped = table
+        .as_ref()
+        .map(|table| table.skipped.as_slice())
+        .unwrap_or_default();
+
+    update_skipped_namespaces(window, id, skipped);
+
     let widget = window.find_widget_mut(id);
     let w = widget.as_mut_table();
 
@@ -180,11 +214,78 @@ fn update_widget_item_for_vec(window: &mut Window, id: &str, vec: Result<Vec<Str
     }
 }
 
+/// Listダイアログの項目（優先バージョン以外は灰色で表示する）
+fn api_resource_dialog_item(key: &ApiResource) -> LiteralItem {
+    let Ok(json) = serde_json::to_string(key) else {
+        unreachable!()
+    };
+
+    let metadata = BTreeMap::from([("key".into(), json)]);
+
+    let item = if key.is_api() || key.is_preferred_version() {
+        key.to_string()
+    } else {
+        format!("\x1b[90m{}\x1b[39m", key)
+    };
+
+    LiteralItem::new(item, Some(metadata))
+}
+
+/// リソースの種類と、カーソルのある種類のテーブルを更新する
+fn update_list_widgets(window: &mut Window, tables: Result<Vec<ApiTable>>) {
+    let tables = match tables {
+        Ok(tables) => tables,
+        Err(e) => {
+            update_widget_item_for_table(window, LIST_WIDGET_ID, Err(e));
+            return;
+        }
+    };
+
+    let items = tables
+        .iter()
+        .map(|ApiTable { resource, table }| {
+            let item = match table {
+                Ok(table) => format!("{} ({})", resource, table.rows.len()),
+                Err(_) => format!("{} \x1b[31m(error)\x1b[39m", resource),
+            };
+
+            LiteralItem::new(
+                item,
+                Some(BTreeMap::from([("key".into(), resource.to_string())])),
+            )
+        })
+        .collect();
+
+    let widget = window.find_widget_mut(LIST_KIND_WIDGET_ID);
+
+    widget.update_widget_item(Item::Array(items));
+
+    let selected_key = match widget.widget_item() {
+        Some(SelectedItem::Literal {
+            metadata: Some(metadata),
+            ..
+        }) => metadata.get("key").cloned(),
+        _ => None,
+    };
+
+    let Some(ApiTable { table, .. }) = tables
+        .into_iter()
+        .find(|ApiTable { resource, .. }| Some(resource.to_string()) == selected_key)
+    else {
+        window.widget_clear(LIST_WIDGET_ID);
+        return;
+    };
+
+    update_widget_item_for_table(window, LIST_WIDGET_ID, table);
+}
+
 pub fn update_contents(
     window: &mut Window,
     ev: Kube,
     context: &mut Context,
     namespace: &mut Namespace,
+    clipboard: &Option<Rc<RefCell<Clipboard>>>,
+    tx: &Sender<Message>,
 ) {
     match ev {
         Kube::Pod(pods_table) => {
@@ -222,11 +323,21 @@ pub fn update_contents(
                 Data(data) => {
                     update_widget_item_for_vec(window, CONFIG_RAW_DATA_WIDGET_ID, data);
                 }
+                SecretKeys(keys) => {
+                    update_widget_item_for_vec(window, CONFIG_SECRET_REVEAL_DIALOG_ID, Ok(keys));
+                }
             }
         }
 
         Kube::Event(ev) => {
-            update_widget_item_for_vec(window, EVENT_WIDGET_ID, ev);
+            let skipped = ev
+                .as_ref()
+                .map(|ev| ev.skipped.as_slice())
+                .unwrap_or_default();
+
+            update_skipped_namespaces(window, EVENT_WIDGET_ID, skipped);
+
+            update_widget_item_for_vec(window, EVENT_WIDGET_ID, ev.map(|ev| ev.lines));
         }
 
         Kube::Namespace(NamespaceMessage::Response(res)) => match res {
@@ -289,21 +400,7 @@ pub fn update_contents(
                 .as_mut_multiple_select();
 
             for key in list {
-                let Ok(json) = serde_json::to_string(&key) else {
-                    unreachable!()
-                };
-
-                let metadata = BTreeMap::from([("key".into(), json)]);
-
-                let item = if key.is_api() || key.is_preferred_version() {
-                    key.to_string()
-                } else {
-                    format!("\x1b[90m{}\x1b[39m", key)
-                };
-
-                let literal_item = LiteralItem::new(item, Some(metadata));
-
-                w.select_item(&literal_item);
+                w.select_item(&api_resource_dialog_item(&key));
             }
         }
 
@@ -314,23 +411,7 @@ pub fn update_contents(
                     let widget = window.find_widget_mut(LIST_DIALOG_ID);
                     match list {
                         Ok(i) => {
-                            let items = i
-                                .into_iter()
-                                .map(|key| {
-                                    let Ok(json) = serde_json::to_string(&key) else {
-                                        unreachable!()
-                                    };
-                                    let metadata = BTreeMap::from([("key".into(), json)]);
-
-                                    let item = if key.is_api() || key.is_preferred_version() {
-                                        key.to_string()
-                                    } else {
-                                        format!("\x1b[90m{}\x1b[39m", key)
-                                    };
-
-                                    LiteralItem::new(item, Some(metadata))
-                                })
-                                .collect();
+                            let items = i.iter().map(api_resource_dialog_item).collect();
 
                             widget.update_widget_item(Item::Array(items));
                         }
@@ -339,8 +420,67 @@ pub fn update_contents(
                         }
                     }
                 }
-                Poll(list) => {
-                    update_widget_item_for_vec(window, LIST_WIDGET_ID, list);
+                Poll(tables) => {
+                    update_list_widgets(window, tables);
+                }
+                SetView(list) => match list {
+                    Ok(list) => {
+                        let w = window
+                            .find_widget_mut(LIST_DIALOG_ID)
+                            .as_mut_multiple_select();
+
+                        w.unselect_all();
+
+                        for key in list {
+                            w.select_item(&api_resource_dialog_item(&key));
+                        }
+
+                        window.widget_clear(LIST_KIND_WIDGET_ID);
+                        window.widget_clear(LIST_WIDGET_ID);
+                    }
+                    Err(e) => {
+                        update_widget_item_for_table(window, LIST_WIDGET_ID, Err(e));
+                    }
+                },
+                SaveView(res) => {
+                    if let Ok(views) = &res {
+                        window
+                            .find_widget_mut(LIST_VIEW_DIALOG_ID)
+                            .update_widget_item(Item::Array(view_items(views)));
+                    }
+
+                    // 追加したビューは一覧の末尾にある
+                    let res = res.map(|views| {
+                        let Some(view) = views.last() else {
+                            return Vec::new();
+                        };
+
+                        let key = match view.key_char() {
+                            Some(key) => {
+                                window.add_action(
+                                    KeyEvent::new(KeyCode::Char(key), KeyModifiers::ALT),
+                                    switch_view(tx.clone(), view.resources.clone()),
+                                );
+
+                                format!("key: Alt+{}", key)
+                            }
+                            None => "key: none (Alt+1..9 are already used)".into(),
+                        };
+
+                        vec![
+                            format!("Saved view '{}' to the config file", view.name),
+                            format!("resources: {}", view.resources.join(", ")),
+                            key,
+                        ]
+                    });
+
+                    update_widget_item_for_vec(window, LIST_SAVE_VIEW_RESULT_DIALOG_ID, res);
+                }
+                Conditions(res) => {
+                    update_widget_item_for_vec(window, LIST_CONDITIONS_DIALOG_ID, res);
+                }
+                OwnerTree(res) => {
+                    update_widget_item_for_table(window, LIST_OWNER_TREE_DIALOG_ID, res);
                 }
             }
         }
@@ -427,6 +567,19 @@ pub fn update_contents(
                 Yaml(res) => {
                     update_widget_item_for_vec(window, YAML_WIDGET_ID, res);
                 }
+                CompareContexts(contexts) => {
+                    update_widget_item_for_vec(
+                        window,
+                        YAML_COMPARE_CONTEXT_DIALOG_ID,
+                        Ok(contexts),
+                    );
+                }
+                CompareNamespaces(res) => {
+                    update_widget_item_for_vec(window, YAML_COMPARE_NAMESPACE_DIALOG_ID, res);
+                }
+                Compare(res) => {
+                    update_widget_item_for_vec(window, YAML_COMPARE_DIALOG_ID, res);
+                }
             }
         }
 
@@ -437,6 +590,21 @@ pub fn update_contents(
             update_widget_item_for_vec(window, YAML_DIALOG_ID, yaml);
         }
 
+        Kube::Get(GetMessage::CopyYamlResponse(res)) => match res {
+            Ok(yaml) => {
+                if let Some(clipboard) = clipboard {
+                    logger!(info, "Clipboard saved yaml");
+
+                    if let Err(e) = clipboard.borrow_mut().set_contents(yaml) {
+                        logger!(error, "Clipboard Error '{}'", e);
+                    }
+                }
+            }
+            Err(e) => {
+                logger!(error, "Failed to fetch yaml for clipboard: {}", e);
+            }
+        },
+
         Kube::Network(NetworkMessage::Response(ev)) => {
             use NetworkResponse::*;
 
@@ -445,9 +613,84 @@ pub fn update_contents(
                 Yaml(res) => {
                     update_widget_item_for_vec(window, NETWORK_DESCRIPTION_WIDGET_ID, res);
                 }
+                Simulation(res) => {
+                    update_widget_item_for_vec(window, NETWORK_SIMULATION_DIALOG_ID, res);
+                }
             }
         }
 
+        Kube::Helm(HelmMessage::Response(ev)) => match ev {
+            HelmResponse::Table(res) => {
+                update_widget_item_for_table(window, HELM_WIDGET_ID, res);
+            }
+            HelmResponse::Description(res) => {
+                update_widget_item_for_vec(window, HELM_DESCRIPTION_WIDGET_ID, res);
+            }
+            HelmResponse::History(res) => {
+                update_widget_item_for_table(window, HELM_HISTORY_WIDGET_ID, res);
+            }
+            HelmResponse::Diff(res) => {
+                update_widget_item_for_vec(window, HELM_DIFF_DIALOG_ID, res);
+            }
+        },
+
+        Kube::Workload(WorkloadMessage::Response(ev)) => match ev {
+            WorkloadResponse::Table(res) => {
+                update_widget_item_for_table(window, WORKLOAD_WIDGET_ID, res);
+            }
+            WorkloadResponse::Description(res) => {
+                update_widget_item_for_vec(window, WORKLOAD_DESCRIPTION_WIDGET_ID, res);
+            }
+            WorkloadResponse::Progress(progress) => {
+                *(window
+                    .find_widget_mut(WORKLOAD_ROLLOUT_WIDGET_ID)
+                    .widget_base_mut()
+                    .append_title_mut()) = Some(format!(" {}", progress).into());
+            }
+            WorkloadResponse::History(res) => {
+                update_widget_item_for_table(window, WORKLOAD_ROLLOUT_WIDGET_ID, res);
+            }
+            WorkloadResponse::Diff(res) => {
+                update_widget_item_for_vec(window, WORKLOAD_DIFF_DIALOG_ID, res);
+            }
+            WorkloadResponse::Trigger(res) => {
+                update_widget_item_for_vec(
+                    window,
+                    WORKLOAD_TRIGGER_DIALOG_ID,
+                    res.map(|message| vec![message]),
+                );
+            }
+        },
+
+        Kube::Rbac(RbacMessage::Response(ev)) => match ev {
+            RbacResponse::Subjects(res) => {
+                update_widget_item_for_table(window, RBAC_SUBJECT_WIDGET_ID, res);
+            }
+            RbacResponse::Permissions(res) => {
+                update_widget_item_for_table(window, RBAC_PERMISSION_WIDGET_ID, res);
+            }
+            RbacResponse::CanI(res) => {
+                let incomplete = matches!(
+                    res,
+                    Ok(RulesReview {
+                        incomplete: true,
+                        ..
+                    })
+                );
+
+                *(window
+                    .find_widget_mut(RBAC_CAN_I_WIDGET_ID)
+                    .widget_base_mut()
+                    .append_title_mut()) = incomplete.then(|| " (incomplete)".into());
+
+                update_widget_item_for_table(
+                    window,
+                    RBAC_CAN_I_WIDGET_ID,
+                    res.map(|review| review.table),
+                );
+            }
+        },
+
         _ => unreachable!(),
     }
 }
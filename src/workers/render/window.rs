@@ -1,13 +1,6 @@
-use std::{cell::RefCell, rc::Rc};
+use std::{cell::RefCell, collections::BTreeMap, rc::Rc};
 
 use crossbeam::channel::Sender;
-use k8s_openapi::{
-    api::{
-        core::v1::{ConfigMap, Pod, Secret, Service},
-        networking::v1::{Ingress, NetworkPolicy},
-    },
-    Resource as _,
-};
 use ratatui::{
     crossterm::event::{KeyCode, KeyEvent, KeyModifiers},
     layout::Direction,
@@ -18,40 +11,41 @@ use ratatui::{
 use crate::{
     clipboard::Clipboard,
     features::{
-        api_resources::view::ListTab,
+        api_resources::{
+            kube::SavedView,
+            view::{switch_view, ListTab},
+        },
         component_id::{
-            CONFIG_WIDGET_ID, CONTEXT_DIALOG_ID, HELP_DIALOG_ID, MULTIPLE_NAMESPACES_DIALOG_ID,
-            NETWORK_WIDGET_ID, POD_WIDGET_ID, SINGLE_NAMESPACE_DIALOG_ID, YAML_DIALOG_ID,
+            CONFIG_WIDGET_ID, CONTEXT_DIALOG_ID, COPY_DIALOG_ID, HELP_DIALOG_ID, LIST_WIDGET_ID,
+            MULTIPLE_NAMESPACES_DIALOG_ID, NETWORK_WIDGET_ID, POD_WIDGET_ID,
+            SINGLE_NAMESPACE_DIALOG_ID, WORKLOAD_WIDGET_ID, YAML_DIALOG_ID,
         },
         config::view::ConfigTab,
         context::{message::ContextRequest, view::ContextDialog},
         event::view::EventTab,
         get::{
-            message::{GetRequest, GetYamlKind},
-            view::YamlDialog,
+            message::GetRequest,
+            view::{copy_items, CopyDialog, YamlDialog},
         },
+        helm::view::HelmTab,
         help::HelpDialog,
         namespace::{
             message::NamespaceRequest,
             view::{MultipleNamespacesDialog, SingleNamespaceDialog},
         },
-        network::{
-            message::{GatewayVersion, HTTPRouteVersion},
-            view::NetworkTab,
-        },
+        network::view::NetworkTab,
         pod::view::PodTab,
-        yaml::view::YamlTab,
-    },
-    kube::{
-        apis::networking::gateway::v1::{Gateway, HTTPRoute},
-        context::{Context, Namespace},
+        rbac::view::RbacTab,
+        workload::view::WorkloadTab,
+        yaml::{message::YamlRequest, view::YamlTab},
     },
+    kube::context::{Context, Namespace},
     logger,
     message::{Message, UserEvent},
     ui::{
         dialog::Dialog,
         event::{CallbackFn, EventResult},
-        widget::{SelectedItem, WidgetTrait},
+        widget::{Item, SelectedItem, Widget, WidgetTrait},
         Header, Tab, Window, WindowAction,
     },
 };
@@ -61,6 +55,9 @@ pub struct WindowInit {
     tx: Sender<Message>,
     context: Rc<RefCell<Context>>,
     namespaces: Rc<RefCell<Namespace>>,
+    clipboard: Option<Rc<RefCell<Clipboard>>>,
+    saved_views: Vec<SavedView>,
+    read_only: bool,
 }
 
 impl WindowInit {
@@ -69,12 +66,18 @@ impl WindowInit {
         tx: Sender<Message>,
         context: Rc<RefCell<Context>>,
         namespaces: Rc<RefCell<Namespace>>,
+        clipboard: Option<Rc<RefCell<Clipboard>>>,
+        saved_views: Vec<SavedView>,
+        read_only: bool,
     ) -> Self {
         Self {
             split_mode,
             tx,
             context,
             namespaces,
+            clipboard,
+            saved_views,
+            read_only,
         }
     }
 
@@ -129,6 +132,10 @@ impl WindowInit {
 
         let builder = builder.action('h', open_help).action('?', open_help);
         let builder = builder.action('y', open_yaml);
+        let builder = builder.action(
+            KeyEvent::new(KeyCode::Char('Y'), KeyModifiers::SHIFT),
+            open_copy_dialog,
+        );
 
         //　分割方向を変更する
         let toggle_split_direction = move |w: &mut Window| {
@@ -146,6 +153,18 @@ impl WindowInit {
 
         let builder = builder.action('q', fn_close).action(KeyCode::Esc, fn_close);
 
+        // 保存したビューをAlt+数字キーで切り替える
+        let builder = self.saved_views.iter().fold(builder, |builder, view| {
+            let Some(key) = view.key_char() else {
+                return builder;
+            };
+
+            builder.action(
+                KeyEvent::new(KeyCode::Char(key), KeyModifiers::ALT),
+                switch_view(self.tx.clone(), view.resources.clone()),
+            )
+        });
+
         let context = self.context.clone();
         let namespaces = self.namespaces.clone();
 
@@ -164,7 +183,7 @@ impl WindowInit {
     }
 
     fn tabs_dialogs(&self) -> (Vec<Tab<'static>>, Vec<Dialog<'static>>) {
-        let clipboard = Some(Rc::new(RefCell::new(Clipboard::new())));
+        let clipboard = self.clipboard.clone();
 
         let PodTab {
             tab: pod_tab,
@@ -177,26 +196,65 @@ impl WindowInit {
             self.namespaces.clone(),
         );
 
-        let ConfigTab { tab: config_tab } =
-            ConfigTab::new("Config", &self.tx, &clipboard, self.split_mode);
+        let ConfigTab {
+            tab: config_tab,
+            secret_reveal_dialog: config_secret_reveal_dialog,
+        } = ConfigTab::new("Config", &self.tx, &clipboard, self.split_mode);
 
-        let NetworkTab { tab: network_tab } =
-            NetworkTab::new("Network", &self.tx, &clipboard, self.split_mode);
+        let NetworkTab {
+            tab: network_tab,
+            simulation_query_dialog: network_simulation_query_dialog,
+            simulation_dialog: network_simulation_dialog,
+        } = NetworkTab::new("Network", &self.tx, &clipboard, self.split_mode);
 
         let EventTab { tab: event_tab } = EventTab::new("Event", &clipboard);
 
         let ListTab {
             tab: list_tab,
             dialog: list_dialog,
-        } = ListTab::new("List", &self.tx, &clipboard);
+            view_dialog: list_view_dialog,
+            save_view_dialog: list_save_view_dialog,
+            save_view_result_dialog: list_save_view_result_dialog,
+            conditions_dialog: list_conditions_dialog,
+            owner_tree_dialog: list_owner_tree_dialog,
+        } = ListTab::new(
+            "List",
+            &self.tx,
+            &clipboard,
+            self.split_mode,
+            &self.saved_views,
+        );
 
         let YamlTab {
             tab: yaml_tab,
             kind_dialog: yaml_kind_dialog,
             name_dialog: yaml_name_dialog,
             not_found_dialog: yaml_not_found_dialog,
+            compare_context_dialog: yaml_compare_context_dialog,
+            compare_namespace_dialog: yaml_compare_namespace_dialog,
+            compare_dialog: yaml_compare_dialog,
         } = YamlTab::new("Yaml", &self.tx, &clipboard);
 
+        let HelmTab {
+            tab: helm_tab,
+            diff_dialog: helm_diff_dialog,
+        } = HelmTab::new("Helm", &self.tx, &clipboard, self.split_mode);
+
+        let WorkloadTab {
+            tab: workload_tab,
+            diff_dialog: workload_diff_dialog,
+            trigger_dialog: workload_trigger_dialog,
+            on_close_trigger_dialog: on_close_workload_trigger_dialog,
+        } = WorkloadTab::new(
+            "Workloads",
+            &self.tx,
+            &clipboard,
+            self.split_mode,
+            self.read_only,
+        );
+
+        let RbacTab { tab: rbac_tab } = RbacTab::new("RBAC", &self.tx, self.split_mode);
+
         let ContextDialog {
             widget: context_dialog,
         } = ContextDialog::new(&self.tx);
@@ -215,7 +273,13 @@ impl WindowInit {
 
         let YamlDialog {
             widget: yaml_dialog,
-        } = YamlDialog::new(&clipboard);
+        } = YamlDialog::new(&self.tx, &clipboard);
+
+        let CopyDialog {
+            widget: copy_dialog,
+        } = CopyDialog::new(&self.tx, &clipboard);
+
+        let tx = self.tx.clone();
 
         // Init Window
         let tabs = vec![
@@ -225,6 +289,9 @@ impl WindowInit {
             event_tab,
             list_tab,
             yaml_tab,
+            helm_tab,
+            workload_tab,
+            rbac_tab,
         ];
 
         let dialogs = vec![
@@ -232,12 +299,30 @@ impl WindowInit {
             Dialog::new(single_namespace_dialog),
             Dialog::new(multiple_namespaces_dialog),
             Dialog::new(list_dialog),
+            Dialog::new(list_view_dialog),
+            Dialog::new(list_save_view_dialog).fixed_height(5),
+            Dialog::new(list_save_view_result_dialog),
+            Dialog::new(list_conditions_dialog),
+            Dialog::new(list_owner_tree_dialog),
             Dialog::new(yaml_kind_dialog),
             Dialog::new(yaml_name_dialog),
             Dialog::new(yaml_not_found_dialog),
+            Dialog::new(yaml_compare_context_dialog),
+            Dialog::new(yaml_compare_namespace_dialog),
+            Dialog::new(yaml_compare_dialog).on_close(move || {
+                tx.send(YamlRequest::CompareClose.into())
+                    .expect("Failed to send YamlRequest::CompareClose");
+            }),
             Dialog::new(help_dialog),
             Dialog::new(log_query_help_dialog),
             Dialog::new(yaml_dialog),
+            Dialog::new(copy_dialog),
+            Dialog::new(network_simulation_query_dialog).fixed_height(5),
+            Dialog::new(network_simulation_dialog),
+            Dialog::new(config_secret_reveal_dialog),
+            Dialog::new(helm_diff_dialog),
+            Dialog::new(workload_diff_dialog),
+            Dialog::new(workload_trigger_dialog).on_close(on_close_workload_trigger_dialog),
         ];
 
         (tabs, dialogs)
@@ -249,65 +334,57 @@ fn open_yaml(tx: Sender<Message>) -> impl CallbackFn {
         let widget = w.active_tab().active_widget();
 
         match widget.id() {
-            POD_WIDGET_ID | CONFIG_WIDGET_ID | NETWORK_WIDGET_ID => {}
+            POD_WIDGET_ID | CONFIG_WIDGET_ID | NETWORK_WIDGET_ID | LIST_WIDGET_ID
+            | WORKLOAD_WIDGET_ID => {}
             _ => {
                 return EventResult::Ignore;
             }
         }
 
-        let Some(SelectedItem::TableRow { metadata, .. }) = widget.widget_item() else {
+        let Some(req) = selected_table_row_metadata(w)
+            .as_ref()
+            .and_then(GetRequest::from_metadata)
+        else {
             return EventResult::Ignore;
         };
 
-        let Some(ref metadata) = metadata else {
-            return EventResult::Ignore;
-        };
+        tx.send(req.into())
+            .expect("Failed to send YamlMessage::Request");
 
-        let Some(ref namespace) = metadata.get("namespace") else {
-            return EventResult::Ignore;
-        };
+        w.widget_clear(YAML_DIALOG_ID);
+        w.open_dialog(YAML_DIALOG_ID);
 
-        let Some(ref name) = metadata.get("name") else {
-            return EventResult::Ignore;
-        };
+        EventResult::Nop
+    }
+}
 
-        let version = metadata.get("version");
-
-        let kind = match metadata.get("kind").map(|v| v.as_str()) {
-            Some(Pod::KIND) => GetYamlKind::Pod,
-            Some(ConfigMap::KIND) => GetYamlKind::ConfigMap,
-            Some(Secret::KIND) => GetYamlKind::Secret,
-            Some(Ingress::KIND) => GetYamlKind::Ingress,
-            Some(Service::KIND) => GetYamlKind::Service,
-            Some(NetworkPolicy::KIND) => GetYamlKind::NetworkPolicy,
-            Some(Gateway::KIND) => match version.as_ref().map(|v| v.as_str()) {
-                Some("v1") => GetYamlKind::Gateway(GatewayVersion::V1),
-                Some("v1beta1") => GetYamlKind::Gateway(GatewayVersion::V1Beta1),
-                _ => unreachable!(),
-            },
-            Some(HTTPRoute::KIND) => match version.as_ref().map(|v| v.as_str()) {
-                Some("v1") => GetYamlKind::HTTPRoute(HTTPRouteVersion::V1),
-                Some("v1beta1") => GetYamlKind::HTTPRoute(HTTPRouteVersion::V1Beta1),
-                _ => unreachable!(),
-            },
-            _ => {
-                unreachable!();
-            }
-        };
+/// アクティブなウィジェットがテーブルのとき、選択している行のmetadataを返す
+fn selected_table_row_metadata(w: &Window) -> Option<BTreeMap<String, String>> {
+    let Widget::Table(table) = w.active_tab().active_widget() else {
+        return None;
+    };
 
-        tx.send(
-            GetRequest {
-                name: name.to_string(),
-                namespace: namespace.to_string(),
-                kind,
-            }
-            .into(),
-        )
-        .expect("Failed to send YamlMessage::Request");
+    let Some(SelectedItem::TableRow { metadata, .. }) = table.widget_item() else {
+        return None;
+    };
 
-        w.widget_clear(YAML_DIALOG_ID);
-        w.open_dialog(YAML_DIALOG_ID);
+    metadata
+}
 
-        EventResult::Nop
+fn open_copy_dialog(w: &mut Window) -> EventResult {
+    let Some(metadata) = selected_table_row_metadata(w) else {
+        return EventResult::Ignore;
+    };
+
+    let items = copy_items(&metadata);
+
+    if items.is_empty() {
+        return EventResult::Ignore;
     }
+
+    w.find_widget_mut(COPY_DIALOG_ID)
+        .update_widget_item(Item::Array(items));
+    w.open_dialog(COPY_DIALOG_ID);
+
+    EventResult::Nop
 }